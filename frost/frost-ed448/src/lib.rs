@@ -207,6 +207,10 @@ pub mod keys {
 	use super::*;
 	use sp_std::collections::btree_map::BTreeMap;
 
+	pub mod dkg;
+	pub mod refresh;
+	pub mod repairable;
+
 	/// The identifier list to use when generating key shares.
 	pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, E>;
 
@@ -0,0 +1,74 @@
+use frost_ed448::Ed448Shake256;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use tg_frost_core as frost_core;
+
+fn rng() -> ChaCha20Rng {
+	ChaCha20Rng::seed_from_u64(0)
+}
+
+#[test]
+fn check_refresh_shares_with_dealer() {
+	frost_core::tests::refresh::check_refresh_shares_with_dealer::<Ed448Shake256, _>(rng());
+}
+
+#[test]
+fn check_refresh_shares_with_dealer_removes_participant() {
+	frost_core::tests::refresh::check_refresh_shares_with_dealer_removes_participant::<
+		Ed448Shake256,
+		_,
+	>(rng());
+}
+
+#[test]
+fn check_refresh_shares_with_dealer_fails_with_invalid_input() {
+	frost_core::tests::refresh::check_refresh_shares_with_dealer_fails_with_invalid_input::<
+		Ed448Shake256,
+		_,
+	>(rng());
+}
+
+#[test]
+fn check_repair_share() {
+	frost_core::tests::repairable::check_repair_share::<Ed448Shake256, _>(rng());
+}
+
+#[test]
+fn check_repair_share_step_1_fails_with_invalid_helpers() {
+	frost_core::tests::repairable::check_repair_share_step_1_fails_with_invalid_helpers::<
+		Ed448Shake256,
+		_,
+	>(rng());
+}
+
+#[test]
+fn check_repair_share_step_3_fails_with_invalid_sigmas() {
+	frost_core::tests::repairable::check_repair_share_step_3_fails_with_invalid_sigmas::<
+		Ed448Shake256,
+		_,
+	>(rng());
+}
+
+#[test]
+fn check_dkg_part1_part2_part3() {
+	frost_core::tests::dkg::check_dkg_part1_part2_part3::<Ed448Shake256, _>(rng());
+}
+
+#[test]
+fn check_dkg_part2_fails_with_invalid_proof_of_knowledge() {
+	frost_core::tests::dkg::check_dkg_part2_fails_with_invalid_proof_of_knowledge::<Ed448Shake256, _>(
+		rng(),
+	);
+}
+
+#[test]
+fn check_dkg_part3_fails_with_invalid_secret_share() {
+	frost_core::tests::dkg::check_dkg_part3_fails_with_invalid_secret_share::<Ed448Shake256, _>(
+		rng(),
+	);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_dkg_packages_serialization() {
+	frost_core::tests::dkg::check_dkg_packages_serialization::<Ed448Shake256, _>(rng());
+}
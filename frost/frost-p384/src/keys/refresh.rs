@@ -0,0 +1,39 @@
+//! Refresh Shares
+//!
+//! Implements share refreshing, which rotates the participants' signing shares
+//! while keeping the group verifying key unchanged.
+
+use sp_std::vec::Vec;
+
+use super::*;
+
+/// Generates the refreshing shares of zero for the given `identifiers`, and
+/// the refreshed [`PublicKeyPackage`].
+///
+/// `min_signers` must be the threshold the group was created with. Participants
+/// missing from `identifiers` are removed from the refreshed [`PublicKeyPackage`].
+pub fn compute_refreshing_shares<R: RngCore + CryptoRng>(
+	pub_key_package: PublicKeyPackage,
+	max_signers: u16,
+	min_signers: u16,
+	identifiers: &[Identifier],
+	rng: &mut R,
+) -> Result<(Vec<SecretShare>, PublicKeyPackage), Error> {
+	frost::keys::refresh::compute_refreshing_shares(
+		pub_key_package,
+		max_signers,
+		min_signers,
+		identifiers,
+		rng,
+	)
+}
+
+/// Refreshes the `current_key_package` of a participant with the
+/// `refreshing_share` they received from the dealer, returning the refreshed
+/// [`KeyPackage`].
+pub fn refresh_share(
+	refreshing_share: SecretShare,
+	current_key_package: &KeyPackage,
+) -> Result<KeyPackage, Error> {
+	frost::keys::refresh::refresh_share(refreshing_share, current_key_package)
+}
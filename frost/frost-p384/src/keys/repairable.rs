@@ -0,0 +1,43 @@
+//! Repairable Threshold Scheme
+//!
+//! Implements the Repairable Threshold Scheme (RTS) from <https://eprint.iacr.org/2017/1155>.
+//! The RTS is used to help a signer (participant) repair their lost share. This is achieved
+//! using a subset of the other signers known here as `helpers`.
+
+use super::*;
+
+/// Step 1 of RTS.
+///
+/// Generates the "delta" values from the helper with `key_package_i` to send to
+/// the helpers (including itself), in order to help `participant` recover their
+/// share.
+///
+/// Returns a map from each helper identifier to the delta to send to them.
+pub fn repair_share_step_1<R: RngCore + CryptoRng>(
+	helpers: &[Identifier],
+	key_package_i: &KeyPackage,
+	rng: &mut R,
+	participant: Identifier,
+) -> Result<BTreeMap<Identifier, Scalar>, Error> {
+	frost::keys::repairable::repair_share_step_1(helpers, key_package_i, rng, participant)
+}
+
+/// Step 2 of RTS.
+///
+/// Generates the "sigma" value from all the `deltas_j` received by the helper
+/// from all the helpers (including itself), to send to the participant.
+pub fn repair_share_step_2(deltas_j: &[Scalar]) -> Scalar {
+	frost::keys::repairable::repair_share_step_2::<P>(deltas_j)
+}
+
+/// Step 3 of RTS.
+///
+/// The participant with `identifier` sums the `sigmas` received from all the
+/// helpers to recover their [`KeyPackage`].
+pub fn repair_share_step_3(
+	sigmas: &[Scalar],
+	identifier: Identifier,
+	public_key_package: &PublicKeyPackage,
+) -> Result<KeyPackage, Error> {
+	frost::keys::repairable::repair_share_step_3(sigmas, identifier, public_key_package)
+}
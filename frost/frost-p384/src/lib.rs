@@ -237,6 +237,10 @@ pub mod keys {
 
 	use super::*;
 
+	pub mod dkg;
+	pub mod refresh;
+	pub mod repairable;
+
 	/// The identifier list to use when generating key shares.
 	pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, P>;
 
@@ -0,0 +1,71 @@
+use frost_p384::P384Sha384;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use tg_frost_core as frost_core;
+
+fn rng() -> ChaCha20Rng {
+	ChaCha20Rng::seed_from_u64(0)
+}
+
+#[test]
+fn check_refresh_shares_with_dealer() {
+	frost_core::tests::refresh::check_refresh_shares_with_dealer::<P384Sha384, _>(rng());
+}
+
+#[test]
+fn check_refresh_shares_with_dealer_removes_participant() {
+	frost_core::tests::refresh::check_refresh_shares_with_dealer_removes_participant::<P384Sha384, _>(
+		rng(),
+	);
+}
+
+#[test]
+fn check_refresh_shares_with_dealer_fails_with_invalid_input() {
+	frost_core::tests::refresh::check_refresh_shares_with_dealer_fails_with_invalid_input::<
+		P384Sha384,
+		_,
+	>(rng());
+}
+
+#[test]
+fn check_repair_share() {
+	frost_core::tests::repairable::check_repair_share::<P384Sha384, _>(rng());
+}
+
+#[test]
+fn check_repair_share_step_1_fails_with_invalid_helpers() {
+	frost_core::tests::repairable::check_repair_share_step_1_fails_with_invalid_helpers::<
+		P384Sha384,
+		_,
+	>(rng());
+}
+
+#[test]
+fn check_repair_share_step_3_fails_with_invalid_sigmas() {
+	frost_core::tests::repairable::check_repair_share_step_3_fails_with_invalid_sigmas::<
+		P384Sha384,
+		_,
+	>(rng());
+}
+
+#[test]
+fn check_dkg_part1_part2_part3() {
+	frost_core::tests::dkg::check_dkg_part1_part2_part3::<P384Sha384, _>(rng());
+}
+
+#[test]
+fn check_dkg_part2_fails_with_invalid_proof_of_knowledge() {
+	frost_core::tests::dkg::check_dkg_part2_fails_with_invalid_proof_of_knowledge::<P384Sha384, _>(
+		rng(),
+	);
+}
+
+#[test]
+fn check_dkg_part3_fails_with_invalid_secret_share() {
+	frost_core::tests::dkg::check_dkg_part3_fails_with_invalid_secret_share::<P384Sha384, _>(rng());
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_dkg_packages_serialization() {
+	frost_core::tests::dkg::check_dkg_packages_serialization::<P384Sha384, _>(rng());
+}
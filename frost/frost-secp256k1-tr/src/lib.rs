@@ -470,6 +470,10 @@ pub type Identifier = frost::Identifier<S>;
 pub mod keys {
 	use super::*;
 
+	pub mod dkg;
+	pub mod refresh;
+	pub mod repairable;
+
 	/// The identifier list to use when generating key shares.
 	pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, S>;
 
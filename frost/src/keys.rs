@@ -28,6 +28,10 @@ use crate::serialization::{Deserialize, Serialize};
 
 use super::compute_lagrange_coefficient;
 
+pub mod dkg;
+pub mod refresh;
+pub mod repairable;
+
 /// Sum the commitments from all participants in a distributed key generation
 /// run into a single group commitment.
 #[cfg_attr(feature = "internals", visibility::make(pub))]
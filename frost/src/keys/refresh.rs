@@ -0,0 +1,135 @@
+//! Refresh Shares
+//!
+//! Implements share refreshing, which rotates the participants' signing shares
+//! while keeping the group [`VerifyingKey`](crate::VerifyingKey) unchanged.
+//! Shares that leaked before a refresh can not be combined with shares issued
+//! after it.
+//!
+//! A trusted dealer calls [`compute_refreshing_shares`] to generate shares of
+//! zero and the refreshed [`PublicKeyPackage`], and sends each participant their
+//! refreshing [`SecretShare`]. Each participant then calls [`refresh_share`] to
+//! combine it with their current [`KeyPackage`].
+//!
+//! Participants that are not given a refreshing share are removed from the
+//! group, since their old share can not be combined with the refreshed ones.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{Ciphersuite, Error, Field, Group, Identifier, SigningKey};
+
+use super::{
+	CoefficientCommitment, KeyPackage, PublicKeyPackage, SecretShare, SigningShare,
+	VerifiableSecretSharingCommitment, VerifyingShare, generate_coefficients,
+	generate_secret_shares, validate_num_of_signers,
+};
+
+/// Generates the refreshing shares of zero for the given `identifiers`, and
+/// the refreshed [`PublicKeyPackage`].
+///
+/// `min_signers` must be the threshold the group was created with. The
+/// `identifiers` must all be part of `pub_key_package`; participants missing
+/// from `identifiers` are removed from the refreshed [`PublicKeyPackage`].
+///
+/// The refreshing shares do not include the commitment to the constant term,
+/// which is always the identity, so they can be serialized. It is restored by
+/// [`refresh_share`].
+pub fn compute_refreshing_shares<C: Ciphersuite, R: RngCore + CryptoRng>(
+	pub_key_package: PublicKeyPackage<C>,
+	max_signers: u16,
+	min_signers: u16,
+	identifiers: &[Identifier<C>],
+	rng: &mut R,
+) -> Result<(Vec<SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+	if identifiers.len() != max_signers as usize {
+		return Err(Error::IncorrectNumberOfIdentifiers);
+	}
+	validate_num_of_signers(min_signers, max_signers)?;
+
+	// Shares of a polynomial with a zero constant term, so adding them to the
+	// current shares does not change the group secret.
+	let refreshing_key = SigningKey { scalar: <<C::Group as Group>::Field>::zero() };
+
+	let coefficients = generate_coefficients::<C, R>(min_signers as usize - 1, rng);
+	let refreshing_shares = generate_secret_shares(
+		&refreshing_key,
+		max_signers,
+		min_signers,
+		coefficients,
+		identifiers,
+	)?;
+
+	let mut verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>> = BTreeMap::new();
+	let mut refreshing_shares_minus_identity: Vec<SecretShare<C>> =
+		Vec::with_capacity(refreshing_shares.len());
+
+	for mut share in refreshing_shares {
+		let verifying_share = pub_key_package
+			.verifying_shares
+			.get(&share.identifier)
+			.ok_or(Error::UnknownIdentifier)?;
+		let refreshing_verifying_share: VerifyingShare<C> = share.signing_share.into();
+		verifying_shares.insert(
+			share.identifier,
+			VerifyingShare::new(
+				verifying_share.to_element() + refreshing_verifying_share.to_element(),
+			),
+		);
+
+		share.commitment.0.remove(0);
+		refreshing_shares_minus_identity.push(share);
+	}
+
+	Ok((
+		refreshing_shares_minus_identity,
+		PublicKeyPackage {
+			header: pub_key_package.header,
+			verifying_shares,
+			verifying_key: pub_key_package.verifying_key,
+		},
+	))
+}
+
+/// Refreshes the `current_key_package` of a participant with the
+/// `refreshing_share` they received from the dealer, returning the refreshed
+/// [`KeyPackage`].
+///
+/// The refreshing share is verified against its commitment before it is used.
+/// The participant must replace their [`KeyPackage`] and [`PublicKeyPackage`]
+/// with the refreshed ones, and delete the old ones.
+pub fn refresh_share<C: Ciphersuite>(
+	mut refreshing_share: SecretShare<C>,
+	current_key_package: &KeyPackage<C>,
+) -> Result<KeyPackage<C>, Error<C>> {
+	if refreshing_share.identifier != current_key_package.identifier {
+		return Err(Error::UnknownIdentifier);
+	}
+	// The refreshing polynomial must have the same degree as the current one,
+	// otherwise the threshold of the group would change.
+	if refreshing_share.commitment.0.len() + 1 != current_key_package.min_signers as usize {
+		return Err(Error::IncorrectNumberOfCommitments);
+	}
+
+	// Restore the identity commitment to the constant term, which is removed
+	// by `compute_refreshing_shares`.
+	refreshing_share.commitment = VerifiableSecretSharingCommitment::new(
+		core::iter::once(CoefficientCommitment::new(<C::Group>::identity()))
+			.chain(refreshing_share.commitment.0)
+			.collect(),
+	);
+	refreshing_share.verify()?;
+
+	let signing_share = SigningShare::new(
+		current_key_package.signing_share.to_scalar() + refreshing_share.signing_share.to_scalar(),
+	);
+
+	Ok(KeyPackage {
+		header: current_key_package.header,
+		identifier: current_key_package.identifier,
+		signing_share,
+		verifying_share: signing_share.into(),
+		verifying_key: current_key_package.verifying_key,
+		min_signers: current_key_package.min_signers,
+	})
+}
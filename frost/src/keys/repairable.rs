@@ -0,0 +1,127 @@
+//! Repairable Threshold Scheme
+//!
+//! Implements the Repairable Threshold Scheme (RTS) from <https://eprint.iacr.org/2017/1155>.
+//! The RTS is used to help a signer (participant) repair their lost share. This is achieved
+//! using a subset of the other signers known here as `helpers`.
+//!
+//! Each helper calls [`repair_share_step_1`] and sends one delta to every other
+//! helper, over a confidential and authenticated channel. Each helper then calls
+//! [`repair_share_step_2`] with the deltas they received (including their own)
+//! and sends the resulting sigma to the participant, who calls
+//! [`repair_share_step_3`] with all the sigmas to recover their [`KeyPackage`].
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+	Ciphersuite, Error, Field, Group, Header, Identifier, Scalar, compute_lagrange_coefficient,
+};
+
+use super::{KeyPackage, PublicKeyPackage, SigningShare, VerifyingShare, generate_coefficients};
+
+/// Step 1 of RTS.
+///
+/// Generates the "delta" values from the helper with `key_package_i` to send to
+/// the helpers (including itself), in order to help `participant` recover their
+/// share.
+///
+/// `helpers` must contain exactly `min_signers` distinct identifiers, including
+/// the one of `key_package_i` and excluding `participant`.
+///
+/// Returns a map from each helper identifier to the delta to send to them.
+pub fn repair_share_step_1<C: Ciphersuite, R: RngCore + CryptoRng>(
+	helpers: &[Identifier<C>],
+	key_package_i: &KeyPackage<C>,
+	rng: &mut R,
+	participant: Identifier<C>,
+) -> Result<BTreeMap<Identifier<C>, Scalar<C>>, Error<C>> {
+	if helpers.len() != key_package_i.min_signers as usize {
+		return Err(Error::IncorrectNumberOfIdentifiers);
+	}
+	let helpers_set: BTreeSet<_> = helpers.iter().copied().collect();
+	if helpers_set.len() != helpers.len() {
+		return Err(Error::DuplicatedIdentifier);
+	}
+	if !helpers_set.contains(&key_package_i.identifier) || helpers_set.contains(&participant) {
+		return Err(Error::UnknownIdentifier);
+	}
+
+	let random_values = generate_coefficients::<C, R>(helpers.len() - 1, rng);
+
+	compute_last_random_value(&helpers_set, key_package_i, &random_values, participant)
+}
+
+/// Compute the last delta value given the (generated uniformly at random)
+/// remaining ones, so that all deltas sum up to the helper's Lagrange-weighted
+/// share.
+fn compute_last_random_value<C: Ciphersuite>(
+	helpers: &BTreeSet<Identifier<C>>,
+	key_package_i: &KeyPackage<C>,
+	random_values: &[Scalar<C>],
+	participant: Identifier<C>,
+) -> Result<BTreeMap<Identifier<C>, Scalar<C>>, Error<C>> {
+	// Calculate the Lagrange coefficient of the helper at the participant's identifier
+	let zeta_i =
+		compute_lagrange_coefficient(helpers, Some(participant), key_package_i.identifier)?;
+
+	let lhs = zeta_i * key_package_i.signing_share.to_scalar();
+
+	let mut out: BTreeMap<Identifier<C>, Scalar<C>> =
+		helpers.iter().copied().zip(random_values.iter().copied()).collect();
+
+	let sum_i_deltas = random_values
+		.iter()
+		.fold(<<C::Group as Group>::Field>::zero(), |sum, delta| sum + *delta);
+
+	out.insert(*helpers.last().ok_or(Error::IncorrectNumberOfIdentifiers)?, lhs - sum_i_deltas);
+
+	Ok(out)
+}
+
+/// Step 2 of RTS.
+///
+/// Generates the "sigma" value from all the `deltas_j` received by the helper
+/// from all the helpers (including itself), to send to the participant.
+pub fn repair_share_step_2<C: Ciphersuite>(deltas_j: &[Scalar<C>]) -> Scalar<C> {
+	deltas_j
+		.iter()
+		.fold(<<C::Group as Group>::Field>::zero(), |sum, delta| sum + *delta)
+}
+
+/// Step 3 of RTS.
+///
+/// The participant with `identifier` sums the `sigmas` received from all the
+/// helpers to recover their [`KeyPackage`]. The recovered signing share is
+/// checked against the verifying share of the participant in
+/// `public_key_package`.
+pub fn repair_share_step_3<C: Ciphersuite>(
+	sigmas: &[Scalar<C>],
+	identifier: Identifier<C>,
+	public_key_package: &PublicKeyPackage<C>,
+) -> Result<KeyPackage<C>, Error<C>> {
+	let signing_share = SigningShare::new(
+		sigmas
+			.iter()
+			.fold(<<C::Group as Group>::Field>::zero(), |sum, sigma| sum + *sigma),
+	);
+	let verifying_share: VerifyingShare<C> = signing_share.into();
+
+	if public_key_package
+		.verifying_shares
+		.get(&identifier)
+		.ok_or(Error::UnknownIdentifier)? !=
+		&verifying_share
+	{
+		return Err(Error::InvalidSecretShare { culprit: None });
+	}
+
+	Ok(KeyPackage {
+		header: Header::default(),
+		identifier,
+		signing_share,
+		verifying_share,
+		verifying_key: public_key_package.verifying_key,
+		min_signers: sigmas.len() as u16,
+	})
+}
@@ -34,6 +34,8 @@ mod scalar_mul;
 pub mod serialization;
 mod signature;
 mod signing_key;
+#[cfg(any(test, feature = "test-impl"))]
+pub mod tests;
 
 mod traits;
 mod verifying_key;
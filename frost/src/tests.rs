@@ -0,0 +1,46 @@
+//! Ciphersuite-generic test functions.
+#![allow(clippy::unwrap_used)]
+
+use alloc::collections::BTreeMap;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+	Ciphersuite, Identifier, SigningPackage, aggregate,
+	keys::{KeyPackage, PublicKeyPackage},
+	round1, round2,
+};
+
+pub mod dkg;
+pub mod refresh;
+pub mod repairable;
+
+/// Sign a message with the given `key_packages` and check the aggregated
+/// signature against the verifying key of `pubkeys`.
+fn check_sign<C: Ciphersuite, R: RngCore + CryptoRng>(
+	key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+	pubkeys: &PublicKeyPackage<C>,
+	rng: &mut R,
+) {
+	let message = b"message to sign";
+
+	let mut nonces = BTreeMap::new();
+	let mut commitments = BTreeMap::new();
+	for (identifier, key_package) in key_packages {
+		let (signing_nonces, signing_commitments) =
+			round1::commit(key_package.signing_share(), rng);
+		nonces.insert(*identifier, signing_nonces);
+		commitments.insert(*identifier, signing_commitments);
+	}
+
+	let signing_package = SigningPackage::new(commitments, message);
+	let signature_shares: BTreeMap<_, _> = key_packages
+		.iter()
+		.map(|(identifier, key_package)| {
+			(*identifier, round2::sign(&signing_package, &nonces[identifier], key_package).unwrap())
+		})
+		.collect();
+
+	let signature = aggregate(&signing_package, &signature_shares, pubkeys).unwrap();
+	pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
@@ -0,0 +1,182 @@
+//! Test for Distributed Key Generation
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+	Ciphersuite, Error, Identifier,
+	keys::{
+		KeyPackage, PublicKeyPackage,
+		dkg::{part1, part2, part3, round1, round2},
+	},
+};
+
+use super::check_sign;
+
+const MAX_SIGNERS: u16 = 5;
+const MIN_SIGNERS: u16 = 3;
+
+type Round1Output<C> = (
+	BTreeMap<Identifier<C>, round1::SecretPackage<C>>,
+	BTreeMap<Identifier<C>, round1::Package<C>>,
+);
+type Round2Output<C> = (
+	BTreeMap<Identifier<C>, round2::SecretPackage<C>>,
+	BTreeMap<Identifier<C>, BTreeMap<Identifier<C>, round2::Package<C>>>,
+);
+
+/// Runs part 1 of the DKG for every participant.
+fn run_part1<C: Ciphersuite, R: RngCore + CryptoRng>(rng: &mut R) -> Round1Output<C> {
+	let mut secret_packages = BTreeMap::new();
+	let mut packages = BTreeMap::new();
+	for i in 1..=MAX_SIGNERS {
+		let identifier = i.try_into().unwrap();
+		let (secret_package, package) =
+			part1(identifier, MAX_SIGNERS, MIN_SIGNERS, &mut *rng).unwrap();
+		secret_packages.insert(identifier, secret_package);
+		packages.insert(identifier, package);
+	}
+	(secret_packages, packages)
+}
+
+/// Returns the packages broadcast to `receiver`, i.e. those of every other
+/// participant.
+fn received<C: Ciphersuite, P: Clone>(
+	packages: &BTreeMap<Identifier<C>, P>,
+	receiver: Identifier<C>,
+) -> BTreeMap<Identifier<C>, P> {
+	packages
+		.iter()
+		.filter(|(identifier, _)| **identifier != receiver)
+		.map(|(identifier, package)| (*identifier, package.clone()))
+		.collect()
+}
+
+/// Runs part 2 of the DKG for every participant, returning the round 2
+/// packages indexed by receiver and then by sender.
+fn run_part2<C: Ciphersuite>(
+	secret_packages: BTreeMap<Identifier<C>, round1::SecretPackage<C>>,
+	packages: &BTreeMap<Identifier<C>, round1::Package<C>>,
+) -> Round2Output<C> {
+	let mut round2_secret_packages = BTreeMap::new();
+	let mut round2_packages: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+	for (sender, secret_package) in secret_packages {
+		let (round2_secret_package, sent) =
+			part2(secret_package, &received(packages, sender)).unwrap();
+		round2_secret_packages.insert(sender, round2_secret_package);
+		for (receiver, package) in sent {
+			round2_packages.entry(receiver).or_default().insert(sender, package);
+		}
+	}
+	(round2_secret_packages, round2_packages)
+}
+
+/// Check that a full DKG run produces consistent key packages that can sign.
+pub fn check_dkg_part1_part2_part3<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+	let (secret_packages, packages) = run_part1::<C, R>(&mut rng);
+	let (round2_secret_packages, round2_packages) = run_part2(secret_packages, &packages);
+
+	let mut key_packages = BTreeMap::new();
+	let mut pubkeys: Vec<PublicKeyPackage<C>> = Vec::new();
+	for (identifier, round2_secret_package) in &round2_secret_packages {
+		let (key_package, pubkey_package) = part3(
+			round2_secret_package,
+			&received(&packages, *identifier),
+			&round2_packages[identifier],
+		)
+		.unwrap();
+		assert_eq!(*key_package.min_signers(), MIN_SIGNERS);
+		key_packages.insert(*identifier, key_package);
+		pubkeys.push(pubkey_package);
+	}
+
+	// Every participant derives the same public key package.
+	assert!(pubkeys.windows(2).all(|pair| pair[0] == pair[1]));
+	let pubkeys = &pubkeys[0];
+	for (identifier, key_package) in &key_packages {
+		assert_eq!(key_package.verifying_key(), pubkeys.verifying_key());
+		assert_eq!(key_package.verifying_share(), &pubkeys.verifying_shares()[identifier]);
+	}
+
+	// Any `MIN_SIGNERS` participants can sign.
+	let signers: BTreeMap<Identifier<C>, KeyPackage<C>> =
+		key_packages.into_iter().skip(1).take(MIN_SIGNERS as usize).collect();
+	check_sign(&signers, pubkeys, &mut rng);
+}
+
+/// Check that part 2 of the DKG rejects a round 1 package with an invalid
+/// proof of knowledge, blaming its sender.
+pub fn check_dkg_part2_fails_with_invalid_proof_of_knowledge<
+	C: Ciphersuite,
+	R: RngCore + CryptoRng,
+>(
+	mut rng: R,
+) {
+	let (mut secret_packages, packages) = run_part1::<C, R>(&mut rng);
+	let ids: Vec<_> = packages.keys().copied().collect();
+
+	// The package of `ids[2]` carries the proof of knowledge of `ids[1]`.
+	let mut received = received(&packages, ids[0]);
+	let forged = round1::Package::new(
+		packages[&ids[2]].commitment().clone(),
+		*packages[&ids[1]].proof_of_knowledge(),
+	);
+	received.insert(ids[2], forged);
+
+	let secret_package = secret_packages.remove(&ids[0]).unwrap();
+	assert_eq!(
+		part2(secret_package, &received).unwrap_err(),
+		Error::InvalidProofOfKnowledge { culprit: ids[2] }
+	);
+}
+
+/// Check that part 3 of the DKG rejects a round 2 package with a share that
+/// does not match the commitment of its sender, blaming the sender.
+pub fn check_dkg_part3_fails_with_invalid_secret_share<C: Ciphersuite, R: RngCore + CryptoRng>(
+	mut rng: R,
+) {
+	let (secret_packages, packages) = run_part1::<C, R>(&mut rng);
+	let ids: Vec<_> = packages.keys().copied().collect();
+	let (round2_secret_packages, mut round2_packages) = run_part2(secret_packages, &packages);
+
+	// `ids[2]` sends `ids[0]` the share it received from `ids[1]`.
+	let received_by_0 = round2_packages.get_mut(&ids[0]).unwrap();
+	let forged = received_by_0[&ids[1]].clone();
+	received_by_0.insert(ids[2], forged);
+
+	assert_eq!(
+		part3(
+			&round2_secret_packages[&ids[0]],
+			&received(&packages, ids[0]),
+			&round2_packages[&ids[0]],
+		)
+		.unwrap_err(),
+		Error::InvalidSecretShare { culprit: Some(ids[2]) }
+	);
+}
+
+/// Check that the round 1 and round 2 packages survive a serialization
+/// round-trip.
+#[cfg(feature = "serialization")]
+pub fn check_dkg_packages_serialization<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+	let (secret_packages, packages) = run_part1::<C, R>(&mut rng);
+	for package in packages.values() {
+		let bytes = package.serialize().unwrap();
+		assert_eq!(&round1::Package::deserialize(&bytes).unwrap(), package);
+	}
+	for secret_package in secret_packages.values() {
+		let bytes = secret_package.serialize().unwrap();
+		assert_eq!(&round1::SecretPackage::deserialize(&bytes).unwrap(), secret_package);
+	}
+
+	let (round2_secret_packages, round2_packages) = run_part2(secret_packages, &packages);
+	for package in round2_packages.values().flat_map(BTreeMap::values) {
+		let bytes = package.serialize().unwrap();
+		assert_eq!(&round2::Package::deserialize(&bytes).unwrap(), package);
+	}
+	for secret_package in round2_secret_packages.values() {
+		let bytes = secret_package.serialize().unwrap();
+		assert_eq!(&round2::SecretPackage::deserialize(&bytes).unwrap(), secret_package);
+	}
+}
@@ -0,0 +1,163 @@
+//! Test for Refreshing shares
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+	Ciphersuite, Error, Identifier,
+	keys::{
+		IdentifierList, KeyPackage, PublicKeyPackage, SecretShare, SigningShare,
+		generate_with_dealer, reconstruct,
+		refresh::{compute_refreshing_shares, refresh_share},
+	},
+};
+
+use super::check_sign;
+
+/// Generates shares with a trusted dealer for `max_signers` participants and
+/// `min_signers` threshold, and converts them into key packages.
+fn key_packages_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
+	max_signers: u16,
+	min_signers: u16,
+	rng: &mut R,
+) -> (BTreeMap<Identifier<C>, KeyPackage<C>>, PublicKeyPackage<C>) {
+	let (shares, pubkeys) =
+		generate_with_dealer(max_signers, min_signers, IdentifierList::Default, rng).unwrap();
+	let key_packages = shares
+		.into_iter()
+		.map(|(identifier, share)| (identifier, KeyPackage::try_from(share).unwrap()))
+		.collect();
+	(key_packages, pubkeys)
+}
+
+/// Refreshes the given key packages with the given refreshing shares.
+fn refresh_key_packages<C: Ciphersuite>(
+	refreshing_shares: Vec<SecretShare<C>>,
+	key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+) -> BTreeMap<Identifier<C>, KeyPackage<C>> {
+	refreshing_shares
+		.into_iter()
+		.map(|share| {
+			let identifier = share.identifier;
+			(identifier, refresh_share(share, &key_packages[&identifier]).unwrap())
+		})
+		.collect()
+}
+
+/// Check that refreshing the shares keeps the group key, changes every
+/// signing share and lets the refreshed shares sign.
+pub fn check_refresh_shares_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+	let (max_signers, min_signers) = (5, 3);
+	let (key_packages, pubkeys) =
+		key_packages_with_dealer::<C, R>(max_signers, min_signers, &mut rng);
+	let identifiers: Vec<_> = key_packages.keys().copied().collect();
+
+	let (refreshing_shares, new_pubkeys) = compute_refreshing_shares(
+		pubkeys.clone(),
+		max_signers,
+		min_signers,
+		&identifiers,
+		&mut rng,
+	)
+	.unwrap();
+	let new_key_packages = refresh_key_packages(refreshing_shares, &key_packages);
+
+	assert_eq!(new_pubkeys.verifying_key(), pubkeys.verifying_key());
+	for (identifier, new_key_package) in &new_key_packages {
+		let key_package = &key_packages[identifier];
+		assert_ne!(new_key_package.signing_share(), key_package.signing_share());
+		assert_eq!(new_key_package.verifying_key(), key_package.verifying_key());
+		assert_eq!(&new_pubkeys.verifying_shares()[identifier], new_key_package.verifying_share());
+	}
+
+	// The refreshed shares still share the same secret...
+	let old_secret =
+		reconstruct(&key_packages.values().take(3).cloned().collect::<Vec<_>>()).unwrap();
+	let new_secret =
+		reconstruct(&new_key_packages.values().skip(2).cloned().collect::<Vec<_>>()).unwrap();
+	assert_eq!(new_secret.serialize(), old_secret.serialize());
+
+	// ...which can not be recovered by mixing old and refreshed shares.
+	let mixed: Vec<_> = key_packages
+		.values()
+		.take(2)
+		.chain(new_key_packages.values().skip(2).take(1))
+		.cloned()
+		.collect();
+	assert_ne!(reconstruct(&mixed).unwrap().serialize(), old_secret.serialize());
+
+	let signers: BTreeMap<_, _> = new_key_packages.into_iter().take(3).collect();
+	check_sign(&signers, &new_pubkeys, &mut rng);
+}
+
+/// Check that participants without a refreshing share are removed from the group.
+pub fn check_refresh_shares_with_dealer_removes_participant<
+	C: Ciphersuite,
+	R: RngCore + CryptoRng,
+>(
+	mut rng: R,
+) {
+	let (key_packages, pubkeys) = key_packages_with_dealer::<C, R>(5, 3, &mut rng);
+	let removed = *key_packages.keys().next_back().unwrap();
+	let identifiers: Vec<_> = key_packages.keys().copied().filter(|id| *id != removed).collect();
+
+	let (refreshing_shares, new_pubkeys) =
+		compute_refreshing_shares(pubkeys.clone(), 4, 3, &identifiers, &mut rng).unwrap();
+	let new_key_packages = refresh_key_packages(refreshing_shares, &key_packages);
+
+	assert_eq!(new_pubkeys.verifying_shares().len(), 4);
+	assert!(!new_pubkeys.verifying_shares().contains_key(&removed));
+	assert_eq!(new_pubkeys.verifying_key(), pubkeys.verifying_key());
+
+	let signers: BTreeMap<_, _> = new_key_packages.into_iter().skip(1).collect();
+	check_sign(&signers, &new_pubkeys, &mut rng);
+}
+
+/// Check that refreshing shares are rejected for unknown participants, and
+/// when they do not match their commitment or the threshold of the group.
+pub fn check_refresh_shares_with_dealer_fails_with_invalid_input<
+	C: Ciphersuite,
+	R: RngCore + CryptoRng,
+>(
+	mut rng: R,
+) {
+	let (key_packages, pubkeys) = key_packages_with_dealer::<C, R>(5, 3, &mut rng);
+	let identifiers: Vec<_> = key_packages.keys().copied().collect();
+
+	assert_eq!(
+		compute_refreshing_shares(pubkeys.clone(), 4, 3, &identifiers, &mut rng),
+		Err(Error::IncorrectNumberOfIdentifiers)
+	);
+	let unknown: Vec<_> = (1..=6u16).map(|id| Identifier::try_from(id).unwrap()).collect();
+	assert_eq!(
+		compute_refreshing_shares(pubkeys.clone(), 6, 3, &unknown, &mut rng),
+		Err(Error::UnknownIdentifier)
+	);
+
+	// A share sent to the wrong participant.
+	let (mut refreshing_shares, _) =
+		compute_refreshing_shares(pubkeys.clone(), 5, 3, &identifiers, &mut rng).unwrap();
+	let share = refreshing_shares.remove(0);
+	assert_eq!(
+		refresh_share(share.clone(), &key_packages[&identifiers[1]]),
+		Err(Error::UnknownIdentifier)
+	);
+
+	// A share that does not match its commitment.
+	let mut invalid = share;
+	let doubled = invalid.signing_share.to_scalar() + invalid.signing_share.to_scalar();
+	invalid.signing_share = SigningShare::new(doubled);
+	assert_eq!(
+		refresh_share(invalid, &key_packages[&identifiers[0]]),
+		Err(Error::InvalidSecretShare { culprit: None })
+	);
+
+	// A share that would change the threshold of the group.
+	let (mut refreshing_shares, _) =
+		compute_refreshing_shares(pubkeys, 5, 2, &identifiers, &mut rng).unwrap();
+	assert_eq!(
+		refresh_share(refreshing_shares.remove(0), &key_packages[&identifiers[0]]),
+		Err(Error::IncorrectNumberOfCommitments)
+	);
+}
@@ -0,0 +1,136 @@
+//! Test for Repairable Threshold Scheme
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+	Ciphersuite, Error, Identifier, Scalar,
+	keys::{
+		IdentifierList, KeyPackage, PublicKeyPackage, generate_with_dealer,
+		repairable::{repair_share_step_1, repair_share_step_2, repair_share_step_3},
+	},
+};
+
+use super::check_sign;
+
+/// Runs the first two steps of RTS with the given `helpers`, returning the
+/// sigmas to send to `participant`.
+fn compute_sigmas<C: Ciphersuite, R: RngCore + CryptoRng>(
+	helpers: &[Identifier<C>],
+	key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+	participant: Identifier<C>,
+	rng: &mut R,
+) -> Vec<Scalar<C>> {
+	// Each helper computes a delta for every helper.
+	let deltas: BTreeMap<_, _> = helpers
+		.iter()
+		.map(|helper| {
+			let deltas_i =
+				repair_share_step_1(helpers, &key_packages[helper], rng, participant).unwrap();
+			(*helper, deltas_i)
+		})
+		.collect();
+
+	// Each helper sums the deltas sent to them.
+	helpers
+		.iter()
+		.map(|helper| {
+			let deltas_j: Vec<_> = deltas.values().map(|deltas_i| deltas_i[helper]).collect();
+			repair_share_step_2(&deltas_j)
+		})
+		.collect()
+}
+
+fn key_packages_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
+	rng: &mut R,
+) -> (BTreeMap<Identifier<C>, KeyPackage<C>>, PublicKeyPackage<C>) {
+	let (shares, pubkeys) = generate_with_dealer(5, 3, IdentifierList::Default, rng).unwrap();
+	let key_packages = shares
+		.into_iter()
+		.map(|(identifier, share)| (identifier, KeyPackage::try_from(share).unwrap()))
+		.collect();
+	(key_packages, pubkeys)
+}
+
+/// Check that a participant recovers their lost key package with the help of
+/// `min_signers` other participants, and can sign with it.
+pub fn check_repair_share<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+	let (key_packages, pubkeys) = key_packages_with_dealer::<C, R>(&mut rng);
+	let ids: Vec<_> = key_packages.keys().copied().collect();
+	let participant = ids[4];
+	let helpers = [ids[0], ids[2], ids[3]];
+
+	let sigmas = compute_sigmas(&helpers, &key_packages, participant, &mut rng);
+	let repaired = repair_share_step_3(&sigmas, participant, &pubkeys).unwrap();
+
+	assert_eq!(repaired, key_packages[&participant]);
+
+	let signers: BTreeMap<_, _> = [ids[1], ids[3], participant]
+		.into_iter()
+		.map(|id| {
+			if id == participant { (id, repaired.clone()) } else { (id, key_packages[&id].clone()) }
+		})
+		.collect();
+	check_sign(&signers, &pubkeys, &mut rng);
+}
+
+/// Check that step 1 of RTS rejects invalid sets of helpers.
+pub fn check_repair_share_step_1_fails_with_invalid_helpers<
+	C: Ciphersuite,
+	R: RngCore + CryptoRng,
+>(
+	mut rng: R,
+) {
+	let (key_packages, _) = key_packages_with_dealer::<C, R>(&mut rng);
+	let ids: Vec<_> = key_packages.keys().copied().collect();
+	let helper = &key_packages[&ids[0]];
+	let participant = ids[4];
+
+	// Less than `min_signers` helpers.
+	assert_eq!(
+		repair_share_step_1(&[ids[0], ids[1]], helper, &mut rng, participant),
+		Err(Error::IncorrectNumberOfIdentifiers)
+	);
+	// Duplicated helpers.
+	assert_eq!(
+		repair_share_step_1(&[ids[0], ids[1], ids[1]], helper, &mut rng, participant),
+		Err(Error::DuplicatedIdentifier)
+	);
+	// The helper itself is not part of the helpers.
+	assert_eq!(
+		repair_share_step_1(&[ids[1], ids[2], ids[3]], helper, &mut rng, participant),
+		Err(Error::UnknownIdentifier)
+	);
+	// The participant is one of the helpers.
+	assert_eq!(
+		repair_share_step_1(&[ids[0], ids[1], participant], helper, &mut rng, participant),
+		Err(Error::UnknownIdentifier)
+	);
+}
+
+/// Check that step 3 of RTS rejects a share that does not match the verifying
+/// share of the participant.
+pub fn check_repair_share_step_3_fails_with_invalid_sigmas<
+	C: Ciphersuite,
+	R: RngCore + CryptoRng,
+>(
+	mut rng: R,
+) {
+	let (key_packages, pubkeys) = key_packages_with_dealer::<C, R>(&mut rng);
+	let ids: Vec<_> = key_packages.keys().copied().collect();
+	let participant = ids[4];
+
+	let sigmas = compute_sigmas(&ids[..3], &key_packages, participant, &mut rng);
+
+	// A missing sigma.
+	assert_eq!(
+		repair_share_step_3(&sigmas[..2], participant, &pubkeys),
+		Err(Error::InvalidSecretShare { culprit: None })
+	);
+	// Sigmas for another participant.
+	assert_eq!(
+		repair_share_step_3(&sigmas, ids[3], &pubkeys),
+		Err(Error::InvalidSecretShare { culprit: None })
+	);
+}
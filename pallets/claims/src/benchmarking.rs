@@ -4,7 +4,7 @@ use frame_benchmarking::{account, benchmarks};
 use frame_support::{BoundedVec, traits::UnfilteredDispatchable};
 use frame_system::{RawOrigin, pallet_prelude::*};
 use secp_utils::*;
-use sp_runtime::{AccountId32, DispatchResult, traits::ValidateUnsigned};
+use sp_runtime::{AccountId32, DispatchError, DispatchResult, traits::ValidateUnsigned};
 
 const SEED: u32 = 0;
 
@@ -47,6 +47,58 @@ fn create_claim_attest<T: Config>(input: u32) -> DispatchResult {
 	Ok(())
 }
 
+fn get_vesting_stages<T: Config>() -> VestingStages<T> {
+	BoundedVec::try_from(vec![VestingStage {
+		amount: 100_000u32.into(),
+		start: 0u32.into(),
+		duration: 100u32.into(),
+	}])
+	.unwrap()
+}
+
+fn create_funded_campaign<T: Config>(expiry: u32) -> Result<CampaignId, DispatchError> {
+	let funder: T::AccountId = account("funder", 0, SEED);
+	CurrencyOf::<T>::make_free_balance_be(&funder, (VALUE * 10).into());
+	let campaign_id = NextCampaignId::<T>::get();
+	ClaimsPallet::<T>::create_claim_campaign(
+		RawOrigin::Root.into(),
+		Asset::Custom(T::AssetId::default()),
+		expiry.into(),
+		MultiAddress::Native(account("sweep", 0, SEED)),
+	)?;
+	ClaimsPallet::<T>::fund_claim_campaign(
+		RawOrigin::Signed(funder).into(),
+		campaign_id,
+		(VALUE * 2).into(),
+	)?;
+	Ok(campaign_id)
+}
+
+fn claim_campaign_for<T: Config>(
+	campaign_id: CampaignId,
+	input: u32,
+	dest: Option<MultiAddress>,
+) -> DispatchResult {
+	let secret_key = libsecp256k1::SecretKey::parse(&keccak_256(&input.encode())).unwrap();
+	ClaimsPallet::<T>::mint_campaign_claim(
+		RawOrigin::Root.into(),
+		campaign_id,
+		eth(&secret_key),
+		VALUE.into(),
+		get_vesting_stages::<T>(),
+	)?;
+	let signature =
+		sig::<T>(&secret_key, &(campaign_id, &dest, &None::<T::AccountId>).encode(), &[][..]);
+	ClaimsPallet::<T>::claim_from_campaign(
+		RawOrigin::None.into(),
+		campaign_id,
+		dest,
+		None,
+		signature,
+		None,
+	)
+}
+
 benchmarks! {
 	// Benchmark `claim` including `validate_unsigned` logic.
 	claim {
@@ -162,6 +214,164 @@ benchmarks! {
 
 	}: _(RawOrigin::Root, new_expiry.into(), MultiAddress::Native(account) )
 
+	create_claim_campaign {
+		let account: AccountId32 = account("user", 0, SEED);
+		let campaign_id = NextCampaignId::<T>::get();
+	}: _(RawOrigin::Root, Asset::Custom(T::AssetId::default()), 1000u32.into(), MultiAddress::Native(account))
+	verify {
+		assert!(Campaigns::<T>::contains_key(campaign_id));
+	}
+
+	fund_claim_campaign {
+		let campaign_id = create_funded_campaign::<T>(1000)?;
+		let funder: T::AccountId = account("funder", 0, SEED);
+	}: _(RawOrigin::Signed(funder), campaign_id, VALUE.into())
+	verify {
+		assert_eq!(
+			CurrencyOf::<T>::free_balance(&ClaimsPallet::<T>::campaign_account(campaign_id)),
+			(VALUE * 3).into()
+		);
+	}
+
+	mint_campaign_claim {
+		let campaign_id = create_funded_campaign::<T>(1000)?;
+		let secret_key = libsecp256k1::SecretKey::parse(&keccak_256(&0u32.encode())).unwrap();
+		let eth_address = eth(&secret_key);
+	}: _(RawOrigin::Root, campaign_id, eth_address.clone(), VALUE.into(), get_vesting_stages::<T>())
+	verify {
+		assert!(CampaignClaims::<T>::contains_key(campaign_id, eth_address));
+	}
+
+	// Benchmark `claim_from_campaign` including `validate_unsigned` logic.
+	claim_from_campaign {
+		let campaign_id = create_funded_campaign::<T>(1000)?;
+		let secret_key = libsecp256k1::SecretKey::parse(&keccak_256(&0u32.encode())).unwrap();
+		let eth_address = eth(&secret_key);
+		ClaimsPallet::<T>::mint_campaign_claim(
+			RawOrigin::Root.into(),
+			campaign_id,
+			eth_address.clone(),
+			VALUE.into(),
+			get_vesting_stages::<T>(),
+		)?;
+		let account: AccountId32 = account("user", 0, SEED);
+		let dest = Some(MultiAddress::Native(account));
+		let signature =
+			sig::<T>(&secret_key, &(campaign_id, &dest, &None::<T::AccountId>).encode(), &[][..]);
+		let source = sp_runtime::transaction_validity::TransactionSource::External;
+		let call_enc = Call::<T>::claim_from_campaign {
+			campaign_id,
+			dest,
+			signer: None,
+			signature,
+			delegate_to: None,
+		}.encode();
+	}: {
+		let call = <Call<T> as Decode>::decode(&mut &*call_enc)
+			.expect("call is encoded above, encoding must be correct");
+		ClaimsPallet::<T>::validate_unsigned(source, &call).map_err(|e| -> &'static str { e.into() })?;
+		call.dispatch_bypass_filter(RawOrigin::None.into())?;
+	}
+	verify {
+		assert!(!CampaignClaims::<T>::contains_key(campaign_id, eth_address));
+	}
+
+	release_vested_claim {
+		let campaign_id = create_funded_campaign::<T>(1000)?;
+		let user: AccountId32 = account("user", 0, SEED);
+		claim_campaign_for::<T>(campaign_id, 0, Some(MultiAddress::Native(user.clone())))?;
+		frame_system::Pallet::<T>::set_block_number(50u32.into());
+		let who = ClaimsPallet::<T>::convert_multi_address_to_account_id(MultiAddress::Native(user))
+			.map_err(|_| "invalid account")?;
+	}: _(RawOrigin::Signed(who.clone()), campaign_id)
+	verify {
+		assert!(CampaignVestings::<T>::contains_key(campaign_id, who));
+	}
+
+	sweep_claim_campaign {
+		let campaign_id = create_funded_campaign::<T>(10)?;
+		let user: AccountId32 = account("user", 0, SEED);
+		claim_campaign_for::<T>(campaign_id, 0, Some(MultiAddress::Native(user)))?;
+		frame_system::Pallet::<T>::set_block_number(11u32.into());
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), campaign_id, None)
+	verify {
+		assert!(Campaigns::<T>::get(campaign_id).unwrap().unclaimed.is_zero());
+	}
+
+	set_campaign_merkle_root {
+		let campaign_id = create_funded_campaign::<T>(1000)?;
+		let merkle_root = H256::repeat_byte(1);
+	}: _(RawOrigin::Root, campaign_id, merkle_root, VALUE.into())
+	verify {
+		assert_eq!(Campaigns::<T>::get(campaign_id).unwrap().merkle_root, Some(merkle_root));
+	}
+
+	// Benchmark `claim_from_merkle_campaign` including `validate_unsigned` logic.
+	claim_from_merkle_campaign {
+		let p in 0 .. T::MaxMerkleProofLength::get();
+
+		let campaign_id = create_funded_campaign::<T>(1000)?;
+		let secret_key = libsecp256k1::SecretKey::parse(&keccak_256(&0u32.encode())).unwrap();
+		let claim = CampaignClaim { amount: VALUE.into(), vesting: get_vesting_stages::<T>() };
+		let proof = (0..p).map(|i| H256::repeat_byte(i as u8)).collect::<Vec<_>>();
+		let leaf = ClaimsPallet::<T>::merkle_leaf(0, &eth(&secret_key), &claim);
+		let merkle_root = proof.iter().fold(leaf, |node, sibling| merkle_node(node, *sibling));
+		ClaimsPallet::<T>::set_campaign_merkle_root(
+			RawOrigin::Root.into(),
+			campaign_id,
+			merkle_root,
+			VALUE.into(),
+		)?;
+		let account: AccountId32 = account("user", 0, SEED);
+		let dest = Some(MultiAddress::Native(account));
+		let signature =
+			sig::<T>(&secret_key, &(campaign_id, &dest, &None::<T::AccountId>).encode(), &[][..]);
+		let source = sp_runtime::transaction_validity::TransactionSource::External;
+		let call_enc = Call::<T>::claim_from_merkle_campaign {
+			campaign_id,
+			index: 0,
+			amount: claim.amount,
+			vesting: claim.vesting,
+			proof: BoundedVec::truncate_from(proof),
+			dest,
+			signer: None,
+			signature,
+			delegate_to: None,
+		}.encode();
+	}: {
+		let call = <Call<T> as Decode>::decode(&mut &*call_enc)
+			.expect("call is encoded above, encoding must be correct");
+		ClaimsPallet::<T>::validate_unsigned(source, &call).map_err(|e| -> &'static str { e.into() })?;
+		call.dispatch_bypass_filter(RawOrigin::None.into())?;
+	}
+	verify {
+		assert!(ClaimsPallet::<T>::is_merkle_claimed(campaign_id, 0));
+	}
+
+	sweep_expired_claims {
+		create_claim::<T>(0)?;
+		let account: AccountId32 = account("user", 0, SEED);
+		ClaimsPallet::<T>::force_set_expiry_config(
+			RawOrigin::Root.into(),
+			10u32.into(),
+			MultiAddress::Native(account),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(11u32.into());
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), None)
+	verify {
+		assert!(Total::<T>::get().is_zero());
+	}
+
+	set_claim_campaign_expiry {
+		let campaign_id = create_funded_campaign::<T>(10)?;
+		frame_system::Pallet::<T>::set_block_number(11u32.into());
+	}: _(RawOrigin::Root, campaign_id, 1000u32.into())
+	verify {
+		assert_eq!(Campaigns::<T>::get(campaign_id).unwrap().expiry, 1000u32.into());
+	}
+
 	// Benchmark the time it takes to do `repeat` number of keccak256 hashes
 	#[extra]
 	keccak256 {
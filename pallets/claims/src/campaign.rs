@@ -0,0 +1,401 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Claim campaigns.
+//!
+//! A campaign pays its claims in a single asset out of its own account, which has to be funded
+//! before claims can be minted against it. Claims can vest in several stages, and are claimable
+//! until the campaign expires. After that, anyone can sweep the funds that were not claimed, in
+//! full or in parts, to the sweep destination of the campaign. The expiry of a campaign can be
+//! changed until it is swept, as long as the campaign account holds the funds of its claims. The
+//! vesting funds of claims paid to the same recipient are merged.
+//!
+//! Instead of minting every claim individually, a campaign can commit to its claims through the
+//! root of a Merkle tree. Each leaf is the Keccak-256 hash of the SCALE encoded
+//! `(index, address, amount, vesting)` of a claim, and inner nodes hash the concatenation of their
+//! sorted children. Claimed leaves are tracked by index in a bitmap.
+use super::*;
+use frame_support::traits::{
+	ExistenceRequirement,
+	fungibles::{Inspect, Mutate},
+	tokens::{Fortitude, Preservation},
+};
+use sp_runtime::{
+	Perbill,
+	traits::{AccountIdConversion, AtLeast32BitUnsigned},
+};
+use tangle_primitives::traits::MultiAssetDelegationRestaker;
+
+/// Identifier of a claim campaign.
+pub type CampaignId = u32;
+
+/// A stage of the vesting schedule of a campaign claim.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingStage<Balance, BlockNumber> {
+	/// The amount unlocked by this stage.
+	pub amount: Balance,
+	/// The block at which the stage starts unlocking.
+	pub start: BlockNumber,
+	/// The number of blocks `amount` unlocks linearly over. A stage without duration is a cliff,
+	/// unlocking all of `amount` at `start`.
+	pub duration: BlockNumber,
+}
+
+impl<Balance, BlockNumber> VestingStage<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// The amount of this stage unlocked at block `now`.
+	pub fn vested(&self, now: BlockNumber) -> Balance {
+		if now < self.start {
+			return Zero::zero();
+		}
+		let elapsed = now - self.start;
+		if elapsed >= self.duration {
+			self.amount
+		} else {
+			Perbill::from_rational(elapsed, self.duration).mul_floor(self.amount)
+		}
+	}
+}
+
+/// The vesting schedule of a campaign claim.
+pub type VestingStages<T> =
+	BoundedVec<VestingStage<BalanceOf<T>, BlockNumberFor<T>>, <T as Config>::MaxVestingSchedules>;
+
+/// A claim campaign.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimCampaign<AccountId, AssetId, Balance, BlockNumber> {
+	/// The asset the claims of the campaign are paid in.
+	pub asset: Asset<AssetId>,
+	/// The last block in which claims can be made.
+	pub expiry: BlockNumber,
+	/// The account receiving the unclaimed funds once the campaign expired.
+	pub sweep_destination: AccountId,
+	/// The total amount of the claims not claimed yet.
+	pub unclaimed: Balance,
+	/// The total amount claimed but still vesting in the campaign account.
+	pub vesting: Balance,
+	/// The root of the Merkle tree of the claims of the campaign, if any.
+	pub merkle_root: Option<H256>,
+	/// The total amount swept to the sweep destination after the campaign expired.
+	pub swept: Balance,
+}
+
+/// A claim of a campaign.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxStages))]
+#[codec(mel_bound(Balance: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct CampaignClaim<Balance, BlockNumber, MaxStages: Get<u32>> {
+	/// The total amount of the claim.
+	pub amount: Balance,
+	/// The stages the amount vests in. The part of the amount not covered by a stage is paid
+	/// out when claiming.
+	pub vesting: BoundedVec<VestingStage<Balance, BlockNumber>, MaxStages>,
+}
+
+/// The claimed funds of a recipient that are still vesting in the campaign account.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxStages))]
+#[codec(mel_bound(Balance: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct CampaignVesting<Balance, BlockNumber, MaxStages: Get<u32>> {
+	/// The stages the funds vest in.
+	pub stages: BoundedVec<VestingStage<Balance, BlockNumber>, MaxStages>,
+	/// The amount released to the recipient so far.
+	pub released: Balance,
+}
+
+impl<Balance, BlockNumber, MaxStages> CampaignVesting<Balance, BlockNumber, MaxStages>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+	MaxStages: Get<u32>,
+{
+	/// The total amount vesting.
+	pub fn total(&self) -> Balance {
+		self.stages
+			.iter()
+			.fold(Zero::zero(), |acc: Balance, stage| acc.saturating_add(stage.amount))
+	}
+
+	/// The amount unlocked at block `now` but not released yet.
+	pub fn releasable(&self, now: BlockNumber) -> Balance {
+		self.stages
+			.iter()
+			.fold(Zero::zero(), |acc: Balance, stage| acc.saturating_add(stage.vested(now)))
+			.saturating_sub(self.released)
+	}
+
+	/// Adds the `stages` of another claim, merging them into the stages that unlock over the same
+	/// blocks.
+	///
+	/// Returns `false` if the stages do not fit.
+	pub fn merge(
+		&mut self,
+		stages: impl IntoIterator<Item = VestingStage<Balance, BlockNumber>>,
+	) -> bool {
+		for stage in stages {
+			if let Some(existing) = self.stages.iter_mut().find(|existing| {
+				existing.start == stage.start && existing.duration == stage.duration
+			}) {
+				existing.amount = existing.amount.saturating_add(stage.amount);
+			} else if self.stages.try_push(stage).is_err() {
+				return false;
+			}
+		}
+		true
+	}
+}
+
+/// Hashes two nodes of a claims Merkle tree into their parent.
+pub fn merkle_node(a: H256, b: H256) -> H256 {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+	let mut data = [0u8; 64];
+	data[..32].copy_from_slice(first.as_bytes());
+	data[32..].copy_from_slice(second.as_bytes());
+	H256(keccak_256(&data))
+}
+
+/// Whether `proof` proves that `leaf` is part of the claims Merkle tree with the given `root`.
+pub fn verify_merkle_proof(root: H256, leaf: H256, proof: &[H256]) -> bool {
+	proof.iter().fold(leaf, |node, sibling| merkle_node(node, *sibling)) == root
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account holding the funds of a campaign.
+	pub fn campaign_account(campaign_id: CampaignId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(campaign_id)
+	}
+
+	/// The message a claim of a campaign signs, binding the claim to its destination and the
+	/// operator the claimed funds are delegated to.
+	pub(crate) fn campaign_claim_payload(
+		campaign_id: CampaignId,
+		dest: &Option<MultiAddress>,
+		delegate_to: &Option<T::AccountId>,
+	) -> Vec<u8> {
+		to_ascii_hex(&(campaign_id, dest, delegate_to).encode())
+	}
+
+	/// Ensures `vesting` is a valid vesting schedule for a claim of `amount`.
+	pub(crate) fn ensure_valid_vesting(
+		amount: BalanceOf<T>,
+		vesting: &VestingStages<T>,
+	) -> DispatchResult {
+		let mut vesting_total = BalanceOf::<T>::zero();
+		for stage in vesting.iter() {
+			ensure!(!stage.amount.is_zero(), Error::<T>::InvalidVestingSchedule);
+			vesting_total = vesting_total.saturating_add(stage.amount);
+		}
+		ensure!(!amount.is_zero() && vesting_total <= amount, Error::<T>::InvalidVestingSchedule);
+		Ok(())
+	}
+
+	/// The campaign with the given id, if claims can still be made from it.
+	pub(crate) fn claimable_campaign(
+		campaign_id: CampaignId,
+	) -> Result<ClaimCampaign<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumberFor<T>>, Error<T>>
+	{
+		let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= campaign.expiry,
+			Error::<T>::CampaignExpired
+		);
+		Ok(campaign)
+	}
+
+	/// The leaf of the claims Merkle tree for the claim of `who` at `index`.
+	pub fn merkle_leaf(
+		index: u32,
+		who: &MultiAddress,
+		claim: &CampaignClaim<BalanceOf<T>, BlockNumberFor<T>, T::MaxVestingSchedules>,
+	) -> H256 {
+		H256(keccak_256(&(index, who, claim).encode()))
+	}
+
+	/// Whether the leaf at `index` of the claims Merkle tree of a campaign was claimed.
+	pub fn is_merkle_claimed(campaign_id: CampaignId, index: u32) -> bool {
+		let bit = 1u128 << (index % 128);
+		MerkleClaimed::<T>::get(campaign_id, index / 128) & bit != 0
+	}
+
+	pub(crate) fn process_campaign_claim(
+		campaign_id: CampaignId,
+		signer: MultiAddress,
+		dest: Option<MultiAddress>,
+		delegate_to: Option<T::AccountId>,
+	) -> DispatchResult {
+		let campaign = Self::claimable_campaign(campaign_id)?;
+		let claim =
+			CampaignClaims::<T>::take(campaign_id, &signer).ok_or(Error::<T>::SignerHasNoClaim)?;
+		Self::pay_campaign_claim(campaign_id, campaign, signer, claim, dest, delegate_to)
+	}
+
+	pub(crate) fn process_merkle_claim(
+		campaign_id: CampaignId,
+		index: u32,
+		signer: MultiAddress,
+		claim: CampaignClaim<BalanceOf<T>, BlockNumberFor<T>, T::MaxVestingSchedules>,
+		proof: &[H256],
+		dest: Option<MultiAddress>,
+		delegate_to: Option<T::AccountId>,
+	) -> DispatchResult {
+		let campaign = Self::claimable_campaign(campaign_id)?;
+		let merkle_root = campaign.merkle_root.ok_or(Error::<T>::NotMerkleCampaign)?;
+		ensure!(!Self::is_merkle_claimed(campaign_id, index), Error::<T>::AlreadyClaimed);
+		Self::ensure_valid_vesting(claim.amount, &claim.vesting)?;
+		ensure!(
+			verify_merkle_proof(merkle_root, Self::merkle_leaf(index, &signer, &claim), proof),
+			Error::<T>::InvalidMerkleProof
+		);
+
+		MerkleClaimed::<T>::mutate(campaign_id, index / 128, |bits| {
+			*bits |= 1u128 << (index % 128)
+		});
+		Self::pay_campaign_claim(campaign_id, campaign, signer, claim, dest, delegate_to)
+	}
+
+	/// Pays out `claim`, moving the part of it that is still vesting to the vesting funds of the
+	/// recipient.
+	fn pay_campaign_claim(
+		campaign_id: CampaignId,
+		mut campaign: ClaimCampaign<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumberFor<T>>,
+		signer: MultiAddress,
+		claim: CampaignClaim<BalanceOf<T>, BlockNumberFor<T>, T::MaxVestingSchedules>,
+		dest: Option<MultiAddress>,
+		delegate_to: Option<T::AccountId>,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		let recipient = Self::convert_multi_address_to_account_id(dest.unwrap_or(signer.clone()))?;
+
+		let vesting_total = claim
+			.vesting
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |acc, stage| acc.saturating_add(stage.amount));
+		campaign.unclaimed =
+			campaign.unclaimed.checked_sub(&claim.amount).ok_or(Error::<T>::PotUnderflow)?;
+		let mut payout = claim.amount.saturating_sub(vesting_total);
+		if !vesting_total.is_zero() {
+			// Claims can be paid to any recipient, so the vesting funds of a recipient that
+			// already has some are merged instead of blocking the claim.
+			let mut vesting = CampaignVestings::<T>::get(campaign_id, &recipient)
+				.unwrap_or(CampaignVesting { stages: Default::default(), released: Zero::zero() });
+			ensure!(vesting.merge(claim.vesting), Error::<T>::TooManyVestingStages);
+			campaign.vesting = campaign.vesting.saturating_add(vesting_total);
+			CampaignVestings::<T>::insert(campaign_id, &recipient, vesting);
+			payout = payout.saturating_add(Self::release_vested(
+				campaign_id,
+				&mut campaign,
+				&recipient,
+				now,
+			));
+		}
+
+		if !payout.is_zero() {
+			Self::transfer_campaign_asset(
+				campaign.asset,
+				&Self::campaign_account(campaign_id),
+				&recipient,
+				payout,
+				false,
+			)?;
+			if let Some(operator) = &delegate_to {
+				T::DelegationRestaker::deposit_and_delegate(
+					&recipient,
+					operator,
+					campaign.asset,
+					payout,
+				)?;
+			}
+		}
+		Campaigns::<T>::insert(campaign_id, campaign);
+
+		Self::deposit_event(Event::<T>::CampaignClaimed {
+			campaign_id,
+			recipient,
+			source: signer,
+			amount: claim.amount,
+			delegated_to: delegate_to,
+		});
+		Ok(())
+	}
+
+	/// Marks the vested funds of `who` as released, returning the amount to pay out.
+	pub(crate) fn release_vested(
+		campaign_id: CampaignId,
+		campaign: &mut ClaimCampaign<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumberFor<T>>,
+		who: &T::AccountId,
+		now: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		let Some(mut vesting) = CampaignVestings::<T>::get(campaign_id, who) else {
+			return Zero::zero();
+		};
+		let releasable = vesting.releasable(now);
+		vesting.released = vesting.released.saturating_add(releasable);
+		campaign.vesting = campaign.vesting.saturating_sub(releasable);
+		if vesting.released >= vesting.total() {
+			CampaignVestings::<T>::remove(campaign_id, who);
+		} else {
+			CampaignVestings::<T>::insert(campaign_id, who, vesting);
+		}
+		releasable
+	}
+
+	/// The balance of `who` in a campaign asset, the native currency being
+	/// `Asset::Custom(T::AssetId::default())`.
+	pub(crate) fn campaign_asset_balance(
+		asset: Asset<T::AssetId>,
+		who: &T::AccountId,
+	) -> BalanceOf<T> {
+		match asset {
+			Asset::Custom(id) if id == T::AssetId::default() => CurrencyOf::<T>::free_balance(who),
+			Asset::Custom(id) => T::Fungibles::reducible_balance(
+				id,
+				who,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			),
+			Asset::Erc20(_) => Zero::zero(),
+		}
+	}
+
+	/// Transfers `amount` of a campaign asset, keeping `from` alive if `keep_alive` is set.
+	pub(crate) fn transfer_campaign_asset(
+		asset: Asset<T::AssetId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		keep_alive: bool,
+	) -> DispatchResult {
+		match asset {
+			Asset::Custom(id) if id == T::AssetId::default() => {
+				let existence = if keep_alive {
+					ExistenceRequirement::KeepAlive
+				} else {
+					ExistenceRequirement::AllowDeath
+				};
+				CurrencyOf::<T>::transfer(from, to, amount, existence)
+			},
+			Asset::Custom(id) => {
+				let preservation =
+					if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
+				T::Fungibles::transfer(id, from, to, amount, preservation).map(|_| ())
+			},
+			Asset::Erc20(_) => Err(Error::<T>::UnsupportedAsset.into()),
+		}
+	}
+}
@@ -24,36 +24,44 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+mod campaign;
+pub mod migrations;
 mod utils;
 pub mod weights;
 use weights::WeightInfo;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-pub use crate::utils::{
-	MultiAddress, MultiAddressSignature,
-	ethereum_address::{EcdsaSignature, EthereumAddress},
+pub use crate::{
+	campaign::*,
+	utils::{
+		MultiAddress, MultiAddressSignature,
+		ethereum_address::{EcdsaSignature, EthereumAddress},
+	},
 };
 use frame_support::{
-	ensure,
-	traits::{Currency, Get, VestingSchedule},
+	BoundedVec, PalletId, ensure,
+	pallet_prelude::DispatchResult,
+	traits::{Currency, ExistenceRequirement, Get, VestingSchedule},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use pallet_evm::AddressMapping;
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use serde::{self, Deserialize, Serialize};
-use sp_core::{H160, sr25519::Public};
+use sp_core::{H160, H256, sr25519::Public};
 use sp_io::{
 	crypto::{secp256k1_ecdsa_recover, sr25519_verify},
 	hashing::keccak_256,
 };
 use sp_runtime::{
 	AccountId32, RuntimeDebug, Saturating,
-	traits::{CheckedSub, Zero},
+	traits::{AccountIdConversion, CheckedSub, Zero},
 	transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
 };
 use sp_std::{convert::TryInto, prelude::*, vec};
+use tangle_primitives::services::Asset;
 use utils::Sr25519Signature;
 /// Custom validity errors used in Polkadot while validating transactions.
 #[repr(u8)]
@@ -117,10 +125,18 @@ impl Default for StatementKind {
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::fungibles::{self, Inspect},
+	};
 	use frame_system::pallet_prelude::*;
+	use tangle_primitives::traits::MultiAssetDelegationRestaker;
+
+	/// The in-code storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -138,6 +154,25 @@ pub mod pallet {
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type MaxVestingSchedules: Get<u32>;
 		type WeightInfo: weights::WeightInfo;
+
+		/// Type representing the unique ID of an asset claim campaigns pay out in.
+		type AssetId: Parameter + Member + Copy + Ord + Default + MaxEncodedLen + TypeInfo;
+
+		/// The fungibles claim campaigns pay out in, besides the native currency.
+		type Fungibles: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+			+ fungibles::Mutate<Self::AccountId, AssetId = Self::AssetId>;
+
+		/// Restaker used to delegate claimed funds on behalf of their recipient.
+		type DelegationRestaker: MultiAssetDelegationRestaker<Self::AccountId, BalanceOf<Self>, Self::AssetId>;
+
+		/// The pallet id, used for deriving the accounts holding the funds of the claims and of
+		/// the claim campaigns.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum length of the Merkle proof of a campaign claim.
+		#[pallet::constant]
+		type MaxMerkleProofLength: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -145,6 +180,37 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Someone claimed some native tokens.
 		Claimed { recipient: T::AccountId, source: MultiAddress, amount: BalanceOf<T> },
+		/// A claim campaign was created.
+		ClaimCampaignCreated {
+			campaign_id: CampaignId,
+			asset: Asset<T::AssetId>,
+			expiry: BlockNumberFor<T>,
+			sweep_destination: T::AccountId,
+		},
+		/// A claim campaign was funded.
+		ClaimCampaignFunded { campaign_id: CampaignId, who: T::AccountId, amount: BalanceOf<T> },
+		/// Someone claimed from a claim campaign.
+		CampaignClaimed {
+			campaign_id: CampaignId,
+			recipient: T::AccountId,
+			source: MultiAddress,
+			amount: BalanceOf<T>,
+			delegated_to: Option<T::AccountId>,
+		},
+		/// Vested funds of a campaign claim were released to their recipient.
+		VestedClaimReleased { campaign_id: CampaignId, who: T::AccountId, amount: BalanceOf<T> },
+		/// The claims of a claim campaign were committed to through a Merkle root.
+		CampaignMerkleRootSet { campaign_id: CampaignId, merkle_root: H256, total: BalanceOf<T> },
+		/// The unclaimed funds of an expired claim campaign were swept.
+		ClaimCampaignSwept {
+			campaign_id: CampaignId,
+			destination: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// The expiry of a claim campaign was changed.
+		ClaimCampaignExpirySet { campaign_id: CampaignId, expiry: BlockNumberFor<T> },
+		/// Unclaimed funds of the expired claims were swept from the claims pot.
+		ExpiredClaimsSwept { destination: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -166,6 +232,42 @@ pub mod pallet {
 		InvalidStatement,
 		/// The account already has a vested balance.
 		VestedBalanceExists,
+		/// The claim campaign does not exist.
+		CampaignNotFound,
+		/// The claim campaign has expired.
+		CampaignExpired,
+		/// The claim campaign has not expired yet.
+		CampaignNotExpired,
+		/// The campaign account does not hold enough funds for the claims of the campaign.
+		CampaignUnderfunded,
+		/// The asset cannot be paid out by a claim campaign.
+		UnsupportedAsset,
+		/// The vesting stages are empty or exceed the amount of the claim.
+		InvalidVestingSchedule,
+		/// The address already has a claim in the campaign.
+		ClaimAlreadyExists,
+		/// There are no vested funds to release.
+		NothingToRelease,
+		/// There are no unclaimed funds to sweep.
+		NothingToSweep,
+		/// The claim campaign already has a Merkle root.
+		MerkleRootAlreadySet,
+		/// The claim campaign has no Merkle root.
+		NotMerkleCampaign,
+		/// The Merkle proof does not prove the claim.
+		InvalidMerkleProof,
+		/// The claim was already claimed.
+		AlreadyClaimed,
+		/// The claims have expired.
+		ClaimsExpired,
+		/// The claims have not expired yet.
+		ClaimsNotExpired,
+		/// The vesting stages of the claim do not fit the vesting funds of the recipient.
+		TooManyVestingStages,
+		/// The unclaimed funds of the claim campaign were swept.
+		CampaignSwept,
+		/// The unclaimed funds of the expired claims were swept.
+		ClaimsSwept,
 	}
 
 	#[pallet::storage]
@@ -181,6 +283,11 @@ pub mod pallet {
 	#[pallet::getter(fn expiry_time)]
 	pub(super) type ExpiryConfig<T: Config> = StorageValue<_, (BlockNumberFor<T>, MultiAddress)>;
 
+	/// The total amount of unclaimed funds swept from the claims pot after expiry.
+	#[pallet::storage]
+	#[pallet::getter(fn swept)]
+	pub(super) type Swept<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
 	/// Vesting schedule for a claim.
 	/// First balance is the total amount that should be held for vesting.
 	/// Second balance is how much should be unlocked per block.
@@ -198,6 +305,47 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type Signing<T: Config> = StorageMap<_, Identity, MultiAddress, StatementKind>;
 
+	/// The id of the next claim campaign.
+	#[pallet::storage]
+	pub type NextCampaignId<T: Config> = StorageValue<_, CampaignId, ValueQuery>;
+
+	/// The claim campaigns.
+	#[pallet::storage]
+	pub type Campaigns<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CampaignId,
+		ClaimCampaign<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// The claims of the claim campaigns, by the address allowed to claim them.
+	#[pallet::storage]
+	pub type CampaignClaims<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CampaignId,
+		Blake2_128Concat,
+		MultiAddress,
+		CampaignClaim<BalanceOf<T>, BlockNumberFor<T>, T::MaxVestingSchedules>,
+	>;
+
+	/// The bitmap of the claimed leaves of the claims Merkle tree of a campaign, in words of 128
+	/// leaves.
+	#[pallet::storage]
+	pub type MerkleClaimed<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CampaignId, Twox64Concat, u32, u128, ValueQuery>;
+
+	/// The claimed funds of campaign claims that are still vesting, by recipient.
+	#[pallet::storage]
+	pub type CampaignVestings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CampaignId,
+		Blake2_128Concat,
+		T::AccountId,
+		CampaignVesting<BalanceOf<T>, BlockNumberFor<T>, T::MaxVestingSchedules>,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub claims: Vec<(MultiAddress, BalanceOf<T>, Option<StatementKind>)>,
@@ -221,10 +369,11 @@ pub mod pallet {
 			self.claims.iter().map(|(a, b, _)| (a.clone(), b.clone())).for_each(|(a, b)| {
 				Claims::<T>::insert(a, b);
 			});
-			// build `Total`
-			Total::<T>::put(
-				self.claims.iter().fold(Zero::zero(), |acc: BalanceOf<T>, &(_, b, _)| acc + b),
-			);
+			// build `Total` and fund the claims pot with it
+			let total =
+				self.claims.iter().fold(Zero::zero(), |acc: BalanceOf<T>, &(_, b, _)| acc + b);
+			Total::<T>::put(total);
+			Pallet::<T>::fund_claims_pot();
 			// build `Vesting`
 			self.vesting.iter().for_each(|(k, v)| {
 				Vesting::<T>::insert(k, v);
@@ -241,32 +390,6 @@ pub mod pallet {
 		}
 	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_finalize(current_block: BlockNumberFor<T>) {
-			// check if we have an expiry time set and have we crossed the limit
-			let expiry_config = ExpiryConfig::<T>::get();
-			if let Some(expiry_config) = expiry_config {
-				if current_block > expiry_config.0 {
-					let unclaimed_amount = Total::<T>::take();
-					log::info!(
-						"Claims : Expiry block passed, sweeping remaining amount of {:?} to destination",
-						unclaimed_amount
-					);
-					let expiry_destination =
-						match Self::convert_multi_address_to_account_id(expiry_config.1) {
-							Ok(a) => a,
-							Err(_) => return,
-						};
-					let _ =
-						CurrencyOf::<T>::deposit_creating(&expiry_destination, unclaimed_amount);
-					// clear the expiry detail
-					ExpiryConfig::<T>::take();
-				}
-			}
-		}
-	}
-
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Make a claim to collect your tokens.
@@ -318,6 +441,8 @@ pub mod pallet {
 		/// - `value`: The number of native tokens that will be claimed.
 		/// - `vesting_schedule`: An optional vesting schedule for these native tokens.
 		///
+		/// The value is deposited into the claims pot, which pays out the claim.
+		///
 		/// <weight>
 		/// The weight of this call is invariant over the input parameters.
 		/// We assume worst case that both vesting and statement is being inserted.
@@ -338,6 +463,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 
 			<Total<T>>::mutate(|t| *t = t.saturating_add(value));
+			Self::fund_claims_pot();
 			<Claims<T>>::insert(who.clone(), value);
 			if let Some(vs) = vesting_schedule {
 				<Vesting<T>>::insert(who.clone(), vs);
@@ -416,6 +542,10 @@ pub mod pallet {
 
 		/// Set the value for expiryconfig
 		/// Can only be called by ForceOrigin
+		///
+		/// Claims can be made until `expiry_block`, after which the unclaimed funds can be swept
+		/// to `dest` through `sweep_expired_claims`. Setting a later block extends the claims, as
+		/// long as none of their funds were swept.
 		#[pallet::weight(T::WeightInfo::force_set_expiry_config())]
 		#[pallet::call_index(5)]
 		pub fn force_set_expiry_config(
@@ -424,6 +554,7 @@ pub mod pallet {
 			dest: MultiAddress,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Self::swept().is_zero(), Error::<T>::ClaimsSwept);
 			ExpiryConfig::<T>::set(Some((expiry_block, dest)));
 			Ok(())
 		}
@@ -440,6 +571,416 @@ pub mod pallet {
 			Self::process_claim(signer, dest)?;
 			Ok(())
 		}
+
+		/// Create a claim campaign paying its claims in `asset`.
+		///
+		/// The dispatch origin for this call must be `ForceOrigin`.
+		///
+		/// The campaign has to be funded through `fund_claim_campaign` before claims can be minted
+		/// against it. Claims can be made until the `expiry` block, after which the funds left
+		/// unclaimed can be swept to `sweep_destination`.
+		///
+		/// Parameters:
+		/// - `asset`: The asset the claims are paid in.
+		/// - `expiry`: The last block in which claims can be made.
+		/// - `sweep_destination`: The account receiving the unclaimed funds after expiry.
+		#[pallet::weight(T::WeightInfo::create_claim_campaign())]
+		#[pallet::call_index(7)]
+		pub fn create_claim_campaign(
+			origin: OriginFor<T>,
+			asset: Asset<T::AssetId>,
+			expiry: BlockNumberFor<T>,
+			sweep_destination: MultiAddress,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			match asset {
+				Asset::Custom(id) if id == T::AssetId::default() => {},
+				Asset::Custom(id) => {
+					ensure!(T::Fungibles::asset_exists(id), Error::<T>::UnsupportedAsset)
+				},
+				Asset::Erc20(_) => return Err(Error::<T>::UnsupportedAsset.into()),
+			}
+			ensure!(
+				expiry >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::CampaignExpired
+			);
+			let sweep_destination = Self::convert_multi_address_to_account_id(sweep_destination)?;
+
+			let campaign_id = NextCampaignId::<T>::mutate(|id| {
+				let campaign_id = *id;
+				*id = id.saturating_add(1);
+				campaign_id
+			});
+			Campaigns::<T>::insert(
+				campaign_id,
+				ClaimCampaign {
+					asset,
+					expiry,
+					sweep_destination: sweep_destination.clone(),
+					unclaimed: Zero::zero(),
+					vesting: Zero::zero(),
+					merkle_root: None,
+					swept: Zero::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::ClaimCampaignCreated {
+				campaign_id,
+				asset,
+				expiry,
+				sweep_destination,
+			});
+			Ok(())
+		}
+
+		/// Fund a claim campaign with `amount` of its asset.
+		#[pallet::weight(T::WeightInfo::fund_claim_campaign())]
+		#[pallet::call_index(8)]
+		pub fn fund_claim_campaign(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= campaign.expiry,
+				Error::<T>::CampaignExpired
+			);
+
+			Self::transfer_campaign_asset(
+				campaign.asset,
+				&who,
+				&Self::campaign_account(campaign_id),
+				amount,
+				true,
+			)?;
+
+			Self::deposit_event(Event::<T>::ClaimCampaignFunded { campaign_id, who, amount });
+			Ok(())
+		}
+
+		/// Mint a new claim in a claim campaign.
+		///
+		/// The dispatch origin for this call must be `ForceOrigin`.
+		///
+		/// Parameters:
+		/// - `campaign_id`: The campaign to mint the claim in.
+		/// - `who`: The address allowed to collect this claim.
+		/// - `amount`: The amount of the campaign asset that will be claimed.
+		/// - `vesting`: The stages part of the amount vests in after claiming. The rest of the
+		///   amount is paid out when claiming.
+		#[pallet::weight(T::WeightInfo::mint_campaign_claim())]
+		#[pallet::call_index(9)]
+		pub fn mint_campaign_claim(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			who: MultiAddress,
+			amount: BalanceOf<T>,
+			vesting: VestingStages<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let mut campaign =
+				Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= campaign.expiry,
+				Error::<T>::CampaignExpired
+			);
+			ensure!(
+				!CampaignClaims::<T>::contains_key(campaign_id, &who),
+				Error::<T>::ClaimAlreadyExists
+			);
+			Self::ensure_valid_vesting(amount, &vesting)?;
+
+			campaign.unclaimed = campaign.unclaimed.saturating_add(amount);
+			let funds =
+				Self::campaign_asset_balance(campaign.asset, &Self::campaign_account(campaign_id));
+			ensure!(
+				funds >= campaign.unclaimed.saturating_add(campaign.vesting),
+				Error::<T>::CampaignUnderfunded
+			);
+
+			CampaignClaims::<T>::insert(campaign_id, who, CampaignClaim { amount, vesting });
+			Campaigns::<T>::insert(campaign_id, campaign);
+			Ok(())
+		}
+
+		/// Make a claim from a claim campaign.
+		///
+		/// The dispatch origin for this call must be _None_.
+		///
+		/// Unsigned Validation:
+		/// A call to `claim_from_campaign` is deemed valid if the signature provided matches
+		/// the expected signed message of:
+		///
+		/// > Ethereum Signed Message:
+		/// > (configured prefix string)(campaign id, destination, operator)
+		///
+		/// Parameters:
+		/// - `campaign_id`: The campaign to claim from.
+		/// - `dest`: The destination account to payout the claim.
+		/// - `signer`: The native address of the signer, required for sr25519 signatures.
+		/// - `signature`: The signature of the message described above.
+		/// - `delegate_to`: The operator to delegate the claimed funds to, if any.
+		#[pallet::weight(T::WeightInfo::claim_from_campaign())]
+		#[pallet::call_index(10)]
+		pub fn claim_from_campaign(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			dest: Option<MultiAddress>,
+			signer: Option<MultiAddress>,
+			signature: MultiAddressSignature,
+			delegate_to: Option<T::AccountId>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let data = Self::campaign_claim_payload(campaign_id, &dest, &delegate_to);
+			let signer = Self::get_signer_multi_address(signer, signature, data, vec![])?;
+			Self::process_campaign_claim(campaign_id, signer, dest, delegate_to)
+		}
+
+		/// Release the vested funds of the campaign claims of the caller.
+		#[pallet::weight(T::WeightInfo::release_vested_claim())]
+		#[pallet::call_index(11)]
+		pub fn release_vested_claim(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut campaign =
+				Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+
+			let amount = Self::release_vested(
+				campaign_id,
+				&mut campaign,
+				&who,
+				frame_system::Pallet::<T>::block_number(),
+			);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToRelease);
+			Self::transfer_campaign_asset(
+				campaign.asset,
+				&Self::campaign_account(campaign_id),
+				&who,
+				amount,
+				false,
+			)?;
+			Campaigns::<T>::insert(campaign_id, campaign);
+
+			Self::deposit_event(Event::<T>::VestedClaimReleased { campaign_id, who, amount });
+			Ok(())
+		}
+
+		/// Sweep the unclaimed funds of an expired claim campaign to its sweep destination.
+		///
+		/// The funds still vesting for recipients that already claimed stay in the campaign
+		/// account.
+		///
+		/// Parameters:
+		/// - `campaign_id`: The campaign to sweep.
+		/// - `amount`: The maximum amount to sweep, or all unclaimed funds if `None`.
+		#[pallet::weight(T::WeightInfo::sweep_claim_campaign())]
+		#[pallet::call_index(12)]
+		pub fn sweep_claim_campaign(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut campaign =
+				Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > campaign.expiry,
+				Error::<T>::CampaignNotExpired
+			);
+
+			let campaign_account = Self::campaign_account(campaign_id);
+			let sweepable = Self::campaign_asset_balance(campaign.asset, &campaign_account)
+				.saturating_sub(campaign.vesting);
+			let amount = amount.map_or(sweepable, |amount| amount.min(sweepable));
+			ensure!(!amount.is_zero(), Error::<T>::NothingToSweep);
+			let keep_alive = !campaign.vesting.is_zero() || amount < sweepable;
+			Self::transfer_campaign_asset(
+				campaign.asset,
+				&campaign_account,
+				&campaign.sweep_destination,
+				amount,
+				keep_alive,
+			)?;
+			// The funds beyond the unclaimed claims are swept first.
+			campaign.unclaimed = campaign.unclaimed.min(sweepable.saturating_sub(amount));
+			campaign.swept = campaign.swept.saturating_add(amount);
+			let destination = campaign.sweep_destination.clone();
+			Campaigns::<T>::insert(campaign_id, campaign);
+
+			Self::deposit_event(Event::<T>::ClaimCampaignSwept {
+				campaign_id,
+				destination,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Commit to the claims of a claim campaign through the root of their Merkle tree.
+		///
+		/// The dispatch origin for this call must be `ForceOrigin`.
+		///
+		/// Parameters:
+		/// - `campaign_id`: The campaign the claims belong to.
+		/// - `merkle_root`: The root of the Merkle tree of the claims.
+		/// - `total`: The total amount of the claims in the tree, which the campaign account must
+		///   hold besides the other claims of the campaign.
+		#[pallet::weight(T::WeightInfo::set_campaign_merkle_root())]
+		#[pallet::call_index(13)]
+		pub fn set_campaign_merkle_root(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			merkle_root: H256,
+			total: BalanceOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let mut campaign = Self::claimable_campaign(campaign_id)?;
+			ensure!(campaign.merkle_root.is_none(), Error::<T>::MerkleRootAlreadySet);
+
+			campaign.merkle_root = Some(merkle_root);
+			campaign.unclaimed = campaign.unclaimed.saturating_add(total);
+			let funds =
+				Self::campaign_asset_balance(campaign.asset, &Self::campaign_account(campaign_id));
+			ensure!(
+				funds >= campaign.unclaimed.saturating_add(campaign.vesting),
+				Error::<T>::CampaignUnderfunded
+			);
+			Campaigns::<T>::insert(campaign_id, campaign);
+
+			Self::deposit_event(Event::<T>::CampaignMerkleRootSet {
+				campaign_id,
+				merkle_root,
+				total,
+			});
+			Ok(())
+		}
+
+		/// Make a claim from the claims Merkle tree of a claim campaign.
+		///
+		/// The dispatch origin for this call must be _None_.
+		///
+		/// Unsigned Validation:
+		/// A call to `claim_from_merkle_campaign` is deemed valid if the signature provided
+		/// matches the expected signed message of `claim_from_campaign`, and `proof` proves the
+		/// leaf of the signer at `index` is part of the Merkle tree of the campaign.
+		///
+		/// Parameters:
+		/// - `campaign_id`: The campaign to claim from.
+		/// - `index`: The index of the leaf of the claim.
+		/// - `amount`: The amount of the claim.
+		/// - `vesting`: The vesting stages of the claim.
+		/// - `proof`: The Merkle proof of the leaf of the claim.
+		/// - `dest`: The destination account to payout the claim.
+		/// - `signer`: The native address of the signer, required for sr25519 signatures.
+		/// - `signature`: The signature of the signer.
+		/// - `delegate_to`: The operator to delegate the claimed funds to, if any.
+		#[pallet::weight(T::WeightInfo::claim_from_merkle_campaign(proof.len() as u32))]
+		#[pallet::call_index(14)]
+		pub fn claim_from_merkle_campaign(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			index: u32,
+			amount: BalanceOf<T>,
+			vesting: VestingStages<T>,
+			proof: BoundedVec<H256, T::MaxMerkleProofLength>,
+			dest: Option<MultiAddress>,
+			signer: Option<MultiAddress>,
+			signature: MultiAddressSignature,
+			delegate_to: Option<T::AccountId>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let data = Self::campaign_claim_payload(campaign_id, &dest, &delegate_to);
+			let signer = Self::get_signer_multi_address(signer, signature, data, vec![])?;
+			Self::process_merkle_claim(
+				campaign_id,
+				index,
+				signer,
+				CampaignClaim { amount, vesting },
+				&proof,
+				dest,
+				delegate_to,
+			)
+		}
+
+		/// Sweep the unclaimed funds of the expired claims from the claims pot to the expiry
+		/// destination.
+		///
+		/// Anyone can sweep once the expiry block of `ExpiryConfig` has passed.
+		///
+		/// Parameters:
+		/// - `amount`: The maximum amount to sweep, or all unclaimed funds if `None`.
+		#[pallet::weight(T::WeightInfo::sweep_expired_claims())]
+		#[pallet::call_index(15)]
+		pub fn sweep_expired_claims(
+			origin: OriginFor<T>,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let (expiry, destination) =
+				ExpiryConfig::<T>::get().ok_or(Error::<T>::ClaimsNotExpired)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > expiry,
+				Error::<T>::ClaimsNotExpired
+			);
+			let destination = Self::convert_multi_address_to_account_id(destination)?;
+
+			let total = Self::total();
+			let amount = amount.map_or(total, |amount| amount.min(total));
+			ensure!(!amount.is_zero(), Error::<T>::NothingToSweep);
+			CurrencyOf::<T>::transfer(
+				&Self::claims_pot(),
+				&destination,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			<Total<T>>::put(total.saturating_sub(amount));
+			<Swept<T>>::mutate(|swept| *swept = swept.saturating_add(amount));
+
+			Self::deposit_event(Event::<T>::ExpiredClaimsSwept { destination, amount });
+			Ok(())
+		}
+
+		/// Change the expiry of a claim campaign, reopening it if it already expired.
+		///
+		/// The dispatch origin for this call must be `ForceOrigin`.
+		///
+		/// The campaign account must still hold the funds for the claims of the campaign, so the
+		/// expiry of a campaign that was swept cannot be changed anymore.
+		///
+		/// Parameters:
+		/// - `campaign_id`: The campaign to change the expiry of.
+		/// - `expiry`: The new last block in which claims can be made.
+		#[pallet::weight(T::WeightInfo::set_claim_campaign_expiry())]
+		#[pallet::call_index(16)]
+		pub fn set_claim_campaign_expiry(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let mut campaign =
+				Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			ensure!(campaign.swept.is_zero(), Error::<T>::CampaignSwept);
+			ensure!(
+				expiry >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::CampaignExpired
+			);
+			let funds =
+				Self::campaign_asset_balance(campaign.asset, &Self::campaign_account(campaign_id));
+			ensure!(
+				funds >= campaign.unclaimed.saturating_add(campaign.vesting),
+				Error::<T>::CampaignUnderfunded
+			);
+
+			campaign.expiry = expiry;
+			Campaigns::<T>::insert(campaign_id, campaign);
+
+			Self::deposit_event(Event::<T>::ClaimCampaignExpirySet { campaign_id, expiry });
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -449,6 +990,77 @@ pub mod pallet {
 		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
 			const PRIORITY: u64 = 100;
 
+			if let Call::claim_from_campaign { campaign_id, dest, signer, signature, delegate_to } =
+				call
+			{
+				let data = Self::campaign_claim_payload(*campaign_id, dest, delegate_to);
+				let signer =
+					Self::get_signer_multi_address(signer.clone(), signature.clone(), data, vec![])
+						.map_err(|_| {
+							InvalidTransaction::Custom(
+								ValidityError::InvalidEthereumSignature.into(),
+							)
+						})?;
+
+				let e = InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into());
+				ensure!(CampaignClaims::<T>::contains_key(campaign_id, &signer), e);
+				let expired = Campaigns::<T>::get(campaign_id).map_or(true, |campaign| {
+					frame_system::Pallet::<T>::block_number() > campaign.expiry
+				});
+				ensure!(!expired, InvalidTransaction::Stale);
+
+				return Ok(ValidTransaction {
+					priority: PRIORITY,
+					requires: vec![],
+					provides: vec![("claims", campaign_id, signer).encode()],
+					longevity: TransactionLongevity::max_value(),
+					propagate: true,
+				});
+			}
+
+			if let Call::claim_from_merkle_campaign {
+				campaign_id,
+				index,
+				amount,
+				vesting,
+				proof,
+				dest,
+				signer,
+				signature,
+				delegate_to,
+			} = call
+			{
+				let data = Self::campaign_claim_payload(*campaign_id, dest, delegate_to);
+				let signer =
+					Self::get_signer_multi_address(signer.clone(), signature.clone(), data, vec![])
+						.map_err(|_| {
+							InvalidTransaction::Custom(
+								ValidityError::InvalidEthereumSignature.into(),
+							)
+						})?;
+
+				let campaign = Self::claimable_campaign(*campaign_id)
+					.map_err(|_| InvalidTransaction::Stale)?;
+				let claim = CampaignClaim { amount: *amount, vesting: vesting.clone() };
+				let e = InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into());
+				ensure!(!Self::is_merkle_claimed(*campaign_id, *index), e);
+				let leaf = Self::merkle_leaf(*index, &signer, &claim);
+				ensure!(
+					campaign
+						.merkle_root
+						.map_or(false, |root| verify_merkle_proof(root, leaf, proof)),
+					e
+				);
+
+				return Ok(ValidTransaction {
+					priority: PRIORITY,
+					requires: vec![],
+					provides: vec![("claims", campaign_id, index).encode()],
+					longevity: TransactionLongevity::max_value(),
+					propagate: true,
+				});
+			}
+
 			let (maybe_signer, maybe_statement) = match call {
 				// <weight>
 				// The weight of this logic is included in the `claim` dispatchable.
@@ -489,6 +1101,7 @@ pub mod pallet {
 
 			let e = InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into());
 			ensure!(<Claims<T>>::contains_key(&signer), e);
+			ensure!(!Self::claims_expired(), InvalidTransaction::Stale);
 
 			let e = InvalidTransaction::Custom(ValidityError::InvalidStatement.into());
 			match Signing::<T>::get(signer.clone()) {
@@ -519,6 +1132,28 @@ fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The account of the claims pot, holding the funds of the unclaimed claims.
+	pub fn claims_pot() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Mints the funds the claims pot misses to pay out all unclaimed claims, returning the
+	/// minted amount.
+	///
+	/// The claims are only ever funded through this top-up, so no claim is minted twice.
+	pub(crate) fn fund_claims_pot() -> BalanceOf<T> {
+		let pot = Self::claims_pot();
+		let missing = Self::total().saturating_sub(CurrencyOf::<T>::free_balance(&pot));
+		let _ = CurrencyOf::<T>::deposit_creating(&pot, missing);
+		missing
+	}
+
+	/// Whether the expiry block of the claims has passed.
+	pub fn claims_expired() -> bool {
+		ExpiryConfig::<T>::get()
+			.map_or(false, |(expiry, _)| frame_system::Pallet::<T>::block_number() > expiry)
+	}
+
 	fn encode_multi_address(multi_address: &MultiAddress) -> Vec<u8> {
 		match multi_address {
 			MultiAddress::EVM(address) => address.using_encoded(to_ascii_hex),
@@ -608,6 +1243,7 @@ impl<T: Config> Pallet<T> {
 		dest: Option<MultiAddress>,
 	) -> sp_runtime::DispatchResult {
 		let balance_due = <Claims<T>>::get(&signer).ok_or(Error::<T>::SignerHasNoClaim)?;
+		ensure!(!Self::claims_expired(), Error::<T>::ClaimsExpired);
 
 		let new_total = Self::total().checked_sub(&balance_due).ok_or(Error::<T>::PotUnderflow)?;
 		// If there is a destination, then we need to transfer the balance to it.
@@ -620,8 +1256,13 @@ impl<T: Config> Pallet<T> {
 
 		let vesting = Vesting::<T>::get(&signer);
 
-		// We first need to deposit the balance to ensure that the account exists.
-		let _ = CurrencyOf::<T>::deposit_creating(&recipient, balance_due);
+		// We first need to transfer the balance to ensure that the account exists.
+		CurrencyOf::<T>::transfer(
+			&Self::claims_pot(),
+			&recipient,
+			balance_due,
+			ExistenceRequirement::AllowDeath,
+		)?;
 
 		// Check if this claim should have a vesting schedule.
 		if let Some(vs) = vesting {
@@ -0,0 +1,40 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, STORAGE_VERSION};
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Migration funding the claims pot with the unclaimed funds of the claims.
+///
+/// Claims used to be minted when claimed, so the pot is topped up once to `Total`, the same way
+/// `mint_claim` funds new claims.
+pub struct FundClaimsPot<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for FundClaimsPot<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			log::info!("Claims: claims pot already funded, skipping migration");
+			return T::DbWeight::get().reads(1);
+		}
+
+		let missing = Pallet::<T>::fund_claims_pot();
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		log::info!("Claims: funded the claims pot with {:?}", missing);
+		T::DbWeight::get().reads_writes(3, 4)
+	}
+}
@@ -7,14 +7,15 @@ use sp_std::convert::TryFrom;
 // or public keys. `u64` is used as the `AccountId` and no `Signature`s are required.
 use crate::{pallet as pallet_airdrop_claims, sr25519_utils::sub, tests::get_bounded_vec};
 use frame_support::{
-	derive_impl, ord_parameter_types, parameter_types,
-	traits::{OnFinalize, OnInitialize, WithdrawReasons},
+	PalletId, derive_impl, ord_parameter_types, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU64, OnFinalize, OnInitialize, WithdrawReasons},
 };
 use pallet_balances;
 use sp_runtime::{
-	AccountId32, BuildStorage,
+	AccountId32, BuildStorage, DispatchResult,
 	traits::{BlakeTwo256, Identity},
 };
+use tangle_primitives::{services::Asset, traits::MultiAssetDelegationRestaker};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -24,6 +25,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		VestingPallet: pallet_vesting,
+		Assets: pallet_assets,
 		ClaimsPallet: pallet_airdrop_claims,
 	}
 );
@@ -96,8 +98,50 @@ impl pallet_vesting::Config for Test {
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId32>>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId32>;
+	type AssetDeposit = ConstU64<0>;
+	type AssetAccountDeposit = ConstU64<0>;
+	type MetadataDepositBase = ConstU64<0>;
+	type MetadataDepositPerByte = ConstU64<0>;
+	type ApprovalDeposit = ConstU64<0>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	type Extra = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub static Restaked: Vec<(AccountId32, AccountId32, Asset<u32>, u64)> = vec![];
+}
+
+pub struct MockRestaker;
+
+impl MultiAssetDelegationRestaker<AccountId32, u64, u32> for MockRestaker {
+	fn deposit_and_delegate(
+		who: &AccountId32,
+		operator: &AccountId32,
+		asset: Asset<u32>,
+		amount: u64,
+	) -> DispatchResult {
+		Restaked::mutate(|r| r.push((who.clone(), operator.clone(), asset, amount)));
+		Ok(())
+	}
+}
+
 parameter_types! {
 	pub Prefix: &'static [u8] = b"Pay RUSTs to the TEST account:";
+	pub const ClaimsPalletId: PalletId = PalletId(*b"py/claim");
 }
 ord_parameter_types! {
 	pub const Six: AccountId32 = get_multi_address_account_id(6).to_account_id_32();
@@ -112,6 +156,11 @@ impl Config for Test {
 	type MaxVestingSchedules = ConstU32<8>;
 	type MoveClaimOrigin = frame_system::EnsureSignedBy<Six, AccountId32>;
 	type WeightInfo = ();
+	type AssetId = u32;
+	type Fungibles = Assets;
+	type DelegationRestaker = MockRestaker;
+	type PalletId = ClaimsPalletId;
+	type MaxMerkleProofLength = ConstU32<16>;
 }
 
 pub fn run_to_block(n: u64) {
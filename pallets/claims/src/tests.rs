@@ -31,6 +31,7 @@ pub fn get_bounded_vec<T: Debug>(elt: T) -> BoundedVec<T, ConstU32<8>> {
 fn basic_setup_works() {
 	new_test_ext().execute_with(|| {
 		assert_eq!(ClaimsPallet::total(), total_claims());
+		assert_eq!(Balances::free_balance(ClaimsPallet::claims_pot()), total_claims());
 		assert_eq!(ClaimsPallet::claims(&eth(&alice())), Some(100));
 		assert_eq!(ClaimsPallet::claims(&eth(&dave())), Some(200));
 		assert_eq!(ClaimsPallet::claims(&eth(&eve())), Some(300));
@@ -336,6 +337,7 @@ fn add_claim_works() {
 		);
 		assert_ok!(ClaimsPallet::mint_claim(RuntimeOrigin::root(), eth(&bob()), 200, None, None));
 		assert_eq!(ClaimsPallet::total(), total_claims() + 200);
+		assert_eq!(Balances::free_balance(ClaimsPallet::claims_pot()), total_claims() + 200);
 		assert_ok!(ClaimsPallet::claim(
 			RuntimeOrigin::none(),
 			Some(get_multi_address_account_id(69)),
@@ -355,6 +357,7 @@ fn add_claim_works() {
 			None
 		);
 		assert_eq!(ClaimsPallet::total(), total_claims());
+		assert_eq!(Balances::free_balance(ClaimsPallet::claims_pot()), total_claims());
 	});
 }
 
@@ -770,6 +773,8 @@ fn test_unclaimed_returned_to_destination() {
 	new_test_ext().execute_with(|| {
 		let original_total_claims = Total::<Test>::get();
 		let claim_of_alice = 100;
+		let caller = RuntimeOrigin::signed(get_multi_address_account_id(1).to_account_id_32());
+		let destination = get_multi_address_account_id(100).to_account_id_32();
 		assert_ok!(ClaimsPallet::claim(
 			RuntimeOrigin::none(),
 			Some(get_multi_address_account_id(42)),
@@ -782,24 +787,29 @@ fn test_unclaimed_returned_to_destination() {
 		));
 		assert_eq!(Total::<Test>::get(), original_total_claims - claim_of_alice);
 
+		// nothing can be swept without an expiry
+		assert_noop!(
+			ClaimsPallet::sweep_expired_claims(caller.clone(), None),
+			Error::<Test>::ClaimsNotExpired
+		);
+
 		// force set the expiry config
 		assert_ok!(ClaimsPallet::force_set_expiry_config(
 			RuntimeOrigin::root(),
 			5,
 			get_multi_address_account_id(100)
 		));
+		assert_noop!(
+			ClaimsPallet::sweep_expired_claims(caller.clone(), None),
+			Error::<Test>::ClaimsNotExpired
+		);
 
-		// run to after expiry block
+		// run to after expiry block, nothing is swept on its own
 		run_to_block(7);
-		assert_eq!(Total::<Test>::get(), 0);
-		// the dest account should receive the remaining pot balance
-		assert_eq!(
-			Balances::free_balance(get_multi_address_account_id(100).to_account_id_32()),
-			original_total_claims - claim_of_alice
-		);
+		assert_eq!(Total::<Test>::get(), original_total_claims - claim_of_alice);
+		assert_eq!(Balances::free_balance(&destination), 0);
 
-		// all further claims should fail with PotUnderflow error since the funds have been
-		// emptied
+		// all further claims should fail since the claims expired
 		assert_noop!(
 			ClaimsPallet::claim(
 				RuntimeOrigin::none(),
@@ -811,8 +821,110 @@ fn test_unclaimed_returned_to_destination() {
 					&[][..]
 				)
 			),
-			Error::<Test>::PotUnderflow
+			Error::<Test>::ClaimsExpired
+		);
+
+		// the unclaimed funds can be swept in parts
+		assert_ok!(ClaimsPallet::sweep_expired_claims(caller.clone(), Some(500)));
+		assert_eq!(Total::<Test>::get(), original_total_claims - claim_of_alice - 500);
+		assert_eq!(Balances::free_balance(&destination), 500);
+		assert_eq!(ClaimsPallet::swept(), 500);
+		System::assert_last_event(RuntimeEvent::ClaimsPallet(Event::ExpiredClaimsSwept {
+			destination: destination.clone(),
+			amount: 500,
+		}));
+
+		// the dest account should receive the remaining pot balance
+		assert_ok!(ClaimsPallet::sweep_expired_claims(caller.clone(), None));
+		assert_eq!(Total::<Test>::get(), 0);
+		assert_eq!(Balances::free_balance(&destination), original_total_claims - claim_of_alice);
+		assert_eq!(ClaimsPallet::swept(), original_total_claims - claim_of_alice);
+		assert_eq!(Balances::free_balance(ClaimsPallet::claims_pot()), 0);
+		assert_noop!(
+			ClaimsPallet::sweep_expired_claims(caller, None),
+			Error::<Test>::NothingToSweep
+		);
+
+		// the claims cannot be reopened once their funds were swept
+		assert_noop!(
+			ClaimsPallet::force_set_expiry_config(
+				RuntimeOrigin::root(),
+				10,
+				get_multi_address_account_id(100)
+			),
+			Error::<Test>::ClaimsSwept
+		);
+	});
+}
+
+#[test]
+fn claims_pot_is_funded_once() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		// claims used to be minted when claimed.
+		let pot = ClaimsPallet::claims_pot();
+		let _ = <Balances as Currency<_>>::slash(&pot, total_claims());
+		StorageVersion::new(0).put::<ClaimsPallet>();
+		let issuance = <Balances as Currency<_>>::total_issuance();
+
+		// when
+		crate::migrations::FundClaimsPot::<Test>::on_runtime_upgrade();
+
+		// then
+		assert_eq!(Balances::free_balance(&pot), total_claims());
+		assert_eq!(<Balances as Currency<_>>::total_issuance(), issuance + total_claims());
+		assert_eq!(ClaimsPallet::on_chain_storage_version(), crate::STORAGE_VERSION);
+
+		// new claims only mint their own value, and the migration does not run again.
+		assert_ok!(ClaimsPallet::mint_claim(RuntimeOrigin::root(), eth(&bob()), 200, None, None));
+		crate::migrations::FundClaimsPot::<Test>::on_runtime_upgrade();
+		assert_eq!(Balances::free_balance(&pot), total_claims() + 200);
+		assert_eq!(<Balances as Currency<_>>::total_issuance(), issuance + total_claims() + 200);
+	});
+}
+
+#[test]
+fn expiry_can_be_extended() {
+	use sp_runtime::traits::ValidateUnsigned;
+	let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+	new_test_ext().execute_with(|| {
+		let dest = get_multi_address_account_id(42);
+		let signature = sig::<Test>(&alice(), &dest.to_account_id_32().encode(), &[][..]);
+		assert_ok!(ClaimsPallet::force_set_expiry_config(
+			RuntimeOrigin::root(),
+			5,
+			get_multi_address_account_id(100)
+		));
+		run_to_block(7);
+		assert_noop!(
+			ClaimsPallet::claim(RuntimeOrigin::none(), Some(dest.clone()), None, signature.clone()),
+			Error::<Test>::ClaimsExpired
+		);
+		assert_eq!(
+			<Pallet<Test>>::validate_unsigned(
+				source,
+				&ClaimsCall::claim {
+					dest: Some(dest.clone()),
+					signer: None,
+					signature: signature.clone()
+				}
+			),
+			InvalidTransaction::Stale.into(),
 		);
+
+		// when
+		assert_ok!(ClaimsPallet::force_set_expiry_config(
+			RuntimeOrigin::root(),
+			10,
+			get_multi_address_account_id(100)
+		));
+
+		// then
+		assert_ok!(ClaimsPallet::claim(RuntimeOrigin::none(), Some(dest.clone()), None, signature));
+		assert_eq!(Balances::free_balance(dest.to_account_id_32()), 100);
+		assert_eq!(Balances::free_balance(ClaimsPallet::claims_pot()), total_claims() - 100);
 	});
 }
 
@@ -857,8 +969,12 @@ fn test_claim_from_substrate_address_to_evm() {
 			get_multi_address_account_id(100)
 		));
 
-		// run to after expiry block
+		// run to after expiry block and sweep
 		run_to_block(7);
+		assert_ok!(ClaimsPallet::sweep_expired_claims(
+			RuntimeOrigin::signed(get_multi_address_account_id(1).to_account_id_32()),
+			None
+		));
 		assert_eq!(Total::<Test>::get(), 0);
 		// the dest account should receive the remaining pot balance
 		assert_eq!(
@@ -866,8 +982,7 @@ fn test_claim_from_substrate_address_to_evm() {
 			original_total_claims - claim_of_sub_alice
 		);
 
-		// all further claims should fail with PotUnderflow error since the funds have been
-		// emptied
+		// all further claims should fail since the claims expired
 		assert_noop!(
 			ClaimsPallet::claim(
 				RuntimeOrigin::none(),
@@ -879,7 +994,7 @@ fn test_claim_from_substrate_address_to_evm() {
 					&[][..]
 				)
 			),
-			Error::<Test>::PotUnderflow
+			Error::<Test>::ClaimsExpired
 		);
 	});
 }
@@ -935,3 +1050,839 @@ fn test_double_claim_fails_for_substrate_account() {
 		);
 	});
 }
+
+fn create_native_campaign(expiry: u64, funds: u64) -> CampaignId {
+	let funder = get_multi_address_account_id(1).to_account_id_32();
+	let _ = Balances::deposit_creating(&funder, funds + 1);
+	let campaign_id = NextCampaignId::<Test>::get();
+	assert_ok!(ClaimsPallet::create_claim_campaign(
+		RuntimeOrigin::root(),
+		Asset::Custom(0),
+		expiry,
+		get_multi_address_account_id(7),
+	));
+	assert_ok!(ClaimsPallet::fund_claim_campaign(
+		RuntimeOrigin::signed(funder),
+		campaign_id,
+		funds
+	));
+	campaign_id
+}
+
+fn campaign_sig(
+	secret: &libsecp256k1::SecretKey,
+	campaign_id: CampaignId,
+	dest: &Option<MultiAddress>,
+	delegate_to: &Option<AccountId32>,
+) -> MultiAddressSignature {
+	sig::<Test>(secret, &(campaign_id, dest, delegate_to).encode(), &[][..])
+}
+
+#[test]
+fn create_claim_campaign_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			ClaimsPallet::create_claim_campaign(
+				RuntimeOrigin::signed(get_multi_address_account_id(1).to_account_id_32()),
+				Asset::Custom(0),
+				100,
+				get_multi_address_account_id(7),
+			),
+			BadOrigin
+		);
+		// only the native currency and existing assets can be paid out.
+		assert_noop!(
+			ClaimsPallet::create_claim_campaign(
+				RuntimeOrigin::root(),
+				Asset::Custom(1),
+				100,
+				get_multi_address_account_id(7),
+			),
+			Error::<Test>::UnsupportedAsset
+		);
+		assert_noop!(
+			ClaimsPallet::create_claim_campaign(
+				RuntimeOrigin::root(),
+				Asset::Erc20(Default::default()),
+				100,
+				get_multi_address_account_id(7),
+			),
+			Error::<Test>::UnsupportedAsset
+		);
+
+		let campaign_id = create_native_campaign(100, 1000);
+
+		assert_eq!(campaign_id, 0);
+		assert_eq!(
+			Campaigns::<Test>::get(campaign_id),
+			Some(ClaimCampaign {
+				asset: Asset::Custom(0),
+				expiry: 100,
+				sweep_destination: get_multi_address_account_id(7).to_account_id_32(),
+				unclaimed: 0,
+				vesting: 0,
+				merkle_root: None,
+				swept: 0,
+			})
+		);
+		assert_eq!(Balances::free_balance(ClaimsPallet::campaign_account(campaign_id)), 1000);
+		// claims cannot exceed the funds of the campaign.
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&alice()),
+			600,
+			Default::default(),
+		));
+		assert_noop!(
+			ClaimsPallet::mint_campaign_claim(
+				RuntimeOrigin::root(),
+				campaign_id,
+				eth(&bob()),
+				401,
+				Default::default(),
+			),
+			Error::<Test>::CampaignUnderfunded
+		);
+		assert_noop!(
+			ClaimsPallet::mint_campaign_claim(
+				RuntimeOrigin::root(),
+				campaign_id,
+				eth(&bob()),
+				100,
+				BoundedVec::truncate_from(vec![VestingStage {
+					amount: 101,
+					start: 1,
+					duration: 0
+				}]),
+			),
+			Error::<Test>::InvalidVestingSchedule
+		);
+		assert_noop!(
+			ClaimsPallet::mint_campaign_claim(
+				RuntimeOrigin::root(),
+				campaign_id,
+				eth(&alice()),
+				100,
+				Default::default(),
+			),
+			Error::<Test>::ClaimAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn campaign_claim_with_vesting_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let campaign_id = create_native_campaign(100, 1000);
+		// a cliff of 100 at block 10, followed by 100 unlocking linearly over 10 blocks.
+		let vesting = BoundedVec::truncate_from(vec![
+			VestingStage { amount: 100, start: 10, duration: 0 },
+			VestingStage { amount: 100, start: 10, duration: 10 },
+		]);
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&alice()),
+			300,
+			vesting,
+		));
+		let dest = Some(get_multi_address_account_id(42));
+		let recipient = get_multi_address_account_id(42).to_account_id_32();
+
+		// the signature binds the destination.
+		assert_noop!(
+			ClaimsPallet::claim_from_campaign(
+				RuntimeOrigin::none(),
+				campaign_id,
+				Some(get_multi_address_account_id(43)),
+				None,
+				campaign_sig(&alice(), campaign_id, &dest, &None),
+				None,
+			),
+			Error::<Test>::SignerHasNoClaim
+		);
+
+		// when
+		assert_ok!(ClaimsPallet::claim_from_campaign(
+			RuntimeOrigin::none(),
+			campaign_id,
+			dest.clone(),
+			None,
+			campaign_sig(&alice(), campaign_id, &dest, &None),
+			None,
+		));
+
+		// then only the part not vesting is paid out.
+		assert_eq!(Balances::free_balance(&recipient), 100);
+		System::assert_last_event(RuntimeEvent::ClaimsPallet(Event::CampaignClaimed {
+			campaign_id,
+			recipient: recipient.clone(),
+			source: eth(&alice()),
+			amount: 300,
+			delegated_to: None,
+		}));
+		let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+		assert_eq!((campaign.unclaimed, campaign.vesting), (0, 200));
+		assert_noop!(
+			ClaimsPallet::release_vested_claim(
+				RuntimeOrigin::signed(recipient.clone()),
+				campaign_id
+			),
+			Error::<Test>::NothingToRelease
+		);
+
+		// when the cliff passed and half of the linear stage unlocked
+		System::set_block_number(15);
+		assert_ok!(ClaimsPallet::release_vested_claim(
+			RuntimeOrigin::signed(recipient.clone()),
+			campaign_id
+		));
+
+		// then
+		assert_eq!(Balances::free_balance(&recipient), 250);
+		System::assert_last_event(RuntimeEvent::ClaimsPallet(Event::VestedClaimReleased {
+			campaign_id,
+			who: recipient.clone(),
+			amount: 150,
+		}));
+
+		// when everything unlocked
+		System::set_block_number(25);
+		assert_ok!(ClaimsPallet::release_vested_claim(
+			RuntimeOrigin::signed(recipient.clone()),
+			campaign_id
+		));
+
+		// then
+		assert_eq!(Balances::free_balance(&recipient), 300);
+		assert_eq!(Campaigns::<Test>::get(campaign_id).unwrap().vesting, 0);
+		assert_eq!(CampaignVestings::<Test>::get(campaign_id, &recipient), None);
+		assert_eq!(Balances::free_balance(ClaimsPallet::campaign_account(campaign_id)), 700);
+	});
+}
+
+#[test]
+fn vesting_claims_to_the_same_recipient_are_merged() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let campaign_id = create_native_campaign(100, 1000);
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&alice()),
+			300,
+			BoundedVec::truncate_from(vec![
+				VestingStage { amount: 100, start: 10, duration: 0 },
+				VestingStage { amount: 100, start: 10, duration: 10 },
+			]),
+		));
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&bob()),
+			200,
+			BoundedVec::truncate_from(vec![
+				VestingStage { amount: 50, start: 10, duration: 0 },
+				VestingStage { amount: 100, start: 20, duration: 0 },
+			]),
+		));
+		let dest = Some(get_multi_address_account_id(42));
+		let recipient = get_multi_address_account_id(42).to_account_id_32();
+		assert_ok!(ClaimsPallet::claim_from_campaign(
+			RuntimeOrigin::none(),
+			campaign_id,
+			dest.clone(),
+			None,
+			campaign_sig(&alice(), campaign_id, &dest, &None),
+			None,
+		));
+
+		// when another claim vesting for the same recipient is paid
+		assert_ok!(ClaimsPallet::claim_from_campaign(
+			RuntimeOrigin::none(),
+			campaign_id,
+			dest.clone(),
+			None,
+			campaign_sig(&bob(), campaign_id, &dest, &None),
+			None,
+		));
+
+		// then the stages unlocking over the same blocks are merged.
+		assert_eq!(Balances::free_balance(&recipient), 150);
+		assert_eq!(
+			CampaignVestings::<Test>::get(campaign_id, &recipient)
+				.unwrap()
+				.stages
+				.into_inner(),
+			vec![
+				VestingStage { amount: 150, start: 10, duration: 0 },
+				VestingStage { amount: 100, start: 10, duration: 10 },
+				VestingStage { amount: 100, start: 20, duration: 0 },
+			]
+		);
+		assert_eq!(Campaigns::<Test>::get(campaign_id).unwrap().vesting, 350);
+
+		System::set_block_number(20);
+		assert_ok!(ClaimsPallet::release_vested_claim(
+			RuntimeOrigin::signed(recipient.clone()),
+			campaign_id
+		));
+		assert_eq!(Balances::free_balance(&recipient), 500);
+		assert_eq!(CampaignVestings::<Test>::get(campaign_id, &recipient), None);
+	});
+}
+
+#[test]
+fn campaign_claim_in_asset_can_be_delegated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let funder = get_multi_address_account_id(1).to_account_id_32();
+		let operator = get_multi_address_account_id(9).to_account_id_32();
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, funder.clone(), true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(funder.clone()), 1, funder.clone(), 1001));
+		assert_ok!(ClaimsPallet::create_claim_campaign(
+			RuntimeOrigin::root(),
+			Asset::Custom(1),
+			100,
+			get_multi_address_account_id(7),
+		));
+		assert_ok!(ClaimsPallet::fund_claim_campaign(RuntimeOrigin::signed(funder), 0, 1000));
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			0,
+			sr25519_utils::sub(&alice_sr25519()),
+			500,
+			Default::default(),
+		));
+		let dest = Some(get_multi_address_account_id(42));
+		let recipient = get_multi_address_account_id(42).to_account_id_32();
+		let delegate_to = Some(operator.clone());
+
+		// when
+		assert_ok!(ClaimsPallet::claim_from_campaign(
+			RuntimeOrigin::none(),
+			0,
+			dest.clone(),
+			Some(sr25519_utils::sub(&alice_sr25519())),
+			sr25519_utils::sig::<Test>(
+				&alice_sr25519(),
+				&(0u32, &dest, &delegate_to).encode(),
+				&[][..]
+			),
+			delegate_to.clone(),
+		));
+
+		// then
+		assert_eq!(Assets::balance(1, &recipient), 500);
+		assert_eq!(Assets::balance(1, ClaimsPallet::campaign_account(0)), 500);
+		assert_eq!(Restaked::get(), vec![(recipient, operator, Asset::Custom(1), 500)]);
+	});
+}
+
+#[test]
+fn expired_campaign_can_be_swept() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let campaign_id = create_native_campaign(10, 1000);
+		let vesting =
+			BoundedVec::truncate_from(vec![VestingStage { amount: 100, start: 20, duration: 0 }]);
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&alice()),
+			200,
+			vesting,
+		));
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&bob()),
+			300,
+			Default::default(),
+		));
+		let dest = Some(get_multi_address_account_id(42));
+		assert_ok!(ClaimsPallet::claim_from_campaign(
+			RuntimeOrigin::none(),
+			campaign_id,
+			dest.clone(),
+			None,
+			campaign_sig(&alice(), campaign_id, &dest, &None),
+			None,
+		));
+		let caller = RuntimeOrigin::signed(get_multi_address_account_id(1).to_account_id_32());
+		assert_noop!(
+			ClaimsPallet::sweep_claim_campaign(caller.clone(), campaign_id, None),
+			Error::<Test>::CampaignNotExpired
+		);
+
+		// when
+		System::set_block_number(11);
+
+		// then the campaign cannot be claimed from anymore.
+		assert_noop!(
+			ClaimsPallet::claim_from_campaign(
+				RuntimeOrigin::none(),
+				campaign_id,
+				dest.clone(),
+				None,
+				campaign_sig(&bob(), campaign_id, &dest, &None),
+				None,
+			),
+			Error::<Test>::CampaignExpired
+		);
+		// and everything but the vesting funds is swept.
+		assert_ok!(ClaimsPallet::sweep_claim_campaign(caller.clone(), campaign_id, None));
+		assert_eq!(Balances::free_balance(get_multi_address_account_id(7).to_account_id_32()), 800);
+		System::assert_last_event(RuntimeEvent::ClaimsPallet(Event::ClaimCampaignSwept {
+			campaign_id,
+			destination: get_multi_address_account_id(7).to_account_id_32(),
+			amount: 800,
+		}));
+		assert_noop!(
+			ClaimsPallet::sweep_claim_campaign(caller, campaign_id, None),
+			Error::<Test>::NothingToSweep
+		);
+
+		// vesting funds can still be released.
+		System::set_block_number(20);
+		assert_ok!(ClaimsPallet::release_vested_claim(
+			RuntimeOrigin::signed(get_multi_address_account_id(42).to_account_id_32()),
+			campaign_id
+		));
+		assert_eq!(
+			Balances::free_balance(get_multi_address_account_id(42).to_account_id_32()),
+			200
+		);
+	});
+}
+
+#[test]
+fn expired_campaign_can_be_extended_until_swept() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let campaign_id = create_native_campaign(10, 1000);
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&bob()),
+			300,
+			Default::default(),
+		));
+		let caller = RuntimeOrigin::signed(get_multi_address_account_id(1).to_account_id_32());
+		let sweep_destination = get_multi_address_account_id(7).to_account_id_32();
+		assert_noop!(
+			ClaimsPallet::set_claim_campaign_expiry(caller.clone(), campaign_id, 20),
+			BadOrigin
+		);
+		assert_noop!(
+			ClaimsPallet::set_claim_campaign_expiry(RuntimeOrigin::root(), 99, 20),
+			Error::<Test>::CampaignNotFound
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			ClaimsPallet::set_claim_campaign_expiry(RuntimeOrigin::root(), campaign_id, 5),
+			Error::<Test>::CampaignExpired
+		);
+
+		// when the expired campaign is extended
+		assert_ok!(ClaimsPallet::set_claim_campaign_expiry(RuntimeOrigin::root(), campaign_id, 20));
+		System::assert_last_event(RuntimeEvent::ClaimsPallet(Event::ClaimCampaignExpirySet {
+			campaign_id,
+			expiry: 20,
+		}));
+
+		// then it can be claimed from again, but not swept.
+		assert_noop!(
+			ClaimsPallet::sweep_claim_campaign(caller.clone(), campaign_id, None),
+			Error::<Test>::CampaignNotExpired
+		);
+		let dest = Some(get_multi_address_account_id(42));
+		assert_ok!(ClaimsPallet::claim_from_campaign(
+			RuntimeOrigin::none(),
+			campaign_id,
+			dest.clone(),
+			None,
+			campaign_sig(&bob(), campaign_id, &dest, &None),
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(get_multi_address_account_id(42).to_account_id_32()),
+			300
+		);
+
+		// when part of the funds is swept once it expired again
+		System::set_block_number(21);
+		assert_ok!(ClaimsPallet::sweep_claim_campaign(caller.clone(), campaign_id, Some(400)));
+
+		// then the campaign cannot be reopened anymore.
+		assert_eq!(Balances::free_balance(&sweep_destination), 400);
+		assert_noop!(
+			ClaimsPallet::set_claim_campaign_expiry(RuntimeOrigin::root(), campaign_id, 30),
+			Error::<Test>::CampaignSwept
+		);
+
+		// and the rest can be swept.
+		assert_ok!(ClaimsPallet::sweep_claim_campaign(caller, campaign_id, None));
+		assert_eq!(Balances::free_balance(&sweep_destination), 700);
+		let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+		assert_eq!((campaign.unclaimed, campaign.swept), (0, 700));
+	});
+}
+
+#[test]
+fn swept_claims_of_a_campaign_cannot_be_claimed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let campaign_id = create_native_campaign(10, 1000);
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&bob()),
+			300,
+			Default::default(),
+		));
+		let caller = RuntimeOrigin::signed(get_multi_address_account_id(1).to_account_id_32());
+
+		// when the funds of the claims are swept
+		System::set_block_number(11);
+		assert_ok!(ClaimsPallet::sweep_claim_campaign(caller, campaign_id, Some(800)));
+		assert_eq!(Campaigns::<Test>::get(campaign_id).unwrap().unclaimed, 200);
+
+		// then the campaign cannot be reopened for the claims.
+		assert_noop!(
+			ClaimsPallet::set_claim_campaign_expiry(RuntimeOrigin::root(), campaign_id, 20),
+			Error::<Test>::CampaignSwept
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_campaign_claim_works() {
+	use sp_runtime::traits::ValidateUnsigned;
+	let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+	new_test_ext().execute_with(|| {
+		let campaign_id = create_native_campaign(10, 1000);
+		assert_ok!(ClaimsPallet::mint_campaign_claim(
+			RuntimeOrigin::root(),
+			campaign_id,
+			eth(&alice()),
+			100,
+			Default::default(),
+		));
+		let dest = Some(get_multi_address_account_id(42));
+
+		assert_eq!(
+			<Pallet<Test>>::validate_unsigned(
+				source,
+				&ClaimsCall::claim_from_campaign {
+					campaign_id,
+					dest: dest.clone(),
+					signer: None,
+					signature: campaign_sig(&alice(), campaign_id, &dest, &None),
+					delegate_to: None,
+				}
+			),
+			Ok(ValidTransaction {
+				priority: 100,
+				requires: vec![],
+				provides: vec![("claims", campaign_id, eth(&alice())).encode()],
+				longevity: TransactionLongevity::max_value(),
+				propagate: true,
+			})
+		);
+		assert_eq!(
+			<Pallet<Test>>::validate_unsigned(
+				source,
+				&ClaimsCall::claim_from_campaign {
+					campaign_id,
+					dest: dest.clone(),
+					signer: None,
+					signature: campaign_sig(&bob(), campaign_id, &dest, &None),
+					delegate_to: None,
+				}
+			),
+			InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into()).into(),
+		);
+
+		System::set_block_number(11);
+		assert_eq!(
+			<Pallet<Test>>::validate_unsigned(
+				source,
+				&ClaimsCall::claim_from_campaign {
+					campaign_id,
+					dest: dest.clone(),
+					signer: None,
+					signature: campaign_sig(&alice(), campaign_id, &dest, &None),
+					delegate_to: None,
+				}
+			),
+			InvalidTransaction::Stale.into(),
+		);
+	});
+}
+
+/// Builds the claims Merkle tree of `leaves`, returning its root and the proof of every leaf.
+fn merkle_tree(leaves: Vec<H256>) -> (H256, Vec<Vec<H256>>) {
+	let mut proofs = vec![vec![]; leaves.len()];
+	let mut positions = (0..leaves.len()).collect::<Vec<_>>();
+	let mut level = leaves;
+	while level.len() > 1 {
+		for (leaf, position) in positions.iter_mut().enumerate() {
+			if let Some(sibling) = level.get(*position ^ 1) {
+				proofs[leaf].push(*sibling);
+			}
+			*position /= 2;
+		}
+		level = level
+			.chunks(2)
+			.map(|pair| if let [a, b] = pair { merkle_node(*a, *b) } else { pair[0] })
+			.collect();
+	}
+	(level[0], proofs)
+}
+
+fn merkle_claims() -> Vec<(MultiAddress, CampaignClaim<u64, u64, ConstU32<8>>)> {
+	vec![
+		(eth(&alice()), CampaignClaim { amount: 300, vesting: Default::default() }),
+		(
+			eth(&bob()),
+			CampaignClaim {
+				amount: 200,
+				vesting: BoundedVec::truncate_from(vec![VestingStage {
+					amount: 100,
+					start: 10,
+					duration: 0,
+				}]),
+			},
+		),
+		(
+			sr25519_utils::sub(&alice_sr25519()),
+			CampaignClaim { amount: 100, vesting: Default::default() },
+		),
+	]
+}
+
+fn create_merkle_campaign() -> (CampaignId, Vec<Vec<H256>>) {
+	let campaign_id = create_native_campaign(100, 1000);
+	let leaves = merkle_claims()
+		.iter()
+		.enumerate()
+		.map(|(index, (who, claim))| ClaimsPallet::merkle_leaf(index as u32, who, claim))
+		.collect();
+	let (root, proofs) = merkle_tree(leaves);
+	assert_ok!(ClaimsPallet::set_campaign_merkle_root(
+		RuntimeOrigin::root(),
+		campaign_id,
+		root,
+		600
+	));
+	(campaign_id, proofs)
+}
+
+#[test]
+fn set_campaign_merkle_root_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let campaign_id = create_native_campaign(100, 1000);
+		let root = H256::repeat_byte(1);
+		assert_noop!(
+			ClaimsPallet::set_campaign_merkle_root(
+				RuntimeOrigin::signed(get_multi_address_account_id(1).to_account_id_32()),
+				campaign_id,
+				root,
+				600
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			ClaimsPallet::set_campaign_merkle_root(RuntimeOrigin::root(), campaign_id, root, 1001),
+			Error::<Test>::CampaignUnderfunded
+		);
+
+		// when
+		assert_ok!(ClaimsPallet::set_campaign_merkle_root(
+			RuntimeOrigin::root(),
+			campaign_id,
+			root,
+			600
+		));
+
+		// then
+		let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+		assert_eq!((campaign.merkle_root, campaign.unclaimed), (Some(root), 600));
+		System::assert_last_event(RuntimeEvent::ClaimsPallet(Event::CampaignMerkleRootSet {
+			campaign_id,
+			merkle_root: root,
+			total: 600,
+		}));
+		assert_noop!(
+			ClaimsPallet::set_campaign_merkle_root(RuntimeOrigin::root(), campaign_id, root, 0),
+			Error::<Test>::MerkleRootAlreadySet
+		);
+	});
+}
+
+#[test]
+fn merkle_campaign_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (campaign_id, proofs) = create_merkle_campaign();
+		let claims = merkle_claims();
+		let dest = Some(get_multi_address_account_id(42));
+		let claim = |index: usize, amount: u64, signature| {
+			ClaimsPallet::claim_from_merkle_campaign(
+				RuntimeOrigin::none(),
+				campaign_id,
+				index as u32,
+				amount,
+				claims[index].1.vesting.clone(),
+				BoundedVec::truncate_from(proofs[index].clone()),
+				dest.clone(),
+				None,
+				signature,
+				None,
+			)
+		};
+
+		// the claim must match its leaf.
+		assert_noop!(
+			claim(0, 301, campaign_sig(&alice(), campaign_id, &dest, &None)),
+			Error::<Test>::InvalidMerkleProof
+		);
+		assert_noop!(
+			claim(0, 300, campaign_sig(&bob(), campaign_id, &dest, &None)),
+			Error::<Test>::InvalidMerkleProof
+		);
+
+		// when
+		assert_ok!(claim(0, 300, campaign_sig(&alice(), campaign_id, &dest, &None)));
+
+		// then
+		assert_eq!(
+			Balances::free_balance(get_multi_address_account_id(42).to_account_id_32()),
+			300
+		);
+		assert!(ClaimsPallet::is_merkle_claimed(campaign_id, 0));
+		assert!(!ClaimsPallet::is_merkle_claimed(campaign_id, 1));
+		System::assert_last_event(RuntimeEvent::ClaimsPallet(Event::CampaignClaimed {
+			campaign_id,
+			recipient: get_multi_address_account_id(42).to_account_id_32(),
+			source: eth(&alice()),
+			amount: 300,
+			delegated_to: None,
+		}));
+		assert_noop!(
+			claim(0, 300, campaign_sig(&alice(), campaign_id, &dest, &None)),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		// vesting leaves vest like minted claims.
+		let dest = Some(get_multi_address_account_id(43));
+		assert_ok!(ClaimsPallet::claim_from_merkle_campaign(
+			RuntimeOrigin::none(),
+			campaign_id,
+			1,
+			200,
+			claims[1].1.vesting.clone(),
+			BoundedVec::truncate_from(proofs[1].clone()),
+			dest.clone(),
+			None,
+			campaign_sig(&bob(), campaign_id, &dest, &None),
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(get_multi_address_account_id(43).to_account_id_32()),
+			100
+		);
+		let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+		assert_eq!((campaign.unclaimed, campaign.vesting), (100, 100));
+
+		// sr25519 signers claim with their native address.
+		assert_ok!(ClaimsPallet::claim_from_merkle_campaign(
+			RuntimeOrigin::none(),
+			campaign_id,
+			2,
+			100,
+			Default::default(),
+			BoundedVec::truncate_from(proofs[2].clone()),
+			dest.clone(),
+			Some(sr25519_utils::sub(&alice_sr25519())),
+			sr25519_utils::sig::<Test>(
+				&alice_sr25519(),
+				&(campaign_id, &dest, &None::<AccountId32>).encode(),
+				&[][..]
+			),
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(get_multi_address_account_id(43).to_account_id_32()),
+			200
+		);
+		assert_eq!(Campaigns::<Test>::get(campaign_id).unwrap().unclaimed, 0);
+	});
+}
+
+#[test]
+fn merkle_claim_requires_merkle_campaign() {
+	new_test_ext().execute_with(|| {
+		let campaign_id = create_native_campaign(100, 1000);
+		let dest = Some(get_multi_address_account_id(42));
+		assert_noop!(
+			ClaimsPallet::claim_from_merkle_campaign(
+				RuntimeOrigin::none(),
+				campaign_id,
+				0,
+				300,
+				Default::default(),
+				Default::default(),
+				dest.clone(),
+				None,
+				campaign_sig(&alice(), campaign_id, &dest, &None),
+				None,
+			),
+			Error::<Test>::NotMerkleCampaign
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_merkle_claim_works() {
+	use sp_runtime::traits::ValidateUnsigned;
+	let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+	new_test_ext().execute_with(|| {
+		let (campaign_id, proofs) = create_merkle_campaign();
+		let dest = Some(get_multi_address_account_id(42));
+		let call = |amount: u64| ClaimsCall::claim_from_merkle_campaign {
+			campaign_id,
+			index: 0,
+			amount,
+			vesting: Default::default(),
+			proof: BoundedVec::truncate_from(proofs[0].clone()),
+			dest: dest.clone(),
+			signer: None,
+			signature: campaign_sig(&alice(), campaign_id, &dest, &None),
+			delegate_to: None,
+		};
+
+		assert_eq!(
+			<Pallet<Test>>::validate_unsigned(source, &call(300)),
+			Ok(ValidTransaction {
+				priority: 100,
+				requires: vec![],
+				provides: vec![("claims", campaign_id, 0u32).encode()],
+				longevity: TransactionLongevity::max_value(),
+				propagate: true,
+			})
+		);
+		assert_eq!(
+			<Pallet<Test>>::validate_unsigned(source, &call(301)),
+			InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into()).into(),
+		);
+	});
+}
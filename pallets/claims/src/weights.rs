@@ -38,6 +38,16 @@ pub trait WeightInfo {
 	fn claim_attest() -> Weight;
 	fn move_claim() -> Weight;
 	fn force_set_expiry_config() -> Weight;
+	fn create_claim_campaign() -> Weight;
+	fn fund_claim_campaign() -> Weight;
+	fn mint_campaign_claim() -> Weight;
+	fn claim_from_campaign() -> Weight;
+	fn release_vested_claim() -> Weight;
+	fn sweep_claim_campaign() -> Weight;
+	fn set_campaign_merkle_root() -> Weight;
+	fn claim_from_merkle_campaign(p: u32, ) -> Weight;
+	fn sweep_expired_claims() -> Weight;
+	fn set_claim_campaign_expiry() -> Weight;
 }
 
 /// Weights for `pallet_airdrop_claims` using the Substrate node and recommended hardware.
@@ -53,7 +63,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Claims::Vesting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1169), added: 3644, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `Claims::ExpiryConfig` (r:1 w:0)
+	/// Proof: `Claims::ExpiryConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
@@ -65,8 +77,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4764`
 		// Minimum execution time: 157_000_000 picoseconds.
 		Weight::from_parts(265_000_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Claims::Total` (r:1 w:1)
 	/// Proof: `Claims::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -76,14 +88,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Claims::Signing` (r:0 w:1)
 	/// Proof: `Claims::Signing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn mint_claim() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `145`
-		//  Estimated: `1630`
+		//  Estimated: `3593`
 		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 1630)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Claims::Claims` (r:1 w:1)
 	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -95,7 +109,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Claims::Vesting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1169), added: 3644, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `Claims::ExpiryConfig` (r:1 w:0)
+	/// Proof: `Claims::ExpiryConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
@@ -107,8 +123,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4764`
 		// Minimum execution time: 161_000_000 picoseconds.
 		Weight::from_parts(252_000_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Claims::Claims` (r:1 w:2)
 	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -135,6 +151,155 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Claims::NextCampaignId` (r:1 w:1)
+	/// Proof: `Claims::NextCampaignId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::Campaigns` (r:0 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_claim_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1561`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 1561)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:0)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fund_claim_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `390`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(61_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignClaims` (r:1 w:1)
+	/// Proof: `Claims::CampaignClaims` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn mint_campaign_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3816`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3816)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignClaims` (r:1 w:1)
+	/// Proof: `Claims::CampaignClaims` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignVestings` (r:1 w:1)
+	/// Proof: `Claims::CampaignVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_from_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `6196`
+		// Minimum execution time: 148_000_000 picoseconds.
+		Weight::from_parts(171_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignVestings` (r:1 w:1)
+	/// Proof: `Claims::CampaignVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_vested_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(67_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sweep_claim_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `434`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_campaign_merkle_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3816`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3816)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::MerkleClaimed` (r:1 w:1)
+	/// Proof: `Claims::MerkleClaimed` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignVestings` (r:1 w:1)
+	/// Proof: `Claims::CampaignVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim_from_merkle_campaign(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `6196`
+		// Minimum execution time: 152_000_000 picoseconds.
+		Weight::from_parts(168_412_000, 6196)
+			// Standard Error: 4_120
+			.saturating_add(Weight::from_parts(1_893_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Claims::ExpiryConfig` (r:1 w:0)
+	/// Proof: `Claims::ExpiryConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::Total` (r:1 w:1)
+	/// Proof: `Claims::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::Swept` (r:1 w:1)
+	/// Proof: `Claims::Swept` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn sweep_expired_claims() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `6196`
+		// Minimum execution time: 51_000_000 picoseconds.
+		Weight::from_parts(58_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_claim_campaign_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3816`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 3816)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -149,7 +314,9 @@ impl WeightInfo for () {
 	/// Proof: `Claims::Vesting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1169), added: 3644, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `Claims::ExpiryConfig` (r:1 w:0)
+	/// Proof: `Claims::ExpiryConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
@@ -161,8 +328,8 @@ impl WeightInfo for () {
 		//  Estimated: `4764`
 		// Minimum execution time: 157_000_000 picoseconds.
 		Weight::from_parts(265_000_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Claims::Total` (r:1 w:1)
 	/// Proof: `Claims::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -172,14 +339,16 @@ impl WeightInfo for () {
 	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Claims::Signing` (r:0 w:1)
 	/// Proof: `Claims::Signing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn mint_claim() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `145`
-		//  Estimated: `1630`
+		//  Estimated: `3593`
 		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 1630)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Claims::Claims` (r:1 w:1)
 	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -191,7 +360,9 @@ impl WeightInfo for () {
 	/// Proof: `Claims::Vesting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1169), added: 3644, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `Claims::ExpiryConfig` (r:1 w:0)
+	/// Proof: `Claims::ExpiryConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
@@ -203,8 +374,8 @@ impl WeightInfo for () {
 		//  Estimated: `4764`
 		// Minimum execution time: 161_000_000 picoseconds.
 		Weight::from_parts(252_000_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Claims::Claims` (r:1 w:2)
 	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -231,4 +402,153 @@ impl WeightInfo for () {
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Claims::NextCampaignId` (r:1 w:1)
+	/// Proof: `Claims::NextCampaignId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::Campaigns` (r:0 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_claim_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1561`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 1561)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:0)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fund_claim_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `390`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(61_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignClaims` (r:1 w:1)
+	/// Proof: `Claims::CampaignClaims` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn mint_campaign_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3816`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3816)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignClaims` (r:1 w:1)
+	/// Proof: `Claims::CampaignClaims` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignVestings` (r:1 w:1)
+	/// Proof: `Claims::CampaignVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_from_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `6196`
+		// Minimum execution time: 148_000_000 picoseconds.
+		Weight::from_parts(171_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignVestings` (r:1 w:1)
+	/// Proof: `Claims::CampaignVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_vested_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(67_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sweep_claim_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `434`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_campaign_merkle_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3816`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3816)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::MerkleClaimed` (r:1 w:1)
+	/// Proof: `Claims::MerkleClaimed` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::CampaignVestings` (r:1 w:1)
+	/// Proof: `Claims::CampaignVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim_from_merkle_campaign(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `6196`
+		// Minimum execution time: 152_000_000 picoseconds.
+		Weight::from_parts(168_412_000, 6196)
+			// Standard Error: 4_120
+			.saturating_add(Weight::from_parts(1_893_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Claims::ExpiryConfig` (r:1 w:0)
+	/// Proof: `Claims::ExpiryConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Claims::Total` (r:1 w:1)
+	/// Proof: `Claims::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::Swept` (r:1 w:1)
+	/// Proof: `Claims::Swept` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn sweep_expired_claims() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `6196`
+		// Minimum execution time: 51_000_000 picoseconds.
+		Weight::from_parts(58_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Claims::Campaigns` (r:1 w:1)
+	/// Proof: `Claims::Campaigns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_claim_campaign_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3816`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 3816)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
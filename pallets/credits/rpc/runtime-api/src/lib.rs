@@ -58,5 +58,18 @@ sp_api::decl_runtime_apis! {
 			Balance,
 			sp_runtime::DispatchError,
 		>;
+
+		/// Query the unexpired on-chain ledger credits held by an account.
+		///
+		/// ## Arguments
+		/// - `account_id`: The account id.
+		/// ## Return
+		/// - [`Balance`]: The spendable ledger credits of the account.
+		fn query_ledger_credits(
+			account_id: AccountId,
+		) -> Result<
+			Balance,
+			sp_runtime::DispatchError,
+		>;
 	}
 }
@@ -55,6 +55,13 @@ where
 		asset_id: AssetId,
 		at: Option<BlockHash>,
 	) -> RpcResult<Balance>;
+
+	#[method(name = "credits_queryLedgerCredits")]
+	fn query_ledger_credits(
+		&self,
+		account_id: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
 }
 
 /// Provides RPC methods to query a dispatchable's class, weight and fee.
@@ -111,6 +118,21 @@ where
 			Err(e) => Err(map_err(format!("{:?}", e), "Unable to query user credits with asset")),
 		}
 	}
+
+	fn query_ledger_credits(
+		&self,
+		account_id: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_ledger_credits(at, account_id) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(map_err(format!("{:?}", e), "Unable to query ledger credits")),
+			Err(e) => Err(map_err(format!("{:?}", e), "Unable to query ledger credits")),
+		}
+	}
 }
 
 fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
@@ -19,7 +19,11 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::{types::StakeTier, BalanceOf, Config, LastRewardUpdateBlock, Pallet as Credits};
+use crate::{
+	types::{BlockNumberOf, CreditBatch, CreditHolder, CreditHolderOf, StakeCheckpoint, StakeTier},
+	BalanceOf, Config, CreditBalances, LastRewardUpdateBlock, LedgerEnabled, Pallet as Credits,
+	StakeCheckpoints,
+};
 use frame_benchmarking::{v2::*, BenchmarkError};
 use frame_support::{
 	traits::{Currency, Get},
@@ -50,6 +54,19 @@ fn setup_delegation<T: Config>(
 	let current_block = frame_system::Pallet::<T>::block_number();
 	LastRewardUpdateBlock::<T>::insert(delegator, current_block);
 
+	// Worst case: a full stake history, every checkpoint holding the same stake
+	let checkpoints = (0..T::MaxStakeCheckpoints::get())
+		.map(|i| StakeCheckpoint {
+			block: current_block.saturating_add(i.into()),
+			stake: stake_amount,
+		})
+		.collect::<Vec<_>>();
+	StakeCheckpoints::<T>::insert(
+		delegator,
+		T::AssetId::default(),
+		BoundedVec::truncate_from(checkpoints),
+	);
+
 	Ok(())
 }
 
@@ -66,6 +83,17 @@ fn create_stake_tiers<T: Config>(tiers_count: u32) -> Vec<StakeTier<BalanceOf<T>
 	tiers
 }
 
+/// Fill a ledger holder with the maximum number of credit batches, expiring from `first_expiry`
+fn fill_credit_batches<T: Config>(holder: &CreditHolderOf<T>, first_expiry: BlockNumberOf<T>) {
+	let batches = (0..T::MaxCreditBatches::get())
+		.map(|i| CreditBatch {
+			amount: 1000u32.into(),
+			expires_at: first_expiry.saturating_add(i.into()),
+		})
+		.collect::<Vec<_>>();
+	CreditBalances::<T>::insert(holder, BoundedVec::truncate_from(batches));
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -194,5 +222,62 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_ledger_enabled() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		set_ledger_enabled(RawOrigin::Root, true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_credits() -> Result<(), BenchmarkError> {
+		// Worst case: every batch of the sender is split across a full receiver
+		let from = setup_account::<T>(1, 0u32.into());
+		let to = setup_account::<T>(2, 0u32.into());
+		let now = frame_system::Pallet::<T>::block_number();
+		LedgerEnabled::<T>::put(true);
+		fill_credit_batches::<T>(&CreditHolder::Account(from.clone()), now + 1u32.into());
+		fill_credit_batches::<T>(&CreditHolder::Account(to.clone()), now + 1u32.into());
+		let amount: BalanceOf<T> =
+			(T::MaxCreditBatches::get().saturating_mul(1000).saturating_sub(1)).into();
+
+		#[extrinsic_call]
+		transfer_credits(RawOrigin::Signed(from), CreditHolder::Account(to), amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn spend_offchain_credits() -> Result<(), BenchmarkError> {
+		let id: BoundedVec<u8, T::MaxOffchainAccountIdLength> =
+			b"benchmark_spend_id".to_vec().try_into().expect("ID should not be too long");
+		let now = frame_system::Pallet::<T>::block_number();
+		LedgerEnabled::<T>::put(true);
+		fill_credit_batches::<T>(&CreditHolder::Offchain(id.clone()), now + 1u32.into());
+		let amount: BalanceOf<T> =
+			(T::MaxCreditBatches::get().saturating_mul(1000).saturating_sub(1)).into();
+
+		#[extrinsic_call]
+		spend_offchain_credits(RawOrigin::Root, id, amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn purge_expired_credits() -> Result<(), BenchmarkError> {
+		let caller = setup_account::<T>(1, 0u32.into());
+		let holder = CreditHolder::Account(setup_account::<T>(2, 0u32.into()));
+		let now = frame_system::Pallet::<T>::block_number();
+		fill_credit_batches::<T>(&holder, now + 1u32.into());
+		let expired_at = now + T::MaxCreditBatches::get().into() + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(expired_at);
+
+		#[extrinsic_call]
+		purge_expired_credits(RawOrigin::Signed(caller), holder);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Credits, crate::mock::new_test_ext(vec![]), crate::mock::Runtime);
 }
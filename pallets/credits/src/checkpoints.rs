@@ -0,0 +1,113 @@
+use crate::{types::*, BalanceOf, Config, Pallet, StakeCheckpoints};
+use core::cmp::{max, min};
+use frame_support::pallet_prelude::*;
+use sp_runtime::traits::{One, SaturatedConversion, Saturating, Zero};
+use sp_std::vec::Vec;
+use tangle_primitives::{services::Asset, traits::DelegationHooks};
+
+impl<T: Config> Pallet<T> {
+	/// Records the current stake of `who` in `asset_id` as a checkpoint at the current block.
+	///
+	/// A checkpoint in the same block overwrites the previous one, and an unchanged stake is not
+	/// recorded. Checkpoints that no longer affect any block inside the claim window are pruned;
+	/// if the history is still full, the oldest checkpoint is dropped, so the blocks it covered
+	/// accrue nothing.
+	pub(crate) fn checkpoint_stake(who: &T::AccountId, asset_id: T::AssetId) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let stake = Self::get_staked_amount_for_asset(who, asset_id);
+		let window_start = now.saturating_sub(T::ClaimWindowBlocks::get());
+
+		StakeCheckpoints::<T>::mutate_exists(who, asset_id, |maybe_checkpoints| {
+			let mut checkpoints = maybe_checkpoints.take().unwrap_or_default();
+			match checkpoints.last_mut() {
+				Some(last) if last.stake == stake => {},
+				Some(last) if last.block == now => last.stake = stake,
+				None if stake.is_zero() => {},
+				_ => {
+					while checkpoints.len() > 1 &&
+						checkpoints.get(1).is_some_and(|next| next.block <= window_start)
+					{
+						checkpoints.remove(0);
+					}
+					if checkpoints.is_full() {
+						checkpoints.remove(0);
+					}
+					let _ = checkpoints.try_push(StakeCheckpoint { block: now, stake });
+				},
+			}
+			if !checkpoints.is_empty() {
+				*maybe_checkpoints = Some(checkpoints);
+			}
+		});
+	}
+
+	/// Starts the stake history of `who` in `asset_id` from the current block if none exists yet,
+	/// so that accounts which staked before checkpoints were recorded also accrue time-weighted.
+	pub(crate) fn start_stake_history(who: &T::AccountId, asset_id: T::AssetId) {
+		if !StakeCheckpoints::<T>::contains_key(who, asset_id) {
+			Self::checkpoint_stake(who, asset_id);
+		}
+	}
+
+	/// Accrues credits for `who` over the blocks `(start, end]`, rating each block by the stake
+	/// held in `asset_id` during it.
+	///
+	/// Blocks before the first checkpoint accrue nothing. Accounts without any stake history fall
+	/// back to `current_stake` for the whole range.
+	pub(crate) fn accrue_over_window(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		start: BlockNumberOf<T>,
+		end: BlockNumberOf<T>,
+		current_stake: BalanceOf<T>,
+		rate: impl Fn(BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::stake_spans(who, asset_id, start, end, current_stake)
+			.into_iter()
+			.try_fold(Zero::zero(), |acc: BalanceOf<T>, (stake, blocks)| {
+				if stake.is_zero() {
+					return Ok(acc);
+				}
+				let blocks: u64 = blocks.saturated_into();
+				let credits = rate(stake)?.saturating_mul(BalanceOf::<T>::saturated_from(blocks));
+				Ok(acc.saturating_add(credits))
+			})
+	}
+
+	/// Splits the blocks `(start, end]` into spans of constant stake, returned as
+	/// `(stake, number_of_blocks)` pairs.
+	fn stake_spans(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		start: BlockNumberOf<T>,
+		end: BlockNumberOf<T>,
+		current_stake: BalanceOf<T>,
+	) -> Vec<(BalanceOf<T>, BlockNumberOf<T>)> {
+		let checkpoints = StakeCheckpoints::<T>::get(who, asset_id);
+		if checkpoints.is_empty() {
+			return sp_std::vec![(current_stake, end.saturating_sub(start))];
+		}
+
+		let first_block = start.saturating_add(One::one());
+		let mut spans = Vec::new();
+		for (index, checkpoint) in checkpoints.iter().enumerate() {
+			let from = max(checkpoint.block, first_block);
+			let to = checkpoints
+				.get(index + 1)
+				.map_or(end, |next| min(next.block.saturating_sub(One::one()), end));
+			if to >= from {
+				spans.push((checkpoint.stake, to.saturating_sub(from).saturating_add(One::one())));
+			}
+		}
+		spans
+	}
+}
+
+impl<T: Config> DelegationHooks<T::AccountId, T::AssetId> for Pallet<T> {
+	fn on_stake_changed(who: &T::AccountId, asset: &Asset<T::AssetId>) {
+		// Only custom asset deposits count towards the stake credits are accrued from.
+		if let Asset::Custom(asset_id) = asset {
+			Self::checkpoint_stake(who, *asset_id);
+		}
+	}
+}
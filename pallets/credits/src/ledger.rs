@@ -0,0 +1,224 @@
+use crate::{types::*, BalanceOf, Config, CreditBalances, Error, Event, LedgerEnabled, Pallet};
+use frame_support::{ensure, pallet_prelude::*};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::vec::Vec;
+use tangle_primitives::traits::CreditsLedger;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the ledger holder credited by a claim towards `offchain_account_id`.
+	///
+	/// Claims without an off-chain account ID are credited to the claiming account.
+	pub(crate) fn claim_holder(
+		who: &T::AccountId,
+		offchain_account_id: &OffchainAccountIdOf<T>,
+	) -> CreditHolderOf<T> {
+		if offchain_account_id.is_empty() {
+			CreditHolder::Account(who.clone())
+		} else {
+			CreditHolder::Offchain(offchain_account_id.clone())
+		}
+	}
+
+	/// Returns the unexpired ledger credits held by `holder`.
+	pub fn ledger_balance(holder: &CreditHolderOf<T>) -> BalanceOf<T> {
+		let now = frame_system::Pallet::<T>::block_number();
+		CreditBalances::<T>::get(holder)
+			.iter()
+			.filter(|batch| batch.expires_at > now)
+			.fold(Zero::zero(), |acc: BalanceOf<T>, batch| acc.saturating_add(batch.amount))
+	}
+
+	/// Returns the expiry block of credits granted now.
+	///
+	/// Expiries are rounded up to the next multiple of `CreditExpiryBlocks / MaxCreditBatches`,
+	/// so the credits granted over an expiry period fall into about `MaxCreditBatches` batches.
+	pub(crate) fn credit_expiry_block() -> BlockNumberOf<T> {
+		let expires_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::CreditExpiryBlocks::get());
+		let bucket = T::CreditExpiryBlocks::get() / T::MaxCreditBatches::get().max(1).into();
+		if bucket.is_zero() {
+			return expires_at;
+		}
+		match expires_at % bucket {
+			rem if rem.is_zero() => expires_at,
+			rem => expires_at.saturating_add(bucket - rem),
+		}
+	}
+
+	/// Records `amount` newly granted credits for `holder`, expiring at least
+	/// `CreditExpiryBlocks` from now. Does nothing while the ledger is disabled.
+	pub(crate) fn deposit_ledger_credits(
+		holder: CreditHolderOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if !LedgerEnabled::<T>::get() || amount.is_zero() {
+			return Ok(());
+		}
+
+		let expires_at = Self::credit_expiry_block();
+		Self::purge_expired(&holder);
+		CreditBalances::<T>::mutate(&holder, |batches| {
+			Self::insert_credit_batch(batches, CreditBatch { amount, expires_at })
+		});
+
+		Self::deposit_event(Event::CreditsDeposited { holder, amount, expires_at });
+		Ok(())
+	}
+
+	/// Moves `amount` ledger credits from `from` to `to`, preserving their expiry.
+	pub(crate) fn do_transfer_credits(
+		from: CreditHolderOf<T>,
+		to: CreditHolderOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(LedgerEnabled::<T>::get(), Error::<T>::LedgerDisabled);
+		ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+		ensure!(from != to, Error::<T>::CannotTransferToSelf);
+
+		Self::purge_expired(&from);
+		Self::purge_expired(&to);
+
+		let taken = Self::take_credits(&from, amount)?;
+		CreditBalances::<T>::mutate(&to, |batches| {
+			taken.into_iter().for_each(|batch| Self::insert_credit_batch(batches, batch))
+		});
+
+		Self::deposit_event(Event::CreditsTransferred { from, to, amount });
+		Ok(())
+	}
+
+	/// Spends `amount` ledger credits held by `holder`, earliest-expiring first.
+	pub(crate) fn do_spend_credits(
+		holder: CreditHolderOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(LedgerEnabled::<T>::get(), Error::<T>::LedgerDisabled);
+		ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+		Self::purge_expired(&holder);
+		Self::take_credits(&holder, amount)?;
+
+		Self::deposit_event(Event::CreditsSpent { holder, amount });
+		Ok(())
+	}
+
+	/// Removes all expired batches held by `holder`, returning the amount of credits removed.
+	pub(crate) fn purge_expired(holder: &CreditHolderOf<T>) -> BalanceOf<T> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut expired = BalanceOf::<T>::zero();
+		CreditBalances::<T>::mutate_exists(holder, |maybe_batches| {
+			if let Some(batches) = maybe_batches {
+				batches.retain(|batch| {
+					if batch.expires_at <= now {
+						expired = expired.saturating_add(batch.amount);
+						false
+					} else {
+						true
+					}
+				});
+				if batches.is_empty() {
+					*maybe_batches = None;
+				}
+			}
+		});
+
+		if !expired.is_zero() {
+			Self::deposit_event(Event::CreditsExpired { holder: holder.clone(), amount: expired });
+		}
+		expired
+	}
+
+	/// Withdraws `amount` credits from `holder`, earliest-expiring first, and returns the
+	/// withdrawn portions with their original expiry. Expired batches must already be purged.
+	fn take_credits(
+		holder: &CreditHolderOf<T>,
+		amount: BalanceOf<T>,
+	) -> Result<Vec<CreditBatchOf<T>>, DispatchError> {
+		let batches = CreditBalances::<T>::get(holder);
+		let available = batches
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |acc, batch| acc.saturating_add(batch.amount));
+		ensure!(available >= amount, Error::<T>::InsufficientCredits);
+
+		let mut remaining = amount;
+		let mut taken = Vec::new();
+		let mut kept = Vec::new();
+		for mut batch in batches.into_inner() {
+			if remaining.is_zero() {
+				kept.push(batch);
+				continue;
+			}
+			let take = batch.amount.min(remaining);
+			remaining = remaining.saturating_sub(take);
+			batch.amount = batch.amount.saturating_sub(take);
+			taken.push(CreditBatch { amount: take, expires_at: batch.expires_at });
+			if !batch.amount.is_zero() {
+				kept.push(batch);
+			}
+		}
+
+		if kept.is_empty() {
+			CreditBalances::<T>::remove(holder);
+		} else {
+			CreditBalances::<T>::insert(holder, BoundedVec::truncate_from(kept));
+		}
+		Ok(taken)
+	}
+
+	/// Inserts `batch` keeping `batches` sorted by expiry, merging it into an existing batch with
+	/// the same expiry block.
+	///
+	/// If `batches` is full, the batch is merged into the one with the nearest expiry instead, so
+	/// that a holder can always receive credits.
+	fn insert_credit_batch(
+		batches: &mut BoundedVec<CreditBatchOf<T>, T::MaxCreditBatches>,
+		batch: CreditBatchOf<T>,
+	) {
+		let (index, batch) = match batches
+			.binary_search_by_key(&batch.expires_at, |existing| existing.expires_at)
+		{
+			Ok(index) => (index, batch),
+			Err(index) => match batches.try_insert(index, batch) {
+				Ok(()) => return,
+				Err(batch) => (Self::nearest_credit_batch(batches, index, batch.expires_at), batch),
+			},
+		};
+
+		if let Some(existing) = batches.get_mut(index) {
+			existing.amount = existing.amount.saturating_add(batch.amount);
+		}
+	}
+
+	/// Returns the index of the batch expiring nearest to `expires_at`, which would be inserted
+	/// at `index`.
+	fn nearest_credit_batch(
+		batches: &[CreditBatchOf<T>],
+		index: usize,
+		expires_at: BlockNumberOf<T>,
+	) -> usize {
+		let distance = |batch: &CreditBatchOf<T>| {
+			batch.expires_at.max(expires_at) - batch.expires_at.min(expires_at)
+		};
+		match (index.checked_sub(1).and_then(|i| batches.get(i)), batches.get(index)) {
+			(Some(before), Some(after)) if distance(after) < distance(before) => index,
+			(Some(_), _) => index - 1,
+			(None, _) => index,
+		}
+	}
+}
+
+impl<T: Config> CreditsLedger<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn spendable_credits(who: &T::AccountId) -> BalanceOf<T> {
+		if !LedgerEnabled::<T>::get() {
+			return Zero::zero();
+		}
+		Self::ledger_balance(&CreditHolder::Account(who.clone()))
+	}
+
+	fn spend_credits(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		Self::do_spend_credits(CreditHolder::Account(who.clone()), amount)
+	}
+}
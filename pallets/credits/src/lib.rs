@@ -16,6 +16,11 @@
 //!   via `MultiAssetDelegationInfo`. Accrual is **capped** to a configurable time window
 //!   (`ClaimWindowBlocks`). Users do not accrue additional potential credits for periods longer
 //!   than this window without claiming.
+//! - **Time-Weighted Stake:** Accrual follows a checkpointed history of each user's stake per asset
+//!   (`StakeCheckpoints`), recorded by `pallet-multi-asset-delegation` through
+//!   `tangle_primitives::traits::DelegationHooks` whenever a deposit, delegation or unstake
+//!   changes. Every block in the window is rated by the stake actually held in it, so staking right
+//!   before a claim only earns credits from that block onwards.
 //! - **Stake Tier Configuration:** Credit emission rates based on stake size are defined via
 //!   `StakeTier` structs, which are configured during genesis and stored on-chain.
 //! - **TNT Burning Event:** Burning TNT emits an event (`CreditsGrantedFromBurn`) indicating
@@ -26,6 +31,13 @@
 //!   `CreditsClaimed` event. **No on-chain balance is stored or deducted.**
 //! - **Window Cap:** Inactivity beyond the `ClaimWindowBlocks` simply results in no further
 //!   potential credit accrual for that past period.
+//! - **Optional On-Chain Ledger:** When enabled via `set_ledger_enabled`, burned and claimed
+//!   credits are additionally recorded on-chain. Burned credits are held by the burning account;
+//!   claimed credits are held by the off-chain account ID (or by the claiming account if the ID is
+//!   empty). Ledger credits expire `CreditExpiryBlocks` after they are granted, rounded up to a
+//!   multiple of `CreditExpiryBlocks / MaxCreditBatches` blocks. They can be transferred to other
+//!   holders with their expiry preserved, and are spent earliest-expiring first by other pallets
+//!   (e.g. `pallet-services`) through `tangle_primitives::traits::CreditsLedger`.
 //!
 //! ## Integration
 //!
@@ -33,6 +45,8 @@
 //!
 //! - An implementation of `tangle_primitives::traits::MultiAssetDelegationInfo`
 //!   (`Config::MultiAssetDelegationInfo`) to query the active TNT stake for users.
+//! - The multi-asset delegation system calling this pallet's
+//!   `tangle_primitives::traits::DelegationHooks` implementation whenever a user's stake changes.
 //! - An implementation of `frame_support::traits::Currency` (`Config::Currency`) to handle TNT
 //!   token balance checks and burning.
 //! - `frame_system` for basic system types and block numbers.
@@ -51,6 +65,10 @@
 //!   action also updates the `LastRewardUpdateBlock` marker.
 //! - **Stake Tier:** A configuration struct defining a TNT stake threshold and the corresponding
 //!   potential credit emission rate per block.
+//! - **Stake Checkpoint:** The stake held by a user from a given block onwards. Users without any
+//!   checkpoints accrue from their current stake until their next claim starts their history.
+//! - **Ledger Credits:** Credits recorded on-chain while the ledger is enabled, held by a
+//!   `CreditHolder` in batches that share an expiry block.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -59,6 +77,9 @@ pub use pallet::*;
 
 pub mod types;
 
+mod checkpoints;
+mod ledger;
+
 #[cfg(test)]
 mod mock;
 
@@ -87,7 +108,9 @@ pub mod pallet {
 	use scale_info::prelude::vec::Vec;
 	use sp_runtime::traits::{CheckedMul, MaybeDisplay, SaturatedConversion, Saturating, Zero};
 	use sp_std::fmt::Debug;
-	use tangle_primitives::{rewards::AssetType, traits::MultiAssetDelegationInfo};
+	use tangle_primitives::{
+		rewards::AssetType, services::Asset, traits::MultiAssetDelegationInfo,
+	};
 
 	// Move STORAGE_VERSION inside the pallet mod
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
@@ -156,6 +179,24 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRatePerBlock: Get<BalanceOf<Self>>;
 
+		/// The number of blocks after which ledger credits expire.
+		#[pallet::constant]
+		type CreditExpiryBlocks: Get<BlockNumberOf<Self>>;
+
+		/// The maximum number of distinct expiry batches a single ledger holder can hold.
+		///
+		/// Credits granted to a holder with this many batches are merged into the batch expiring
+		/// nearest to them.
+		#[pallet::constant]
+		type MaxCreditBatches: Get<u32>;
+
+		/// Type for the origin that is allowed to spend credits held by off-chain account IDs.
+		type CreditSpendOrigin: frame_support::traits::EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of stake checkpoints kept per account for time-weighted accrual.
+		#[pallet::constant]
+		type MaxStakeCheckpoints: Get<u32>;
+
 		/// The weight information for the pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -185,6 +226,40 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Whether the on-chain credit ledger is enabled.
+	#[pallet::storage]
+	#[pallet::getter(fn ledger_enabled)]
+	pub type LedgerEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Ledger credits per holder, sorted by expiry block ascending.
+	#[pallet::storage]
+	#[pallet::getter(fn credit_balances)]
+	pub type CreditBalances<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CreditHolderOf<T>,
+		BoundedVec<CreditBatchOf<T>, T::MaxCreditBatches>,
+		ValueQuery,
+	>;
+
+	/// Stake history per account and asset, sorted by block ascending, used for time-weighted
+	/// accrual.
+	///
+	/// Each checkpoint records the stake held in the asset from its block onwards. Checkpoints
+	/// that no longer affect any block inside the claim window are pruned as new ones are
+	/// recorded.
+	#[pallet::storage]
+	#[pallet::getter(fn stake_checkpoints)]
+	pub type StakeCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<StakeCheckpointOf<T>, T::MaxStakeCheckpoints>,
+		ValueQuery,
+	>;
+
 	// --- Genesis Configuration ---
 
 	#[pallet::genesis_config]
@@ -243,6 +318,20 @@ pub mod pallet {
 		StakeTiersUpdated,
 		/// Asset-specific stake tiers were updated.
 		AssetStakeTiersUpdated { asset_id: T::AssetId },
+		/// The on-chain credit ledger was enabled or disabled.
+		LedgerEnabledSet { enabled: bool },
+		/// Credits were recorded in the on-chain ledger.
+		CreditsDeposited {
+			holder: CreditHolderOf<T>,
+			amount: BalanceOf<T>,
+			expires_at: BlockNumberOf<T>,
+		},
+		/// Ledger credits were transferred between holders.
+		CreditsTransferred { from: CreditHolderOf<T>, to: CreditHolderOf<T>, amount: BalanceOf<T> },
+		/// Ledger credits were spent.
+		CreditsSpent { holder: CreditHolderOf<T>, amount: BalanceOf<T> },
+		/// Expired ledger credits were removed.
+		CreditsExpired { holder: CreditHolderOf<T>, amount: BalanceOf<T> },
 	}
 
 	// --- Errors ---
@@ -272,6 +361,12 @@ pub mod pallet {
 		AssetRatesNotConfigured,
 		/// Rate per block exceeds maximum allowed value.
 		RateTooHigh,
+		/// The on-chain credit ledger is not enabled.
+		LedgerDisabled,
+		/// The holder does not have enough unexpired ledger credits.
+		InsufficientCredits,
+		/// Credits cannot be transferred to the sending holder.
+		CannotTransferToSelf,
 	}
 
 	#[pallet::call]
@@ -296,6 +391,8 @@ pub mod pallet {
 				amount.checked_mul(&conversion_rate).ok_or(Error::<T>::Overflow)?;
 			ensure!(credits_granted > Zero::zero(), Error::<T>::Overflow);
 
+			Self::deposit_ledger_credits(CreditHolder::Account(who.clone()), credits_granted)?;
+
 			Self::deposit_event(Event::CreditsGrantedFromBurn {
 				who,
 				tnt_burned: amount,
@@ -332,6 +429,11 @@ pub mod pallet {
 				Error::<T>::ClaimAmountExceedsWindowAllowance
 			);
 
+			Self::deposit_ledger_credits(
+				Self::claim_holder(&who, &offchain_account_id),
+				amount_to_claim,
+			)?;
+
 			// Emit event with the *requested* amount
 			Self::deposit_event(Event::CreditsClaimed {
 				who,
@@ -374,6 +476,11 @@ pub mod pallet {
 				Error::<T>::ClaimAmountExceedsWindowAllowance
 			);
 
+			Self::deposit_ledger_credits(
+				Self::claim_holder(&who, &offchain_account_id),
+				amount_to_claim,
+			)?;
+
 			// Emit event with the *requested* amount
 			Self::deposit_event(Event::CreditsClaimed {
 				who,
@@ -485,6 +592,83 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Enable or disable the on-chain credit ledger.
+		///
+		/// While disabled, burns and claims only emit events and ledger credits can be neither
+		/// transferred nor spent. Existing ledger balances are kept.
+		///
+		/// Parameters:
+		/// - `origin`: Must be the ForceOrigin
+		/// - `enabled`: Whether the ledger should be enabled
+		///
+		/// Emits `LedgerEnabledSet` on success.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_ledger_enabled())]
+		pub fn set_ledger_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			LedgerEnabled::<T>::put(enabled);
+			Self::deposit_event(Event::<T>::LedgerEnabledSet { enabled });
+			Ok(())
+		}
+
+		/// Transfer ledger credits held by the caller's account to another holder, such as a
+		/// delegate account or an off-chain account ID. Transferred credits keep their expiry.
+		///
+		/// Parameters:
+		/// - `origin`: The account holding the credits
+		/// - `to`: The holder receiving the credits
+		/// - `amount`: The amount of credits to transfer, taken earliest-expiring first
+		///
+		/// Emits `CreditsTransferred` on success.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::transfer_credits())]
+		pub fn transfer_credits(
+			origin: OriginFor<T>,
+			to: CreditHolderOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_transfer_credits(CreditHolder::Account(who), to, amount)
+		}
+
+		/// Spend ledger credits held by an off-chain account ID. Used by the off-chain system to
+		/// settle usage against credits claimed on-chain.
+		///
+		/// Parameters:
+		/// - `origin`: Must be the CreditSpendOrigin
+		/// - `offchain_account_id`: The off-chain account ID holding the credits
+		/// - `amount`: The amount of credits to spend, taken earliest-expiring first
+		///
+		/// Emits `CreditsSpent` on success.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::spend_offchain_credits())]
+		pub fn spend_offchain_credits(
+			origin: OriginFor<T>,
+			offchain_account_id: OffchainAccountIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::CreditSpendOrigin::ensure_origin(origin)?;
+			Self::do_spend_credits(CreditHolder::Offchain(offchain_account_id), amount)
+		}
+
+		/// Remove expired credits from a holder's ledger balance. Callable by anyone.
+		///
+		/// Parameters:
+		/// - `origin`: Any signed account
+		/// - `holder`: The holder whose expired credits should be removed
+		///
+		/// Emits `CreditsExpired` if any credits were removed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::purge_expired_credits())]
+		pub fn purge_expired_credits(
+			origin: OriginFor<T>,
+			holder: CreditHolderOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::purge_expired(&holder);
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -498,14 +682,14 @@ pub mod pallet {
 		///     * `effective_start_block` = `max(last_update, current_block.saturating_sub(window))`
 		///     * `effective_end_block` = `current_block`
 		///     * If `effective_start_block >= effective_end_block`, accrued credits = 0.
-		/// 2. Calculate the number of blocks in this window:
-		///     * `blocks_in_window` = `effective_end_block.saturating_sub(effective_start_block)`
-		/// 3. Fetch the user's current total staked TNT amount (`staked_amount`).
-		/// 4. Determine the credit emission `rate` per block based on `staked_amount` using
-		///    `get_current_rate`.
-		/// 5. Calculate the accrued credits (using saturating math):
-		///     * `accrued_credits` = `rate.saturating_mul(BalanceOf::<T>::from(blocks_in_window.
-		///       unique_saturated_into::<u32>()))`
+		/// 2. Split the blocks in `(effective_start_block, effective_end_block]` into spans of
+		///    constant stake using the user's TNT `StakeCheckpoints`. Blocks before the first
+		///    checkpoint count as unstaked. Users without any checkpoints fall back to their
+		///    current staked TNT amount for the whole window.
+		/// 3. For each span, determine the credit emission `rate` per block based on the span's
+		///    stake using `get_current_rate`.
+		/// 4. Sum the accrued credits of every span (using saturating math):
+		///     * `accrued_credits` += `rate.saturating_mul(blocks_in_span)`
 		///
 		/// # Returns
 		/// The calculated potential credits accrued within the window, or `DispatchError`.
@@ -530,40 +714,18 @@ pub mod pallet {
 				return Ok(Zero::zero());
 			}
 
-			// Fetch stake *once* for the current block (simplification: assumes stake is constant
-			// during window) A more complex approach could sample stake at intervals, but adds
-			// significant complexity.
-			let staked_amount =
-				T::MultiAssetDelegationInfo::get_user_deposit_by_asset_type(who, AssetType::Tnt)
-					.unwrap_or(Zero::zero());
-
-			if staked_amount.is_zero() {
-				return Ok(Zero::zero());
-			}
-			let rate = Self::get_current_rate(staked_amount);
-			if rate.is_zero() {
-				return Ok(Zero::zero());
-			}
-
-			// Calculate blocks within the effective window
-			let blocks_in_window = effective_end_block.saturating_sub(start_block);
-			// Already checked if start_block >= effective_end_block, so blocks_in_window > 0 here
-			// unless effective_end_block == start_block, but that case is covered too.
-			// We still check for zero just in case, although it should be unreachable.
-			if blocks_in_window.is_zero() {
-				return Ok(Zero::zero()); // Should be unreachable given prior checks
-			}
-
-			// Convert BlockNumber to u64 safely for the multiplication
-			let blocks_in_window_u64: u64 = blocks_in_window.saturated_into();
-			if blocks_in_window_u64 == 0 {
-				return Ok(Zero::zero());
-			}
-
-			let new_credits =
-				rate.saturating_mul(BalanceOf::<T>::saturated_from(blocks_in_window_u64));
+			// The current stake is only used for accounts without any stake history
+			let native_asset_id = Default::default();
+			let staked_amount = Self::get_staked_amount_for_asset(who, native_asset_id);
 
-			Ok(new_credits)
+			Self::accrue_over_window(
+				who,
+				native_asset_id,
+				start_block,
+				effective_end_block,
+				staked_amount,
+				|stake| Ok(Self::get_current_rate(stake)),
+			)
 		}
 
 		/// Calculates potential credits accrued within the allowed window ending now,
@@ -583,6 +745,7 @@ pub mod pallet {
 
 			// Update the block regardless of calculation result
 			LastRewardUpdateBlock::<T>::insert(who, current_block);
+			Self::start_stake_history(who, Default::default());
 
 			result
 		}
@@ -683,6 +846,11 @@ pub mod pallet {
 			current_block: Option<BlockNumberOf<T>>,
 			asset_id: T::AssetId,
 		) -> Result<BalanceOf<T>, DispatchError> {
+			ensure!(
+				asset_id == Default::default() || AssetStakeTiers::<T>::contains_key(asset_id),
+				Error::<T>::AssetRatesNotConfigured
+			);
+
 			let current_block =
 				current_block.unwrap_or_else(|| frame_system::Pallet::<T>::block_number());
 			let last_update = LastRewardUpdateBlock::<T>::get(who);
@@ -698,59 +866,27 @@ pub mod pallet {
 				return Ok(Zero::zero());
 			}
 
-			// Get staked amount for the specified asset
-			let staked_amount = Self::get_staked_amount_for_asset(who, asset_id)?;
-
-			if staked_amount.is_zero() {
-				return Ok(Zero::zero());
-			}
+			// The current stake is only used for accounts without any stake history
+			let staked_amount = Self::get_staked_amount_for_asset(who, asset_id);
 
 			// Use asset-specific rate calculation
-			let rate = Self::get_current_rate_for_asset(staked_amount, asset_id)?;
-
-			if rate.is_zero() {
-				return Ok(Zero::zero());
-			}
-
-			let blocks_in_window = effective_end_block.saturating_sub(start_block);
-			if blocks_in_window.is_zero() {
-				return Ok(Zero::zero());
-			}
-
-			let blocks_in_window_u64: u64 = blocks_in_window.saturated_into();
-			if blocks_in_window_u64 == 0 {
-				return Ok(Zero::zero());
-			}
-
-			let new_credits =
-				rate.saturating_mul(BalanceOf::<T>::saturated_from(blocks_in_window_u64));
-			Ok(new_credits)
+			Self::accrue_over_window(
+				who,
+				asset_id,
+				start_block,
+				effective_end_block,
+				staked_amount,
+				|stake| Self::get_current_rate_for_asset(stake, asset_id),
+			)
 		}
 
 		/// Get staked amount for a specific asset
-		fn get_staked_amount_for_asset(
+		pub(crate) fn get_staked_amount_for_asset(
 			who: &T::AccountId,
 			asset_id: T::AssetId,
-		) -> Result<BalanceOf<T>, DispatchError> {
-			let asset_type = Self::asset_id_to_asset_type(asset_id)?;
-
-			let staked_amount =
-				T::MultiAssetDelegationInfo::get_user_deposit_by_asset_type(who, asset_type)
-					.unwrap_or(Zero::zero());
-
-			Ok(staked_amount)
-		}
-
-		/// Convert AssetId to AssetType
-		fn asset_id_to_asset_type(
-			asset_id: T::AssetId,
-		) -> Result<AssetType<T::AssetId>, DispatchError> {
-			let native_asset_id = Default::default();
-			if asset_id == native_asset_id {
-				Ok(AssetType::Tnt)
-			} else {
-				Ok(AssetType::Native(asset_id))
-			}
+		) -> BalanceOf<T> {
+			T::MultiAssetDelegationInfo::get_user_deposit_with_locks(who, Asset::Custom(asset_id))
+				.map_or(Zero::zero(), |deposit| deposit.unlocked_amount)
 		}
 
 		/// Update reward block and get accrued amount for a specific asset
@@ -761,6 +897,7 @@ pub mod pallet {
 		) -> Result<BalanceOf<T>, DispatchError> {
 			let result = Self::get_accrued_amount_for_asset(who, Some(current_block), asset_id);
 			LastRewardUpdateBlock::<T>::insert(who, current_block);
+			Self::start_stake_history(who, asset_id);
 			result
 		}
 	}
@@ -456,6 +456,16 @@ parameter_types! {
 	#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 	pub const MaxDelegations: u32 = 10;
+
+	#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub const MaxOperatorProfileFieldLength: u32 = 64;
+
+	#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub const MaxOperatorProfileDescriptionLength: u32 = 256;
+	pub const OperatorProfileDepositBase: Balance = 10;
+	pub const OperatorProfileDepositPerByte: Balance = 1;
 	pub const PID: PalletId = PalletId(*b"tngl/mad");
 }
 
@@ -514,6 +524,7 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type SlashRecipient = SlashRecipient;
+	type NominationSlashHandler = ();
 	type MinOperatorBondAmount = MinOperatorBondAmount;
 	type CurrencyToVote = U128CurrencyToVote;
 	type StakingInterface = Staking;
@@ -532,10 +543,15 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type MaxWithdrawRequests = MaxWithdrawRequests;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type MaxDelegations = MaxDelegations;
+	type MaxOperatorProfileFieldLength = MaxOperatorProfileFieldLength;
+	type MaxOperatorProfileDescriptionLength = MaxOperatorProfileDescriptionLength;
+	type OperatorProfileDepositBase = OperatorProfileDepositBase;
+	type OperatorProfileDepositPerByte = OperatorProfileDepositPerByte;
 	type EvmRunner = ();
 	type EvmGasWeightMapping = MockGasWeightMapping;
 	type EvmAddressMapping = MockAddressMapping;
 	type RewardsManager = MockRewardsManager;
+	type DelegationHooks = Credits;
 	type WeightInfo = ();
 }
 
@@ -565,6 +581,10 @@ impl pallet_credits::Config for Runtime {
 	type MaxStakeTiers = MaxStakeTiers;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxRatePerBlock = MaxRatePerBlock;
+	type CreditExpiryBlocks = ConstU64<{ tangle_primitives::credits::CREDIT_EXPIRY_BLOCKS }>;
+	type MaxCreditBatches = ConstU32<{ tangle_primitives::credits::MAX_CREDIT_BATCHES }>;
+	type MaxStakeCheckpoints = ConstU32<{ tangle_primitives::credits::MAX_STAKE_CHECKPOINTS }>;
+	type CreditSpendOrigin = frame_system::EnsureRoot<AccountId>;
 	type WeightInfo = ();
 }
 
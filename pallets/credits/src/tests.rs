@@ -1,5 +1,6 @@
 use crate::{
-	mock::*, types::*, BalanceOf, Error, Event, Pallet as CreditsPallet, StoredStakeTiers,
+	mock::*, types::*, BalanceOf, CreditBalances, Error, Event, Pallet as CreditsPallet,
+	StoredStakeTiers,
 };
 use frame_support::{
 	assert_noop, assert_ok,
@@ -991,9 +992,20 @@ mod decimal_precision_tests {
 			);
 			assert_eq!(rate, Ok(16u128));
 
-			// Set up delegation for testing claim functionality
-			let operator = BOB;
-			setup_delegation(alice.clone(), operator.clone(), stake_amount);
+			// Deposit the zero decimal asset so it accrues credits at its own tiers
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(alice.clone()),
+				zero_decimal_asset,
+				alice.clone(),
+				stake_amount
+			));
+			assert_ok!(MultiAssetDelegation::<Runtime>::deposit(
+				RuntimeOrigin::signed(alice.clone()),
+				tangle_primitives::services::Asset::Custom(zero_decimal_asset),
+				stake_amount,
+				None,
+				None,
+			));
 
 			// Advance blocks and claim credits
 			run_to_block(10);
@@ -1714,14 +1726,13 @@ fn multiple_delegations_accumulate_tiers_correctly() {
 			Default::default()
 		));
 
-		// Should now be tier 1 with combined delegations (60 + 50 = 110 > 100)
-		// Since the user has never claimed before and now has tier 1 status,
-		// they should get tier 1 rate for all blocks from 1 to 20 (20 blocks total)
+		// Should now be tier 1 with combined delegations (60 + 50 = 110 > 100), but only from
+		// block 10 when the second delegation was made (blocks 10 to 20, 11 blocks total)
 		run_to_block(20);
-		let max_claimable_2 = get_max_claimable(user.clone());
+		let max_claimable_2 = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
 		assert_eq!(
-			max_claimable_2, 20,
-			"Combined delegations should qualify for tier 1 from start"
+			max_claimable_2, 11,
+			"Combined delegations should qualify for tier 1 from the second delegation"
 		);
 		assert_ok!(claim_credits(user.clone(), max_claimable_2, dave_id_str));
 
@@ -1760,7 +1771,7 @@ fn tier_switching_during_claim_window_works() {
 	new_test_ext(vec![]).execute_with(|| {
 		let user = DAVE;
 		let operator = EVE;
-		let _dave_id_str = b"dave_window_tier";
+		let dave_id_str = b"dave_window_tier";
 		let tnt_asset_id = 0;
 		let tnt_asset = tangle_primitives::services::Asset::Custom(tnt_asset_id);
 
@@ -1797,15 +1808,13 @@ fn tier_switching_during_claim_window_works() {
 		// Advance more blocks at tier 2
 		run_to_block(50);
 
-		// The key test: credits should reflect the current tier (tier 2) for the entire window
-		// Since delegation happened at block 25, and current stake determines the rate
-		let max_claimable = get_max_claimable(user.clone());
-		// User should get tier 2 rate (5) for 50 blocks (from block 1 to 50)
-		let expected = 50 * 5;
-		assert_eq!(
-			max_claimable, expected,
-			"Should use current tier rate for entire claimable window"
-		);
+		// The key test: credits should be weighted by the time each tier was held
+		// Since delegation happened at block 25, tier 2 only applies from then on
+		let max_claimable = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
+		// User should get tier 1 rate (1) for blocks 1 to 24 and tier 2 rate (5) for 25 to 50
+		let expected = 24 + 26 * 5;
+		assert_eq!(max_claimable, expected, "Should weight each tier by the blocks it was held");
+		assert_ok!(claim_credits(user.clone(), max_claimable, dave_id_str));
 	});
 }
 
@@ -2008,3 +2017,374 @@ fn tier_switching_with_locked_multipliers_works() {
 		assert_ok!(claim_credits(user.clone(), max_claimable, dave_id_str));
 	});
 }
+
+fn deposit_tnt(who: AccountId, amount: Balance) {
+	Balances::make_free_balance_be(&who, Balances::free_balance(&who) + amount);
+	assert_ok!(MultiAssetDelegation::<Runtime>::deposit(
+		RuntimeOrigin::signed(who),
+		tangle_primitives::services::Asset::Custom(0),
+		amount,
+		None,
+		None,
+	));
+}
+
+fn checkpoint_blocks(who: AccountId) -> Vec<BlockNumber> {
+	CreditsPallet::<Runtime>::stake_checkpoints(who, 0)
+		.iter()
+		.map(|c| c.block)
+		.collect()
+}
+
+#[test]
+fn stake_increase_before_claim_only_accrues_from_change() {
+	new_test_ext(vec![]).execute_with(|| {
+		let user = DAVE;
+		setup_delegation(user.clone(), EVE, 150);
+
+		// Jump to tier 3 right before claiming
+		run_to_block(99);
+		deposit_tnt(user.clone(), 14_850);
+		assert_eq!(checkpoint_blocks(user.clone()), vec![1, 99]);
+
+		run_to_block(100);
+		// Tier 1 for blocks 1 to 98, tier 3 for blocks 99 and 100
+		let accrued = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
+		assert_eq!(accrued, 98 + 2 * 15);
+
+		assert_noop!(
+			claim_credits(user.clone(), 100 * 15, b"dave_late_stake"),
+			Error::<Runtime>::ClaimAmountExceedsWindowAllowance
+		);
+		assert_ok!(claim_credits(user, accrued, b"dave_late_stake"));
+	});
+}
+
+#[test]
+fn stake_decrease_mid_window_reduces_accrual() {
+	new_test_ext(vec![]).execute_with(|| {
+		let user = DAVE;
+		let tnt_asset = tangle_primitives::services::Asset::Custom(0);
+		setup_delegation(user.clone(), EVE, 150);
+		deposit_tnt(user.clone(), 14_850);
+		// Changes within the same block overwrite the checkpoint
+		assert_eq!(checkpoint_blocks(user.clone()), vec![1]);
+
+		run_to_block(50);
+		assert_ok!(MultiAssetDelegation::<Runtime>::schedule_withdraw(
+			RuntimeOrigin::signed(user.clone()),
+			tnt_asset,
+			14_850
+		));
+
+		run_to_block(100);
+		// Tier 3 for blocks 1 to 49, tier 1 for blocks 50 to 100
+		let accrued = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
+		assert_eq!(accrued, 49 * 15 + 51);
+		assert_ok!(claim_credits(user, accrued, b"dave_withdraw"));
+	});
+}
+
+#[test]
+fn stake_history_is_tracked_per_asset() {
+	new_test_ext(vec![]).execute_with(|| {
+		let user = DAVE;
+		let other_asset_id = 5;
+		setup_delegation(user.clone(), EVE, 150);
+
+		create_and_mint_tokens(other_asset_id, user.clone(), 1_000);
+		assert_ok!(CreditsPallet::<Runtime>::set_asset_stake_tiers(
+			RuntimeOrigin::root(),
+			other_asset_id,
+			vec![StakeTier { threshold: 100, rate_per_block: 4 }]
+		));
+
+		// The second asset is only staked from block 60
+		run_to_block(60);
+		assert_ok!(MultiAssetDelegation::<Runtime>::deposit(
+			RuntimeOrigin::signed(user.clone()),
+			tangle_primitives::services::Asset::Custom(other_asset_id),
+			1_000,
+			None,
+			None,
+		));
+		assert_eq!(checkpoint_blocks(user.clone()), vec![1]);
+		let other_blocks: Vec<BlockNumber> =
+			CreditsPallet::<Runtime>::stake_checkpoints(user.clone(), other_asset_id)
+				.iter()
+				.map(|c| c.block)
+				.collect();
+		assert_eq!(other_blocks, vec![60]);
+
+		run_to_block(100);
+		// TNT accrues tier 1 for blocks 1 to 100, unaffected by the second deposit
+		let accrued = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
+		assert_eq!(accrued, 100);
+		// The second asset only accrues for blocks 60 to 100
+		let accrued_other =
+			CreditsPallet::<Runtime>::get_accrued_amount_for_asset(&user, None, other_asset_id)
+				.unwrap();
+		assert_eq!(accrued_other, 41 * 4);
+	});
+}
+
+#[test]
+fn stake_checkpoints_are_pruned_outside_claim_window() {
+	new_test_ext(vec![]).execute_with(|| {
+		let user = DAVE;
+		let window: BlockNumber = <Runtime as crate::Config>::ClaimWindowBlocks::get();
+		setup_delegation(user.clone(), EVE, 150);
+		run_to_block(2);
+		deposit_tnt(user.clone(), 1_000);
+
+		// The checkpoint at block 1 no longer affects any block inside the window
+		run_to_block(window + 5);
+		deposit_tnt(user.clone(), 1_000);
+		assert_eq!(checkpoint_blocks(user.clone()), vec![2, window + 5]);
+
+		let accrued = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
+		assert_eq!(accrued, (window as u128) * 5);
+	});
+}
+
+#[test]
+fn accounts_without_stake_history_accrue_from_current_stake() {
+	new_test_ext(vec![]).execute_with(|| {
+		let user = DAVE;
+		setup_delegation(user.clone(), EVE, 1200);
+		crate::StakeCheckpoints::<Runtime>::remove(&user, 0);
+
+		run_to_block(10);
+		assert_eq!(get_max_claimable(user.clone()), 10 * 5);
+		assert_ok!(claim_credits(user.clone(), 10 * 5, b"dave_legacy"));
+
+		// Claiming starts the stake history from the current block
+		assert_eq!(checkpoint_blocks(user), vec![10]);
+	});
+}
+
+fn enable_ledger() {
+	assert_ok!(CreditsPallet::<Runtime>::set_ledger_enabled(RuntimeOrigin::root(), true));
+}
+
+fn ledger_credits(holder: CreditHolderOf<Runtime>) -> Balance {
+	CreditsPallet::<Runtime>::ledger_balance(&holder)
+}
+
+#[test]
+fn ledger_disabled_by_default() {
+	new_test_ext(vec![]).execute_with(|| {
+		System::set_block_number(10);
+		Balances::make_free_balance_be(&BOB, 1000);
+
+		assert!(!CreditsPallet::<Runtime>::ledger_enabled());
+		assert_ok!(CreditsPallet::<Runtime>::burn(RuntimeOrigin::signed(BOB), 50));
+		assert_eq!(ledger_credits(CreditHolder::Account(BOB)), 0);
+
+		assert_noop!(
+			CreditsPallet::<Runtime>::transfer_credits(
+				RuntimeOrigin::signed(BOB),
+				CreditHolder::Account(CHARLIE),
+				1
+			),
+			Error::<Runtime>::LedgerDisabled
+		);
+		assert_noop!(
+			CreditsPallet::<Runtime>::set_ledger_enabled(RuntimeOrigin::signed(BOB), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn burn_and_claim_credit_the_ledger() {
+	new_test_ext(vec![]).execute_with(|| {
+		System::set_block_number(10);
+		enable_ledger();
+		Balances::make_free_balance_be(&BOB, 1000);
+
+		assert_ok!(CreditsPallet::<Runtime>::burn(RuntimeOrigin::signed(BOB), 50));
+		let credits = 50 * <Runtime as crate::Config>::BurnConversionRate::get();
+		let expires_at = CreditsPallet::<Runtime>::credit_expiry_block();
+		assert!(expires_at >= 10 + <Runtime as crate::Config>::CreditExpiryBlocks::get());
+		System::assert_has_event(
+			Event::CreditsDeposited {
+				holder: CreditHolder::Account(BOB),
+				amount: credits,
+				expires_at,
+			}
+			.into(),
+		);
+		assert_eq!(ledger_credits(CreditHolder::Account(BOB)), credits);
+
+		// Claims are held by the off-chain account ID they were claimed towards.
+		let user = DAVE;
+		let operator = EVE;
+		setup_delegation(user.clone(), operator, 1000);
+		run_to_block(20);
+		let max_claimable = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
+		assert!(max_claimable > 0);
+		let id: OffchainAccountIdOf<Runtime> = b"dave_ledger".to_vec().try_into().unwrap();
+		assert_ok!(claim_credits(user.clone(), max_claimable, b"dave_ledger"));
+		assert_eq!(ledger_credits(CreditHolder::Offchain(id)), max_claimable);
+		assert_eq!(ledger_credits(CreditHolder::Account(user)), 0);
+	});
+}
+
+#[test]
+fn transfer_and_spend_ledger_credits() {
+	new_test_ext(vec![]).execute_with(|| {
+		use tangle_primitives::traits::CreditsLedger;
+
+		System::set_block_number(10);
+		enable_ledger();
+		Balances::make_free_balance_be(&BOB, 1000);
+		assert_ok!(CreditsPallet::<Runtime>::burn(RuntimeOrigin::signed(BOB), 1));
+		let credits = <Runtime as crate::Config>::BurnConversionRate::get();
+
+		// Transfer half to a delegate account.
+		assert_ok!(CreditsPallet::<Runtime>::transfer_credits(
+			RuntimeOrigin::signed(BOB),
+			CreditHolder::Account(CHARLIE),
+			credits / 2
+		));
+		assert_eq!(ledger_credits(CreditHolder::Account(BOB)), credits - credits / 2);
+		assert_eq!(ledger_credits(CreditHolder::Account(CHARLIE)), credits / 2);
+		assert_noop!(
+			CreditsPallet::<Runtime>::transfer_credits(
+				RuntimeOrigin::signed(BOB),
+				CreditHolder::Account(BOB),
+				1
+			),
+			Error::<Runtime>::CannotTransferToSelf
+		);
+
+		// The delegate spends through the ledger trait used by other pallets.
+		assert_eq!(CreditsPallet::<Runtime>::spendable_credits(&CHARLIE), credits / 2);
+		assert_noop!(
+			CreditsPallet::<Runtime>::spend_credits(&CHARLIE, credits / 2 + 1),
+			Error::<Runtime>::InsufficientCredits
+		);
+		assert_ok!(CreditsPallet::<Runtime>::spend_credits(&CHARLIE, credits / 2));
+		assert_eq!(CreditsPallet::<Runtime>::spendable_credits(&CHARLIE), 0);
+
+		// Credits moved to an off-chain ID are spent by the spend origin only.
+		let id: OffchainAccountIdOf<Runtime> = b"bob_offchain".to_vec().try_into().unwrap();
+		assert_ok!(CreditsPallet::<Runtime>::transfer_credits(
+			RuntimeOrigin::signed(BOB),
+			CreditHolder::Offchain(id.clone()),
+			10
+		));
+		assert_noop!(
+			CreditsPallet::<Runtime>::spend_offchain_credits(
+				RuntimeOrigin::signed(BOB),
+				id.clone(),
+				10
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(CreditsPallet::<Runtime>::spend_offchain_credits(
+			RuntimeOrigin::root(),
+			id.clone(),
+			10
+		));
+		System::assert_last_event(
+			Event::CreditsSpent { holder: CreditHolder::Offchain(id), amount: 10 }.into(),
+		);
+	});
+}
+
+#[test]
+fn ledger_credits_expire() {
+	new_test_ext(vec![]).execute_with(|| {
+		System::set_block_number(10);
+		enable_ledger();
+		Balances::make_free_balance_be(&BOB, 1000);
+		let bucket = <Runtime as crate::Config>::CreditExpiryBlocks::get() /
+			<Runtime as crate::Config>::MaxCreditBatches::get() as u64;
+		let rate = <Runtime as crate::Config>::BurnConversionRate::get();
+
+		// Credits granted within the same expiry bucket share a batch.
+		assert_ok!(CreditsPallet::<Runtime>::burn(RuntimeOrigin::signed(BOB), 1));
+		let first_expiry = CreditsPallet::<Runtime>::credit_expiry_block();
+		System::set_block_number(10 + bucket);
+		assert_ok!(CreditsPallet::<Runtime>::burn(RuntimeOrigin::signed(BOB), 2));
+		let second_expiry = CreditsPallet::<Runtime>::credit_expiry_block();
+		assert_eq!(second_expiry, first_expiry + bucket);
+		assert_eq!(CreditsPallet::<Runtime>::credit_balances(CreditHolder::Account(BOB)).len(), 2);
+
+		// Spending takes the earliest-expiring batch first.
+		assert_ok!(CreditsPallet::<Runtime>::transfer_credits(
+			RuntimeOrigin::signed(BOB),
+			CreditHolder::Account(CHARLIE),
+			rate
+		));
+		assert_eq!(
+			CreditsPallet::<Runtime>::credit_balances(CreditHolder::Account(CHARLIE)).into_inner(),
+			vec![CreditBatch { amount: rate, expires_at: first_expiry }]
+		);
+
+		// Once the first batch expires only the second remains spendable.
+		System::set_block_number(first_expiry);
+		assert_eq!(ledger_credits(CreditHolder::Account(CHARLIE)), 0);
+		assert_eq!(ledger_credits(CreditHolder::Account(BOB)), 2 * rate);
+
+		System::set_block_number(second_expiry);
+		assert_eq!(ledger_credits(CreditHolder::Account(BOB)), 0);
+		assert_noop!(
+			CreditsPallet::<Runtime>::transfer_credits(
+				RuntimeOrigin::signed(BOB),
+				CreditHolder::Account(CHARLIE),
+				1
+			),
+			Error::<Runtime>::InsufficientCredits
+		);
+
+		assert_ok!(CreditsPallet::<Runtime>::purge_expired_credits(
+			RuntimeOrigin::signed(ALICE),
+			CreditHolder::Account(BOB)
+		));
+		System::assert_last_event(
+			Event::CreditsExpired { holder: CreditHolder::Account(BOB), amount: 2 * rate }.into(),
+		);
+		assert!(!CreditBalances::<Runtime>::contains_key(CreditHolder::Account(BOB)));
+	});
+}
+
+#[test]
+fn full_ledger_merges_credits_into_nearest_batch() {
+	new_test_ext(vec![]).execute_with(|| {
+		System::set_block_number(10);
+		enable_ledger();
+
+		// Fill every batch of the holder, as dust transfers with distinct expiries would.
+		let user = DAVE;
+		let operator = EVE;
+		let id: OffchainAccountIdOf<Runtime> = b"dave_ledger".to_vec().try_into().unwrap();
+		let holder = CreditHolder::Offchain(id);
+		let max_batches = <Runtime as crate::Config>::MaxCreditBatches::get();
+		let first_expiry = 1_000_000;
+		let batches = (0..max_batches as u64)
+			.map(|i| CreditBatch { amount: 1, expires_at: first_expiry + i })
+			.collect::<Vec<_>>();
+		CreditBalances::<Runtime>::insert(&holder, BoundedVec::truncate_from(batches));
+
+		// Claims towards the victim still succeed, merged into the batch expiring nearest.
+		setup_delegation(user.clone(), operator, 1000);
+		run_to_block(20);
+		let max_claimable = CreditsPallet::<Runtime>::get_accrued_amount(&user, None).unwrap();
+		assert!(max_claimable > 0);
+		assert_ok!(claim_credits(user, max_claimable, b"dave_ledger"));
+
+		let batches = CreditsPallet::<Runtime>::credit_balances(&holder);
+		assert_eq!(batches.len(), max_batches as usize);
+		assert_eq!(
+			batches.last().unwrap(),
+			&CreditBatch {
+				amount: 1 + max_claimable,
+				expires_at: first_expiry + max_batches as u64 - 1
+			}
+		);
+		assert_eq!(ledger_credits(holder), max_batches as u128 + max_claimable);
+	});
+}
@@ -32,3 +32,43 @@ pub type BlockNumberOf<T> = frame_system::pallet_prelude::BlockNumberFor<T>;
 
 /// Type alias for the bounded vector representing the off-chain account ID.
 pub type OffchainAccountIdOf<T> = BoundedVec<u8, <T as crate::Config>::MaxOffchainAccountIdLength>;
+
+/// The owner of an on-chain credit balance.
+///
+/// Credits earned on-chain are held by the earning account, while credits claimed towards an
+/// off-chain identity are held by that identity until spent by the off-chain system.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CreditHolder<AccountId, OffchainAccountId> {
+	/// Credits held by an on-chain account.
+	Account(AccountId),
+	/// Credits held by an off-chain account ID.
+	Offchain(OffchainAccountId),
+}
+
+/// A batch of ledger credits sharing the same expiry block.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CreditBatch<Balance, BlockNumber> {
+	/// The amount of credits remaining in this batch.
+	pub amount: Balance,
+	/// The block at which the remaining credits in this batch expire.
+	pub expires_at: BlockNumber,
+}
+
+/// Type alias for a ledger credit holder of the pallet.
+pub type CreditHolderOf<T> =
+	CreditHolder<<T as frame_system::Config>::AccountId, OffchainAccountIdOf<T>>;
+
+/// Type alias for a ledger credit batch of the pallet.
+pub type CreditBatchOf<T> = CreditBatch<crate::BalanceOf<T>, BlockNumberOf<T>>;
+
+/// The stake held by an account from `block` onwards, until the next checkpoint.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StakeCheckpoint<Balance, BlockNumber> {
+	/// The block at which the stake changed.
+	pub block: BlockNumber,
+	/// The stake held from `block` onwards.
+	pub stake: Balance,
+}
+
+/// Type alias for a stake checkpoint of the pallet.
+pub type StakeCheckpointOf<T> = StakeCheckpoint<crate::BalanceOf<T>, BlockNumberOf<T>>;
@@ -52,6 +52,14 @@ pub trait WeightInfo {
 	fn claim_credits_with_asset() -> Weight;
 	/// Weight for the `set_asset_stake_tiers` extrinsic
 	fn set_asset_stake_tiers() -> Weight;
+	/// Weight for the `set_ledger_enabled` extrinsic
+	fn set_ledger_enabled() -> Weight;
+	/// Weight for the `transfer_credits` extrinsic
+	fn transfer_credits() -> Weight;
+	/// Weight for the `spend_offchain_credits` extrinsic
+	fn spend_offchain_credits() -> Weight;
+	/// Weight for the `purge_expired_credits` extrinsic
+	fn purge_expired_credits() -> Weight;
 }
 
 /// Weights for credits pallet using the Substrate node and recommended hardware.
@@ -130,6 +138,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(19_450_000, 32)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: `Credits::LedgerEnabled` (r:0 w:1)
+	/// Proof: `Credits::LedgerEnabled` (`max_values`: Some(1), `max_size`: Some(1), mode: `Measured`)
+	fn set_ledger_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_100_000 picoseconds.
+		Weight::from_parts(9_550_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Credits::LedgerEnabled` (r:1 w:0)
+	/// Proof: `Credits::LedgerEnabled` (`max_values`: Some(1), `max_size`: Some(1), mode: `Measured`)
+	/// Storage: `Credits::CreditBalances` (r:2 w:2)
+	/// Proof: `Credits::CreditBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_credits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1536`
+		//  Estimated: `7476`
+		// Minimum execution time: 41_300_000 picoseconds.
+		Weight::from_parts(42_600_000, 7476)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Credits::LedgerEnabled` (r:1 w:0)
+	/// Proof: `Credits::LedgerEnabled` (`max_values`: Some(1), `max_size`: Some(1), mode: `Measured`)
+	/// Storage: `Credits::CreditBalances` (r:1 w:1)
+	/// Proof: `Credits::CreditBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn spend_offchain_credits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `768`
+		//  Estimated: `4233`
+		// Minimum execution time: 27_900_000 picoseconds.
+		Weight::from_parts(28_700_000, 4233)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Credits::CreditBalances` (r:1 w:1)
+	/// Proof: `Credits::CreditBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn purge_expired_credits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `768`
+		//  Estimated: `4233`
+		// Minimum execution time: 21_400_000 picoseconds.
+		Weight::from_parts(22_150_000, 4233)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -161,4 +220,27 @@ impl WeightInfo for () {
 		Weight::from_parts(19_450_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	fn set_ledger_enabled() -> Weight {
+		Weight::from_parts(9_550_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn transfer_credits() -> Weight {
+		Weight::from_parts(42_600_000, 7476)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	fn spend_offchain_credits() -> Weight {
+		Weight::from_parts(28_700_000, 4233)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn purge_expired_credits() -> Weight {
+		Weight::from_parts(22_150_000, 4233)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the multi-asset-delegation pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::type_complexity)]
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait MultiAssetDelegationApi<AccountId, OperatorProfile, PendingRequest>
+	where
+		AccountId: Codec,
+		OperatorProfile: Codec,
+		PendingRequest: Codec,
+	{
+		/// Query the public profile of an operator.
+		///
+		/// ## Arguments
+		/// - `operator`: The account id of the operator.
+		/// ## Return
+		/// - The profile of the operator, or `None` if the operator has not set one.
+		fn operator_profile(operator: AccountId) -> Option<OperatorProfile>;
+
+		/// Query the operators whose profile matches the given filters.
+		///
+		/// ## Arguments
+		/// - `region`: Only return operators in this region, if set.
+		/// - `hardware_class`: Only return operators with this hardware class, if set.
+		/// ## Return
+		/// - The matching operators along with their profiles.
+		fn operators_by_profile(
+			region: Option<Vec<u8>>,
+			hardware_class: Option<Vec<u8>>,
+		) -> Vec<(AccountId, OperatorProfile)>;
+
+		/// Query the pending withdraw and unstake requests of a delegator.
+		///
+		/// ## Arguments
+		/// - `who`: The account id of the delegator.
+		/// ## Return
+		/// - The pending requests, each with the round from which it can be executed.
+		fn pending_requests(who: AccountId) -> Vec<PendingRequest>;
+	}
+}
@@ -15,18 +15,22 @@
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 use super::*;
 use crate::{Pallet as MultiAssetDelegation, types::*};
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_benchmarking::{BenchmarkError, account, benchmarks, whitelisted_caller};
 use frame_support::{
 	BoundedVec,
-	traits::{Currency, Get},
+	traits::{Currency, EnsureOrigin, Get, fungibles::Inspect},
 };
-use frame_system::RawOrigin;
+use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
 use sp_core::H160;
+use sp_runtime::{Perbill, traits::Zero};
 use sp_std::vec;
 use tangle_primitives::{BlueprintId, rewards::LockMultiplier, services::Asset};
 
 const SEED: u32 = 0;
 const INITIAL_BALANCE: u32 = 1_000_000;
+/// The maximum number of assets delegated under a lock tier that `set_lock_tier` is benchmarked
+/// with.
+const MAX_LOCKED_ASSETS: u32 = 100;
 
 fn native_asset_id<T: Config>() -> T::AssetId
 where
@@ -51,6 +55,107 @@ where
 	Ok(caller)
 }
 
+fn liquid_receipt_asset_id<T: Config>() -> T::AssetId
+where
+	T::AssetId: From<u32>,
+{
+	1_000u32.into()
+}
+
+fn setup_operator<T: Config>() -> Result<T::AccountId, &'static str>
+where
+	T::AssetId: From<u32>,
+{
+	let operator: T::AccountId = account("operator", 1, SEED);
+	let bond_amount: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+	T::Currency::make_free_balance_be(
+		&operator,
+		T::Currency::minimum_balance() * INITIAL_BALANCE.into(),
+	);
+	MultiAssetDelegation::<T>::join_operators(
+		RawOrigin::Signed(operator.clone()).into(),
+		bond_amount,
+	)?;
+	Ok(operator)
+}
+
+/// Sets up a native liquid vault with shares held by another account and rewards pending to be
+/// delegated again, so that every call on the vault claims and compounds its rewards.
+fn setup_liquid_vault<T: Config>() -> Result<(T::AccountId, Asset<T::AssetId>), BenchmarkError>
+where
+	T::AssetId: From<u32>,
+{
+	let operator = setup_operator::<T>()?;
+	let asset = Asset::Custom(native_asset_id::<T>());
+	let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	MultiAssetDelegation::<T>::create_liquid_vault(
+		origin,
+		operator.clone(),
+		asset,
+		liquid_receipt_asset_id::<T>(),
+	)?;
+
+	let holder: T::AccountId = account("holder", 0, SEED);
+	let amount: BalanceOf<T> = T::Currency::minimum_balance() * 100u32.into();
+	T::Currency::make_free_balance_be(
+		&holder,
+		T::Currency::minimum_balance() * INITIAL_BALANCE.into(),
+	);
+	MultiAssetDelegation::<T>::deposit(
+		RawOrigin::Signed(holder.clone()).into(),
+		asset,
+		amount,
+		None,
+		None,
+	)?;
+	MultiAssetDelegation::<T>::liquid_delegate(
+		RawOrigin::Signed(holder).into(),
+		operator.clone(),
+		asset,
+		amount,
+	)?;
+
+	let vault_account = MultiAssetDelegation::<T>::liquid_vault_account(&operator, asset);
+	T::Currency::make_free_balance_be(&vault_account, T::MinDelegateAmount::get() * 2u32.into());
+	Ok((operator, asset))
+}
+
+fn max_operator_profile<T: Config>() -> OperatorProfileOf<T> {
+	let field_length = T::MaxOperatorProfileFieldLength::get() as usize;
+	let description_length = T::MaxOperatorProfileDescriptionLength::get() as usize;
+	let field = || BoundedVec::truncate_from(vec![b'a'; field_length]);
+	OperatorProfile {
+		name: field(),
+		website: field(),
+		contact: field(),
+		region: field(),
+		hardware_class: field(),
+		blueprints_description: BoundedVec::truncate_from(vec![b'a'; description_length]),
+	}
+}
+
+fn setup_operator_with_profile<T: Config>(
+	profile: OperatorProfileOf<T>,
+) -> Result<T::AccountId, &'static str>
+where
+	T::AssetId: From<u32>,
+{
+	let caller = setup_benchmark::<T>()?;
+	let bond_amount: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+	let max_deposit =
+		MultiAssetDelegation::<T>::operator_profile_deposit(&max_operator_profile::<T>());
+	T::Currency::make_free_balance_be(&caller, T::Currency::free_balance(&caller) + max_deposit);
+	MultiAssetDelegation::<T>::join_operators(
+		RawOrigin::Signed(caller.clone()).into(),
+		bond_amount,
+	)?;
+	MultiAssetDelegation::<T>::set_operator_profile(
+		RawOrigin::Signed(caller.clone()).into(),
+		profile,
+	)?;
+	Ok(caller)
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -452,4 +557,188 @@ benchmarks! {
 			assert!(!ids.contains(&blueprint_id));
 		}
 	}
+
+	set_operator_profile {
+		// Replace a minimal profile with one of the maximum size, which reserves the difference.
+		let caller = setup_operator_with_profile::<T>(OperatorProfile {
+			name: BoundedVec::truncate_from(vec![b'a']),
+			website: Default::default(),
+			contact: Default::default(),
+			region: Default::default(),
+			hardware_class: Default::default(),
+			blueprints_description: Default::default(),
+		})?;
+		let profile = max_operator_profile::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), profile.clone())
+	verify {
+		assert_eq!(OperatorProfiles::<T>::get(&caller).map(|(profile, _)| profile), Some(profile));
+	}
+
+	clear_operator_profile {
+		let caller = setup_operator_with_profile::<T>(max_operator_profile::<T>())?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!OperatorProfiles::<T>::contains_key(&caller));
+	}
+
+	create_liquid_vault {
+		let operator = setup_operator::<T>()?;
+		let asset = Asset::Custom(native_asset_id::<T>());
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, operator.clone(), asset, liquid_receipt_asset_id::<T>())
+	verify {
+		assert!(LiquidVaults::<T>::contains_key(&operator, asset));
+	}
+
+	liquid_delegate {
+		let (operator, asset) = setup_liquid_vault::<T>()?;
+		let caller = setup_benchmark::<T>()?;
+		let amount: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+		MultiAssetDelegation::<T>::deposit(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset,
+			amount,
+			None,
+			None
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), operator, asset, amount)
+	verify {
+		assert!(!T::Fungibles::balance(liquid_receipt_asset_id::<T>(), &caller).is_zero());
+	}
+
+	liquid_unstake {
+		let (operator, asset) = setup_liquid_vault::<T>()?;
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let shares = T::Fungibles::balance(liquid_receipt_asset_id::<T>(), &holder) / 2u32.into();
+	}: _(RawOrigin::Signed(holder.clone()), operator, asset, shares)
+	verify {
+		assert_eq!(LiquidUnstakeRequests::<T>::get(&holder).len(), 1);
+	}
+
+	execute_liquid_unstake {
+		// Every ready request hands back deposit from the vault.
+		let n in 1 .. T::MaxUnstakeRequests::get();
+		let (operator, asset) = setup_liquid_vault::<T>()?;
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let shares = T::Fungibles::balance(liquid_receipt_asset_id::<T>(), &holder) / (n + 1).into();
+		for _ in 0..n {
+			MultiAssetDelegation::<T>::liquid_unstake(
+				RawOrigin::Signed(holder.clone()).into(),
+				operator.clone(),
+				asset,
+				shares
+			)?;
+		}
+		let current_round = Pallet::<T>::current_round();
+		CurrentRound::<T>::put(current_round + T::DelegationBondLessDelay::get());
+	}: _(RawOrigin::Signed(holder.clone()))
+	verify {
+		assert!(LiquidUnstakeRequests::<T>::get(&holder).is_empty());
+	}
+
+	execute_all_ready {
+		let u in 1 .. T::MaxUnstakeRequests::get();
+		let w in 1 .. T::MaxWithdrawRequests::get();
+		let caller = setup_benchmark::<T>()?;
+		let operator = setup_operator::<T>()?;
+		let unit: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+		let asset = Asset::Custom(native_asset_id::<T>());
+		let delegated = unit * u.into();
+
+		MultiAssetDelegation::<T>::deposit(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset,
+			delegated + unit * w.into(),
+			None,
+			None
+		)?;
+		MultiAssetDelegation::<T>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			operator.clone(),
+			asset,
+			delegated,
+			DelegatorBlueprintSelection::All
+		)?;
+		// Requests made in different rounds are not merged.
+		let start = Pallet::<T>::current_round();
+		for round in 0..u.max(w) {
+			CurrentRound::<T>::put(start + round);
+			if round < u {
+				MultiAssetDelegation::<T>::schedule_delegator_unstake(
+					RawOrigin::Signed(caller.clone()).into(),
+					operator.clone(),
+					asset,
+					unit
+				)?;
+			}
+			if round < w {
+				MultiAssetDelegation::<T>::schedule_withdraw(
+					RawOrigin::Signed(caller.clone()).into(),
+					asset,
+					unit
+				)?;
+			}
+		}
+		let delay = T::DelegationBondLessDelay::get().max(T::LeaveDelegatorsDelay::get());
+		CurrentRound::<T>::put(start + u.max(w) + delay);
+	}: _(RawOrigin::Signed(caller.clone()), None)
+	verify {
+		let delegator = Delegators::<T>::get(&caller).unwrap();
+		assert!(delegator.delegator_unstake_requests.is_empty());
+		assert!(delegator.withdraw_requests.is_empty());
+	}
+
+	claim_liquid_vault_rewards {
+		let (operator, asset) = setup_liquid_vault::<T>()?;
+		let caller: T::AccountId = whitelisted_caller();
+		let vault_account = MultiAssetDelegation::<T>::liquid_vault_account(&operator, asset);
+	}: _(RawOrigin::Signed(caller), operator, asset)
+	verify {
+		// The rewards of the native vault were delegated again.
+		assert!(T::Currency::free_balance(&vault_account) < T::MinDelegateAmount::get());
+	}
+
+	set_lock_tier {
+		// Every asset delegated under the tier has its vault score updated with the new multiplier.
+		let n in 0 .. MAX_LOCKED_ASSETS;
+		let amount: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+		for i in 0..n {
+			LockedDelegations::<T>::insert(
+				LockMultiplier::TwoMonths,
+				Asset::Custom((i + 1).into()),
+				amount
+			);
+		}
+		let duration: BlockNumberFor<T> = 100u32.into();
+	}: _(RawOrigin::Root, LockMultiplier::TwoMonths, duration, 3, n)
+	verify {
+		let tier = LockTiers::<T>::get(LockMultiplier::TwoMonths).unwrap();
+		assert_eq!(tier.duration, duration);
+		assert_eq!(tier.multiplier, 3);
+	}
+
+	set_early_unlock_penalty {
+		let penalty = Some(Perbill::from_percent(10));
+	}: _(RawOrigin::Root, penalty)
+	verify {
+		assert_eq!(EarlyUnlockPenalty::<T>::get(), penalty);
+	}
+
+	extend_lock {
+		let caller = setup_benchmark::<T>()?;
+		let amount: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+		let asset = Asset::Custom(native_asset_id::<T>());
+		MultiAssetDelegation::<T>::deposit(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset,
+			amount,
+			None,
+			Some(LockMultiplier::OneMonth)
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), asset, 0, LockMultiplier::SixMonths)
+	verify {
+		let delegator = Delegators::<T>::get(&caller).unwrap();
+		let locks = delegator.deposits.get(&asset).unwrap().locks.clone().unwrap();
+		assert_eq!(locks[0].lock_multiplier, LockMultiplier::SixMonths);
+	}
 }
@@ -29,7 +29,7 @@ use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 use tangle_primitives::{
 	RoundIndex,
 	services::Asset,
-	traits::{MultiAssetDelegationInfo, RewardsManager},
+	traits::{DelegationHooks, MultiAssetDelegationInfo, RewardsManager},
 };
 
 pub const DELEGATION_LOCK_ID: LockIdentifier = *b"delegate";
@@ -37,15 +37,13 @@ pub const DELEGATION_LOCK_ID: LockIdentifier = *b"delegate";
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 type DelegationResult<T> = Vec<(AccountIdOf<T>, Asset<<T as Config>::AssetId>, BalanceOf<T>)>;
-type BondLessRequestResult<T> = Result<
-	BondLessRequest<
-		AccountIdOf<T>,
-		<T as Config>::AssetId,
-		BalanceOf<T>,
-		<T as Config>::MaxDelegatorBlueprints,
-	>,
-	DispatchError,
+type BondLessRequestOf<T> = BondLessRequest<
+	AccountIdOf<T>,
+	<T as Config>::AssetId,
+	BalanceOf<T>,
+	<T as Config>::MaxDelegatorBlueprints,
 >;
+type BondLessRequestResult<T> = Result<BondLessRequestOf<T>, DispatchError>;
 type DelegatorBondInfo<T> = BondInfoDelegator<
 	AccountIdOf<T>,
 	BalanceOf<T>,
@@ -118,7 +116,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(Self::is_operator_active(&operator), Error::<T>::NotActiveOperator);
 		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
 
-		Delegators::<T>::try_mutate(&who, |maybe_metadata| {
+		Delegators::<T>::try_mutate(&who, |maybe_metadata| -> DispatchResult {
 			let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
 
 			// Ensure enough deposited balance and update it
@@ -170,12 +168,18 @@ impl<T: Config> Pallet<T> {
 			// Record credits and delegation tracking
 			let _ =
 				T::RewardsManager::record_delegate(&who, &operator, asset, amount, lock_multiplier);
+			if let Some(lock_multiplier) = lock_multiplier {
+				Self::record_locked_delegation(asset, amount, lock_multiplier);
+			}
 
 			// Emit event
 			Self::deposit_event(Event::Delegated { who: who.clone(), operator, amount, asset });
 
 			Ok(())
-		})
+		})?;
+
+		T::DelegationHooks::on_stake_changed(&who, &asset);
+		Ok(())
 	}
 
 	/// Schedules a stake reduction for a delegator.
@@ -259,28 +263,29 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	/// Cancels a scheduled stake reduction for a delegator.
+	/// Cancels an amount of the scheduled stake reductions of a delegator.
 	///
-	/// This function removes a pending unstake request without modifying any actual delegations.
-	/// It performs a simple lookup and removal of the matching request.
+	/// This function reduces pending unstake requests without modifying any actual delegations.
+	/// The amount does not need to match a single request: it is taken from the most recent
+	/// requests for the operator and asset first, partially reducing a request if needed.
 	///
 	/// # Performance Considerations
 	///
 	/// - Single storage read for request verification
-	/// - Single storage write for request removal
-	/// - O(n) search through unstake requests
+	/// - Single storage write for request update
+	/// - O(n) pass through unstake requests
 	///
 	/// # Arguments
 	///
 	/// * `who` - The account ID of the delegator
-	/// * `operator` - The operator whose unstake request to cancel
-	/// * `asset` - The asset of the unstake request
-	/// * `amount` - The exact amount of the unstake request to cancel
+	/// * `operator` - The operator whose unstake requests to cancel
+	/// * `asset` - The asset of the unstake requests
+	/// * `amount` - The amount to cancel
 	///
 	/// # Errors
 	///
 	/// * `NotDelegator` - Account is not a delegator
-	/// * `NoBondLessRequest` - No matching unstake request found
+	/// * `NoBondLessRequest` - The pending unstake requests do not add up to the amount
 	/// * `InvalidAmount` - Amount specified is zero
 	///
 	/// # Example
@@ -305,19 +310,29 @@ impl<T: Config> Pallet<T> {
 		Delegators::<T>::try_mutate(&who, |maybe_metadata| {
 			let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
 
-			// Find and remove the matching unstake request
-			let request_index = metadata
+			let is_match = |r: &BondLessRequestOf<T>| {
+				r.asset == asset && r.operator == operator && !r.is_nomination
+			};
+			let pending: BalanceOf<T> = metadata
 				.delegator_unstake_requests
 				.iter()
-				.position(|r| {
-					r.asset == asset &&
-						r.amount == amount && r.operator == operator &&
-						!r.is_nomination
-				})
-				.ok_or(Error::<T>::NoBondLessRequest)?;
+				.filter(|r| is_match(r))
+				.fold(Zero::zero(), |acc, r| acc.saturating_add(r.amount));
+			ensure!(pending >= amount, Error::<T>::NoBondLessRequest);
 
-			// Remove the request and emit event
-			metadata.delegator_unstake_requests.remove(request_index);
+			// Take the amount from the most recent requests first
+			let mut remaining = amount;
+			for request in metadata.delegator_unstake_requests.iter_mut().rev() {
+				if remaining.is_zero() {
+					break;
+				}
+				if is_match(request) {
+					let cancelled = remaining.min(request.amount);
+					request.amount = request.amount.saturating_sub(cancelled);
+					remaining = remaining.saturating_sub(cancelled);
+				}
+			}
+			metadata.delegator_unstake_requests.retain(|r| !r.amount.is_zero());
 
 			Ok(())
 		})
@@ -350,7 +365,7 @@ impl<T: Config> Pallet<T> {
 	pub fn process_execute_delegator_unstake(
 		who: T::AccountId,
 	) -> Result<DelegationResult<T>, DispatchError> {
-		Delegators::<T>::try_mutate(
+		let unstaked = Delegators::<T>::try_mutate(
 			&who,
 			|maybe_metadata| -> Result<DelegationResult<T>, DispatchError> {
 				let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
@@ -366,18 +381,8 @@ impl<T: Config> Pallet<T> {
 				let (deposit_updates, delegation_updates, operator_updates, indices_to_remove) =
 					Self::aggregate_unstake_requests(metadata, current_round, delay)?;
 
-				// Create a map to aggregate amounts by operator and asset
-				let mut event_aggregates =
-					BTreeMap::<(T::AccountId, Asset<T::AssetId>), BalanceOf<T>>::new();
-
-				// Sum up amounts by operator and asset
-				for &idx in &indices_to_remove {
-					if let Some(request) = metadata.delegator_unstake_requests.get(idx) {
-						let key = (request.operator.clone(), request.asset);
-						let entry = event_aggregates.entry(key).or_insert(Zero::zero());
-						*entry = entry.saturating_add(request.amount);
-					}
-				}
+				// The unstaked amounts by operator and asset
+				let event_aggregates = operator_updates.clone();
 
 				// Apply updates in batches
 				// 1. Update deposits
@@ -428,7 +433,12 @@ impl<T: Config> Pallet<T> {
 					.map(|((operator, asset), amount)| (operator, asset, amount))
 					.collect())
 			},
-		)
+		)?;
+
+		for (_, asset, _) in &unstaked {
+			T::DelegationHooks::on_stake_changed(&who, asset);
+		}
+		Ok(unstaked)
 	}
 
 	/// Processes the delegation of nominated tokens to an operator.
@@ -896,7 +906,10 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Helper function to create an unstake request
+	/// Helper function to create an unstake request.
+	///
+	/// A request for the same delegation made in the current round matures at the same time, so
+	/// the amount is merged into it instead of taking up another slot.
 	fn create_unstake_request(
 		metadata: &mut DelegatorMetadataOf<T>,
 		operator: T::AccountId,
@@ -905,11 +918,22 @@ impl<T: Config> Pallet<T> {
 		blueprint_selection: DelegatorBlueprintSelection<T::MaxDelegatorBlueprints>,
 		is_nomination: bool,
 	) -> DispatchResult {
+		let current_round = Self::current_round();
+		if let Some(request) = metadata.delegator_unstake_requests.iter_mut().find(|r| {
+			r.operator == operator &&
+				r.asset == asset &&
+				r.is_nomination == is_nomination &&
+				r.requested_round == current_round
+		}) {
+			request.amount = request.amount.saturating_add(amount);
+			return Ok(());
+		}
+
 		let unstake_request = BondLessRequest {
 			operator,
 			asset,
 			amount,
-			requested_round: Self::current_round(),
+			requested_round: current_round,
 			blueprint_selection,
 			is_nomination,
 		};
@@ -935,25 +959,32 @@ impl<T: Config> Pallet<T> {
 		let mut operator_updates = BTreeMap::new();
 
 		for (idx, request) in metadata.delegator_unstake_requests.iter().enumerate() {
-			if current_round < delay + request.requested_round {
+			// Nomination unstakes are executed separately, per operator
+			if request.is_nomination || current_round < delay + request.requested_round {
 				continue;
 			}
 
-			*deposit_updates.entry(request.asset).or_default() += request.amount;
-
 			let delegation_key = (request.operator.clone(), request.asset);
-			if let Some(delegation_idx) = metadata.delegations.iter().position(|d| {
-				d.operator == request.operator && d.asset == request.asset && !d.is_nomination
-			}) {
-				let (_, total_unstake) = delegation_updates
-					.entry(delegation_key.clone())
-					.or_insert((delegation_idx, BalanceOf::<T>::zero()));
-				*total_unstake += request.amount;
-			} else {
-				return Err(Error::<T>::NoActiveDelegation);
-			}
-
-			*operator_updates.entry(delegation_key).or_default() += request.amount;
+			let delegation_idx = metadata
+				.delegations
+				.iter()
+				.position(|d| {
+					d.operator == request.operator && d.asset == request.asset && !d.is_nomination
+				})
+				.ok_or(Error::<T>::NoActiveDelegation)?;
+			let (_, total_unstake) = delegation_updates
+				.entry(delegation_key.clone())
+				.or_insert((delegation_idx, BalanceOf::<T>::zero()));
+
+			// Slashes reduce the requests pro-rata with rounding up, clamp anyway so that the
+			// requests can never unstake more than what remains of the delegation
+			let remaining =
+				metadata.delegations[delegation_idx].amount.saturating_sub(*total_unstake);
+			let amount = request.amount.min(remaining);
+			*total_unstake += amount;
+
+			*deposit_updates.entry(request.asset).or_default() += amount;
+			*operator_updates.entry(delegation_key).or_default() += amount;
 			indices_to_remove.push(idx);
 		}
 		ensure!(!indices_to_remove.is_empty(), Error::<T>::BondLessNotReady);
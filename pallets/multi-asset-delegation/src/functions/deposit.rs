@@ -21,9 +21,10 @@ use frame_support::{
 	traits::{Currency, Get, fungibles::Mutate, tokens::Preservation},
 };
 use sp_core::H160;
-use sp_runtime::traits::Zero;
+use sp_runtime::traits::{Saturating, Zero};
 use tangle_primitives::{
 	services::{Asset, EvmAddressMapping},
+	traits::DelegationHooks,
 	types::rewards::LockMultiplier,
 };
 
@@ -98,11 +99,15 @@ impl<T: Config> Pallet<T> {
 		lock_multiplier: Option<LockMultiplier>,
 	) -> DispatchResult {
 		ensure!(amount >= T::MinDelegateAmount::get(), Error::<T>::BondTooLow);
+		if let Some(lock_multiplier) = lock_multiplier {
+			Self::ensure_lock_tier(lock_multiplier)?;
+		}
 
 		// Transfer the amount to the pallet account
 		Self::handle_transfer_to_pallet(&who, asset, amount)?;
 
 		let now = <frame_system::Pallet<T>>::block_number();
+		let lock = lock_multiplier.map(|multiplier| (multiplier, Self::lock_duration(multiplier)));
 
 		// Update storage
 		Delegators::<T>::try_mutate(&who, |maybe_metadata| -> DispatchResult {
@@ -110,17 +115,18 @@ impl<T: Config> Pallet<T> {
 			// If there's an existing deposit, increase it
 			if let Some(existing) = metadata.deposits.get_mut(&asset) {
 				existing
-					.increase_deposited_amount(amount, lock_multiplier, now)
+					.increase_deposited_amount(amount, lock, now)
 					.map_err(|_| Error::<T>::InsufficientBalance)?;
 			} else {
 				// Create a new deposit if none exists
-				let new_deposit = Deposit::new(amount, lock_multiplier, now);
+				let new_deposit = Deposit::new(amount, lock, now);
 				metadata.deposits.insert(asset, new_deposit);
 			}
 
 			Ok(())
 		})?;
 
+		T::DelegationHooks::on_stake_changed(&who, &asset);
 		Ok(())
 	}
 
@@ -141,7 +147,7 @@ impl<T: Config> Pallet<T> {
 		asset: Asset<T::AssetId>,
 		amount: BalanceOf<T>,
 	) -> DispatchResult {
-		Delegators::<T>::try_mutate(&who, |maybe_metadata| {
+		Delegators::<T>::try_mutate(&who, |maybe_metadata| -> DispatchResult {
 			let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
 
 			let now = <frame_system::Pallet<T>>::block_number();
@@ -153,16 +159,27 @@ impl<T: Config> Pallet<T> {
 				.decrease_deposited_amount(amount, now)
 				.map_err(|_| Error::<T>::InsufficientBalance)?;
 
-			// Create the unstake request
+			// Merge into a request for the same asset made this round, since it matures at the
+			// same time, or create a new one
 			let current_round = Self::current_round();
-			let mut withdraw_requests = metadata.withdraw_requests.clone();
-			withdraw_requests
-				.try_push(WithdrawRequest { asset, amount, requested_round: current_round })
-				.map_err(|_| Error::<T>::MaxWithdrawRequestsExceeded)?;
-			metadata.withdraw_requests = withdraw_requests;
+			if let Some(request) = metadata
+				.withdraw_requests
+				.iter_mut()
+				.find(|r| r.asset == asset && r.requested_round == current_round)
+			{
+				request.amount = request.amount.saturating_add(amount);
+			} else {
+				metadata
+					.withdraw_requests
+					.try_push(WithdrawRequest { asset, amount, requested_round: current_round })
+					.map_err(|_| Error::<T>::MaxWithdrawRequestsExceeded)?;
+			}
 
 			Ok(())
-		})
+		})?;
+
+		T::DelegationHooks::on_stake_changed(&who, &asset);
+		Ok(())
 	}
 
 	/// Returns an iterator over all withdraw requests that are ready to be executed.
@@ -262,45 +279,62 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	/// Cancels a withdraw request for a delegator.
+	/// Cancels an amount of the pending withdraw requests of a delegator for an asset.
+	///
+	/// The amount does not need to match a single request: it is taken from the most recent
+	/// requests first, partially reducing a request if needed, and added back to the deposit.
 	///
 	/// # Arguments
 	///
 	/// * `who` - The account ID of the delegator.
-	/// * `asset` - The asset ID of the withdraw request to cancel.
-	/// * `amount` - The amount of the withdraw request to cancel.
+	/// * `asset` - The asset ID of the withdraw requests to cancel.
+	/// * `amount` - The amount to cancel.
 	///
 	/// # Errors
 	///
-	/// Returns an error if the user is not a delegator or if there is no matching withdraw request.
+	/// Returns an error if the user is not a delegator or if the pending withdraw requests for the
+	/// asset do not add up to the amount.
 	pub fn process_cancel_withdraw(
 		who: T::AccountId,
 		asset: Asset<T::AssetId>,
 		amount: BalanceOf<T>,
 	) -> DispatchResult {
-		Delegators::<T>::try_mutate(&who, |maybe_metadata| {
+		Delegators::<T>::try_mutate(&who, |maybe_metadata| -> DispatchResult {
 			let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
 			let now = <frame_system::Pallet<T>>::block_number();
 
-			// Find and remove the matching withdraw request
-			let request_index = metadata
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+			let pending: BalanceOf<T> = metadata
 				.withdraw_requests
 				.iter()
-				.position(|r| r.asset == asset && r.amount == amount)
-				.ok_or(Error::<T>::NoMatchingwithdrawRequest)?;
+				.filter(|r| r.asset == asset)
+				.fold(Zero::zero(), |acc, r| acc.saturating_add(r.amount));
+			ensure!(pending >= amount, Error::<T>::NoMatchingwithdrawRequest);
 
-			let withdraw_request = metadata.withdraw_requests.remove(request_index);
+			// Take the amount from the most recent requests first
+			let mut remaining = amount;
+			for request in metadata.withdraw_requests.iter_mut().rev() {
+				if remaining.is_zero() {
+					break;
+				}
+				if request.asset == asset {
+					let cancelled = remaining.min(request.amount);
+					request.amount = request.amount.saturating_sub(cancelled);
+					remaining = remaining.saturating_sub(cancelled);
+				}
+			}
+			metadata.withdraw_requests.retain(|r| !r.amount.is_zero());
 
 			// Add the amount back to the delegator's deposits
-			if let Some(deposit) = metadata.deposits.get_mut(&withdraw_request.asset) {
+			if let Some(deposit) = metadata.deposits.get_mut(&asset) {
 				deposit
-					.increase_deposited_amount(withdraw_request.amount, None, now)
+					.increase_deposited_amount(amount, None, now)
 					.map_err(|_| Error::<T>::InsufficientBalance)?;
 			} else {
 				// we are only able to withdraw from existing deposits without any locks
 				// so when we add back, add it without any locks
-				let new_deposit = Deposit::new(withdraw_request.amount, None, now);
-				metadata.deposits.insert(withdraw_request.asset, new_deposit);
+				let new_deposit = Deposit::new(amount, None, now);
+				metadata.deposits.insert(asset, new_deposit);
 			}
 
 			// Update the status if no more delegations exist
@@ -309,6 +343,9 @@ impl<T: Config> Pallet<T> {
 			}
 
 			Ok(())
-		})
+		})?;
+
+		T::DelegationHooks::on_stake_changed(&who, &asset);
+		Ok(())
 	}
 }
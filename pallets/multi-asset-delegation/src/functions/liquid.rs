@@ -0,0 +1,428 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	Config, Delegators, Error, LiquidUnstakeRequests, LiquidVaults, Pallet,
+	types::{delegator::DelegatorBlueprintSelection, *},
+};
+use frame_support::{
+	BoundedVec, ensure,
+	pallet_prelude::DispatchResult,
+	sp_runtime::traits::AccountIdConversion,
+	storage::with_storage_layer,
+	traits::{
+		Currency, ExistenceRequirement, Get,
+		fungibles::{Create, Mutate},
+		tokens::{Fortitude, Precision, Preservation},
+	},
+};
+use parity_scale_codec::Encode;
+use sp_runtime::{
+	DispatchError, FixedPointNumber, FixedU128, Percent, Rounding, SaturatedConversion,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{CheckedDiv, One, Saturating, Zero},
+};
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+use tangle_primitives::{
+	services::Asset,
+	traits::{DelegationHooks, RewardsManager},
+};
+
+type LiquidUnstakeResult<T> =
+	Vec<(<T as frame_system::Config>::AccountId, Asset<<T as Config>::AssetId>, BalanceOf<T>)>;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the account holding the deposits and delegation of a liquid vault.
+	pub fn liquid_vault_account(operator: &T::AccountId, asset: Asset<T::AssetId>) -> T::AccountId {
+		let seed = sp_io::hashing::blake2_256(&(b"liquid", operator, asset).encode());
+		T::PalletId::get().into_sub_account_truncating(seed)
+	}
+
+	/// Returns the stake backing the outstanding shares of a liquid vault.
+	///
+	/// This is the vault's delegation to the operator, net of slashes and of the amounts already
+	/// owed to pending unstake requests.
+	pub fn liquid_vault_backing(operator: &T::AccountId, asset: Asset<T::AssetId>) -> BalanceOf<T> {
+		let vault_account = Self::liquid_vault_account(operator, asset);
+		let Some(metadata) = Delegators::<T>::get(&vault_account) else {
+			return Zero::zero();
+		};
+
+		let delegated = metadata
+			.delegations
+			.iter()
+			.find(|d| &d.operator == operator && d.asset == asset && !d.is_nomination)
+			.map_or_else(Zero::zero, |d| d.amount);
+		let pending = metadata
+			.delegator_unstake_requests
+			.iter()
+			.filter(|r| &r.operator == operator && r.asset == asset)
+			.fold(BalanceOf::<T>::zero(), |acc, r| acc.saturating_add(r.amount));
+
+		delegated.saturating_sub(pending)
+	}
+
+	/// Returns the native rewards held by a liquid vault for its share holders.
+	///
+	/// The rewards of a native vault are delegated again when claimed, so only the vaults of
+	/// other assets hold rewards.
+	pub fn liquid_vault_rewards(operator: &T::AccountId, asset: Asset<T::AssetId>) -> BalanceOf<T> {
+		if asset == Asset::Custom(Zero::zero()) {
+			return Zero::zero();
+		}
+		T::Currency::free_balance(&Self::liquid_vault_account(operator, asset))
+	}
+
+	/// Claims the rewards accrued by the delegation of a liquid vault.
+	///
+	/// The rewards of a native vault are deposited and delegated again, raising the value of
+	/// every share, while the rewards of other vaults are held by the vault and paid out to the
+	/// share holders as they unstake. Claiming is best-effort, so a failure never blocks the
+	/// vault.
+	///
+	/// Returns the amount of rewards claimed.
+	pub fn process_claim_liquid_vault_rewards(
+		operator: &T::AccountId,
+		asset: Asset<T::AssetId>,
+	) -> BalanceOf<T> {
+		let vault_account = Self::liquid_vault_account(operator, asset);
+		let claimed = T::RewardsManager::claim_rewards(&vault_account, asset)
+			.unwrap_or_else(|_| Zero::zero());
+
+		if asset == Asset::Custom(Zero::zero()) {
+			let rewards = T::Currency::free_balance(&vault_account);
+			if rewards >= T::MinDelegateAmount::get() {
+				let _ = with_storage_layer(|| -> DispatchResult {
+					Self::process_deposit(vault_account.clone(), asset, rewards, None)?;
+					Self::process_delegate(
+						vault_account.clone(),
+						operator.clone(),
+						asset,
+						rewards,
+						DelegatorBlueprintSelection::All,
+					)
+				});
+			}
+		}
+
+		claimed
+	}
+
+	/// Applies a slash of a delegator to the pending unstake requests of the liquid vault, if the
+	/// delegator is the account of a vault.
+	///
+	/// The vault's own unstake requests are slashed with its delegation, this reduces the amounts
+	/// owed to the share holders that made them in the same proportion.
+	pub(crate) fn slash_liquid_vault_unstakes(
+		delegator: &T::AccountId,
+		operator: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		slash_percent: Percent,
+	) {
+		if delegator != &Self::liquid_vault_account(operator, asset) {
+			return;
+		}
+
+		LiquidVaults::<T>::mutate(operator, asset, |maybe_vault| {
+			if let Some(vault) = maybe_vault {
+				vault.unstake_rate = vault
+					.unstake_rate
+					.saturating_mul(FixedU128::from(slash_percent.left_from_one()));
+			}
+		});
+	}
+
+	/// Creates a liquid vault for an operator and asset, along with its receipt asset.
+	pub fn process_create_liquid_vault(
+		operator: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		receipt_asset: T::AssetId,
+	) -> DispatchResult {
+		ensure!(Self::is_operator(operator), Error::<T>::NotAnOperator);
+		ensure!(
+			!LiquidVaults::<T>::contains_key(operator, asset),
+			Error::<T>::LiquidVaultAlreadyExists
+		);
+
+		T::Fungibles::create(receipt_asset, Self::pallet_account(), false, 1_u32.into())?;
+		LiquidVaults::<T>::insert(
+			operator,
+			asset,
+			LiquidVault {
+				receipt_asset,
+				total_shares: Zero::zero(),
+				unstake_rate: FixedU128::one(),
+			},
+		);
+
+		Ok(())
+	}
+
+	/// Moves an amount of a delegator's deposit into a liquid vault, delegates it to the vault's
+	/// operator and mints receipt shares to the delegator.
+	///
+	/// The pending rewards of the vault are claimed first. If the vault holds rewards for its
+	/// share holders, the delegator buys into them in proportion to the new shares, paying in
+	/// the native currency.
+	///
+	/// Returns the amount of shares minted.
+	pub fn process_liquid_delegate(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		let mut vault =
+			LiquidVaults::<T>::get(operator, asset).ok_or(Error::<T>::LiquidVaultNotFound)?;
+		Self::process_claim_liquid_vault_rewards(operator, asset);
+
+		let backing = Self::liquid_vault_backing(operator, asset);
+		let shares = if vault.total_shares.is_zero() {
+			amount
+		} else {
+			ensure!(!backing.is_zero(), Error::<T>::LiquidVaultInsolvent);
+			Self::convert_liquid_amount(amount, vault.total_shares, backing)?
+		};
+		ensure!(!shares.is_zero(), Error::<T>::InvalidAmount);
+
+		let vault_account = Self::liquid_vault_account(operator, asset);
+		if !vault.total_shares.is_zero() {
+			let rewards = Self::liquid_vault_rewards(operator, asset);
+			let buy_in = Self::convert_liquid_amount(rewards, shares, vault.total_shares)?;
+			if !buy_in.is_zero() {
+				T::Currency::transfer(
+					who,
+					&vault_account,
+					buy_in,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+		}
+
+		Self::move_deposit(who, &vault_account, asset, amount)?;
+		Self::process_delegate(
+			vault_account,
+			operator.clone(),
+			asset,
+			amount,
+			DelegatorBlueprintSelection::All,
+		)?;
+
+		T::Fungibles::mint_into(vault.receipt_asset, who, shares)?;
+		vault.total_shares = vault.total_shares.saturating_add(shares);
+		LiquidVaults::<T>::insert(operator, asset, vault);
+
+		Ok(shares)
+	}
+
+	/// Burns liquid vault shares of an account and schedules the unstake of their value from the
+	/// vault's delegation.
+	///
+	/// The pending rewards of the vault are claimed first, and the share of the rewards held by
+	/// the vault for the burned shares is paid to the account right away.
+	///
+	/// Returns the amount owed to the account once the request is executed.
+	pub fn process_liquid_unstake(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		shares: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(!shares.is_zero(), Error::<T>::InvalidAmount);
+		let mut vault =
+			LiquidVaults::<T>::get(operator, asset).ok_or(Error::<T>::LiquidVaultNotFound)?;
+		ensure!(vault.total_shares >= shares, Error::<T>::InsufficientBalance);
+		Self::process_claim_liquid_vault_rewards(operator, asset);
+
+		let backing = Self::liquid_vault_backing(operator, asset);
+		let amount = Self::convert_liquid_amount(shares, backing, vault.total_shares)?;
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		let rewards = Self::convert_liquid_amount(
+			Self::liquid_vault_rewards(operator, asset),
+			shares,
+			vault.total_shares,
+		)?;
+
+		T::Fungibles::burn_from(
+			vault.receipt_asset,
+			who,
+			shares,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Polite,
+		)
+		.map_err(|_| Error::<T>::InsufficientBalance)?;
+
+		let vault_account = Self::liquid_vault_account(operator, asset);
+		Self::process_schedule_delegator_unstake(
+			vault_account.clone(),
+			operator.clone(),
+			asset,
+			amount,
+		)?;
+		if !rewards.is_zero() {
+			T::Currency::transfer(&vault_account, who, rewards, ExistenceRequirement::AllowDeath)?;
+		}
+
+		LiquidUnstakeRequests::<T>::try_mutate(who, |requests| {
+			requests
+				.try_push(LiquidUnstakeRequest {
+					operator: operator.clone(),
+					asset,
+					amount,
+					requested_round: Self::current_round(),
+					unstake_rate: vault.unstake_rate,
+				})
+				.map_err(|_| Error::<T>::MaxUnstakeRequestsExceeded)
+		})?;
+
+		vault.total_shares = vault.total_shares.saturating_sub(shares);
+		LiquidVaults::<T>::insert(operator, asset, vault);
+
+		Ok(amount)
+	}
+
+	/// Executes the ready liquid unstake requests of an account, returning the unstaked amounts
+	/// to its deposits.
+	///
+	/// The vault unstake requests that are ready are executed on the way, so that the vault's
+	/// delegation is reduced before its deposit is handed back. The amount of each request is
+	/// reduced by the slashes of the vault since it was made, and never exceeds what the vault
+	/// has left to hand back.
+	pub fn process_execute_liquid_unstake(
+		who: &T::AccountId,
+	) -> Result<LiquidUnstakeResult<T>, DispatchError> {
+		let requests = LiquidUnstakeRequests::<T>::get(who);
+		ensure!(!requests.is_empty(), Error::<T>::NoBondLessRequest);
+
+		let current_round = Self::current_round();
+		let delay = T::DelegationBondLessDelay::get();
+		let (ready, pending): (Vec<_>, Vec<_>) = requests
+			.into_inner()
+			.into_iter()
+			.partition(|r| current_round >= r.requested_round.saturating_add(delay));
+		ensure!(!ready.is_empty(), Error::<T>::BondLessNotReady);
+
+		let vaults: BTreeSet<_> = ready.iter().map(|r| (r.operator.clone(), r.asset)).collect();
+		for (operator, asset) in vaults {
+			let vault_account = Self::liquid_vault_account(&operator, asset);
+			let has_ready_requests = Delegators::<T>::get(&vault_account).is_some_and(|metadata| {
+				metadata
+					.delegator_unstake_requests
+					.iter()
+					.any(|r| current_round >= r.requested_round.saturating_add(delay))
+			});
+			if has_ready_requests {
+				Self::process_execute_delegator_unstake(vault_account)?;
+			}
+		}
+
+		let mut results = Vec::with_capacity(ready.len());
+		for request in ready {
+			let vault_account = Self::liquid_vault_account(&request.operator, request.asset);
+			let unstake_rate = LiquidVaults::<T>::get(&request.operator, request.asset)
+				.map_or(request.unstake_rate, |vault| vault.unstake_rate);
+			// The rate of a vault only decreases, so it can only be zero if it was already
+			// zero when the request was made
+			let amount = unstake_rate
+				.checked_div(&request.unstake_rate)
+				.unwrap_or_else(FixedU128::one)
+				.saturating_mul_int(request.amount)
+				.min(Self::liquid_vault_undelegated(&vault_account, request.asset));
+			if !amount.is_zero() {
+				Self::move_deposit(&vault_account, who, request.asset, amount)?;
+			}
+			results.push((request.operator, request.asset, amount));
+		}
+
+		if pending.is_empty() {
+			LiquidUnstakeRequests::<T>::remove(who);
+		} else {
+			// `pending` is a subset of the bounded requests, so this cannot fail.
+			LiquidUnstakeRequests::<T>::insert(who, BoundedVec::truncate_from(pending));
+		}
+
+		Ok(results)
+	}
+
+	/// Returns the deposit of a liquid vault account that is not delegated.
+	fn liquid_vault_undelegated(
+		vault_account: &T::AccountId,
+		asset: Asset<T::AssetId>,
+	) -> BalanceOf<T> {
+		Delegators::<T>::get(vault_account)
+			.and_then(|metadata| metadata.deposits.get(&asset).cloned())
+			.map_or_else(Zero::zero, |deposit| {
+				deposit.amount.saturating_sub(deposit.delegated_amount)
+			})
+	}
+
+	/// Moves an undelegated, unlocked amount of deposit from one account to another.
+	///
+	/// The deposited funds are held by the pallet account, so only the accounting changes.
+	fn move_deposit(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		Delegators::<T>::try_mutate(from, |maybe_metadata| -> DispatchResult {
+			let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
+			metadata
+				.deposits
+				.get_mut(&asset)
+				.ok_or(Error::<T>::InsufficientBalance)?
+				.decrease_deposited_amount(amount, now)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+			Ok(())
+		})?;
+
+		Delegators::<T>::try_mutate(to, |maybe_metadata| -> DispatchResult {
+			let metadata = maybe_metadata.get_or_insert_with(Default::default);
+			if let Some(existing) = metadata.deposits.get_mut(&asset) {
+				existing
+					.increase_deposited_amount(amount, None, now)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+			} else {
+				metadata.deposits.insert(asset, Deposit::new(amount, None, now));
+			}
+			Ok(())
+		})?;
+
+		T::DelegationHooks::on_stake_changed(from, &asset);
+		T::DelegationHooks::on_stake_changed(to, &asset);
+		Ok(())
+	}
+
+	/// Converts `amount` at the rate `numerator / denominator`, rounding down.
+	fn convert_liquid_amount(
+		amount: BalanceOf<T>,
+		numerator: BalanceOf<T>,
+		denominator: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		multiply_by_rational_with_rounding(
+			amount.saturated_into(),
+			numerator.saturated_into(),
+			denominator.saturated_into(),
+			Rounding::Down,
+		)
+		.map(|value| value.saturated_into())
+		.ok_or_else(|| Error::<T>::OverflowRisk.into())
+	}
+}
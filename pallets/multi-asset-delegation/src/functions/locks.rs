@@ -0,0 +1,290 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	Config, Delegators, EarlyUnlockPenalty, Error, LockTiers, LockedDelegations, Pallet, types::*,
+};
+use frame_support::{
+	ensure,
+	pallet_prelude::DispatchResult,
+	traits::{Currency, ExistenceRequirement, Get, fungibles::Mutate, tokens::Preservation},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	DispatchError,
+	traits::{Saturating, Zero},
+};
+use sp_std::vec::Vec;
+use tangle_primitives::{
+	services::{Asset, EvmAddressMapping},
+	traits::{DelegationHooks, RewardsManager},
+	types::rewards::{LockMultiplier, LockTier},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Returns the number of blocks a lock of the given tier lasts.
+	pub fn lock_duration(lock_multiplier: LockMultiplier) -> BlockNumberFor<T> {
+		LockTiers::<T>::get(lock_multiplier)
+			.map_or_else(|| lock_multiplier.get_blocks().into(), |tier| tier.duration)
+	}
+
+	/// Returns the reward multiplier of the given lock tier.
+	pub fn lock_multiplier_value(lock_multiplier: LockMultiplier) -> u32 {
+		LockTiers::<T>::get(lock_multiplier)
+			.map_or_else(|| lock_multiplier.value(), |tier| tier.multiplier)
+	}
+
+	/// Ensures that new locks can be created in the given tier, which for custom tiers requires
+	/// a governance configuration.
+	pub fn ensure_lock_tier(lock_multiplier: LockMultiplier) -> DispatchResult {
+		ensure!(
+			!matches!(lock_multiplier, LockMultiplier::Custom(_)) ||
+				LockTiers::<T>::contains_key(lock_multiplier),
+			Error::<T>::LockTierNotFound
+		);
+		Ok(())
+	}
+
+	/// Records `amount` of `asset` delegated under `lock_multiplier`, whose multiplier is part of
+	/// the reward vault score of the asset.
+	pub(crate) fn record_locked_delegation(
+		asset: Asset<T::AssetId>,
+		amount: BalanceOf<T>,
+		lock_multiplier: LockMultiplier,
+	) {
+		LockedDelegations::<T>::mutate(lock_multiplier, asset, |locked| {
+			*locked = locked.saturating_add(amount)
+		});
+	}
+
+	/// Moves up to `amount` of `asset` delegated under the `from` lock tier to the `to` tier, or
+	/// out of the locked delegations if `to` is `None`, and updates the reward vault score with
+	/// the old and new multiplier.
+	pub(crate) fn move_locked_delegation(
+		asset: Asset<T::AssetId>,
+		amount: BalanceOf<T>,
+		from: LockMultiplier,
+		to: Option<LockMultiplier>,
+	) {
+		let locked = LockedDelegations::<T>::get(from, asset);
+		let amount = amount.min(locked);
+		if amount.is_zero() || to == Some(from) {
+			return;
+		}
+
+		let remaining = locked.saturating_sub(amount);
+		if remaining.is_zero() {
+			LockedDelegations::<T>::remove(from, asset);
+		} else {
+			LockedDelegations::<T>::insert(from, asset, remaining);
+		}
+		if let Some(to) = to {
+			Self::record_locked_delegation(asset, amount, to);
+		}
+
+		let _ = T::RewardsManager::record_lock_multiplier_change(
+			asset,
+			amount,
+			Self::lock_multiplier_value(from),
+			to.map_or(1, Self::lock_multiplier_value),
+		);
+	}
+
+	/// Sets the duration and reward multiplier of a lock tier, updating the reward vault score of
+	/// every asset delegated under it if the multiplier changes.
+	///
+	/// # Errors
+	///
+	/// Returns an error if more than `max_locked_assets` assets are delegated under the tier, in
+	/// which case nothing is changed.
+	pub fn process_set_lock_tier(
+		lock_multiplier: LockMultiplier,
+		tier: LockTier<BlockNumberFor<T>>,
+		max_locked_assets: u32,
+	) -> DispatchResult {
+		let old_multiplier = Self::lock_multiplier_value(lock_multiplier);
+		let locked: Vec<_> = if old_multiplier == tier.multiplier {
+			Vec::new()
+		} else {
+			LockedDelegations::<T>::iter_prefix(lock_multiplier)
+				.take(max_locked_assets.saturating_add(1) as usize)
+				.collect()
+		};
+		ensure!(locked.len() <= max_locked_assets as usize, Error::<T>::TooManyLockedAssets);
+
+		LockTiers::<T>::insert(lock_multiplier, tier);
+		for (asset, amount) in locked {
+			let _ = T::RewardsManager::record_lock_multiplier_change(
+				asset,
+				amount,
+				old_multiplier,
+				tier.multiplier,
+			);
+		}
+		Ok(())
+	}
+
+	/// Extends a lock of a delegator in place to the full duration of `lock_multiplier`.
+	///
+	/// # Arguments
+	///
+	/// * `who` - The account ID of the delegator.
+	/// * `asset` - The asset of the locked deposit.
+	/// * `lock_index` - The index of the lock in the deposit.
+	/// * `lock_multiplier` - The lock tier to extend the lock to.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the tier is an unconfigured custom tier, if the lock does not exist or
+	/// has expired, or if the extension would shorten the lock or lower its tier.
+	pub fn process_extend_lock(
+		who: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		lock_index: u32,
+		lock_multiplier: LockMultiplier,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		Self::ensure_lock_tier(lock_multiplier)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let expiry_block = now + Self::lock_duration(lock_multiplier);
+
+		let (previous_multiplier, delegated) =
+			Delegators::<T>::try_mutate(who, |maybe_metadata| -> Result<_, DispatchError> {
+				let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
+				let deposit = metadata.deposits.get_mut(&asset).ok_or(Error::<T>::LockNotFound)?;
+				let lock = deposit
+					.locks
+					.as_ref()
+					.and_then(|locks| locks.get(lock_index as usize))
+					.ok_or(Error::<T>::LockNotFound)?;
+				ensure!(lock.expiry_block > now, Error::<T>::LockExpired);
+				let delegated = lock.amount.min(deposit.delegated_amount);
+
+				let previous_multiplier = deposit
+					.extend_lock(
+						lock_index as usize,
+						lock_multiplier,
+						expiry_block,
+						now,
+						Self::lock_multiplier_value,
+					)
+					.map_err(|_| Error::<T>::InvalidLockExtension)?;
+				Ok((previous_multiplier, delegated))
+			})?;
+
+		Self::move_locked_delegation(asset, delegated, previous_multiplier, Some(lock_multiplier));
+		Ok(expiry_block)
+	}
+
+	/// Breaks a lock of a delegator before its expiry.
+	///
+	/// Penalties in the native asset are sent to the pot of the reward vault of the asset, which
+	/// pays out native rewards. All other penalties, and native penalties of assets outside a
+	/// reward vault, are sent to the `SlashRecipient`.
+	///
+	/// # Arguments
+	///
+	/// * `who` - The account ID of the delegator.
+	/// * `asset` - The asset of the locked deposit.
+	/// * `lock_index` - The index of the lock in the deposit.
+	///
+	/// # Returns
+	///
+	/// The unlocked amount and the penalty charged for it.
+	///
+	/// # Errors
+	///
+	/// Returns an error if early unlocks are disabled, if the lock does not exist or has expired,
+	/// or if the penalty exceeds the undelegated deposit.
+	pub fn process_early_unlock(
+		who: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		lock_index: u32,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let penalty_rate = EarlyUnlockPenalty::<T>::get().ok_or(Error::<T>::EarlyUnlockDisabled)?;
+		let penalty_recipient = match asset {
+			Asset::Custom(asset_id) if asset_id == Zero::zero() =>
+				T::RewardsManager::get_reward_vault_pot_account(asset),
+			_ => None,
+		}
+		.unwrap_or_else(T::SlashRecipient::get);
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let (lock, delegated, penalty) =
+			Delegators::<T>::try_mutate(who, |maybe_metadata| -> Result<_, DispatchError> {
+				let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
+				let deposit = metadata.deposits.get_mut(&asset).ok_or(Error::<T>::LockNotFound)?;
+				let lock = deposit
+					.locks
+					.as_ref()
+					.and_then(|locks| locks.get(lock_index as usize))
+					.ok_or(Error::<T>::LockNotFound)?;
+				ensure!(lock.expiry_block > now, Error::<T>::LockExpired);
+
+				let penalty = penalty_rate.mul_floor(lock.amount);
+				let delegated = lock.amount.min(deposit.delegated_amount);
+				let lock = deposit
+					.unlock_early(lock_index as usize, penalty, now)
+					.map_err(|_| Error::<T>::EarlyUnlockPenaltyTooHigh)?;
+				Ok((lock, delegated, penalty))
+			})?;
+
+		if !penalty.is_zero() {
+			Self::transfer_from_pallet(asset, &penalty_recipient, penalty)?;
+		}
+
+		Self::move_locked_delegation(asset, delegated, lock.lock_multiplier, None);
+		T::DelegationHooks::on_stake_changed(who, &asset);
+		Ok((lock.amount, penalty))
+	}
+
+	/// Transfers an amount of an asset held by the pallet to `recipient`.
+	///
+	/// Native assets are moved with the currency, custom assets with the fungibles and ERC20
+	/// tokens through the EVM.
+	fn transfer_from_pallet(
+		asset: Asset<T::AssetId>,
+		recipient: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match asset {
+			Asset::Custom(asset_id) if asset_id == Zero::zero() => T::Currency::transfer(
+				&Self::pallet_account(),
+				recipient,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			),
+			Asset::Custom(asset_id) => T::Fungibles::transfer(
+				asset_id,
+				&Self::pallet_account(),
+				recipient,
+				amount,
+				Preservation::Expendable,
+			)
+			.map(|_| ()),
+			Asset::Erc20(address) => {
+				let (success, _weight) = Self::erc20_transfer(
+					address,
+					&Self::pallet_evm_account(),
+					T::EvmAddressMapping::into_address(recipient.clone()),
+					amount,
+				)
+				.map_err(|_| Error::<T>::ERC20TransferFailed)?;
+				ensure!(success, Error::<T>::ERC20TransferFailed);
+				Ok(())
+			},
+		}
+	}
+}
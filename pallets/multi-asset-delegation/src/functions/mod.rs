@@ -21,7 +21,10 @@ use sp_runtime::traits::BadOrigin;
 pub mod delegate;
 pub mod deposit;
 pub mod evm;
+pub mod liquid;
+pub mod locks;
 pub mod operator;
+pub mod requests;
 pub mod session_manager;
 pub mod slash;
 
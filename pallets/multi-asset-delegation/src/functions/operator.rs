@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Config, Error, Operators, Pallet, types::*};
+use crate::{Config, Error, OperatorProfiles, Operators, Pallet, types::*};
 use frame_support::{
 	BoundedVec, ensure,
 	pallet_prelude::DispatchResult,
@@ -22,8 +22,9 @@ use frame_support::{
 };
 use sp_runtime::{
 	DispatchError,
-	traits::{CheckedAdd, CheckedSub},
+	traits::{CheckedAdd, CheckedSub, Saturating, Zero},
 };
+use sp_std::vec::Vec;
 use tangle_primitives::traits::ServiceManager;
 
 impl<T: Config> Pallet<T> {
@@ -135,6 +136,11 @@ impl<T: Config> Pallet<T> {
 		T::Currency::unreserve(who, operator.stake);
 		Operators::<T>::remove(who);
 
+		// Release the profile deposit, the profile is meaningless without the operator
+		if let Some((_, deposit)) = OperatorProfiles::<T>::take(who) {
+			T::Currency::unreserve(who, deposit);
+		}
+
 		Ok(())
 	}
 
@@ -303,4 +309,79 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Sets or replaces the public profile of an operator.
+	///
+	/// The storage deposit is recomputed from the size of the new profile, and only the difference
+	/// with the currently reserved deposit is reserved or released.
+	///
+	/// # Arguments
+	///
+	/// * `who` - The account ID of the operator.
+	/// * `profile` - The new profile of the operator.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the account is not an operator or the deposit cannot be reserved.
+	pub fn process_set_operator_profile(
+		who: &T::AccountId,
+		profile: OperatorProfileOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(Operators::<T>::contains_key(who), Error::<T>::NotAnOperator);
+
+		let deposit = Self::operator_profile_deposit(&profile);
+		let old_deposit =
+			OperatorProfiles::<T>::get(who).map_or(Zero::zero(), |(_, deposit)| deposit);
+
+		if deposit > old_deposit {
+			T::Currency::reserve(who, deposit.saturating_sub(old_deposit))?;
+		} else {
+			T::Currency::unreserve(who, old_deposit.saturating_sub(deposit));
+		}
+
+		OperatorProfiles::<T>::insert(who, (profile, deposit));
+
+		Ok(deposit)
+	}
+
+	/// Removes the public profile of an operator and releases its deposit.
+	///
+	/// # Arguments
+	///
+	/// * `who` - The account ID of the operator.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the operator has no profile.
+	pub fn process_clear_operator_profile(
+		who: &T::AccountId,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let (_, deposit) =
+			OperatorProfiles::<T>::take(who).ok_or(Error::<T>::OperatorProfileNotFound)?;
+		T::Currency::unreserve(who, deposit);
+
+		Ok(deposit)
+	}
+
+	/// Computes the storage deposit required for an operator profile.
+	pub fn operator_profile_deposit(profile: &OperatorProfileOf<T>) -> BalanceOf<T> {
+		T::OperatorProfileDepositBase::get().saturating_add(
+			T::OperatorProfileDepositPerByte::get().saturating_mul(profile.encoded_len().into()),
+		)
+	}
+
+	/// Returns the operators whose profile matches the given region and hardware class.
+	///
+	/// A `None` filter matches every operator with a profile.
+	pub fn operators_by_profile(
+		region: Option<Vec<u8>>,
+		hardware_class: Option<Vec<u8>>,
+	) -> Vec<(T::AccountId, OperatorProfileOf<T>)> {
+		OperatorProfiles::<T>::iter()
+			.filter(|(_, (profile, _))| {
+				profile.matches(region.as_deref(), hardware_class.as_deref())
+			})
+			.map(|(who, (profile, _))| (who, profile))
+			.collect()
+	}
 }
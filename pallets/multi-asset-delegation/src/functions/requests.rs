@@ -0,0 +1,157 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{Config, Delegators, Error, LiquidUnstakeRequests, Pallet, types::*};
+use frame_support::{ensure, traits::Get};
+use sp_runtime::{DispatchError, traits::Saturating};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use tangle_primitives::{RoundIndex, services::Asset};
+
+/// The requests executed by [`Pallet::process_execute_all_ready`].
+pub struct ExecutedRequests<T: Config> {
+	/// The executed delegation unstakes, by operator and asset.
+	pub unstakes: Vec<(T::AccountId, Asset<T::AssetId>, BalanceOf<T>)>,
+	/// The executed nomination unstakes, by operator.
+	pub nomination_unstakes: Vec<(T::AccountId, BalanceOf<T>)>,
+	/// The executed liquid vault unstakes, by operator and asset.
+	pub liquid_unstakes: Vec<(T::AccountId, Asset<T::AssetId>, BalanceOf<T>)>,
+	/// Whether any withdraw request was executed.
+	pub withdrawn: bool,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns all pending withdraw and unstake requests of an account, along with the round
+	/// each of them matures in.
+	pub fn pending_requests(who: &T::AccountId) -> Vec<PendingRequestOf<T>> {
+		let withdraw_delay = T::LeaveDelegatorsDelay::get();
+		let unstake_delay = T::DelegationBondLessDelay::get();
+		let mut requests = Vec::new();
+
+		if let Some(metadata) = Delegators::<T>::get(who) {
+			requests.extend(metadata.withdraw_requests.into_iter().map(|r| PendingRequest {
+				kind: PendingRequestKind::Withdraw,
+				asset: r.asset,
+				amount: r.amount,
+				requested_round: r.requested_round,
+				maturity_round: r.requested_round.saturating_add(withdraw_delay),
+			}));
+			requests.extend(metadata.delegator_unstake_requests.into_iter().map(|r| {
+				PendingRequest {
+					kind: PendingRequestKind::Unstake {
+						operator: r.operator,
+						is_nomination: r.is_nomination,
+					},
+					asset: r.asset,
+					amount: r.amount,
+					requested_round: r.requested_round,
+					maturity_round: r.requested_round.saturating_add(unstake_delay),
+				}
+			}));
+		}
+
+		requests.extend(LiquidUnstakeRequests::<T>::get(who).into_iter().map(|r| PendingRequest {
+			kind: PendingRequestKind::LiquidUnstake { operator: r.operator },
+			asset: r.asset,
+			amount: r.amount,
+			requested_round: r.requested_round,
+			maturity_round: r.requested_round.saturating_add(unstake_delay),
+		}));
+
+		requests
+	}
+
+	/// Executes every matured unstake, liquid unstake and withdraw request of an account.
+	///
+	/// Unstakes are executed first, so that the amounts they return to the deposits are
+	/// available to the withdraw requests executed afterwards.
+	///
+	/// # Errors
+	///
+	/// * `NoReadyRequests` - None of the pending requests of the account has matured
+	pub fn process_execute_all_ready(
+		who: &T::AccountId,
+	) -> Result<ExecutedRequests<T>, DispatchError> {
+		let current_round = Self::current_round();
+		let is_ready = |requested_round: RoundIndex, delay: RoundIndex| {
+			current_round >= requested_round.saturating_add(delay)
+		};
+		let unstake_delay = T::DelegationBondLessDelay::get();
+
+		let mut executed = ExecutedRequests {
+			unstakes: Vec::new(),
+			nomination_unstakes: Vec::new(),
+			liquid_unstakes: Vec::new(),
+			withdrawn: false,
+		};
+
+		if let Some(metadata) = Delegators::<T>::get(who) {
+			let ready_unstakes = metadata
+				.delegator_unstake_requests
+				.iter()
+				.filter(|r| is_ready(r.requested_round, unstake_delay));
+
+			// Nomination unstakes are executed one request at a time, oldest first
+			let mut ready_nominations = BTreeMap::<T::AccountId, u32>::new();
+			let mut has_ready_unstakes = false;
+			for request in ready_unstakes {
+				if request.is_nomination {
+					*ready_nominations.entry(request.operator.clone()).or_default() += 1;
+				} else {
+					has_ready_unstakes = true;
+				}
+			}
+
+			if has_ready_unstakes {
+				executed.unstakes = Self::process_execute_delegator_unstake(who.clone())?;
+			}
+			for (operator, count) in ready_nominations {
+				for _ in 0..count {
+					let amount =
+						Self::process_execute_delegator_nomination_unstake(who, operator.clone())?;
+					executed.nomination_unstakes.push((operator.clone(), amount));
+				}
+			}
+		}
+
+		if LiquidUnstakeRequests::<T>::get(who)
+			.iter()
+			.any(|r| is_ready(r.requested_round, unstake_delay))
+		{
+			executed.liquid_unstakes = Self::process_execute_liquid_unstake(who)?;
+		}
+
+		let withdraw_delay = T::LeaveDelegatorsDelay::get();
+		if Delegators::<T>::get(who).is_some_and(|metadata| {
+			metadata
+				.withdraw_requests
+				.iter()
+				.any(|r| is_ready(r.requested_round, withdraw_delay))
+		}) {
+			Self::process_execute_withdraw(who.clone())?;
+			executed.withdrawn = true;
+		}
+
+		ensure!(
+			!executed.unstakes.is_empty() ||
+				!executed.nomination_unstakes.is_empty() ||
+				!executed.liquid_unstakes.is_empty() ||
+				executed.withdrawn,
+			Error::<T>::NoReadyRequests
+		);
+
+		Ok(executed)
+	}
+}
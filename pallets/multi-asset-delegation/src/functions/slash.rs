@@ -19,18 +19,32 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	traits::{
-		Currency, ExistenceRequirement, Get, ReservableCurrency, fungibles::Mutate,
-		tokens::Preservation,
+		Currency, ExistenceRequirement, Get, Imbalance, ReservableCurrency,
+		fungibles::Mutate,
+		tokens::{Fortitude, Precision, Preservation},
 	},
 	weights::Weight,
 };
 use parity_scale_codec::Encode;
-use sp_runtime::{DispatchError, traits::CheckedSub};
+use sp_core::H160;
+use sp_runtime::{
+	DispatchError, TokenError,
+	traits::{CheckedSub, Saturating, Zero},
+};
+use sp_std::{vec, vec::Vec};
 use tangle_primitives::{
-	services::{Asset, EvmAddressMapping, UnappliedSlash},
-	traits::SlashManager,
+	services::{Asset, EvmAddressMapping, SlashDestination, UnappliedSlash},
+	traits::{NominationSlashHandler, SlashManager},
 };
 
+/// The address ERC20 slashes are sent to when the slash destination is [`SlashDestination::Burn`].
+///
+/// ERC20 tokens have no standard burn entrypoint, so they are made unspendable instead.
+pub const ERC20_BURN_ADDRESS: H160 = H160([
+	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+	0x00, 0x00, 0xde, 0xad,
+]);
+
 impl<T: Config> Pallet<T> {
 	/// Helper function to update operator storage for a slash
 	pub(crate) fn do_slash_operator(
@@ -53,14 +67,34 @@ impl<T: Config> Pallet<T> {
 					.checked_sub(&amount)
 					.ok_or(Error::<T>::InsufficientStakeRemaining)?;
 
-				// transfer the slashed amount to the treasury
-				T::Currency::unreserve(&unapplied_slash.operator, amount);
-				let _ = T::Currency::transfer(
-					&unapplied_slash.operator,
-					&T::SlashRecipient::get(),
-					amount,
-					ExistenceRequirement::AllowDeath,
-				);
+				// route the slashed amount to the slash destination
+				match Self::slash_recipients(&unapplied_slash.destination, amount) {
+					Some(recipients) => {
+						let operator = &unapplied_slash.operator;
+						T::Currency::unreserve(operator, amount);
+						Self::pay_slash_recipients(
+							recipients,
+							|recipient, value| {
+								T::Currency::transfer(
+									operator,
+									recipient,
+									value,
+									ExistenceRequirement::AllowDeath,
+								)
+								.map(|_| Weight::zero())
+							},
+							|value| {
+								// Dropping the imbalance burns the slashed amount
+								let _ = T::Currency::slash(operator, value);
+								Ok(Weight::zero())
+							},
+						)?;
+					},
+					None => {
+						// Dropping the imbalance burns the slashed amount
+						let _ = T::Currency::slash_reserved(&unapplied_slash.operator, amount);
+					},
+				}
 
 				// Emit event for operator slash
 				Self::deposit_event(Event::OperatorSlashed {
@@ -91,6 +125,7 @@ impl<T: Config> Pallet<T> {
 		delegator: &T::AccountId,
 	) -> Result<Weight, DispatchError> {
 		let mut weight = T::DbWeight::get().reads(1);
+		let mut slashed_asset = None;
 
 		Delegators::<T>::try_mutate(delegator, |maybe_metadata| -> DispatchResult {
 			let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::NotDelegator)?;
@@ -112,14 +147,28 @@ impl<T: Config> Pallet<T> {
 				.checked_sub(&slash_amount)
 				.ok_or(Error::<T>::InsufficientStakeRemaining)?;
 
+			// The pending unstakes of the delegation are part of its amount, slash them alike
+			for request in metadata.delegator_unstake_requests.iter_mut().filter(|r| {
+				r.operator == unapplied_slash.operator &&
+					r.asset == delegation.asset &&
+					r.is_nomination == delegation.is_nomination
+			}) {
+				let request_slash = unapplied_slash.slash_percent.mul_ceil(request.amount);
+				request.amount = request.amount.saturating_sub(request_slash);
+			}
+
 			if delegation.is_nomination {
 				Self::apply_nominated_delegation_slash(
 					delegator,
-					&unapplied_slash.operator,
 					slash_amount,
+					&unapplied_slash.destination,
 				)?;
 			} else {
-				Self::handle_asset_transfer(delegation.asset, slash_amount)?;
+				Self::handle_asset_transfer(
+					delegation.asset,
+					slash_amount,
+					&unapplied_slash.destination,
+				)?;
 			}
 
 			match delegation.asset {
@@ -149,56 +198,203 @@ impl<T: Config> Pallet<T> {
 				blueprint_id: unapplied_slash.blueprint_id,
 				era: unapplied_slash.era,
 			});
+			slashed_asset = Some(delegation.asset);
 			Ok(())
 		})?;
 
+		if let Some(asset) = slashed_asset {
+			Self::slash_liquid_vault_unstakes(
+				delegator,
+				&unapplied_slash.operator,
+				asset,
+				unapplied_slash.slash_percent,
+			);
+		}
+
 		Ok(weight)
 	}
 
-	/// Apply a slash for native asset delegations (both nominated and non-nominated)
+	/// Slashes the staked funds of a nomination delegation and pays them to the slash
+	/// destination.
 	fn apply_nominated_delegation_slash(
-		_delegator: &T::AccountId,
-		_operator: &T::AccountId,
-		_slash_amount: BalanceOf<T>,
+		delegator: &T::AccountId,
+		slash_amount: BalanceOf<T>,
+		destination: &SlashDestination<T::AccountId>,
 	) -> Result<Weight, DispatchError> {
 		let weight: Weight = Weight::zero();
 
-		// TODO: Slash the nomination
+		// The nominated funds are bonded in staking, the handler takes them out of the issuance
+		let slashed = T::NominationSlashHandler::slash_nomination(delegator, slash_amount)?;
+
+		// Credit the recipients with what was slashed, burning it otherwise
+		if let Some(recipients) = Self::slash_recipients(destination, slashed) {
+			Self::pay_slash_recipients(
+				recipients,
+				|recipient, value| {
+					let credited = T::Currency::deposit_creating(recipient, value);
+					ensure!(credited.peek() == value, TokenError::BelowMinimum);
+					Ok(Weight::zero())
+				},
+				// The slashed amount is already out of the issuance
+				|_| Ok(Weight::zero()),
+			)?;
+		}
 
 		Ok(weight)
 	}
 
-	/// Apply a slash for non-native asset delegations (custom assets and ERC20)
+	/// Splits a slashed amount between the recipients of a slash destination.
+	///
+	/// Returns `None` if the slashed amount should be burned.
+	pub(crate) fn slash_recipients(
+		destination: &SlashDestination<T::AccountId>,
+		amount: BalanceOf<T>,
+	) -> Option<Vec<(T::AccountId, BalanceOf<T>)>> {
+		let recipients = match destination {
+			SlashDestination::Burn => return None,
+			SlashDestination::Treasury => vec![(T::SlashRecipient::get(), amount)],
+			SlashDestination::TreasuryAndReporter { reporter, reporter_share } => {
+				let reporter_amount = reporter_share.mul_floor(amount);
+				vec![
+					(reporter.clone(), reporter_amount),
+					(T::SlashRecipient::get(), amount.saturating_sub(reporter_amount)),
+				]
+			},
+			SlashDestination::ServiceOwner(owner) => vec![(owner.clone(), amount)],
+		};
+
+		Some(recipients.into_iter().filter(|(_, value)| !value.is_zero()).collect())
+	}
+
+	/// Pays the recipients of a slash with `pay`, falling back to the slash recipient and then to
+	/// `burn` when a payment fails.
+	///
+	/// A recipient that can not receive the funds, e.g. a new account below the existential
+	/// deposit, must not revert the slash and let the slashed account keep its funds.
+	fn pay_slash_recipients(
+		recipients: Vec<(T::AccountId, BalanceOf<T>)>,
+		mut pay: impl FnMut(&T::AccountId, BalanceOf<T>) -> Result<Weight, DispatchError>,
+		mut burn: impl FnMut(BalanceOf<T>) -> Result<Weight, DispatchError>,
+	) -> Result<Weight, DispatchError> {
+		let treasury = T::SlashRecipient::get();
+		let mut weight = Weight::zero();
+
+		for (recipient, value) in recipients {
+			let paid = pay(&recipient, value).or_else(|err| {
+				if recipient == treasury { Err(err) } else { pay(&treasury, value) }
+			});
+			weight += match paid {
+				Ok(paid_weight) => paid_weight,
+				Err(_) => burn(value)?,
+			};
+		}
+
+		Ok(weight)
+	}
+
+	/// Moves the slashed amount of a delegation held by the pallet to the slash destination.
+	///
+	/// Native assets are moved with the currency, custom assets with the fungibles and ERC20
+	/// tokens through the EVM.
 	fn handle_asset_transfer(
 		asset: Asset<T::AssetId>,
 		slash_amount: BalanceOf<T>,
+		destination: &SlashDestination<T::AccountId>,
 	) -> Result<Weight, DispatchError> {
 		let mut weight: Weight = Weight::zero();
+		let recipients = Self::slash_recipients(destination, slash_amount);
 
 		match asset {
+			Asset::Custom(asset_id) if asset_id == Zero::zero() => match recipients {
+				Some(recipients) => {
+					weight += Self::pay_slash_recipients(
+						recipients,
+						|recipient, value| {
+							T::Currency::transfer(
+								&Self::pallet_account(),
+								recipient,
+								value,
+								ExistenceRequirement::AllowDeath,
+							)
+							.map(|_| Weight::zero())
+						},
+						|value| {
+							// Dropping the imbalance burns the slashed amount
+							let _ = T::Currency::slash(&Self::pallet_account(), value);
+							Ok(Weight::zero())
+						},
+					)?;
+				},
+				None => {
+					// Dropping the imbalance burns the slashed amount
+					let _ = T::Currency::slash(&Self::pallet_account(), slash_amount);
+				},
+			},
 			Asset::Custom(asset_id) => {
-				// Transfer slashed amount to the treasury
-				let _ = T::Fungibles::transfer(
-					asset_id,
-					&Self::pallet_account(),
-					&T::SlashRecipient::get(),
-					slash_amount,
-					Preservation::Expendable,
-				);
+				let burn = |value: BalanceOf<T>| {
+					T::Fungibles::burn_from(
+						asset_id,
+						&Self::pallet_account(),
+						value,
+						Preservation::Expendable,
+						Precision::BestEffort,
+						Fortitude::Force,
+					)
+					.map(|_| Weight::zero())
+				};
+				match recipients {
+					Some(recipients) => {
+						weight += Self::pay_slash_recipients(
+							recipients,
+							|recipient, value| {
+								T::Fungibles::transfer(
+									asset_id,
+									&Self::pallet_account(),
+									recipient,
+									value,
+									Preservation::Expendable,
+								)
+								.map(|_| Weight::zero())
+							},
+							burn,
+						)?;
+					},
+					None => {
+						burn(slash_amount)?;
+					},
+				}
 			},
 			Asset::Erc20(address) => {
-				let slashed_amount_recipient_evm =
-					T::EvmAddressMapping::into_address(T::SlashRecipient::get());
-				let (success, _weight) = Self::erc20_transfer(
-					address,
-					&Self::pallet_evm_account(),
-					slashed_amount_recipient_evm,
-					slash_amount,
-				)
-				.map_err(|_| Error::<T>::ERC20TransferFailed)?;
-				ensure!(success, Error::<T>::ERC20TransferFailed);
-
-				weight += _weight;
+				let transfer =
+					|recipient: H160, value: BalanceOf<T>| -> Result<Weight, DispatchError> {
+						let (success, weight) = Self::erc20_transfer(
+							address,
+							&Self::pallet_evm_account(),
+							recipient,
+							value,
+						)
+						.map_err(|_| Error::<T>::ERC20TransferFailed)?;
+						ensure!(success, Error::<T>::ERC20TransferFailed);
+						Ok(weight)
+					};
+				let burn = |value| transfer(ERC20_BURN_ADDRESS, value);
+				match recipients {
+					Some(recipients) => {
+						weight += Self::pay_slash_recipients(
+							recipients,
+							|recipient, value| {
+								transfer(
+									T::EvmAddressMapping::into_address(recipient.clone()),
+									value,
+								)
+							},
+							burn,
+						)?;
+					},
+					None => {
+						weight += burn(slash_amount)?;
+					},
+				}
 			},
 		}
 
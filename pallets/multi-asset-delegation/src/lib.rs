@@ -42,6 +42,9 @@
 //!    withdraw request. Similar to unstake requests, withdraw requests also have a delay before
 //!    they can be executed.
 //!
+//! Requests for the same asset made in the same round are merged, any part of the pending
+//! requests can be cancelled, and `execute_all_ready` executes every matured request at once.
+//!
 //! ## Workflow for Operators
 //!
 //! - **Join Operators**: An account can join as an operator by depositing a minimum stake amount.
@@ -53,6 +56,31 @@
 //!   delay.
 //! - **Go Offline/Online**: Operators can change their status to offline if they need to
 //!   temporarily stop participating in the network, and can come back online when ready.
+//!
+//! ## Liquid Restaking
+//!
+//! Governance can create a liquid vault for an operator and asset. Delegators move deposits into
+//! the vault with `liquid_delegate` and receive transferable receipt shares, minted as a
+//! fungible asset (and therefore usable as an ERC20 through the assets precompile). The vault
+//! delegates on behalf of all share holders, so slashes reduce the value of every share
+//! proportionally. Burning shares with `liquid_unstake` schedules the unstake of their value,
+//! which `execute_liquid_unstake` returns to the caller's deposits after the usual delay. The
+//! rewards of the vault are claimed by `claim_liquid_vault_rewards` and on every liquid
+//! delegation and unstake: native rewards of a native vault are delegated again, while the
+//! rewards of other vaults are paid out to the share holders as they unstake.
+//!
+//! ## Locks
+//!
+//! Deposits can be locked for one of the [`LockMultiplier`] tiers to boost their rewards. The
+//! duration and reward multiplier of each tier default to the values of the tier itself and can
+//! be changed by governance with `set_lock_tier`, which also defines the custom tiers. Changing
+//! the multiplier of a tier updates the reward vault scores of the delegations locked in it. A
+//! lock can be extended in place with `extend_lock`, moving its expiry and optionally raising its
+//! tier. If governance enables it with `set_early_unlock_penalty`, a lock can also be broken with
+//! `early_unlock`, which charges a share of the locked amount. Native penalties are sent to the
+//! reward vault of the asset and all others to the treasury.
+//!
+//! [`LockMultiplier`]: tangle_primitives::types::rewards::LockMultiplier
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
@@ -99,14 +127,17 @@ pub mod pallet {
 	use pallet_session::SessionManager;
 	use scale_info::TypeInfo;
 	use sp_core::H160;
-	use sp_runtime::traits::{MaybeSerializeDeserialize, Member, Zero};
+	use sp_runtime::{
+		Perbill,
+		traits::{MaybeSerializeDeserialize, Member, Zero},
+	};
 	use sp_staking::{SessionIndex, StakingInterface};
 	use sp_std::{fmt::Debug, prelude::*, vec::Vec};
 	use tangle_primitives::{
 		BlueprintId, RoundIndex,
 		services::{Asset, EvmAddressMapping},
 		traits::{RewardsManager, ServiceManager},
-		types::rewards::LockMultiplier,
+		types::rewards::{LockMultiplier, LockTier},
 	};
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -153,6 +184,32 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUnstakeRequests: Get<u32> + TypeInfo + MaxEncodedLen + Clone + Debug + PartialEq;
 
+		/// The maximum length of a text field in an operator profile.
+		#[pallet::constant]
+		type MaxOperatorProfileFieldLength: Get<u32>
+			+ TypeInfo
+			+ MaxEncodedLen
+			+ Clone
+			+ Debug
+			+ PartialEq;
+
+		/// The maximum length of the supported blueprints description in an operator profile.
+		#[pallet::constant]
+		type MaxOperatorProfileDescriptionLength: Get<u32>
+			+ TypeInfo
+			+ MaxEncodedLen
+			+ Clone
+			+ Debug
+			+ PartialEq;
+
+		/// The base deposit reserved for storing an operator profile.
+		#[pallet::constant]
+		type OperatorProfileDepositBase: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved per byte of an operator profile.
+		#[pallet::constant]
+		type OperatorProfileDepositPerByte: Get<BalanceOf<Self>>;
+
 		/// The minimum amount of stake required for an operator.
 		#[pallet::constant]
 		type MinOperatorBondAmount: Get<BalanceOf<Self>>;
@@ -182,7 +239,8 @@ pub mod pallet {
 
 		/// The fungibles trait used for managing fungible assets.
 		type Fungibles: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
-			+ fungibles::Mutate<Self::AccountId, AssetId = Self::AssetId>;
+			+ fungibles::Mutate<Self::AccountId, AssetId = Self::AssetId>
+			+ fungibles::Create<Self::AccountId>;
 
 		/// The pallet's account ID.
 		#[pallet::constant]
@@ -210,6 +268,9 @@ pub mod pallet {
 				BlockNumberFor<Self>,
 			>;
 
+		/// Hooks notified whenever a delegator's deposited or delegated stake changes
+		type DelegationHooks: tangle_primitives::traits::DelegationHooks<Self::AccountId, Self::AssetId>;
+
 		/// Currency to vote conversion
 		type CurrencyToVote: sp_staking::currency_to_vote::CurrencyToVote<BalanceOf<Self>>;
 
@@ -220,9 +281,13 @@ pub mod pallet {
 				CurrencyToVote = Self::CurrencyToVote,
 			>;
 
+		/// The account receiving slashed funds routed to the treasury.
 		#[pallet::constant]
 		type SlashRecipient: Get<Self::AccountId>;
 
+		/// Handler slashing the staked funds of nomination delegations
+		type NominationSlashHandler: tangle_primitives::traits::NominationSlashHandler<Self::AccountId, BalanceOf<Self>>;
+
 		/// A type representing the weights required by the dispatchables of this pallet.
 		type WeightInfo: crate::WeightInfo;
 	}
@@ -262,6 +327,61 @@ pub mod pallet {
 	pub type Delegators<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, DelegatorMetadataOf<T>>;
 
+	/// Storage for operator profiles, along with the deposit reserved for them.
+	#[pallet::storage]
+	#[pallet::getter(fn operator_profiles)]
+	pub type OperatorProfiles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (OperatorProfileOf<T>, BalanceOf<T>)>;
+
+	/// Storage for liquid restaking vaults, keyed by operator and delegated asset.
+	#[pallet::storage]
+	#[pallet::getter(fn liquid_vaults)]
+	pub type LiquidVaults<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Asset<T::AssetId>,
+		LiquidVaultOf<T>,
+	>;
+
+	/// Storage for the pending liquid vault unstake requests of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn liquid_unstake_requests)]
+	pub type LiquidUnstakeRequests<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<LiquidUnstakeRequestOf<T>, T::MaxUnstakeRequests>,
+		ValueQuery,
+	>;
+
+	/// Storage for the governance-configured duration and reward multiplier of lock tiers.
+	#[pallet::storage]
+	#[pallet::getter(fn lock_tiers)]
+	pub type LockTiers<T: Config> =
+		StorageMap<_, Blake2_128Concat, LockMultiplier, LockTier<BlockNumberFor<T>>>;
+
+	/// Storage for the share of a locked amount charged when unlocking it early. Early unlocks are
+	/// disabled while this is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn early_unlock_penalty)]
+	pub type EarlyUnlockPenalty<T: Config> = StorageValue<_, Perbill>;
+
+	/// Storage for the delegated amount of each asset whose lock tier multiplier is part of the
+	/// reward vault score of the asset.
+	#[pallet::storage]
+	#[pallet::getter(fn locked_delegations)]
+	pub type LockedDelegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		LockMultiplier,
+		Blake2_128Concat,
+		Asset<T::AssetId>,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
 	/// Events emitted by the pallet.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -394,6 +514,70 @@ pub mod pallet {
 			operator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// An operator has set or updated their profile.
+		OperatorProfileSet { who: T::AccountId, deposit: BalanceOf<T> },
+		/// An operator has cleared their profile.
+		OperatorProfileCleared { who: T::AccountId, deposit: BalanceOf<T> },
+		/// A liquid restaking vault has been created.
+		LiquidVaultCreated {
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			receipt_asset: T::AssetId,
+		},
+		/// Deposited assets have been delegated through a liquid vault in exchange for shares.
+		LiquidDelegated {
+			who: T::AccountId,
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			amount: BalanceOf<T>,
+			shares: BalanceOf<T>,
+		},
+		/// Liquid vault shares have been burned to schedule an unstake.
+		LiquidUnstakeScheduled {
+			who: T::AccountId,
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			shares: BalanceOf<T>,
+			amount: BalanceOf<T>,
+			when: RoundIndex,
+		},
+		/// A liquid vault unstake has been executed and returned to the deposits of the account.
+		LiquidUnstakeExecuted {
+			who: T::AccountId,
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			amount: BalanceOf<T>,
+		},
+		/// The duration and multiplier of a lock tier have been set.
+		LockTierSet {
+			lock_multiplier: LockMultiplier,
+			duration: BlockNumberFor<T>,
+			multiplier: u32,
+		},
+		/// The early unlock penalty has been set, or early unlocks have been disabled.
+		EarlyUnlockPenaltySet { penalty: Option<Perbill> },
+		/// A lock has been extended in place.
+		LockExtended {
+			who: T::AccountId,
+			asset: Asset<T::AssetId>,
+			lock_index: u32,
+			lock_multiplier: LockMultiplier,
+			expiry_block: BlockNumberFor<T>,
+		},
+		/// A lock has been broken before its expiry and the penalty sent to the reward vault or the
+		/// treasury.
+		LockUnlockedEarly {
+			who: T::AccountId,
+			asset: Asset<T::AssetId>,
+			amount: BalanceOf<T>,
+			penalty: BalanceOf<T>,
+		},
+		/// The rewards of a liquid vault have been claimed.
+		LiquidVaultRewardsClaimed {
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// Errors emitted by the pallet.
@@ -513,6 +697,32 @@ pub mod pallet {
 		CannotGoOfflineWithActiveServices,
 		/// Not a nominator (for native restaking & delegation)
 		NotNominator,
+		/// The operator has no profile
+		OperatorProfileNotFound,
+		/// A liquid vault already exists for the operator and asset
+		LiquidVaultAlreadyExists,
+		/// The liquid vault does not exist
+		LiquidVaultNotFound,
+		/// The liquid vault has outstanding shares but no remaining stake
+		LiquidVaultInsolvent,
+		/// None of the pending requests is ready for execution
+		NoReadyRequests,
+		/// The lock tier must have a non-zero duration and multiplier
+		InvalidLockTier,
+		/// The lock does not exist
+		LockNotFound,
+		/// The lock has already expired
+		LockExpired,
+		/// The lock extension must move the expiry later without lowering the tier
+		InvalidLockExtension,
+		/// Early unlocks are disabled
+		EarlyUnlockDisabled,
+		/// The early unlock penalty exceeds the undelegated deposit
+		EarlyUnlockPenaltyTooHigh,
+		/// The custom lock tier has not been configured
+		LockTierNotFound,
+		/// More assets are delegated under the lock tier than the given witness
+		TooManyLockedAssets,
 	}
 
 	/// Hooks for the pallet.
@@ -786,6 +996,7 @@ pub mod pallet {
 		///
 		/// * [`Error::DepositOverflow`] - Deposit would overflow tracking
 		/// * [`Error::InvalidAsset`] - Asset is not supported
+		/// * [`Error::LockTierNotFound`] - The custom lock tier has not been configured
 		#[pallet::call_index(10)]
 		#[pallet::weight(T::WeightInfo::deposit())]
 		pub fn deposit(
@@ -883,7 +1094,10 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Cancels a scheduled withdraw request.
+		/// Cancels an amount of the scheduled withdraw requests for an asset.
+		///
+		/// The amount is taken from the most recent requests first, so it does not need to match
+		/// a single request, and any part of a request can be cancelled.
 		///
 		/// # Permissions
 		///
@@ -892,8 +1106,8 @@ pub mod pallet {
 		/// # Arguments
 		///
 		/// * `origin` - Origin of the call
-		/// * `asset` - Asset on withdrawal to cancel
-		/// * `amount` - Amount of the withdrawal to cancel
+		/// * `asset` - Asset of the withdrawals to cancel
+		/// * `amount` - Amount of the withdrawals to cancel
 		///
 		/// # Errors
 		///
@@ -1022,7 +1236,10 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Cancels a scheduled request to reduce a delegator's stake.
+		/// Cancels an amount of the scheduled requests to reduce a delegator's stake.
+		///
+		/// The amount is taken from the most recent requests for the operator and asset first, so
+		/// it does not need to match a single request, and any part of a request can be cancelled.
 		///
 		/// # Permissions
 		///
@@ -1259,6 +1476,451 @@ pub mod pallet {
 			Delegators::<T>::insert(&who, metadata);
 			Ok(())
 		}
+
+		/// Sets or updates the public profile of an operator.
+		///
+		/// A storage deposit proportional to the size of the profile is reserved from the
+		/// operator. Updating the profile reserves or releases the difference.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by an operator account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `profile` - The new profile of the operator
+		///
+		/// # Errors
+		///
+		/// * [`Error::NotAnOperator`] - Account is not registered as an operator
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_operator_profile())]
+		pub fn set_operator_profile(
+			origin: OriginFor<T>,
+			profile: OperatorProfileOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let deposit = Self::process_set_operator_profile(&who, profile)?;
+			Self::deposit_event(Event::OperatorProfileSet { who, deposit });
+			Ok(())
+		}
+
+		/// Clears the public profile of an operator and releases its deposit.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the operator account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		///
+		/// # Errors
+		///
+		/// * [`Error::OperatorProfileNotFound`] - The operator has no profile
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::clear_operator_profile())]
+		pub fn clear_operator_profile(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let deposit = Self::process_clear_operator_profile(&who)?;
+			Self::deposit_event(Event::OperatorProfileCleared { who, deposit });
+			Ok(())
+		}
+
+		/// Creates a liquid restaking vault for delegations of an asset to an operator.
+		///
+		/// The receipt asset is created with the pallet account as its owner, and is exposed to the
+		/// EVM as an ERC20 token by the assets precompile.
+		///
+		/// # Permissions
+		///
+		/// * Must be called by the force origin
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `operator` - Operator the vault delegates to
+		/// * `asset` - Asset delegated by the vault
+		/// * `receipt_asset` - ID of the new asset minted as receipt shares
+		///
+		/// # Errors
+		///
+		/// * [`Error::NotAnOperator`] - Account is not registered as an operator
+		/// * [`Error::LiquidVaultAlreadyExists`] - A vault already exists for the operator and
+		///   asset
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::create_liquid_vault())]
+		pub fn create_liquid_vault(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			receipt_asset: T::AssetId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::process_create_liquid_vault(&operator, asset, receipt_asset)?;
+			Self::deposit_event(Event::LiquidVaultCreated { operator, asset, receipt_asset });
+			Ok(())
+		}
+
+		/// Delegates deposited assets through a liquid vault, minting receipt shares.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the delegator account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `operator` - Operator of the vault
+		/// * `asset` - Asset delegated by the vault
+		/// * `amount` - Amount of the deposit to delegate
+		///
+		/// # Errors
+		///
+		/// * [`Error::LiquidVaultNotFound`] - No vault exists for the operator and asset
+		/// * [`Error::InsufficientBalance`] - Not enough unlocked, undelegated deposit
+		/// * [`Error::LiquidVaultInsolvent`] - The vault stake has been fully slashed
+		#[pallet::call_index(27)]
+		#[pallet::weight(
+			T::WeightInfo::liquid_delegate()
+				.saturating_add(T::WeightInfo::claim_liquid_vault_rewards())
+		)]
+		pub fn liquid_delegate(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let shares = Self::process_liquid_delegate(&who, &operator, asset, amount)?;
+			Self::deposit_event(Event::LiquidDelegated { who, operator, asset, amount, shares });
+			Ok(())
+		}
+
+		/// Burns liquid vault shares and schedules the unstake of their value.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the share holder
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `operator` - Operator of the vault
+		/// * `asset` - Asset delegated by the vault
+		/// * `shares` - Amount of receipt shares to burn
+		///
+		/// # Errors
+		///
+		/// * [`Error::LiquidVaultNotFound`] - No vault exists for the operator and asset
+		/// * [`Error::InsufficientBalance`] - Not enough shares
+		/// * [`Error::MaxUnstakeRequestsExceeded`] - Too many pending unstake requests
+		#[pallet::call_index(28)]
+		#[pallet::weight(
+			T::WeightInfo::liquid_unstake()
+				.saturating_add(T::WeightInfo::claim_liquid_vault_rewards())
+		)]
+		pub fn liquid_unstake(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+			shares: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let amount = Self::process_liquid_unstake(&who, &operator, asset, shares)?;
+			Self::deposit_event(Event::LiquidUnstakeScheduled {
+				who,
+				operator,
+				asset,
+				shares,
+				amount,
+				when: Self::current_round() + T::DelegationBondLessDelay::get(),
+			});
+			Ok(())
+		}
+
+		/// Executes the ready liquid vault unstake requests of the caller.
+		///
+		/// The unstaked amounts are returned to the caller's deposits, from where they can be
+		/// withdrawn or delegated again.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the requester
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		///
+		/// # Errors
+		///
+		/// * [`Error::NoBondLessRequest`] - No pending liquid unstake request exists
+		/// * [`Error::BondLessNotReady`] - No request is ready for execution
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::execute_liquid_unstake(T::MaxUnstakeRequests::get()))]
+		pub fn execute_liquid_unstake(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			for (operator, asset, amount) in Self::process_execute_liquid_unstake(&who)? {
+				Self::deposit_event(Event::LiquidUnstakeExecuted {
+					who: who.clone(),
+					operator,
+					asset,
+					amount,
+				});
+			}
+			Ok(())
+		}
+
+		/// Executes every matured unstake, nomination unstake, liquid unstake and withdraw request
+		/// of a delegator at once.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the delegator account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `evm_address` - Optional EVM address
+		///
+		/// # Errors
+		///
+		/// * [`Error::NoReadyRequests`] - None of the pending requests has matured
+		#[pallet::call_index(30)]
+		#[pallet::weight(
+			T::WeightInfo::execute_all_ready(
+				T::MaxUnstakeRequests::get(),
+				T::MaxWithdrawRequests::get(),
+			)
+			// Liquid unstakes are kept apart and nomination unstakes are executed one at a time
+			.saturating_add(T::WeightInfo::execute_liquid_unstake(T::MaxUnstakeRequests::get()))
+			.saturating_add(
+				T::WeightInfo::execute_nomination_unstake()
+					.saturating_mul(T::MaxUnstakeRequests::get().into()),
+			)
+		)]
+		pub fn execute_all_ready(
+			origin: OriginFor<T>,
+			evm_address: Option<H160>,
+		) -> DispatchResult {
+			let who = match evm_address {
+				Some(addr) => {
+					ensure_pallet::<T, _>(origin)?;
+					T::EvmAddressMapping::into_account_id(addr)
+				},
+				None => ensure_signed(origin)?,
+			};
+			let executed = Self::process_execute_all_ready(&who)?;
+
+			for (operator, asset, amount) in executed.unstakes {
+				Self::deposit_event(Event::DelegatorUnstakeExecuted {
+					who: who.clone(),
+					operator,
+					asset,
+					amount,
+				});
+			}
+			for (operator, amount) in executed.nomination_unstakes {
+				Self::deposit_event(Event::NominationUnstakeExecuted {
+					who: who.clone(),
+					operator,
+					amount,
+				});
+			}
+			for (operator, asset, amount) in executed.liquid_unstakes {
+				Self::deposit_event(Event::LiquidUnstakeExecuted {
+					who: who.clone(),
+					operator,
+					asset,
+					amount,
+				});
+			}
+			if executed.withdrawn {
+				Self::deposit_event(Event::ExecutedWithdraw { who });
+			}
+			Ok(())
+		}
+
+		/// Sets the duration and reward multiplier of a lock tier.
+		///
+		/// Existing locks keep their expiry, but are rewarded with the new multiplier, which
+		/// updates the reward vault score of every asset delegated under the tier. The call is
+		/// weighed by `locked_assets`, so it must be at least the number of these assets.
+		///
+		/// # Permissions
+		///
+		/// * Must be called by the force origin
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `lock_multiplier` - The lock tier to configure
+		/// * `duration` - Number of blocks a lock of the tier lasts
+		/// * `multiplier` - Reward multiplier applied to amounts locked in the tier
+		/// * `locked_assets` - Upper bound of the number of assets delegated under the tier
+		///
+		/// # Errors
+		///
+		/// * [`Error::InvalidLockTier`] - The duration or multiplier is zero
+		/// * [`Error::TooManyLockedAssets`] - More assets are delegated under the tier than
+		///   `locked_assets`
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::set_lock_tier(*locked_assets))]
+		pub fn set_lock_tier(
+			origin: OriginFor<T>,
+			lock_multiplier: LockMultiplier,
+			duration: BlockNumberFor<T>,
+			multiplier: u32,
+			locked_assets: u32,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(!duration.is_zero() && multiplier > 0, Error::<T>::InvalidLockTier);
+			Self::process_set_lock_tier(
+				lock_multiplier,
+				LockTier { duration, multiplier },
+				locked_assets,
+			)?;
+			Self::deposit_event(Event::LockTierSet { lock_multiplier, duration, multiplier });
+			Ok(())
+		}
+
+		/// Sets the share of a locked amount charged when unlocking it early, or disables early
+		/// unlocks.
+		///
+		/// # Permissions
+		///
+		/// * Must be called by the force origin
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `penalty` - The penalty, or `None` to disable early unlocks
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_early_unlock_penalty())]
+		pub fn set_early_unlock_penalty(
+			origin: OriginFor<T>,
+			penalty: Option<Perbill>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			EarlyUnlockPenalty::<T>::set(penalty);
+			Self::deposit_event(Event::EarlyUnlockPenaltySet { penalty });
+			Ok(())
+		}
+
+		/// Extends a lock of the caller in place, keeping its amount.
+		///
+		/// The lock is renewed for the full duration of `lock_multiplier` from the current block,
+		/// which must move its expiry later. The tier of the lock can be raised, but not lowered.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the delegator account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `asset` - Asset of the locked deposit
+		/// * `lock_index` - Index of the lock in the deposit
+		/// * `lock_multiplier` - The lock tier to extend the lock to
+		///
+		/// # Errors
+		///
+		/// * [`Error::LockTierNotFound`] - The custom lock tier has not been configured
+		/// * [`Error::NotDelegator`] - Account is not a delegator
+		/// * [`Error::LockNotFound`] - The lock does not exist
+		/// * [`Error::LockExpired`] - The lock has already expired
+		/// * [`Error::InvalidLockExtension`] - The extension shortens the lock or lowers its tier
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::extend_lock())]
+		pub fn extend_lock(
+			origin: OriginFor<T>,
+			asset: Asset<T::AssetId>,
+			lock_index: u32,
+			lock_multiplier: LockMultiplier,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let expiry_block = Self::process_extend_lock(&who, asset, lock_index, lock_multiplier)?;
+			Self::deposit_event(Event::LockExtended {
+				who,
+				asset,
+				lock_index,
+				lock_multiplier,
+				expiry_block,
+			});
+			Ok(())
+		}
+
+		/// Breaks a lock of the caller before its expiry.
+		///
+		/// The early unlock penalty is charged from the deposit. Native penalties are sent to the
+		/// pot of the reward vault of the asset and all others to the `SlashRecipient`.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the delegator account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `asset` - Asset of the locked deposit
+		/// * `lock_index` - Index of the lock in the deposit
+		///
+		/// # Errors
+		///
+		/// * [`Error::EarlyUnlockDisabled`] - Early unlocks are disabled
+		/// * [`Error::NotDelegator`] - Account is not a delegator
+		/// * [`Error::LockNotFound`] - The lock does not exist
+		/// * [`Error::LockExpired`] - The lock has already expired
+		/// * [`Error::EarlyUnlockPenaltyTooHigh`] - The penalty exceeds the undelegated deposit
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::early_unlock())]
+		pub fn early_unlock(
+			origin: OriginFor<T>,
+			asset: Asset<T::AssetId>,
+			lock_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (amount, penalty) = Self::process_early_unlock(&who, asset, lock_index)?;
+			Self::deposit_event(Event::LockUnlockedEarly { who, asset, amount, penalty });
+			Ok(())
+		}
+
+		/// Claims the rewards accrued by a liquid vault.
+		///
+		/// The rewards of a native vault are delegated again, raising the value of its shares,
+		/// while the rewards of other vaults are held for the share holders and paid out as they
+		/// unstake.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by any account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `operator` - Operator of the vault
+		/// * `asset` - Asset delegated by the vault
+		///
+		/// # Errors
+		///
+		/// * [`Error::LiquidVaultNotFound`] - No vault exists for the operator and asset
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::claim_liquid_vault_rewards())]
+		pub fn claim_liquid_vault_rewards(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			asset: Asset<T::AssetId>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				LiquidVaults::<T>::contains_key(&operator, asset),
+				Error::<T>::LiquidVaultNotFound
+			);
+			let amount = Self::process_claim_liquid_vault_rewards(&operator, asset);
+			Self::deposit_event(Event::LiquidVaultRewardsClaimed { operator, asset, amount });
+			Ok(())
+		}
 	}
 
 	/// A Session Manager that wraps another session manager and handles round changes.
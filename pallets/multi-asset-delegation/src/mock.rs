@@ -26,7 +26,7 @@ use frame_support::{
 	PalletId, construct_runtime, derive_impl,
 	pallet_prelude::{Hooks, Weight},
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU128, OneSessionHandler},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU128, Currency, OneSessionHandler},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use mock_evm::MockedEvmRunner;
@@ -329,14 +329,27 @@ parameter_types! {
 
 	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub const MaxDelegations: u32 = 50;
+
+	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub const MaxOperatorProfileFieldLength: u32 = 64;
+
+	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub const MaxOperatorProfileDescriptionLength: u32 = 256;
+
+	pub const OperatorProfileDepositBase: Balance = 10;
+	pub const OperatorProfileDepositPerByte: Balance = 1;
 }
 
 type DelegateCall = (AccountId, AccountId, Asset<AssetId>, Balance, Option<LockMultiplier>);
 type UndelegateCall = (AccountId, AccountId, Asset<AssetId>, Balance);
+type LockMultiplierChange = (Asset<AssetId>, Balance, u32, u32);
 
 thread_local! {
 	static DELEGATE_CALLS: RefCell<Vec<DelegateCall>> = RefCell::new(Vec::new());
 	static UNDELEGATE_CALLS: RefCell<Vec<UndelegateCall>> = RefCell::new(Vec::new());
+	static LOCK_MULTIPLIER_CHANGES: RefCell<Vec<LockMultiplierChange>> = RefCell::new(Vec::new());
+	static REWARD_VAULT_POT: RefCell<Option<AccountId>> = const { RefCell::new(None) };
+	static PENDING_REWARDS: RefCell<Balance> = const { RefCell::new(0) };
 }
 
 pub struct MockRewardsManager;
@@ -375,6 +388,18 @@ impl RewardsManager<AccountId, AssetId, Balance, BlockNumber> for MockRewardsMan
 		Ok(())
 	}
 
+	fn record_lock_multiplier_change(
+		asset: Asset<AssetId>,
+		amount: Balance,
+		old_multiplier: u32,
+		new_multiplier: u32,
+	) -> Result<(), Self::Error> {
+		LOCK_MULTIPLIER_CHANGES.with(|changes| {
+			changes.borrow_mut().push((asset, amount, old_multiplier, new_multiplier));
+		});
+		Ok(())
+	}
+
 	fn record_service_reward(
 		_account_id: &AccountId,
 		_asset: Asset<AssetId>,
@@ -390,6 +415,19 @@ impl RewardsManager<AccountId, AssetId, Balance, BlockNumber> for MockRewardsMan
 	fn get_asset_incentive_cap(_asset: Asset<AssetId>) -> Result<Balance, Self::Error> {
 		Ok(0_u32.into())
 	}
+
+	fn get_reward_vault_pot_account(_asset: Asset<AssetId>) -> Option<AccountId> {
+		REWARD_VAULT_POT.with(|pot| pot.borrow().clone())
+	}
+
+	fn claim_rewards(
+		account_id: &AccountId,
+		_asset: Asset<AssetId>,
+	) -> Result<Balance, Self::Error> {
+		let rewards = PENDING_REWARDS.with(|rewards| rewards.replace(0));
+		let _ = Balances::deposit_creating(account_id, rewards);
+		Ok(rewards)
+	}
 }
 
 impl MockRewardsManager {
@@ -401,9 +439,25 @@ impl MockRewardsManager {
 		UNDELEGATE_CALLS.with(|calls| calls.borrow().clone())
 	}
 
+	pub fn record_lock_multiplier_changes() -> Vec<LockMultiplierChange> {
+		LOCK_MULTIPLIER_CHANGES.with(|changes| changes.borrow().clone())
+	}
+
+	pub fn set_reward_vault_pot(pot: Option<AccountId>) {
+		REWARD_VAULT_POT.with(|current| *current.borrow_mut() = pot);
+	}
+
+	/// Sets the rewards paid to the next account claiming them.
+	pub fn set_pending_rewards(amount: Balance) {
+		PENDING_REWARDS.with(|rewards| *rewards.borrow_mut() = amount);
+	}
+
 	pub fn clear_all() {
 		DELEGATE_CALLS.with(|calls| calls.borrow_mut().clear());
 		UNDELEGATE_CALLS.with(|calls| calls.borrow_mut().clear());
+		LOCK_MULTIPLIER_CHANGES.with(|changes| changes.borrow_mut().clear());
+		REWARD_VAULT_POT.with(|pot| *pot.borrow_mut() = None);
+		PENDING_REWARDS.with(|rewards| *rewards.borrow_mut() = 0);
 	}
 }
 
@@ -412,6 +466,7 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type Currency = Balances;
 	type MinOperatorBondAmount = MinOperatorBondAmount;
 	type SlashRecipient = SlashRecipient;
+	type NominationSlashHandler = ();
 
 	type CurrencyToVote = U128CurrencyToVote;
 	type StakingInterface = Staking;
@@ -430,10 +485,15 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type MaxWithdrawRequests = MaxWithdrawRequests;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type MaxDelegations = MaxDelegations;
+	type MaxOperatorProfileFieldLength = MaxOperatorProfileFieldLength;
+	type MaxOperatorProfileDescriptionLength = MaxOperatorProfileDescriptionLength;
+	type OperatorProfileDepositBase = OperatorProfileDepositBase;
+	type OperatorProfileDepositPerByte = OperatorProfileDepositPerByte;
 	type EvmRunner = MockedEvmRunner;
 	type EvmGasWeightMapping = PalletEVMGasWeightMapping;
 	type EvmAddressMapping = PalletEVMAddressMapping;
 	type RewardsManager = MockRewardsManager;
+	type DelegationHooks = ();
 	type WeightInfo = ();
 }
 
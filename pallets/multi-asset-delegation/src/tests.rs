@@ -18,6 +18,8 @@ use crate::{mock::*, tests::RuntimeEvent};
 
 pub mod delegate;
 pub mod deposit;
+pub mod liquid;
+pub mod locks;
 pub mod native_restaking;
 pub mod operator;
 pub mod session_manager;
@@ -15,7 +15,7 @@
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 #![allow(clippy::all)]
 use super::*;
-use crate::{CurrentRound, Error};
+use crate::{CurrentRound, Error, types::PendingRequestKind};
 use frame_support::{assert_noop, assert_ok};
 use sp_keyring::AccountKeyring::{Alice, Bob, Charlie};
 use tangle_primitives::services::Asset;
@@ -1034,3 +1034,138 @@ fn delegation_unstake_bug_with_nomination_pending() {
 		assert_eq!(second_request.amount, delegation_amount);
 	});
 }
+
+#[test]
+fn cancel_delegator_unstake_can_cancel_part_of_a_request() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(VDOT);
+
+		create_and_mint_tokens(VDOT, who.clone(), 100);
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(operator.clone()),
+			10_000
+		));
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			100,
+			None,
+			None
+		));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(who.clone()),
+			operator.clone(),
+			asset,
+			100,
+			Default::default(),
+		));
+
+		// Requests of the same round are merged into one.
+		for _ in 0..2 {
+			assert_ok!(MultiAssetDelegation::schedule_delegator_unstake(
+				RuntimeOrigin::signed(who.clone()),
+				operator.clone(),
+				asset,
+				30,
+			));
+		}
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		assert_eq!(metadata.delegator_unstake_requests.len(), 1);
+		assert_eq!(metadata.delegator_unstake_requests[0].amount, 60);
+
+		assert_ok!(MultiAssetDelegation::cancel_delegator_unstake(
+			RuntimeOrigin::signed(who.clone()),
+			operator.clone(),
+			asset,
+			25
+		));
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		assert_eq!(metadata.delegator_unstake_requests[0].amount, 35);
+
+		assert_noop!(
+			MultiAssetDelegation::cancel_delegator_unstake(
+				RuntimeOrigin::signed(who.clone()),
+				operator.clone(),
+				asset,
+				36
+			),
+			Error::<Runtime>::NoBondLessRequest
+		);
+	});
+}
+
+#[test]
+fn execute_all_ready_executes_every_matured_request() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(VDOT);
+
+		create_and_mint_tokens(VDOT, who.clone(), 300);
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(operator.clone()),
+			10_000
+		));
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			300,
+			None,
+			None
+		));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(who.clone()),
+			operator.clone(),
+			asset,
+			200,
+			Default::default(),
+		));
+		assert_ok!(MultiAssetDelegation::schedule_delegator_unstake(
+			RuntimeOrigin::signed(who.clone()),
+			operator.clone(),
+			asset,
+			50,
+		));
+		assert_ok!(MultiAssetDelegation::schedule_withdraw(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			100
+		));
+
+		let pending = MultiAssetDelegation::pending_requests(&who);
+		assert_eq!(pending.len(), 2);
+		assert_eq!(pending[0].kind, PendingRequestKind::Withdraw);
+		assert_eq!(pending[0].maturity_round, 1);
+		assert_eq!(
+			pending[1].kind,
+			PendingRequestKind::Unstake { operator: operator.clone(), is_nomination: false }
+		);
+		assert_eq!(pending[1].maturity_round, 5);
+
+		assert_noop!(
+			MultiAssetDelegation::execute_all_ready(RuntimeOrigin::signed(who.clone()), None),
+			Error::<Runtime>::NoReadyRequests
+		);
+
+		CurrentRound::<Runtime>::put(5);
+		assert_ok!(MultiAssetDelegation::execute_all_ready(
+			RuntimeOrigin::signed(who.clone()),
+			None
+		));
+
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		assert_eq!(metadata.delegations[0].amount, 150);
+		assert_eq!(metadata.deposits.get(&asset).unwrap().delegated_amount, 150);
+		assert_eq!(Assets::balance(VDOT, &who), 100);
+		assert!(MultiAssetDelegation::pending_requests(&who).is_empty());
+
+		System::assert_has_event(RuntimeEvent::MultiAssetDelegation(
+			Event::DelegatorUnstakeExecuted { who: who.clone(), operator, asset, amount: 50 },
+		));
+		System::assert_last_event(RuntimeEvent::MultiAssetDelegation(Event::ExecutedWithdraw {
+			who,
+		}));
+	});
+}
@@ -630,3 +630,90 @@ fn deposit_should_work_for_tnt_without_adding_to_reward_vault() {
 		assert_eq!(MockRewardsManager::record_delegate_calls(), vec![]);
 	});
 }
+
+#[test]
+fn schedule_withdraw_merges_requests_of_the_same_round() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let asset = Asset::Custom(VDOT);
+
+		create_and_mint_tokens(VDOT, who.clone(), 200);
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			200,
+			None,
+			None
+		));
+
+		assert_ok!(MultiAssetDelegation::schedule_withdraw(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			50
+		));
+		assert_ok!(MultiAssetDelegation::schedule_withdraw(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			50
+		));
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		assert_eq!(metadata.withdraw_requests.len(), 1);
+		assert_eq!(metadata.withdraw_requests[0].amount, 100);
+
+		// A request made in a later round matures later, so it is kept apart.
+		<CurrentRound<Runtime>>::put(1);
+		assert_ok!(MultiAssetDelegation::schedule_withdraw(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			30
+		));
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		assert_eq!(metadata.withdraw_requests.len(), 2);
+		assert_eq!(metadata.withdraw_requests[1].amount, 30);
+	});
+}
+
+#[test]
+fn cancel_withdraw_can_cancel_part_of_the_pending_requests() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let asset = Asset::Custom(VDOT);
+
+		create_and_mint_tokens(VDOT, who.clone(), 200);
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			200,
+			None,
+			None
+		));
+		assert_ok!(MultiAssetDelegation::schedule_withdraw(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			100
+		));
+		<CurrentRound<Runtime>>::put(1);
+		assert_ok!(MultiAssetDelegation::schedule_withdraw(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			30
+		));
+
+		// The most recent request is cancelled first, then the older one is reduced.
+		assert_ok!(MultiAssetDelegation::cancel_withdraw(
+			RuntimeOrigin::signed(who.clone()),
+			asset,
+			40
+		));
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		assert_eq!(metadata.withdraw_requests.len(), 1);
+		assert_eq!(metadata.withdraw_requests[0].amount, 90);
+		assert_eq!(metadata.withdraw_requests[0].requested_round, 0);
+		assert_eq!(metadata.deposits.get(&asset).unwrap().amount, 110);
+
+		assert_noop!(
+			MultiAssetDelegation::cancel_withdraw(RuntimeOrigin::signed(who.clone()), asset, 91),
+			Error::<Runtime>::NoMatchingwithdrawRequest
+		);
+	});
+}
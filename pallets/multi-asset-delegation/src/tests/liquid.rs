@@ -0,0 +1,342 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+#![allow(clippy::all)]
+use super::*;
+use crate::{CurrentRound, Error, LiquidUnstakeRequests};
+use frame_support::{assert_noop, assert_ok};
+use sp_keyring::AccountKeyring::{Alice, Bob, Charlie, Eve};
+use sp_runtime::Percent;
+use tangle_primitives::services::{Asset, SlashDestination, UnappliedSlash};
+
+const RECEIPT: AssetId = 100;
+
+fn setup_liquid_vault(operator: &AccountId, asset: Asset<AssetId>) {
+	assert_ok!(MultiAssetDelegation::join_operators(
+		RuntimeOrigin::signed(operator.clone()),
+		10_000
+	));
+	assert_ok!(MultiAssetDelegation::create_liquid_vault(
+		RuntimeOrigin::root(),
+		operator.clone(),
+		asset,
+		RECEIPT
+	));
+}
+
+fn deposit_and_liquid_delegate(who: &AccountId, operator: &AccountId, amount: Balance) {
+	assert_ok!(MultiAssetDelegation::deposit(
+		RuntimeOrigin::signed(who.clone()),
+		Asset::Custom(VDOT),
+		amount,
+		None,
+		None,
+	));
+	assert_ok!(MultiAssetDelegation::liquid_delegate(
+		RuntimeOrigin::signed(who.clone()),
+		operator.clone(),
+		Asset::Custom(VDOT),
+		amount,
+	));
+}
+
+#[test]
+fn liquid_delegate_mints_receipt_shares() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(VDOT);
+
+		setup_liquid_vault(&operator, asset);
+		assert_noop!(
+			MultiAssetDelegation::create_liquid_vault(
+				RuntimeOrigin::root(),
+				operator.clone(),
+				asset,
+				RECEIPT + 1
+			),
+			Error::<Runtime>::LiquidVaultAlreadyExists
+		);
+
+		create_and_mint_tokens(VDOT, who.clone(), 200);
+		deposit_and_liquid_delegate(&who, &operator, 200);
+
+		assert_eq!(Assets::balance(RECEIPT, &who), 200);
+		assert_eq!(
+			MultiAssetDelegation::liquid_vaults(&operator, asset).unwrap().total_shares,
+			200
+		);
+
+		// The deposit now belongs to the vault, which delegates it to the operator.
+		let metadata = MultiAssetDelegation::delegators(&who).unwrap();
+		assert_eq!(metadata.deposits.get(&asset).unwrap().amount, 0);
+		let vault_account = MultiAssetDelegation::liquid_vault_account(&operator, asset);
+		let vault_metadata = MultiAssetDelegation::delegators(&vault_account).unwrap();
+		assert_eq!(vault_metadata.delegations[0].operator, operator);
+		assert_eq!(vault_metadata.delegations[0].amount, 200);
+
+		System::assert_last_event(RuntimeEvent::MultiAssetDelegation(Event::LiquidDelegated {
+			who,
+			operator,
+			asset,
+			amount: 200,
+			shares: 200,
+		}));
+	});
+}
+
+#[test]
+fn liquid_vault_shares_absorb_slashes() {
+	new_test_ext().execute_with(|| {
+		let bob: AccountId = Bob.into();
+		let eve: AccountId = Eve.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(VDOT);
+
+		setup_liquid_vault(&operator, asset);
+		create_and_mint_tokens(VDOT, bob.clone(), 200);
+		mint_tokens(bob.clone(), VDOT, eve.clone(), 100);
+		deposit_and_liquid_delegate(&bob, &operator, 200);
+
+		let vault_account = MultiAssetDelegation::liquid_vault_account(&operator, asset);
+		let unapplied_slash = UnappliedSlash {
+			era: 1,
+			blueprint_id: 1,
+			service_id: 42,
+			operator: operator.clone(),
+			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::Treasury,
+		};
+		assert_ok!(MultiAssetDelegation::do_slash_delegator(&unapplied_slash, &vault_account));
+		assert_eq!(MultiAssetDelegation::liquid_vault_backing(&operator, asset), 100);
+
+		// New shares are priced against the slashed stake.
+		deposit_and_liquid_delegate(&eve, &operator, 100);
+		assert_eq!(Assets::balance(RECEIPT, &eve), 200);
+
+		// Bob's shares are only worth the half of his stake left after the slash.
+		assert_ok!(MultiAssetDelegation::liquid_unstake(
+			RuntimeOrigin::signed(bob.clone()),
+			operator.clone(),
+			asset,
+			200
+		));
+		assert_eq!(LiquidUnstakeRequests::<Runtime>::get(&bob)[0].amount, 100);
+		assert_eq!(MultiAssetDelegation::liquid_vault_backing(&operator, asset), 100);
+		assert_eq!(
+			MultiAssetDelegation::liquid_vaults(&operator, asset).unwrap().total_shares,
+			200
+		);
+	});
+}
+
+#[test]
+fn transferred_shares_can_be_unstaked_by_new_holder() {
+	new_test_ext().execute_with(|| {
+		let bob: AccountId = Bob.into();
+		let charlie: AccountId = Charlie.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(VDOT);
+
+		setup_liquid_vault(&operator, asset);
+		create_and_mint_tokens(VDOT, bob.clone(), 200);
+		deposit_and_liquid_delegate(&bob, &operator, 200);
+
+		assert_ok!(Assets::transfer(
+			RuntimeOrigin::signed(bob.clone()),
+			RECEIPT,
+			charlie.clone(),
+			120
+		));
+		assert_noop!(
+			MultiAssetDelegation::liquid_unstake(
+				RuntimeOrigin::signed(charlie.clone()),
+				operator.clone(),
+				asset,
+				121
+			),
+			Error::<Runtime>::InsufficientBalance
+		);
+		assert_ok!(MultiAssetDelegation::liquid_unstake(
+			RuntimeOrigin::signed(charlie.clone()),
+			operator.clone(),
+			asset,
+			120
+		));
+		assert_eq!(Assets::balance(RECEIPT, &charlie), 0);
+
+		assert_noop!(
+			MultiAssetDelegation::execute_liquid_unstake(RuntimeOrigin::signed(charlie.clone())),
+			Error::<Runtime>::BondLessNotReady
+		);
+
+		CurrentRound::<Runtime>::put(5);
+		assert_ok!(MultiAssetDelegation::execute_liquid_unstake(RuntimeOrigin::signed(
+			charlie.clone()
+		)));
+
+		// The unstaked amount is now an undelegated deposit of the new holder.
+		let metadata = MultiAssetDelegation::delegators(&charlie).unwrap();
+		let deposit = metadata.deposits.get(&asset).unwrap();
+		assert_eq!(deposit.amount, 120);
+		assert_eq!(deposit.delegated_amount, 0);
+		assert!(LiquidUnstakeRequests::<Runtime>::get(&charlie).is_empty());
+
+		let vault_account = MultiAssetDelegation::liquid_vault_account(&operator, asset);
+		let vault_metadata = MultiAssetDelegation::delegators(&vault_account).unwrap();
+		assert_eq!(vault_metadata.delegations[0].amount, 80);
+		assert_eq!(MultiAssetDelegation::liquid_vault_backing(&operator, asset), 80);
+	});
+}
+
+#[test]
+fn slashed_pending_liquid_unstakes_execute_pro_rata() {
+	new_test_ext().execute_with(|| {
+		let bob: AccountId = Bob.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(VDOT);
+
+		setup_liquid_vault(&operator, asset);
+		create_and_mint_tokens(VDOT, bob.clone(), 200);
+		deposit_and_liquid_delegate(&bob, &operator, 200);
+		assert_ok!(MultiAssetDelegation::liquid_unstake(
+			RuntimeOrigin::signed(bob.clone()),
+			operator.clone(),
+			asset,
+			100
+		));
+
+		let vault_account = MultiAssetDelegation::liquid_vault_account(&operator, asset);
+		let unapplied_slash = UnappliedSlash {
+			era: 1,
+			blueprint_id: 1,
+			service_id: 42,
+			operator: operator.clone(),
+			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::Treasury,
+		};
+		assert_ok!(MultiAssetDelegation::do_slash_delegator(&unapplied_slash, &vault_account));
+
+		// The pending unstake is slashed along with the shares still in the vault.
+		let vault_metadata = MultiAssetDelegation::delegators(&vault_account).unwrap();
+		assert_eq!(vault_metadata.delegations[0].amount, 100);
+		assert_eq!(vault_metadata.delegator_unstake_requests[0].amount, 50);
+		assert_eq!(MultiAssetDelegation::liquid_vault_backing(&operator, asset), 50);
+
+		CurrentRound::<Runtime>::put(5);
+		assert_ok!(MultiAssetDelegation::execute_liquid_unstake(RuntimeOrigin::signed(
+			bob.clone()
+		)));
+		System::assert_last_event(RuntimeEvent::MultiAssetDelegation(
+			Event::LiquidUnstakeExecuted {
+				who: bob.clone(),
+				operator: operator.clone(),
+				asset,
+				amount: 50,
+			},
+		));
+
+		let deposit = MultiAssetDelegation::delegators(&bob).unwrap().deposits[&asset].clone();
+		assert_eq!(deposit.amount, 50);
+		let vault_metadata = MultiAssetDelegation::delegators(&vault_account).unwrap();
+		assert_eq!(vault_metadata.delegations[0].amount, 50);
+		assert_eq!(MultiAssetDelegation::liquid_vault_backing(&operator, asset), 50);
+	});
+}
+
+#[test]
+fn native_liquid_vault_compounds_rewards() {
+	new_test_ext().execute_with(|| {
+		let bob: AccountId = Bob.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(0);
+
+		setup_liquid_vault(&operator, asset);
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(bob.clone()),
+			asset,
+			200,
+			None,
+			None,
+		));
+		assert_ok!(MultiAssetDelegation::liquid_delegate(
+			RuntimeOrigin::signed(bob.clone()),
+			operator.clone(),
+			asset,
+			200,
+		));
+
+		MockRewardsManager::set_pending_rewards(100);
+		assert_ok!(MultiAssetDelegation::claim_liquid_vault_rewards(
+			RuntimeOrigin::signed(bob.clone()),
+			operator.clone(),
+			asset,
+		));
+		System::assert_has_event(RuntimeEvent::MultiAssetDelegation(
+			Event::LiquidVaultRewardsClaimed { operator: operator.clone(), asset, amount: 100 },
+		));
+
+		// The rewards are delegated again, raising the value of the existing shares.
+		assert_eq!(MultiAssetDelegation::liquid_vault_backing(&operator, asset), 300);
+		assert_eq!(MultiAssetDelegation::liquid_vault_rewards(&operator, asset), 0);
+		assert_ok!(MultiAssetDelegation::liquid_unstake(
+			RuntimeOrigin::signed(bob.clone()),
+			operator.clone(),
+			asset,
+			200
+		));
+		assert_eq!(LiquidUnstakeRequests::<Runtime>::get(&bob)[0].amount, 300);
+	});
+}
+
+#[test]
+fn liquid_vault_rewards_are_paid_to_share_holders() {
+	new_test_ext().execute_with(|| {
+		let bob: AccountId = Bob.into();
+		let eve: AccountId = Eve.into();
+		let operator: AccountId = Alice.into();
+		let asset = Asset::Custom(VDOT);
+
+		setup_liquid_vault(&operator, asset);
+		create_and_mint_tokens(VDOT, bob.clone(), 200);
+		mint_tokens(bob.clone(), VDOT, eve.clone(), 100);
+		deposit_and_liquid_delegate(&bob, &operator, 200);
+
+		MockRewardsManager::set_pending_rewards(100);
+		assert_ok!(MultiAssetDelegation::claim_liquid_vault_rewards(
+			RuntimeOrigin::signed(eve.clone()),
+			operator.clone(),
+			asset,
+		));
+		assert_eq!(MultiAssetDelegation::liquid_vault_rewards(&operator, asset), 100);
+
+		// Eve buys into the rewards held for the existing shares.
+		let eve_balance = Balances::free_balance(&eve);
+		deposit_and_liquid_delegate(&eve, &operator, 100);
+		assert_eq!(Balances::free_balance(&eve), eve_balance - 50);
+		assert_eq!(MultiAssetDelegation::liquid_vault_rewards(&operator, asset), 150);
+
+		// Bob is paid the rewards of his shares as he unstakes them.
+		let bob_balance = Balances::free_balance(&bob);
+		assert_ok!(MultiAssetDelegation::liquid_unstake(
+			RuntimeOrigin::signed(bob.clone()),
+			operator.clone(),
+			asset,
+			200
+		));
+		assert_eq!(Balances::free_balance(&bob), bob_balance + 100);
+		assert_eq!(MultiAssetDelegation::liquid_vault_rewards(&operator, asset), 50);
+	});
+}
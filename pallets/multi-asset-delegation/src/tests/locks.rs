@@ -0,0 +1,430 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::Error;
+use frame_support::{assert_noop, assert_ok};
+use sp_keyring::AccountKeyring::{Alice, Bob, Charlie};
+use sp_runtime::{DispatchError, Perbill};
+use tangle_primitives::{services::Asset, types::rewards::LockMultiplier};
+
+fn deposit_with_lock(who: &AccountId, amount: Balance, lock_multiplier: LockMultiplier) {
+	create_and_mint_tokens(VDOT, who.clone(), amount);
+	assert_ok!(MultiAssetDelegation::deposit(
+		RuntimeOrigin::signed(who.clone()),
+		Asset::Custom(VDOT),
+		amount,
+		None,
+		Some(lock_multiplier),
+	));
+}
+
+#[test]
+fn set_lock_tier_should_work() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+
+		assert_noop!(
+			MultiAssetDelegation::set_lock_tier(
+				RuntimeOrigin::signed(who),
+				LockMultiplier::OneMonth,
+				100,
+				2,
+				0
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			MultiAssetDelegation::set_lock_tier(
+				RuntimeOrigin::root(),
+				LockMultiplier::OneMonth,
+				0,
+				2,
+				0
+			),
+			Error::<Runtime>::InvalidLockTier
+		);
+
+		assert_ok!(MultiAssetDelegation::set_lock_tier(
+			RuntimeOrigin::root(),
+			LockMultiplier::OneMonth,
+			100,
+			2,
+			0
+		));
+		assert_eq!(MultiAssetDelegation::lock_duration(LockMultiplier::OneMonth), 100);
+		assert_eq!(MultiAssetDelegation::lock_multiplier_value(LockMultiplier::OneMonth), 2);
+		// Tiers without governance overrides keep their defaults.
+		assert_eq!(
+			MultiAssetDelegation::lock_duration(LockMultiplier::TwoMonths),
+			LockMultiplier::TwoMonths.get_blocks() as u64
+		);
+		assert_eq!(MultiAssetDelegation::lock_multiplier_value(LockMultiplier::TwoMonths), 2);
+	});
+}
+
+#[test]
+fn deposit_uses_configured_lock_duration() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		System::set_block_number(10);
+		assert_ok!(MultiAssetDelegation::set_lock_tier(
+			RuntimeOrigin::root(),
+			LockMultiplier::OneMonth,
+			100,
+			1,
+			0
+		));
+
+		deposit_with_lock(&who, 100, LockMultiplier::OneMonth);
+
+		let metadata = MultiAssetDelegation::delegators(who).unwrap();
+		let locks = metadata.deposits.get(&Asset::Custom(VDOT)).unwrap().locks.clone().unwrap();
+		assert_eq!(locks[0].expiry_block, 110);
+	});
+}
+
+#[test]
+fn extend_lock_should_work() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		System::set_block_number(1);
+		deposit_with_lock(&who, 100, LockMultiplier::TwoMonths);
+
+		System::set_block_number(50);
+		assert_noop!(
+			MultiAssetDelegation::extend_lock(
+				RuntimeOrigin::signed(who.clone()),
+				Asset::Custom(VDOT),
+				0,
+				LockMultiplier::OneMonth
+			),
+			Error::<Runtime>::InvalidLockExtension
+		);
+		assert_noop!(
+			MultiAssetDelegation::extend_lock(
+				RuntimeOrigin::signed(who.clone()),
+				Asset::Custom(VDOT),
+				1,
+				LockMultiplier::SixMonths
+			),
+			Error::<Runtime>::LockNotFound
+		);
+
+		assert_ok!(MultiAssetDelegation::extend_lock(
+			RuntimeOrigin::signed(who.clone()),
+			Asset::Custom(VDOT),
+			0,
+			LockMultiplier::SixMonths
+		));
+
+		let expiry_block = 50 + LockMultiplier::SixMonths.get_blocks() as u64;
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		let deposit = metadata.deposits.get(&Asset::Custom(VDOT)).unwrap();
+		let lock = &deposit.locks.as_ref().unwrap()[0];
+		assert_eq!(lock.amount, 100);
+		assert_eq!(lock.lock_multiplier, LockMultiplier::SixMonths);
+		assert_eq!(lock.expiry_block, expiry_block);
+		System::assert_last_event(RuntimeEvent::MultiAssetDelegation(crate::Event::LockExtended {
+			who: who.clone(),
+			asset: Asset::Custom(VDOT),
+			lock_index: 0,
+			lock_multiplier: LockMultiplier::SixMonths,
+			expiry_block,
+		}));
+
+		System::set_block_number(expiry_block);
+		assert_noop!(
+			MultiAssetDelegation::extend_lock(
+				RuntimeOrigin::signed(who),
+				Asset::Custom(VDOT),
+				0,
+				LockMultiplier::SixMonths
+			),
+			Error::<Runtime>::LockExpired
+		);
+	});
+}
+
+#[test]
+fn early_unlock_requires_penalty() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		System::set_block_number(1);
+		deposit_with_lock(&who, 100, LockMultiplier::OneMonth);
+
+		assert_noop!(
+			MultiAssetDelegation::early_unlock(
+				RuntimeOrigin::signed(who.clone()),
+				Asset::Custom(VDOT),
+				0
+			),
+			Error::<Runtime>::EarlyUnlockDisabled
+		);
+
+		assert_ok!(MultiAssetDelegation::set_early_unlock_penalty(
+			RuntimeOrigin::root(),
+			Some(Perbill::from_percent(10))
+		));
+		assert_noop!(
+			MultiAssetDelegation::early_unlock(RuntimeOrigin::signed(who), Asset::Custom(VDOT), 1),
+			Error::<Runtime>::LockNotFound
+		);
+	});
+}
+
+#[test]
+fn early_unlock_should_charge_penalty() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let pot: AccountId = Charlie.into();
+		System::set_block_number(1);
+		deposit_with_lock(&who, 100, LockMultiplier::OneMonth);
+		MockRewardsManager::set_reward_vault_pot(Some(pot.clone()));
+		assert_ok!(MultiAssetDelegation::set_early_unlock_penalty(
+			RuntimeOrigin::root(),
+			Some(Perbill::from_percent(10))
+		));
+
+		assert_ok!(MultiAssetDelegation::early_unlock(
+			RuntimeOrigin::signed(who.clone()),
+			Asset::Custom(VDOT),
+			0
+		));
+
+		let metadata = MultiAssetDelegation::delegators(who.clone()).unwrap();
+		let deposit = metadata.deposits.get(&Asset::Custom(VDOT)).unwrap();
+		assert_eq!(deposit.amount, 90);
+		assert!(deposit.locks.is_none());
+		// Only native penalties are paid into the reward vault.
+		assert_eq!(Assets::balance(VDOT, pot), 0);
+		assert_eq!(Assets::balance(VDOT, SlashRecipient::get()), 10);
+		System::assert_last_event(RuntimeEvent::MultiAssetDelegation(
+			crate::Event::LockUnlockedEarly {
+				who: who.clone(),
+				asset: Asset::Custom(VDOT),
+				amount: 100,
+				penalty: 10,
+			},
+		));
+
+		// The unlocked amount can be withdrawn right away.
+		assert_ok!(MultiAssetDelegation::schedule_withdraw(
+			RuntimeOrigin::signed(who),
+			Asset::Custom(VDOT),
+			90
+		));
+	});
+}
+
+#[test]
+fn early_unlock_cannot_take_penalty_from_delegated_stake() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let operator: AccountId = Alice.into();
+		System::set_block_number(1);
+		deposit_with_lock(&who, 100, LockMultiplier::OneMonth);
+		MockRewardsManager::set_reward_vault_pot(Some(Charlie.into()));
+		assert_ok!(MultiAssetDelegation::set_early_unlock_penalty(
+			RuntimeOrigin::root(),
+			Some(Perbill::from_percent(10))
+		));
+
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(operator.clone()),
+			10_000
+		));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(who.clone()),
+			operator,
+			Asset::Custom(VDOT),
+			100,
+			Default::default(),
+		));
+
+		assert_noop!(
+			MultiAssetDelegation::early_unlock(RuntimeOrigin::signed(who), Asset::Custom(VDOT), 0),
+			Error::<Runtime>::EarlyUnlockPenaltyTooHigh
+		);
+	});
+}
+
+#[test]
+fn native_early_unlock_penalty_should_go_to_reward_vault() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let pot: AccountId = Charlie.into();
+		System::set_block_number(1);
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(who.clone()),
+			Asset::Custom(TNT),
+			100,
+			None,
+			Some(LockMultiplier::OneMonth),
+		));
+		MockRewardsManager::set_reward_vault_pot(Some(pot.clone()));
+		assert_ok!(MultiAssetDelegation::set_early_unlock_penalty(
+			RuntimeOrigin::root(),
+			Some(Perbill::from_percent(10))
+		));
+
+		let pot_balance = Balances::free_balance(&pot);
+		assert_ok!(MultiAssetDelegation::early_unlock(
+			RuntimeOrigin::signed(who),
+			Asset::Custom(TNT),
+			0
+		));
+		assert_eq!(Balances::free_balance(&pot), pot_balance + 10);
+	});
+}
+
+#[test]
+fn custom_lock_tier_requires_configuration() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		System::set_block_number(1);
+		create_and_mint_tokens(VDOT, who.clone(), 100);
+
+		assert_noop!(
+			MultiAssetDelegation::deposit(
+				RuntimeOrigin::signed(who.clone()),
+				Asset::Custom(VDOT),
+				100,
+				None,
+				Some(LockMultiplier::Custom(12)),
+			),
+			Error::<Runtime>::LockTierNotFound
+		);
+
+		assert_ok!(MultiAssetDelegation::set_lock_tier(
+			RuntimeOrigin::root(),
+			LockMultiplier::Custom(12),
+			1_000,
+			8,
+			0
+		));
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(who.clone()),
+			Asset::Custom(VDOT),
+			100,
+			None,
+			Some(LockMultiplier::Custom(12)),
+		));
+
+		let metadata = MultiAssetDelegation::delegators(who).unwrap();
+		let locks = metadata.deposits.get(&Asset::Custom(VDOT)).unwrap().locks.clone().unwrap();
+		assert_eq!(locks[0].lock_multiplier, LockMultiplier::Custom(12));
+		assert_eq!(locks[0].expiry_block, 1_001);
+	});
+}
+
+#[test]
+fn extend_lock_compares_configured_multipliers() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		System::set_block_number(1);
+		deposit_with_lock(&who, 100, LockMultiplier::OneMonth);
+		assert_ok!(MultiAssetDelegation::set_lock_tier(
+			RuntimeOrigin::root(),
+			LockMultiplier::OneMonth,
+			LockMultiplier::OneMonth.get_blocks().into(),
+			5,
+			0
+		));
+
+		// Two months last longer, but are configured with a lower multiplier.
+		assert_noop!(
+			MultiAssetDelegation::extend_lock(
+				RuntimeOrigin::signed(who),
+				Asset::Custom(VDOT),
+				0,
+				LockMultiplier::TwoMonths
+			),
+			Error::<Runtime>::InvalidLockExtension
+		);
+	});
+}
+
+#[test]
+fn lock_changes_should_update_vault_score() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Bob.into();
+		let operator: AccountId = Alice.into();
+		System::set_block_number(1);
+		deposit_with_lock(&who, 200, LockMultiplier::OneMonth);
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(operator.clone()),
+			10_000
+		));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(who.clone()),
+			operator,
+			Asset::Custom(VDOT),
+			100,
+			Default::default(),
+		));
+
+		// Only the delegated part of the lock is part of the vault score.
+		assert_ok!(MultiAssetDelegation::extend_lock(
+			RuntimeOrigin::signed(who.clone()),
+			Asset::Custom(VDOT),
+			0,
+			LockMultiplier::SixMonths
+		));
+		// The tier has one locked asset, so the call must be weighed for at least one.
+		assert_noop!(
+			MultiAssetDelegation::set_lock_tier(
+				RuntimeOrigin::root(),
+				LockMultiplier::SixMonths,
+				LockMultiplier::SixMonths.get_blocks().into(),
+				10,
+				0
+			),
+			Error::<Runtime>::TooManyLockedAssets
+		);
+		assert_ok!(MultiAssetDelegation::set_lock_tier(
+			RuntimeOrigin::root(),
+			LockMultiplier::SixMonths,
+			LockMultiplier::SixMonths.get_blocks().into(),
+			10,
+			1
+		));
+		assert_ok!(MultiAssetDelegation::set_early_unlock_penalty(
+			RuntimeOrigin::root(),
+			Some(Perbill::from_percent(10))
+		));
+		assert_ok!(MultiAssetDelegation::early_unlock(
+			RuntimeOrigin::signed(who),
+			Asset::Custom(VDOT),
+			0
+		));
+
+		assert_eq!(
+			MockRewardsManager::record_lock_multiplier_changes(),
+			vec![
+				(Asset::Custom(VDOT), 100, 1, 6),
+				(Asset::Custom(VDOT), 100, 6, 10),
+				(Asset::Custom(VDOT), 100, 10, 1),
+			]
+		);
+		assert_eq!(
+			MultiAssetDelegation::locked_delegations(
+				LockMultiplier::SixMonths,
+				Asset::Custom(VDOT)
+			),
+			0
+		);
+	});
+}
@@ -16,13 +16,18 @@
 use super::*;
 use crate::{
 	CurrentRound, Error,
-	types::{DelegatorBlueprintSelection::Fixed, OperatorStatus},
+	types::{
+		DelegatorBlueprintSelection::Fixed, OperatorProfile, OperatorProfileOf, OperatorStatus,
+	},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, fungibles::Inspect},
 };
-use frame_support::{assert_noop, assert_ok};
 use sp_keyring::AccountKeyring::{Alice, Bob, Eve};
-use sp_runtime::Percent;
+use sp_runtime::{AccountId32, Percent};
 use tangle_primitives::{
-	services::{Asset, UnappliedSlash},
+	services::{Asset, SlashDestination, UnappliedSlash},
 	traits::SlashManager,
 };
 
@@ -691,6 +696,7 @@ fn slash_operator_success() {
 			service_id,
 			operator: Alice.to_account_id(),
 			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::Treasury,
 		};
 
 		// Apply the slash
@@ -747,6 +753,7 @@ fn slash_operator_not_an_operator() {
 			service_id: 42,
 			operator: Alice.to_account_id(),
 			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::Treasury,
 		};
 
 		assert_noop!(
@@ -772,6 +779,7 @@ fn slash_operator_not_active() {
 			service_id: 42,
 			operator: Alice.to_account_id(),
 			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::Treasury,
 		};
 
 		assert_noop!(
@@ -823,6 +831,7 @@ fn slash_delegator_fixed_blueprint_not_selected() {
 			service_id: 42,
 			operator: Alice.to_account_id(),
 			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::Treasury,
 		};
 
 		// Verify delegator is not slashed since they didn't select blueprint 1
@@ -836,3 +845,336 @@ fn slash_delegator_fixed_blueprint_not_selected() {
 		assert_eq!(delegation.amount, delegator_stake); // Amount unchanged
 	});
 }
+
+#[test]
+fn slash_operator_splits_funds_between_treasury_and_reporter() {
+	new_test_ext().execute_with(|| {
+		let operator_stake = 10_000;
+		let delegator_stake = 5_000;
+		let asset = Asset::Custom(1);
+		let blueprint_id = 1;
+		let treasury = SlashRecipient::get();
+
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			operator_stake
+		));
+
+		create_and_mint_tokens(1, Bob.to_account_id(), delegator_stake);
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			asset,
+			delegator_stake,
+			None,
+			None
+		));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			Alice.to_account_id(),
+			asset,
+			delegator_stake,
+			Fixed(vec![blueprint_id].try_into().unwrap()),
+		));
+
+		let eve_native = Balances::free_balance(Eve.to_account_id());
+		let treasury_native = Balances::free_balance(&treasury);
+
+		let unapplied_slash = UnappliedSlash {
+			era: 1,
+			blueprint_id,
+			service_id: 42,
+			operator: Alice.to_account_id(),
+			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::TreasuryAndReporter {
+				reporter: Eve.to_account_id(),
+				reporter_share: Percent::from_percent(20),
+			},
+		};
+		assert_ok!(MultiAssetDelegation::slash_operator(&unapplied_slash));
+
+		// 5_000 of operator stake slashed: 20% to the reporter, the rest to the treasury
+		assert_eq!(Balances::free_balance(Eve.to_account_id()), eve_native + 1_000);
+		assert_eq!(Balances::free_balance(&treasury), treasury_native + 4_000);
+
+		// 2_500 of the delegation slashed and split the same way
+		assert_eq!(Assets::balance(1, Eve.to_account_id()), 500);
+		assert_eq!(Assets::balance(1, &treasury), 2_000);
+	});
+}
+
+#[test]
+fn slash_falls_back_to_treasury_when_recipient_payment_fails() {
+	new_test_ext().execute_with(|| {
+		let operator_stake = 10_000;
+		let delegator_stake = 5_000;
+		let asset = Asset::Custom(1);
+		let blueprint_id = 1;
+		let treasury = SlashRecipient::get();
+		let reporter = AccountId32::new([9u8; 32]);
+
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			operator_stake
+		));
+
+		// The reporter share of the delegation is below the minimum balance of the asset
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			1,
+			Bob.to_account_id(),
+			false,
+			1_000
+		));
+		mint_tokens(Bob.to_account_id(), 1, Bob.to_account_id(), delegator_stake);
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			asset,
+			delegator_stake,
+			None,
+			None
+		));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			Alice.to_account_id(),
+			asset,
+			delegator_stake,
+			Fixed(vec![blueprint_id].try_into().unwrap()),
+		));
+
+		let unapplied_slash = UnappliedSlash {
+			era: 1,
+			blueprint_id,
+			service_id: 42,
+			operator: Alice.to_account_id(),
+			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::TreasuryAndReporter {
+				reporter: reporter.clone(),
+				reporter_share: Percent::from_percent(10),
+			},
+		};
+		assert_ok!(MultiAssetDelegation::slash_operator(&unapplied_slash));
+
+		// The delegator is slashed anyway, and the reporter share goes to the treasury
+		let delegator = MultiAssetDelegation::delegators(Bob.to_account_id()).unwrap();
+		assert_eq!(delegator.delegations[0].amount, 2_500);
+		assert_eq!(Assets::balance(1, &reporter), 0);
+		assert_eq!(Assets::balance(1, &treasury), 2_500);
+		assert_eq!(Balances::free_balance(&reporter), 500);
+	});
+}
+
+#[test]
+fn slash_operator_compensates_service_owner() {
+	new_test_ext().execute_with(|| {
+		let operator_stake = 10_000;
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			operator_stake
+		));
+
+		let owner_balance = Balances::free_balance(Bob.to_account_id());
+		let unapplied_slash = UnappliedSlash {
+			era: 1,
+			blueprint_id: 1,
+			service_id: 42,
+			operator: Alice.to_account_id(),
+			slash_percent: Percent::from_percent(10),
+			destination: SlashDestination::ServiceOwner(Bob.to_account_id()),
+		};
+		assert_ok!(MultiAssetDelegation::slash_operator(&unapplied_slash));
+
+		assert_eq!(Balances::free_balance(Bob.to_account_id()), owner_balance + 1_000);
+	});
+}
+
+#[test]
+fn slash_operator_burns_funds() {
+	new_test_ext().execute_with(|| {
+		let operator_stake = 10_000;
+		let delegator_stake = 5_000;
+		let asset = Asset::Custom(1);
+		let blueprint_id = 1;
+
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			operator_stake
+		));
+
+		create_and_mint_tokens(1, Bob.to_account_id(), delegator_stake);
+		assert_ok!(MultiAssetDelegation::deposit(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			asset,
+			delegator_stake,
+			None,
+			None
+		));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			Alice.to_account_id(),
+			asset,
+			delegator_stake,
+			Fixed(vec![blueprint_id].try_into().unwrap()),
+		));
+
+		let total_issuance = Balances::total_issuance();
+		let asset_supply = Assets::total_issuance(1);
+
+		let unapplied_slash = UnappliedSlash {
+			era: 1,
+			blueprint_id,
+			service_id: 42,
+			operator: Alice.to_account_id(),
+			slash_percent: Percent::from_percent(50),
+			destination: SlashDestination::Burn,
+		};
+		assert_ok!(MultiAssetDelegation::slash_operator(&unapplied_slash));
+
+		assert_eq!(Balances::total_issuance(), total_issuance - 5_000);
+		assert_eq!(Assets::total_issuance(1), asset_supply - 2_500);
+	});
+}
+
+fn operator_profile(region: &[u8], hardware_class: &[u8]) -> OperatorProfileOf<Runtime> {
+	OperatorProfile {
+		name: b"Operator".to_vec().try_into().unwrap(),
+		website: b"https://operator.example".to_vec().try_into().unwrap(),
+		contact: b"ops@operator.example".to_vec().try_into().unwrap(),
+		region: region.to_vec().try_into().unwrap(),
+		hardware_class: hardware_class.to_vec().try_into().unwrap(),
+		blueprints_description: b"Runs MPC and ZK proving blueprints".to_vec().try_into().unwrap(),
+	}
+}
+
+#[test]
+fn set_operator_profile_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+		let profile = operator_profile(b"eu-west", b"gpu");
+		let reserved = Balances::reserved_balance(&alice);
+
+		// Only operators can set a profile
+		assert_noop!(
+			MultiAssetDelegation::set_operator_profile(
+				RuntimeOrigin::signed(alice.clone()),
+				profile.clone()
+			),
+			Error::<Runtime>::NotAnOperator
+		);
+
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(alice.clone()),
+			10_000
+		));
+		assert_ok!(MultiAssetDelegation::set_operator_profile(
+			RuntimeOrigin::signed(alice.clone()),
+			profile.clone()
+		));
+
+		let deposit = MultiAssetDelegation::operator_profile_deposit(&profile);
+		assert_eq!(deposit, 10 + profile.encoded_len() as u128);
+		assert_eq!(Balances::reserved_balance(&alice), reserved + 10_000 + deposit);
+		assert_eq!(MultiAssetDelegation::operator_profiles(&alice), Some((profile, deposit)));
+
+		System::assert_has_event(RuntimeEvent::MultiAssetDelegation(Event::OperatorProfileSet {
+			who: alice.clone(),
+			deposit,
+		}));
+
+		// A smaller profile releases part of the deposit
+		let mut smaller = operator_profile(b"eu", b"cpu");
+		smaller.blueprints_description = Default::default();
+		assert_ok!(MultiAssetDelegation::set_operator_profile(
+			RuntimeOrigin::signed(alice.clone()),
+			smaller.clone()
+		));
+		let smaller_deposit = MultiAssetDelegation::operator_profile_deposit(&smaller);
+		assert!(smaller_deposit < deposit);
+		assert_eq!(Balances::reserved_balance(&alice), reserved + 10_000 + smaller_deposit);
+
+		// Clearing the profile releases the whole deposit
+		assert_ok!(MultiAssetDelegation::clear_operator_profile(RuntimeOrigin::signed(
+			alice.clone()
+		)));
+		assert_eq!(Balances::reserved_balance(&alice), reserved + 10_000);
+		assert_eq!(MultiAssetDelegation::operator_profiles(&alice), None);
+
+		assert_noop!(
+			MultiAssetDelegation::clear_operator_profile(RuntimeOrigin::signed(alice)),
+			Error::<Runtime>::OperatorProfileNotFound
+		);
+	});
+}
+
+#[test]
+fn leaving_operator_releases_profile_deposit() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+		let reserved = Balances::reserved_balance(&alice);
+
+		assert_ok!(MultiAssetDelegation::join_operators(
+			RuntimeOrigin::signed(alice.clone()),
+			10_000
+		));
+		assert_ok!(MultiAssetDelegation::set_operator_profile(
+			RuntimeOrigin::signed(alice.clone()),
+			operator_profile(b"us-east", b"tee")
+		));
+
+		assert_ok!(MultiAssetDelegation::schedule_leave_operators(RuntimeOrigin::signed(
+			alice.clone()
+		)));
+		<CurrentRound<Runtime>>::put(10);
+		assert_ok!(MultiAssetDelegation::execute_leave_operators(RuntimeOrigin::signed(
+			alice.clone()
+		)));
+
+		assert_eq!(Balances::reserved_balance(&alice), reserved);
+		assert_eq!(MultiAssetDelegation::operator_profiles(&alice), None);
+	});
+}
+
+#[test]
+fn operators_by_profile_filters_by_region_and_hardware_class() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+		let bob = Bob.to_account_id();
+		let eve = Eve.to_account_id();
+
+		for (who, region, hardware_class) in [
+			(&alice, &b"eu-west"[..], &b"gpu"[..]),
+			(&bob, &b"eu-west"[..], &b"tee"[..]),
+			(&eve, &b"us-east"[..], &b"gpu"[..]),
+		] {
+			assert_ok!(MultiAssetDelegation::join_operators(
+				RuntimeOrigin::signed(who.clone()),
+				10_000
+			));
+			assert_ok!(MultiAssetDelegation::set_operator_profile(
+				RuntimeOrigin::signed(who.clone()),
+				operator_profile(region, hardware_class)
+			));
+		}
+
+		let operators = |region: Option<&[u8]>, hardware_class: Option<&[u8]>| {
+			let mut operators: Vec<_> = MultiAssetDelegation::operators_by_profile(
+				region.map(|r| r.to_vec()),
+				hardware_class.map(|h| h.to_vec()),
+			)
+			.into_iter()
+			.map(|(who, _)| who)
+			.collect();
+			operators.sort();
+			operators
+		};
+		let sorted = |mut accounts: Vec<AccountId>| {
+			accounts.sort();
+			accounts
+		};
+
+		assert_eq!(operators(None, None), sorted(vec![alice.clone(), bob.clone(), eve.clone()]));
+		assert_eq!(operators(Some(b"eu-west"), None), sorted(vec![alice.clone(), bob.clone()]));
+		assert_eq!(operators(None, Some(b"gpu")), sorted(vec![alice.clone(), eve.clone()]));
+		assert_eq!(operators(Some(b"eu-west"), Some(b"tee")), vec![bob]);
+		assert!(operators(Some(b"ap-south"), None).is_empty());
+	});
+}
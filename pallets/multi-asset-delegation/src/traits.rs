@@ -14,15 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 use super::*;
-use crate::types::{BalanceOf, OperatorStatus};
+use crate::types::{BalanceOf, DelegatorBlueprintSelection, OperatorStatus};
+use frame_support::{ensure, storage::with_storage_layer};
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::traits::Zero;
+use sp_runtime::{DispatchError, DispatchResult, traits::Zero};
 use sp_std::prelude::*;
 use tangle_primitives::{
-	RoundIndex,
+	BlueprintId, RoundIndex,
 	services::Asset,
-	traits::MultiAssetDelegationInfo,
-	types::rewards::{AssetType, UserDepositWithLocks},
+	traits::{
+		MultiAssetDelegationInfo, MultiAssetDelegationRestaker, NominationDelegator, RewardsManager,
+	},
+	types::rewards::{AssetType, LockMultiplier, UserDepositWithLocks},
 };
 
 impl<T: crate::Config>
@@ -109,4 +112,128 @@ impl<T: crate::Config>
 			if total.is_zero() { None } else { Some(total) }
 		})
 	}
+
+	fn get_lock_multiplier_value(lock_multiplier: LockMultiplier) -> u32 {
+		Self::lock_multiplier_value(lock_multiplier)
+	}
+
+	fn get_lock_duration(lock_multiplier: LockMultiplier) -> BlockNumberFor<T> {
+		Self::lock_duration(lock_multiplier)
+	}
+}
+
+impl<T: crate::Config> MultiAssetDelegationRestaker<T::AccountId, BalanceOf<T>, T::AssetId>
+	for crate::Pallet<T>
+{
+	fn deposit_and_delegate(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		// ensure the caps have not been exceeded if the asset is part of a reward vault
+		if let Ok(remaining) = T::RewardsManager::get_asset_deposit_cap_remaining(asset) {
+			ensure!(amount <= remaining, Error::<T>::DepositExceedsCapForAsset);
+		}
+
+		// Reuse the blueprint selection of the existing delegation, if any
+		let blueprint_selection = Delegators::<T>::get(who)
+			.and_then(|metadata| {
+				metadata
+					.delegations
+					.iter()
+					.find(|d| &d.operator == operator && d.asset == asset && !d.is_nomination)
+					.map(|d| d.blueprint_selection.clone())
+			})
+			.unwrap_or(DelegatorBlueprintSelection::All);
+
+		with_storage_layer(|| {
+			Self::process_deposit(who.clone(), asset, amount, None)?;
+			Self::deposit_event(Event::Deposited { who: who.clone(), amount, asset });
+			Self::process_delegate(
+				who.clone(),
+				operator.clone(),
+				asset,
+				amount,
+				blueprint_selection,
+			)
+		})
+	}
+}
+
+impl<T: crate::Config> NominationDelegator<T::AccountId, BalanceOf<T>> for crate::Pallet<T> {
+	fn delegate_nomination(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+		amount: BalanceOf<T>,
+		blueprints: Option<Vec<BlueprintId>>,
+	) -> DispatchResult {
+		let blueprint_selection = match blueprints {
+			Some(blueprints) => DelegatorBlueprintSelection::Fixed(
+				blueprints.try_into().map_err(|_| Error::<T>::MaxBlueprintsExceeded)?,
+			),
+			None => DelegatorBlueprintSelection::All,
+		};
+		Self::process_delegate_nominations(
+			who.clone(),
+			operator.clone(),
+			amount,
+			blueprint_selection,
+		)?;
+		Self::deposit_event(Event::NominationDelegated {
+			who: who.clone(),
+			operator: operator.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	fn schedule_nomination_unstake(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		// Keep the blueprint selection of the delegation that is unstaked from
+		let blueprint_selection = Delegators::<T>::get(who)
+			.and_then(|metadata| {
+				metadata
+					.delegations
+					.iter()
+					.find(|d| &d.operator == operator && d.is_nomination)
+					.map(|d| d.blueprint_selection.clone())
+			})
+			.ok_or(Error::<T>::NoActiveDelegation)?;
+		Self::process_schedule_delegator_nomination_unstake(
+			who,
+			operator.clone(),
+			amount,
+			blueprint_selection,
+		)?;
+		Self::deposit_event(Event::NominationUnstakeScheduled {
+			who: who.clone(),
+			operator: operator.clone(),
+			amount,
+			when: Self::current_round() + T::DelegationBondLessDelay::get(),
+		});
+		Ok(())
+	}
+
+	fn execute_nomination_unstake(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let amount = Self::process_execute_delegator_nomination_unstake(who, operator.clone())?;
+		Self::deposit_event(Event::NominationUnstakeExecuted {
+			who: who.clone(),
+			operator: operator.clone(),
+			amount,
+		});
+		Ok(amount)
+	}
+
+	fn total_nomination_delegations(who: &T::AccountId) -> BalanceOf<T> {
+		Delegators::<T>::get(who)
+			.map(|metadata| metadata.total_nomination_delegations())
+			.unwrap_or_default()
+	}
 }
@@ -24,9 +24,11 @@ use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 use tangle_primitives::types::RoundIndex;
 
 pub mod delegator;
+pub mod liquid;
 pub mod operator;
 
 pub use delegator::*;
+pub use liquid::*;
 pub use operator::*;
 
 pub type BalanceOf<T> =
@@ -47,6 +49,22 @@ pub type OperatorSnapshotOf<T> = OperatorSnapshot<
 	<T as Config>::MaxDelegations,
 >;
 
+pub type OperatorProfileOf<T> = OperatorProfile<
+	<T as Config>::MaxOperatorProfileFieldLength,
+	<T as Config>::MaxOperatorProfileDescriptionLength,
+>;
+
+pub type LiquidVaultOf<T> = LiquidVault<<T as Config>::AssetId, BalanceOf<T>>;
+
+pub type LiquidUnstakeRequestOf<T> = LiquidUnstakeRequest<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::AssetId,
+	BalanceOf<T>,
+>;
+
+pub type PendingRequestOf<T> =
+	PendingRequest<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BalanceOf<T>>;
+
 pub type DelegatorMetadataOf<T> = DelegatorMetadata<
 	<T as frame_system::Config>::AccountId,
 	BalanceOf<T>,
@@ -100,6 +100,40 @@ pub struct BondLessRequest<AccountId, AssetId: Encode + Decode, Balance, MaxBlue
 	pub is_nomination: bool,
 }
 
+/// The kind of a pending delegator request.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum PendingRequestKind<AccountId> {
+	/// A request to withdraw deposited assets.
+	Withdraw,
+	/// A request to unstake a delegation to an operator.
+	Unstake {
+		/// The operator of the delegation.
+		operator: AccountId,
+		/// Whether the delegation is from nominated tokens.
+		is_nomination: bool,
+	},
+	/// A request to unstake burned shares of the liquid vault of an operator.
+	LiquidUnstake {
+		/// The operator of the liquid vault.
+		operator: AccountId,
+	},
+}
+
+/// A pending withdraw or unstake request of a delegator, along with the round it matures in.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingRequest<AccountId, AssetId: Encode + Decode, Balance> {
+	/// The kind of the request.
+	pub kind: PendingRequestKind<AccountId>,
+	/// The asset of the request.
+	pub asset: Asset<AssetId>,
+	/// The amount of the request.
+	pub amount: Balance,
+	/// The round in which the request was made.
+	pub requested_round: RoundIndex,
+	/// The round from which the request can be executed.
+	pub maturity_round: RoundIndex,
+}
+
 /// Represents a delegation bond from a delegator to an operator.
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, Eq, PartialEq)]
 pub struct BondInfoDelegator<AccountId, Balance, AssetId: Encode + Decode, MaxBlueprints: Get<u32>>
@@ -319,13 +353,15 @@ impl<
 	MaxLocks: Get<u32>,
 > Deposit<Balance, BlockNumber, MaxLocks>
 {
+	/// Creates a deposit, locked for `lock_blocks` blocks if a lock tier is given as
+	/// `(lock_multiplier, lock_blocks)`.
 	pub fn new(
 		amount: Balance,
-		lock_multiplier: Option<LockMultiplier>,
+		lock: Option<(LockMultiplier, BlockNumber)>,
 		current_block_number: BlockNumber,
 	) -> Self {
-		let locks = lock_multiplier.map(|multiplier| {
-			let expiry_block = current_block_number.saturating_add(multiplier.get_blocks().into());
+		let locks = lock.map(|(multiplier, lock_blocks)| {
+			let expiry_block = current_block_number.saturating_add(lock_blocks);
 			BoundedVec::try_from(vec![LockInfo {
 				amount: amount.clone(),
 				expiry_block,
@@ -365,19 +401,20 @@ impl<
 		Ok(())
 	}
 
+	/// Increases the deposit, adding a lock of `lock_blocks` blocks for the increase if a lock
+	/// tier is given as `(lock_multiplier, lock_blocks)`.
 	pub fn increase_deposited_amount(
 		&mut self,
 		amount_to_increase: Balance,
-		lock_multiplier: Option<LockMultiplier>,
+		lock: Option<(LockMultiplier, BlockNumber)>,
 		current_block_number: BlockNumber,
 	) -> Result<(), &'static str> {
 		// Update the total amount first
 		self.amount = self.amount.clone().saturating_add(amount_to_increase.clone());
 
 		// If there's a lock multiplier, add a new lock
-		if let Some(multiplier) = lock_multiplier {
-			let lock_blocks = multiplier.get_blocks();
-			let expiry_block = current_block_number.saturating_add(lock_blocks.into());
+		if let Some((multiplier, lock_blocks)) = lock {
+			let expiry_block = current_block_number.saturating_add(lock_blocks);
 
 			let new_lock =
 				LockInfo { amount: amount_to_increase, expiry_block, lock_multiplier: multiplier };
@@ -424,4 +461,60 @@ impl<
 
 		Ok(())
 	}
+	/// Extends the active lock at `index` in place to `expiry_block` under `lock_multiplier`,
+	/// returning the previous tier of the lock.
+	///
+	/// The new expiry must be later than the current one, and the lock tier can not be lowered as
+	/// valued by `multiplier_of`.
+	pub fn extend_lock(
+		&mut self,
+		index: usize,
+		lock_multiplier: LockMultiplier,
+		expiry_block: BlockNumber,
+		current_block_number: BlockNumber,
+		multiplier_of: impl Fn(LockMultiplier) -> u32,
+	) -> Result<LockMultiplier, &'static str> {
+		let lock = self
+			.locks
+			.as_mut()
+			.and_then(|locks| locks.get_mut(index))
+			.ok_or("lock not found")?;
+		ensure!(lock.expiry_block > current_block_number, "lock has expired");
+		ensure!(
+			expiry_block > lock.expiry_block &&
+				multiplier_of(lock_multiplier) >= multiplier_of(lock.lock_multiplier),
+			"lock extension must not shorten the lock or lower its tier"
+		);
+
+		let previous = core::mem::replace(&mut lock.lock_multiplier, lock_multiplier);
+		lock.expiry_block = expiry_block;
+		Ok(previous)
+	}
+
+	/// Removes the active lock at `index` before its expiry, charging `penalty` from the deposit.
+	///
+	/// The penalty can only be taken from the undelegated part of the deposit.
+	pub fn unlock_early(
+		&mut self,
+		index: usize,
+		penalty: Balance,
+		current_block_number: BlockNumber,
+	) -> Result<LockInfo<Balance, BlockNumber>, &'static str> {
+		let locks = self.locks.as_mut().ok_or("lock not found")?;
+		let lock = locks.get(index).ok_or("lock not found")?;
+		ensure!(lock.expiry_block > current_block_number, "lock has expired");
+
+		let remaining = self.amount.clone().saturating_sub(penalty);
+		ensure!(
+			remaining >= self.delegated_amount,
+			"delegated amount cannot be greater than total amount"
+		);
+
+		let lock = locks.remove(index);
+		if locks.is_empty() {
+			self.locks = None;
+		}
+		self.amount = remaining;
+		Ok(lock)
+	}
 }
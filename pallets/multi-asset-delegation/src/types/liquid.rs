@@ -0,0 +1,52 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use sp_runtime::FixedU128;
+use tangle_primitives::services::Asset;
+
+/// A liquid restaking vault pooling the delegations of an asset to an operator.
+///
+/// Delegations made through the vault are held by the vault account, and the delegators receive
+/// receipt shares of the vault as a fungible asset. The shares are freely transferable, and since
+/// the vault's delegation is slashed like any other, slashes are absorbed proportionally by all
+/// share holders and by the pending unstake requests.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LiquidVault<AssetId, Balance> {
+	/// The fungible asset minted as a receipt for shares of the vault.
+	pub receipt_asset: AssetId,
+	/// The total amount of receipt shares in circulation.
+	pub total_shares: Balance,
+	/// The value of the amounts owed to pending unstake requests, starting at one and reduced
+	/// by every slash of the vault's delegation.
+	pub unstake_rate: FixedU128,
+}
+
+/// A request to withdraw an amount from a liquid vault, created by burning receipt shares.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LiquidUnstakeRequest<AccountId, AssetId: Encode + Decode, Balance> {
+	/// The operator of the vault.
+	pub operator: AccountId,
+	/// The delegated asset of the vault.
+	pub asset: Asset<AssetId>,
+	/// The amount of the asset owed to the requester, before the slashes applied since the
+	/// request was made.
+	pub amount: Balance,
+	/// The round in which the request was made.
+	pub requested_round: RoundIndex,
+	/// The unstake rate of the vault when the request was made.
+	pub unstake_rate: FixedU128,
+}
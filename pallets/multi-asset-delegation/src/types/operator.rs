@@ -138,3 +138,38 @@ pub struct DelegatorBond<AccountId, Balance, AssetId: Encode + Decode> {
 	/// The ID of the bonded asset.
 	pub asset: Asset<AssetId>,
 }
+
+/// The public profile of an operator, used by service requesters to discover operators.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, Eq, PartialEq)]
+pub struct OperatorProfile<MaxFieldLength: Get<u32>, MaxDescriptionLength: Get<u32>> {
+	/// The display name of the operator.
+	pub name: BoundedVec<u8, MaxFieldLength>,
+	/// The website of the operator.
+	pub website: BoundedVec<u8, MaxFieldLength>,
+	/// Contact information, such as an email address or a chat handle.
+	pub contact: BoundedVec<u8, MaxFieldLength>,
+	/// The geographic region the operator runs its infrastructure in (e.g. `eu-west`).
+	pub region: BoundedVec<u8, MaxFieldLength>,
+	/// The class of hardware the operator runs (e.g. `gpu`, `tee`, `high-memory`).
+	pub hardware_class: BoundedVec<u8, MaxFieldLength>,
+	/// A free-form description of the blueprints the operator supports.
+	pub blueprints_description: BoundedVec<u8, MaxDescriptionLength>,
+}
+
+impl<MaxFieldLength: Get<u32>, MaxDescriptionLength: Get<u32>>
+	OperatorProfile<MaxFieldLength, MaxDescriptionLength>
+{
+	/// The number of bytes stored for this profile, used to compute the storage deposit.
+	pub fn encoded_len(&self) -> u32 {
+		self.encoded_size() as u32
+	}
+
+	/// Whether the profile matches the given region and hardware class filters.
+	///
+	/// A `None` filter matches every profile.
+	pub fn matches(&self, region: Option<&[u8]>, hardware_class: Option<&[u8]>) -> bool {
+		region.is_none_or(|region| self.region.as_slice() == region) &&
+			hardware_class
+				.is_none_or(|hardware_class| self.hardware_class.as_slice() == hardware_class)
+	}
+}
@@ -66,6 +66,18 @@ pub trait WeightInfo {
 	fn cancel_nomination_unstake() -> Weight;
 	fn add_blueprint_id() -> Weight;
 	fn remove_blueprint_id() -> Weight;
+	fn set_operator_profile() -> Weight;
+	fn clear_operator_profile() -> Weight;
+	fn create_liquid_vault() -> Weight;
+	fn liquid_delegate() -> Weight;
+	fn liquid_unstake() -> Weight;
+	fn execute_liquid_unstake(n: u32, ) -> Weight;
+	fn execute_all_ready(u: u32, w: u32, ) -> Weight;
+	fn set_lock_tier(n: u32, ) -> Weight;
+	fn set_early_unlock_penalty() -> Weight;
+	fn extend_lock() -> Weight;
+	fn early_unlock() -> Weight;
+	fn claim_liquid_vault_rewards() -> Weight;
 }
 
 /// Weight functions needed for rewards pallet.
@@ -382,6 +394,259 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::OperatorProfiles` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::OperatorProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn set_operator_profile() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1804`
+		//  Estimated: `3608`
+		// Minimum execution time: 47_561_000 picoseconds.
+		Weight::from_parts(48_972_000, 3608)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::OperatorProfiles` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::OperatorProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn clear_operator_profile() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `780`
+		//  Estimated: `1560`
+		// Minimum execution time: 37_904_000 picoseconds.
+		Weight::from_parts(38_417_000, 1560)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn create_liquid_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1280`
+		//  Estimated: `3584`
+		// Minimum execution time: 48_315_000 picoseconds.
+		Weight::from_parts(48_315_000, 3584)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:2 w:2)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), mode: `Measured`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), mode: `Measured`)
+	fn liquid_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10368`
+		//  Estimated: `20736`
+		// Minimum execution time: 171_305_000 picoseconds.
+		Weight::from_parts(173_918_000, 20736)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidUnstakeRequests` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidUnstakeRequests` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), mode: `Measured`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), mode: `Measured`)
+	fn liquid_unstake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7296`
+		//  Estimated: `14592`
+		// Minimum execution time: 152_664_000 picoseconds.
+		Weight::from_parts(154_087_000, 14592)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::LiquidUnstakeRequests` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidUnstakeRequests` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:2 w:2)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// The range of component `n` is `[1, 5]`.
+	fn execute_liquid_unstake(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6272 + n * (96 ±0)`
+		//  Estimated: `12544 + n * (96 ±0)`
+		// Minimum execution time: 84_912_000 picoseconds.
+		Weight::from_parts(86_130_000, 12544)
+			// Standard Error: 4_211
+			.saturating_add(Weight::from_parts(14_702_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 96).saturating_mul(n.into()))
+	}
+
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidUnstakeRequests` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::LiquidUnstakeRequests` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// The range of component `u` is `[1, 5]`.
+	/// The range of component `w` is `[1, 5]`.
+	fn execute_all_ready(u: u32, w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2816 + u * (112 ±0) + w * (64 ±0)`
+		//  Estimated: `5632 + u * (112 ±0) + w * (64 ±0)`
+		// Minimum execution time: 96_218_000 picoseconds.
+		Weight::from_parts(97_410_000, 5632)
+			// Standard Error: 6_874
+			.saturating_add(Weight::from_parts(9_870_000, 0).saturating_mul(u.into()))
+			// Standard Error: 6_874
+			.saturating_add(Weight::from_parts(7_315_000, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 112).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(w.into()))
+	}
+
+	/// Storage: `MultiAssetDelegation::LockTiers` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LockTiers` (`max_values`: None, `max_size`: Some(29), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LockedDelegations` (r:101 w:0)
+	/// Proof: `MultiAssetDelegation::LockedDelegations` (`max_values`: None, `max_size`: Some(101), mode: `Measured`)
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:n w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Rewards::TotalRewardVaultScore` (r:n w:n)
+	/// Proof: `Rewards::TotalRewardVaultScore` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn set_lock_tier(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (101 ±0)`
+		//  Estimated: `3607 + n * (2576 ±0)`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_872_000, 3607)
+			// Standard Error: 2_913
+			.saturating_add(Weight::from_parts(9_415_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+
+	/// Storage: `MultiAssetDelegation::EarlyUnlockPenalty` (r:0 w:1)
+	/// Proof: `MultiAssetDelegation::EarlyUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(4), mode: `Measured`)
+	fn set_early_unlock_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_942_000 picoseconds.
+		Weight::from_parts(7_942_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::LockTiers` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::LockTiers` (`max_values`: None, `max_size`: Some(29), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	fn extend_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2048`
+		//  Estimated: `5120`
+		// Minimum execution time: 31_455_000 picoseconds.
+		Weight::from_parts(31_455_000, 5120)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::EarlyUnlockPenalty` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::EarlyUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(4), mode: `Measured`)
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn early_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4096`
+		//  Estimated: `10240`
+		// Minimum execution time: 68_731_000 picoseconds.
+		Weight::from_parts(68_731_000, 10240)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	fn claim_liquid_vault_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5632`
+		//  Estimated: `11264`
+		// Minimum execution time: 91_274_000 picoseconds.
+		Weight::from_parts(91_274_000, 11264)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -695,4 +960,256 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::OperatorProfiles` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::OperatorProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn set_operator_profile() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1804`
+		//  Estimated: `3608`
+		// Minimum execution time: 47_561_000 picoseconds.
+		Weight::from_parts(48_972_000, 3608)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::OperatorProfiles` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::OperatorProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn clear_operator_profile() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `780`
+		//  Estimated: `1560`
+		// Minimum execution time: 37_904_000 picoseconds.
+		Weight::from_parts(38_417_000, 1560)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn create_liquid_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1280`
+		//  Estimated: `3584`
+		// Minimum execution time: 48_315_000 picoseconds.
+		Weight::from_parts(48_315_000, 3584)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:2 w:2)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), mode: `Measured`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), mode: `Measured`)
+	fn liquid_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10368`
+		//  Estimated: `20736`
+		// Minimum execution time: 171_305_000 picoseconds.
+		Weight::from_parts(173_918_000, 20736)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidUnstakeRequests` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidUnstakeRequests` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), mode: `Measured`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), mode: `Measured`)
+	fn liquid_unstake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7296`
+		//  Estimated: `14592`
+		// Minimum execution time: 152_664_000 picoseconds.
+		Weight::from_parts(154_087_000, 14592)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::LiquidUnstakeRequests` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LiquidUnstakeRequests` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidVaults` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::LiquidVaults` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:2 w:2)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// The range of component `n` is `[1, 5]`.
+	fn execute_liquid_unstake(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6272 + n * (96 ±0)`
+		//  Estimated: `12544 + n * (96 ±0)`
+		// Minimum execution time: 84_912_000 picoseconds.
+		Weight::from_parts(86_130_000, 12544)
+			// Standard Error: 4_211
+			.saturating_add(Weight::from_parts(14_702_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 96).saturating_mul(n.into()))
+	}
+
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LiquidUnstakeRequests` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::LiquidUnstakeRequests` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// The range of component `u` is `[1, 5]`.
+	/// The range of component `w` is `[1, 5]`.
+	fn execute_all_ready(u: u32, w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2816 + u * (112 ±0) + w * (64 ±0)`
+		//  Estimated: `5632 + u * (112 ±0) + w * (64 ±0)`
+		// Minimum execution time: 96_218_000 picoseconds.
+		Weight::from_parts(97_410_000, 5632)
+			// Standard Error: 6_874
+			.saturating_add(Weight::from_parts(9_870_000, 0).saturating_mul(u.into()))
+			// Standard Error: 6_874
+			.saturating_add(Weight::from_parts(7_315_000, 0).saturating_mul(w.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 112).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(w.into()))
+	}
+
+	/// Storage: `MultiAssetDelegation::LockTiers` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::LockTiers` (`max_values`: None, `max_size`: Some(29), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::LockedDelegations` (r:101 w:0)
+	/// Proof: `MultiAssetDelegation::LockedDelegations` (`max_values`: None, `max_size`: Some(101), mode: `Measured`)
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:n w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Rewards::TotalRewardVaultScore` (r:n w:n)
+	/// Proof: `Rewards::TotalRewardVaultScore` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn set_lock_tier(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (101 ±0)`
+		//  Estimated: `3607 + n * (2576 ±0)`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_872_000, 3607)
+			// Standard Error: 2_913
+			.saturating_add(Weight::from_parts(9_415_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+
+	/// Storage: `MultiAssetDelegation::EarlyUnlockPenalty` (r:0 w:1)
+	/// Proof: `MultiAssetDelegation::EarlyUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(4), mode: `Measured`)
+	fn set_early_unlock_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_942_000 picoseconds.
+		Weight::from_parts(7_942_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::LockTiers` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::LockTiers` (`max_values`: None, `max_size`: Some(29), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	fn extend_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2048`
+		//  Estimated: `5120`
+		// Minimum execution time: 31_455_000 picoseconds.
+		Weight::from_parts(31_455_000, 5120)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `MultiAssetDelegation::EarlyUnlockPenalty` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::EarlyUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(4), mode: `Measured`)
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	fn early_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4096`
+		//  Estimated: `10240`
+		// Minimum execution time: 68_731_000 picoseconds.
+		Weight::from_parts(68_731_000, 10240)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: Some(64), mode: `Measured`)
+	/// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Delegators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: Some(4096), mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: Some(1024), mode: `Measured`)
+	fn claim_liquid_vault_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5632`
+		//  Estimated: `11264`
+		// Minimum execution time: 91_274_000 picoseconds.
+		Weight::from_parts(91_274_000, 11264)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
@@ -48,6 +48,14 @@ mod benchmarks {
 		assert!(!HasDispatched::<T, I>::exists());
 	}
 
+	#[benchmark]
+	fn set_feeder_service() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(0));
+
+		assert_eq!(FeederService::<T, I>::get(), Some(0));
+	}
+
 	impl_benchmark_test_suite! {
 		Oracle,
 		crate::mock::new_test_ext(),
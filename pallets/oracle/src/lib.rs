@@ -13,6 +13,32 @@
 //!
 //! The data is valid only if feeded by an authorized operator.
 //! `pallet_membership` in FRAME can be used to as source of `T::Members`.
+//!
+//! ## Combine Strategies
+//!
+//! `T::CombineData` decides how the raw values are aggregated:
+//!
+//! - [`DefaultCombineData`] takes the median of the unexpired values.
+//! - [`StakeWeightedCombineData`] takes the median with each feeder weighted by a [`FeederWeight`],
+//!   such as [`RestakedExposure`] from multi-asset-delegation. Restaked assets are valued by a
+//!   [`StakeValuation`], such as [`SingleAsset`] which only counts one configured asset.
+//! - [`OutlierRejectingCombineData`] drops values deviating too far from the median before
+//!   combining the rest with another strategy.
+//! - [`TimeWeightedCombineData`] records the values of another strategy in [`CombinedHistory`] and
+//!   returns their time-weighted average over a window.
+//!
+//! ## Service Feeders
+//!
+//! The operators of the service instance set with `set_feeder_service` may feed values as well,
+//! which puts their restaked exposure behind the data:
+//!
+//! - Every feed counts as a heartbeat of the operator, so feed rounds it misses count against the
+//!   heartbeat QoS of the service.
+//! - At the start of the next block, each service feeder's value for a key fed in the round is
+//!   compared with the median of the values the other feeders fed in the round, as measured by
+//!   `T::ValueDeviation`. Deviating by more than `T::MisreportThreshold` schedules an unapplied
+//!   slash of `T::MisreportSlash` through the slashing pipeline of the service, at most once per
+//!   feeder and round. Values left from earlier rounds are not checked.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 // Disable the following two lints since they originate from an external macro (namely decl_storage)
@@ -33,21 +59,34 @@ use frame_support::{
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Member, DispatchResult, RuntimeDebug};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Member, Saturating, Zero},
+	DispatchResult, Percent, Permill, RuntimeDebug,
+};
 use sp_std::{prelude::*, vec};
-use tangle_primitives::ordered_set::OrderedSet;
 pub use tangle_primitives::traits::{
-	CombineData, DataFeeder, DataProvider, DataProviderExtended, OnNewData,
+	CombineData, DataFeeder, DataProvider, DataProviderExtended, OnNewData, ServiceOperatorManager,
+};
+use tangle_primitives::{ordered_set::OrderedSet, InstanceId};
+
+pub use crate::{
+	default_combine_data::DefaultCombineData,
+	outlier_rejecting_combine_data::OutlierRejectingCombineData,
+	stake_weighted_combine_data::{
+		FeederWeight, RestakedExposure, SingleAsset, StakeValuation, StakeWeightedCombineData,
+	},
+	time_weighted_combine_data::TimeWeightedCombineData,
 };
-
-pub use crate::default_combine_data::DefaultCombineData;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
 mod default_combine_data;
 mod mock;
+mod outlier_rejecting_combine_data;
+mod stake_weighted_combine_data;
 mod tests;
+mod time_weighted_combine_data;
 mod weights;
 
 pub use module::*;
@@ -71,6 +110,34 @@ impl<OracleKey, OracleValue, L: Get<u32>> BenchmarkHelper<OracleKey, OracleValue
 	}
 }
 
+/// Measures how far a fed value deviates from a reference value, for misreport checks.
+pub trait ValueDeviation<OracleValue> {
+	/// Returns whether `value` deviates from `reference` by more than `threshold` of
+	/// `reference`.
+	fn exceeds(value: &OracleValue, reference: &OracleValue, threshold: Permill) -> bool;
+}
+
+/// Never reports a deviation, which disables misreport checks.
+impl<OracleValue> ValueDeviation<OracleValue> for () {
+	fn exceeds(_value: &OracleValue, _reference: &OracleValue, _threshold: Permill) -> bool {
+		false
+	}
+}
+
+/// Measures the absolute deviation of numeric values.
+pub struct AbsoluteDeviation;
+
+impl<OracleValue: AtLeast32BitUnsigned + Clone> ValueDeviation<OracleValue> for AbsoluteDeviation {
+	fn exceeds(value: &OracleValue, reference: &OracleValue, threshold: Permill) -> bool {
+		let deviation = if value > reference {
+			value.clone().saturating_sub(reference.clone())
+		} else {
+			reference.clone().saturating_sub(value.clone())
+		};
+		deviation > threshold.mul_floor(reference.clone())
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -137,6 +204,25 @@ pub mod module {
 		#[pallet::constant]
 		type MaxFeedValues: Get<u32>;
 
+		/// Maximum number of combined values kept in the history of each key
+		#[pallet::constant]
+		type MaxHistoryLength: Get<u32>;
+
+		/// Services whose operators may feed values
+		type ServiceManager: ServiceOperatorManager<Self::AccountId>;
+
+		/// Measures the deviation of a value from the median of the other feeders
+		type ValueDeviation: ValueDeviation<Self::OracleValue>;
+
+		/// Deviation from the median of the other feeders beyond which a service operator
+		/// misreported
+		#[pallet::constant]
+		type MisreportThreshold: Get<Permill>;
+
+		/// Slash scheduled against a service operator that misreported
+		#[pallet::constant]
+		type MisreportSlash: Get<Percent>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<
 			Self::OracleKey,
@@ -158,6 +244,15 @@ pub mod module {
 	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// New feed data is submitted.
 		NewFeedData { sender: T::AccountId, values: Vec<(T::OracleKey, T::OracleValue)> },
+		/// The service whose operators feed values is updated.
+		FeederServiceSet { service_id: Option<InstanceId> },
+		/// A service operator fed a value deviating too far from the median of the other feeders.
+		FeederMisreported {
+			feeder: T::AccountId,
+			key: T::OracleKey,
+			value: T::OracleValue,
+			median: T::OracleValue,
+		},
 	}
 
 	/// Raw values for each oracle operators
@@ -178,19 +273,42 @@ pub mod module {
 	pub type Values<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, <T as Config<I>>::OracleKey, TimestampedValueOf<T, I>>;
 
+	/// History of combined values recorded by time-weighted strategies, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn combined_history)]
+	pub type CombinedHistory<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		<T as Config<I>>::OracleKey,
+		BoundedVec<TimestampedValueOf<T, I>, T::MaxHistoryLength>,
+		ValueQuery,
+	>;
+
+	/// Service instance whose operators may feed values
+	#[pallet::storage]
+	#[pallet::getter(fn feeder_service)]
+	pub type FeederService<T: Config<I>, I: 'static = ()> = StorageValue<_, InstanceId>;
+
 	/// If an oracle operator has fed a value in this block
 	#[pallet::storage]
 	pub(crate) type HasDispatched<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, OrderedSet<T::AccountId, T::MaxHasDispatchedSize>, ValueQuery>;
 
+	/// Keys fed in this block while a feeder service is set, whose service feeders are checked
+	/// for misreports at the start of the next block
+	#[pallet::storage]
+	pub(crate) type RoundKeys<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::OracleKey, T::MaxFeedValues>, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
-		/// `on_initialize` to return the weight used in `on_finalize`.
+		/// Checks the service feeders of the previous round for misreports and returns the weight
+		/// used, together with the weight of `on_finalize`.
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-			T::WeightInfo::on_finalize()
+			Self::check_misreports().saturating_add(T::WeightInfo::on_finalize())
 		}
 
 		fn on_finalize(_n: BlockNumberFor<T>) {
@@ -225,18 +343,54 @@ pub mod module {
 			Self::do_feed_values(who, values.into())?;
 			Ok(Pays::No.into())
 		}
+
+		/// Set the service instance whose operators may feed values.
+		///
+		/// Require root. `None` stops accepting values from service operators.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_feeder_service())]
+		pub fn set_feeder_service(
+			origin: OriginFor<T>,
+			service_id: Option<InstanceId>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			FeederService::<T, I>::set(service_id);
+			Self::deposit_event(Event::FeederServiceSet { service_id });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn read_raw_values(key: &T::OracleKey) -> Vec<TimestampedValueOf<T, I>> {
-		T::Members::sorted_members()
-			.iter()
-			.chain([T::RootOperatorAccountId::get()].iter())
-			.filter_map(|x| Self::raw_values(x, key))
+		Self::read_raw_values_by_feeder(key)
+			.into_iter()
+			.map(|(_, value)| value)
+			.collect()
+	}
+
+	/// Read raw values together with the operator that fed them.
+	pub fn read_raw_values_by_feeder(
+		key: &T::OracleKey,
+	) -> Vec<(T::AccountId, TimestampedValueOf<T, I>)> {
+		let mut feeders = T::Members::sorted_members();
+		for operator in Self::service_feeders() {
+			if !feeders.contains(&operator) {
+				feeders.push(operator);
+			}
+		}
+		feeders
+			.into_iter()
+			.chain([T::RootOperatorAccountId::get()])
+			.filter_map(|x| Self::raw_values(&x, key).map(|value| (x, value)))
 			.collect()
 	}
 
+	/// Operators of the feeder service, if one is set.
+	pub fn service_feeders() -> Vec<T::AccountId> {
+		Self::feeder_service().map_or_else(Vec::new, T::ServiceManager::service_operators)
+	}
+
 	/// Fetch current combined value.
 	pub fn get(key: &T::OracleKey) -> Option<TimestampedValueOf<T, I>> {
 		Self::values(key)
@@ -255,7 +409,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	fn ensure_account(who: Option<T::AccountId>) -> Result<T::AccountId, DispatchError> {
 		// ensure feeder is authorized
 		if let Some(who) = who {
-			ensure!(T::Members::contains(&who), Error::<T, I>::NoPermission);
+			ensure!(
+				T::Members::contains(&who) || Self::service_feeders().contains(&who),
+				Error::<T, I>::NoPermission
+			);
 			Ok(who)
 		} else {
 			Ok(T::RootOperatorAccountId::get())
@@ -267,6 +424,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		values: Vec<(T::OracleKey, T::OracleValue)>,
 	) -> DispatchResult {
 		let now = T::Time::now();
+		let service = Self::feeder_service()
+			.filter(|id| T::ServiceManager::service_operators(*id).contains(&who));
+		let record_round_keys = Self::feeder_service().is_some();
 		for (key, value) in &values {
 			let timestamped = TimestampedValue { value: value.clone(), timestamp: now };
 			RawValues::<T, I>::insert(&who, key, timestamped);
@@ -276,11 +436,101 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				<Values<T, I>>::insert(key, combined);
 			}
 
+			if record_round_keys {
+				// Keys beyond the bound are left unchecked for the round.
+				RoundKeys::<T, I>::mutate(|keys| {
+					if !keys.contains(key) {
+						let _ = keys.try_push(key.clone());
+					}
+				});
+			}
+
 			T::OnNewData::on_new_data(&who, key, value);
 		}
+		if let Some(service_id) = service {
+			T::ServiceManager::record_heartbeat(service_id, &who)?;
+		}
 		Self::deposit_event(Event::NewFeedData { sender: who, values });
 		Ok(())
 	}
+
+	/// Checks the service feeders for misreports once a feed round is over, returning the
+	/// weight used.
+	///
+	/// For every key fed in the round, the value each service feeder fed in the round is compared
+	/// with the median of the values the other feeders fed in it. A value deviating from it by more
+	/// than `T::MisreportThreshold` schedules a slash, at most once per feeder and round however
+	/// many of its values deviate. Values fed in earlier rounds are not checked, a feeder that
+	/// skips a round misses its heartbeat instead.
+	fn check_misreports() -> Weight {
+		let keys = RoundKeys::<T, I>::take();
+		let mut reads = 1;
+		let mut writes = 1;
+		let Some(service_id) = Self::feeder_service() else {
+			return T::DbWeight::get().reads_writes(reads + 1, writes);
+		};
+		let slash_percent = T::MisreportSlash::get();
+		if keys.is_empty() || slash_percent.is_zero() {
+			return T::DbWeight::get().reads_writes(reads + 1, writes);
+		}
+
+		let service_feeders = T::ServiceManager::service_operators(service_id);
+		let mut misreported = Vec::<T::AccountId>::new();
+		// The hooks run before the timestamp of the new block is set, so this is still the moment
+		// of the round being closed, which every value fed in it is timestamped with.
+		let round_moment = T::Time::now();
+		reads += 3;
+		for key in keys {
+			let mut values = Self::read_raw_values_by_feeder(&key);
+			reads = reads.saturating_add(values.len() as u64 + 1);
+			values.retain(|(_, value)| value.timestamp == round_moment);
+			for (feeder, value) in &values {
+				if misreported.contains(feeder) || !service_feeders.contains(feeder) {
+					continue;
+				}
+				let Some(median) = Self::median_of_others(&values, feeder) else {
+					continue;
+				};
+				if !T::ValueDeviation::exceeds(&value.value, &median, T::MisreportThreshold::get())
+				{
+					continue;
+				}
+
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(2);
+				if T::ServiceManager::report_misbehavior(service_id, feeder, slash_percent).is_err()
+				{
+					continue;
+				}
+				misreported.push(feeder.clone());
+				Self::deposit_event(Event::FeederMisreported {
+					feeder: feeder.clone(),
+					key: key.clone(),
+					value: value.value.clone(),
+					median,
+				});
+			}
+		}
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Returns the lower median of the values of every feeder other than `feeder`, if at least
+	/// two other feeders have a value, so a single feeder can not get another one slashed.
+	fn median_of_others(
+		values: &[(T::AccountId, TimestampedValueOf<T, I>)],
+		feeder: &T::AccountId,
+	) -> Option<T::OracleValue> {
+		let mut others: Vec<T::OracleValue> = values
+			.iter()
+			.filter(|(other, _)| other != feeder)
+			.map(|(_, value)| value.value.clone())
+			.collect();
+		if others.len() < 2 {
+			return None;
+		}
+		others.sort();
+		others.get((others.len() - 1) / 2).cloned()
+	}
 }
 
 impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
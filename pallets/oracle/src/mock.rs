@@ -6,7 +6,12 @@ use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{ConstU32, SortedMembers},
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Percent, Permill};
+use tangle_primitives::{
+	services::Asset,
+	traits::MultiAssetDelegationInfo,
+	types::{rewards::UserDepositWithLocks, RoundIndex},
+};
 
 use std::cell::RefCell;
 
@@ -28,6 +33,10 @@ impl frame_system::Config for Test {
 thread_local! {
 	static TIME: RefCell<u32> = const { RefCell::new(0) };
 	static MEMBERS: RefCell<Vec<AccountId>> = RefCell::new(vec![1, 2, 3]);
+	static FEEDER_WEIGHTS: RefCell<Vec<(AccountId, u128)>> = const { RefCell::new(Vec::new()) };
+	static SERVICE_OPERATORS: RefCell<Vec<AccountId>> = const { RefCell::new(Vec::new()) };
+	static HEARTBEATS: RefCell<Vec<(InstanceId, AccountId)>> = const { RefCell::new(Vec::new()) };
+	static SLASHES: RefCell<Vec<(InstanceId, AccountId, Percent)>> = const { RefCell::new(Vec::new()) };
 }
 
 pub struct Timestamp;
@@ -48,6 +57,9 @@ impl Timestamp {
 parameter_types! {
 	pub const RootOperatorAccountId: AccountId = 4;
 	pub const MaxFeedValues: u32 = 5;
+	pub const MaxDeviation: Permill = Permill::from_percent(10);
+	pub const MisreportThreshold: Permill = Permill::from_percent(20);
+	pub const MisreportSlash: Percent = Percent::from_percent(5);
 }
 
 pub struct Members;
@@ -63,6 +75,109 @@ impl SortedMembers<AccountId> for Members {
 	}
 }
 
+pub struct FeederWeights;
+
+impl FeederWeight<AccountId> for FeederWeights {
+	fn feeder_weight(who: &AccountId) -> u128 {
+		FEEDER_WEIGHTS.with(|v| {
+			v.borrow()
+				.iter()
+				.find(|(feeder, _)| feeder == who)
+				.map_or(0, |(_, weight)| *weight)
+		})
+	}
+}
+
+/// An active operator with a self stake of 100 and delegations of 50 in the native asset and
+/// 1_000 in asset 1.
+pub struct Delegation;
+
+impl MultiAssetDelegationInfo<AccountId, u128, u64, u32, ()> for Delegation {
+	fn get_current_round() -> RoundIndex {
+		0
+	}
+
+	fn is_operator(operator: &AccountId) -> bool {
+		*operator == 1
+	}
+
+	fn is_operator_active(operator: &AccountId) -> bool {
+		*operator == 1
+	}
+
+	fn get_operator_stake(_operator: &AccountId) -> u128 {
+		100
+	}
+
+	fn get_total_delegation_by_asset(_operator: &AccountId, _asset: &Asset<u32>) -> u128 {
+		0
+	}
+
+	fn get_delegators_for_operator(_operator: &AccountId) -> Vec<(AccountId, u128, Asset<u32>)> {
+		vec![(2, 50, Asset::Custom(0)), (3, 1_000, Asset::Custom(1))]
+	}
+
+	fn get_user_deposit_with_locks(
+		_who: &AccountId,
+		_asset: Asset<u32>,
+	) -> Option<UserDepositWithLocks<u128, u64>> {
+		None
+	}
+
+	fn get_user_deposit_by_asset_type(_who: &AccountId, _asset_type: ()) -> Option<u128> {
+		None
+	}
+}
+
+parameter_types! {
+	pub const NativeAsset: Asset<u32> = Asset::Custom(0);
+	pub const RestakedAsset: Asset<u32> = Asset::Custom(1);
+}
+
+pub type NativeExposure =
+	RestakedExposure<Delegation, SingleAsset<NativeAsset>, u128, u64, u32, ()>;
+pub type RestakedAssetExposure =
+	RestakedExposure<Delegation, SingleAsset<RestakedAsset>, u128, u64, u32, ()>;
+
+pub struct ServiceManager;
+
+impl ServiceOperatorManager<AccountId> for ServiceManager {
+	fn service_operators(service_id: InstanceId) -> Vec<AccountId> {
+		if service_id == FEEDER_SERVICE_ID {
+			SERVICE_OPERATORS.with(|v| v.borrow().clone())
+		} else {
+			Vec::new()
+		}
+	}
+
+	fn record_heartbeat(service_id: InstanceId, operator: &AccountId) -> DispatchResult {
+		HEARTBEATS.with(|v| v.borrow_mut().push((service_id, *operator)));
+		Ok(())
+	}
+
+	fn report_misbehavior(
+		service_id: InstanceId,
+		operator: &AccountId,
+		slash_percent: Percent,
+	) -> DispatchResult {
+		SLASHES.with(|v| v.borrow_mut().push((service_id, *operator, slash_percent)));
+		Ok(())
+	}
+}
+
+pub type StakeWeighted = StakeWeightedCombineData<Test, FeederWeights, ConstU32<1>, ConstU32<600>>;
+pub type OutlierRejecting = OutlierRejectingCombineData<
+	Test,
+	DefaultCombineData<Test, ConstU32<1>, ConstU32<600>>,
+	MaxDeviation,
+	ConstU32<600>,
+>;
+pub type TimeWeighted = TimeWeightedCombineData<
+	Test,
+	DefaultCombineData<Test, ConstU32<1>, ConstU32<600>>,
+	ConstU32<100>,
+>;
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OnNewData = ();
@@ -75,6 +190,11 @@ impl Config for Test {
 	type WeightInfo = ();
 	type MaxHasDispatchedSize = ConstU32<100>;
 	type MaxFeedValues = MaxFeedValues;
+	type MaxHistoryLength = ConstU32<4>;
+	type ServiceManager = ServiceManager;
+	type ValueDeviation = AbsoluteDeviation;
+	type MisreportThreshold = MisreportThreshold;
+	type MisreportSlash = MisreportSlash;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -92,6 +212,24 @@ pub fn set_members(members: Vec<AccountId>) {
 	MEMBERS.with(|v| *v.borrow_mut() = members);
 }
 
+pub const FEEDER_SERVICE_ID: InstanceId = 7;
+
+pub fn set_service_operators(operators: Vec<AccountId>) {
+	SERVICE_OPERATORS.with(|v| *v.borrow_mut() = operators);
+}
+
+pub fn heartbeats() -> Vec<(InstanceId, AccountId)> {
+	HEARTBEATS.with(|v| v.borrow().clone())
+}
+
+pub fn slashes() -> Vec<(InstanceId, AccountId, Percent)> {
+	SLASHES.with(|v| v.borrow().clone())
+}
+
+pub fn set_feeder_weights(weights: Vec<(AccountId, u128)>) {
+	FEEDER_WEIGHTS.with(|v| *v.borrow_mut() = weights);
+}
+
 // This function basically just builds a genesis storage key/value store
 // according to our desired mockup.
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -0,0 +1,57 @@
+use crate::{Config, MomentOf, TimestampedValueOf};
+use frame_support::traits::{Get, Time};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating},
+	Permill,
+};
+use sp_std::{marker, prelude::*};
+use tangle_primitives::traits::CombineData;
+
+/// Drops expired values and values deviating from their median by more than
+/// `MaxDeviation`, then combines the remaining values with `Inner`.
+pub struct OutlierRejectingCombineData<T, Inner, MaxDeviation, ExpiresIn, I = ()>(
+	marker::PhantomData<(T, I, Inner, MaxDeviation, ExpiresIn)>,
+);
+
+impl<T, I, Inner, MaxDeviation, ExpiresIn>
+	CombineData<<T as Config<I>>::OracleKey, TimestampedValueOf<T, I>>
+	for OutlierRejectingCombineData<T, Inner, MaxDeviation, ExpiresIn, I>
+where
+	T: Config<I>,
+	I: 'static,
+	T::OracleValue: AtLeast32BitUnsigned,
+	Inner: CombineData<<T as Config<I>>::OracleKey, TimestampedValueOf<T, I>>,
+	MaxDeviation: Get<Permill>,
+	ExpiresIn: Get<MomentOf<T, I>>,
+{
+	fn combine_data(
+		key: &<T as Config<I>>::OracleKey,
+		mut values: Vec<TimestampedValueOf<T, I>>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<TimestampedValueOf<T, I>> {
+		let expires_in = ExpiresIn::get();
+		let now = T::Time::now();
+
+		values.retain(|x| x.timestamp.saturating_add(expires_in) > now);
+
+		if !values.is_empty() {
+			let mid_index = values.len() / 2;
+			// Won't panic as `values` ensured not empty.
+			let (_, median, _) =
+				values.select_nth_unstable_by(mid_index, |a, b| a.value.cmp(&b.value));
+			let median = median.value.clone();
+			let max_deviation = MaxDeviation::get().mul_floor(median.clone());
+
+			values.retain(|x| {
+				let deviation = if x.value > median {
+					x.value.clone().saturating_sub(median.clone())
+				} else {
+					median.clone().saturating_sub(x.value.clone())
+				};
+				deviation <= max_deviation
+			});
+		}
+
+		Inner::combine_data(key, values, prev_value)
+	}
+}
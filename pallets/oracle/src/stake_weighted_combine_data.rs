@@ -0,0 +1,139 @@
+use crate::{Config, MomentOf, Pallet, TimestampedValueOf};
+use frame_support::traits::{Get, Time};
+use sp_runtime::traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating, Zero};
+use sp_std::{marker, prelude::*};
+use tangle_primitives::{
+	services::Asset,
+	traits::{CombineData, MultiAssetDelegationInfo},
+};
+
+/// Provides the weight a feeder's values carry when they are combined.
+pub trait FeederWeight<AccountId> {
+	/// Returns the weight of `who`. Feeders without weight are ignored.
+	fn feeder_weight(who: &AccountId) -> u128;
+}
+
+/// Weighs every feeder equally.
+impl<AccountId> FeederWeight<AccountId> for () {
+	fn feeder_weight(_who: &AccountId) -> u128 {
+		1
+	}
+}
+
+/// Values amounts of restaked assets in a common unit, so stake in different
+/// assets can be added up.
+pub trait StakeValuation<AssetId, Balance> {
+	/// Returns the value of `amount` of `asset`. Assets without a value return
+	/// zero.
+	fn value(asset: &Asset<AssetId>, amount: Balance) -> u128;
+}
+
+/// Values the configured `StakeAsset` one to one and ignores every other asset.
+pub struct SingleAsset<StakeAsset>(marker::PhantomData<StakeAsset>);
+
+impl<AssetId, Balance, StakeAsset> StakeValuation<AssetId, Balance> for SingleAsset<StakeAsset>
+where
+	AssetId: PartialEq,
+	Balance: AtLeast32BitUnsigned,
+	StakeAsset: Get<Asset<AssetId>>,
+{
+	fn value(asset: &Asset<AssetId>, amount: Balance) -> u128 {
+		if *asset == StakeAsset::get() {
+			amount.saturated_into()
+		} else {
+			0
+		}
+	}
+}
+
+/// Weighs feeders by their restaked exposure, the operator's self stake in
+/// the native asset plus everything delegated to it, with each asset valued by
+/// `Valuation`.
+pub struct RestakedExposure<Delegation, Valuation, Balance, BlockNumber, AssetId, AssetType>(
+	marker::PhantomData<(Delegation, Valuation, Balance, BlockNumber, AssetId, AssetType)>,
+);
+
+impl<AccountId, Delegation, Valuation, Balance, BlockNumber, AssetId, AssetType>
+	FeederWeight<AccountId>
+	for RestakedExposure<Delegation, Valuation, Balance, BlockNumber, AssetId, AssetType>
+where
+	Delegation: MultiAssetDelegationInfo<AccountId, Balance, BlockNumber, AssetId, AssetType>,
+	Valuation: StakeValuation<AssetId, Balance>,
+	AssetId: Zero,
+{
+	fn feeder_weight(who: &AccountId) -> u128 {
+		if !Delegation::is_operator_active(who) {
+			return 0;
+		}
+		let self_stake =
+			Valuation::value(&Asset::Custom(Zero::zero()), Delegation::get_operator_stake(who));
+		Delegation::get_delegators_for_operator(who)
+			.into_iter()
+			.fold(self_stake, |total, (_, amount, asset)| {
+				total.saturating_add(Valuation::value(&asset, amount))
+			})
+	}
+}
+
+/// Sort by value and returns the weighted median timestamped value, where
+/// each feeder's value is weighted by `Weight`.
+/// Returns prev_value if not enough valid values.
+pub struct StakeWeightedCombineData<T, Weight, MinimumCount, ExpiresIn, I = ()>(
+	marker::PhantomData<(T, I, Weight, MinimumCount, ExpiresIn)>,
+);
+
+impl<T, I, Weight, MinimumCount, ExpiresIn>
+	CombineData<<T as Config<I>>::OracleKey, TimestampedValueOf<T, I>>
+	for StakeWeightedCombineData<T, Weight, MinimumCount, ExpiresIn, I>
+where
+	T: Config<I>,
+	I: 'static,
+	Weight: FeederWeight<T::AccountId>,
+	MinimumCount: Get<u32>,
+	ExpiresIn: Get<MomentOf<T, I>>,
+{
+	fn combine_data(
+		key: &<T as Config<I>>::OracleKey,
+		mut values: Vec<TimestampedValueOf<T, I>>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<TimestampedValueOf<T, I>> {
+		let expires_in = ExpiresIn::get();
+		let now = T::Time::now();
+
+		// `values` carries no feeders, so match each one back to the feeder that
+		// reported it. Values dropped by an outer strategy stay unmatched.
+		let mut weighted: Vec<(TimestampedValueOf<T, I>, u128)> = Vec::new();
+		for (feeder, value) in Pallet::<T, I>::read_raw_values_by_feeder(key) {
+			let Some(index) = values.iter().position(|x| *x == value) else {
+				continue;
+			};
+			values.swap_remove(index);
+			if value.timestamp.saturating_add(expires_in) <= now {
+				continue;
+			}
+			let weight = Weight::feeder_weight(&feeder);
+			if !weight.is_zero() {
+				weighted.push((value, weight));
+			}
+		}
+
+		let count = weighted.len() as u32;
+		let minimum_count = MinimumCount::get();
+		if count < minimum_count || count == 0 {
+			return prev_value;
+		}
+
+		weighted.sort_by(|a, b| a.0.value.cmp(&b.0.value));
+		let total_weight =
+			weighted.iter().fold(0u128, |total, (_, weight)| total.saturating_add(*weight));
+
+		let mut cumulative: u128 = 0;
+		for (value, weight) in weighted {
+			cumulative = cumulative.saturating_add(weight);
+			if cumulative.saturating_mul(2) > total_weight {
+				return Some(value);
+			}
+		}
+		prev_value
+	}
+}
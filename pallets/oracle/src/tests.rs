@@ -1,9 +1,16 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use mock::*;
 
+/// Ends the feed round of `block` and starts the next one.
+fn end_round(block: u64) {
+	ModuleOracle::on_finalize(block);
+	System::set_block_number(block + 1);
+	ModuleOracle::on_initialize(block + 1);
+}
+
 #[test]
 fn should_feed_values_from_member() {
 	new_test_ext().execute_with(|| {
@@ -321,3 +328,268 @@ fn values_are_updated_on_feed() {
 		);
 	});
 }
+
+#[test]
+fn stake_weighted_median_follows_feeder_weights() {
+	new_test_ext().execute_with(|| {
+		let key: u32 = 50;
+		for (feeder, value) in [(1, 1000), (2, 1100), (3, 1200)] {
+			assert_ok!(ModuleOracle::feed_values(
+				RuntimeOrigin::signed(feeder),
+				vec![(key, value)].try_into().unwrap()
+			));
+		}
+		let values = ModuleOracle::read_raw_values(&key);
+		let timestamped = |value| Some(TimestampedValue { value, timestamp: 12345 });
+
+		set_feeder_weights(vec![(1, 1), (2, 1), (3, 1)]);
+		assert_eq!(StakeWeighted::combine_data(&key, values.clone(), None), timestamped(1100));
+
+		set_feeder_weights(vec![(1, 1), (2, 1), (3, 10)]);
+		assert_eq!(StakeWeighted::combine_data(&key, values.clone(), None), timestamped(1200));
+
+		// Feeders without weight are ignored.
+		set_feeder_weights(vec![(1, 5), (2, 1)]);
+		assert_eq!(StakeWeighted::combine_data(&key, values.clone(), None), timestamped(1000));
+
+		// Values removed before combining no longer count, even for heavy feeders.
+		set_feeder_weights(vec![(1, 1), (2, 1), (3, 10)]);
+		let without_third = values.into_iter().filter(|x| x.value != 1200).collect();
+		assert_eq!(StakeWeighted::combine_data(&key, without_third, None), timestamped(1100));
+
+		set_feeder_weights(vec![]);
+		assert_eq!(StakeWeighted::combine_data(&key, vec![], timestamped(7)), timestamped(7));
+	});
+}
+
+#[test]
+fn restaked_exposure_values_stake_per_asset() {
+	// Only the native self stake and delegations count.
+	assert_eq!(NativeExposure::feeder_weight(&1), 150);
+	// The native self stake does not count towards another asset.
+	assert_eq!(RestakedAssetExposure::feeder_weight(&1), 1_000);
+	// Inactive operators carry no weight.
+	assert_eq!(NativeExposure::feeder_weight(&2), 0);
+}
+
+#[test]
+fn outlier_rejecting_drops_deviating_values() {
+	new_test_ext().execute_with(|| {
+		let key: u32 = 50;
+		let values = [1000, 1020, 1040, 1500]
+			.into_iter()
+			.map(|value| TimestampedValue { value, timestamp: 12345 })
+			.collect::<Vec<_>>();
+
+		// The median is 1040, so 1500 deviates by more than 10% and is dropped.
+		assert_eq!(
+			OutlierRejecting::combine_data(&key, values.clone(), None),
+			Some(TimestampedValue { value: 1020, timestamp: 12345 })
+		);
+
+		// Expired values are dropped as well.
+		Timestamp::set_timestamp(12345 + 600);
+		assert_eq!(
+			OutlierRejecting::combine_data(
+				&key,
+				values,
+				Some(TimestampedValue { value: 1, timestamp: 1 })
+			),
+			Some(TimestampedValue { value: 1, timestamp: 1 })
+		);
+	});
+}
+
+#[test]
+fn time_weighted_averages_over_window() {
+	new_test_ext().execute_with(|| {
+		let key: u32 = 50;
+		let combine_at = |timestamp, value| {
+			Timestamp::set_timestamp(timestamp);
+			TimeWeighted::combine_data(&key, vec![TimestampedValue { value, timestamp }], None)
+				.map(|x| x.value)
+		};
+
+		// Without any elapsed time the latest value is returned.
+		assert_eq!(combine_at(12345, 100), Some(100));
+		assert_eq!(combine_at(12365, 200), Some(100));
+		// 100 for 20 and 200 for 30.
+		assert_eq!(combine_at(12395, 200), Some(160));
+		assert_eq!(ModuleOracle::combined_history(key).len(), 3);
+
+		// The window starts at 12365, so the first value no longer counts and is pruned.
+		assert_eq!(combine_at(12465, 400), Some(200));
+		assert_eq!(
+			ModuleOracle::combined_history(key).into_inner(),
+			vec![
+				TimestampedValue { value: 200, timestamp: 12365 },
+				TimestampedValue { value: 200, timestamp: 12395 },
+				TimestampedValue { value: 400, timestamp: 12465 },
+			]
+		);
+
+		// A value in the same timestamp replaces the previous one.
+		assert_eq!(combine_at(12465, 500), Some(200));
+		assert_eq!(ModuleOracle::combined_history(key).len(), 3);
+
+		// 200 for 70 and 500 for 30.
+		assert_eq!(combine_at(12495, 500), Some(290));
+	});
+}
+
+#[test]
+fn service_operators_feed_values() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_service_operators(vec![10, 11]);
+
+		assert_noop!(
+			ModuleOracle::feed_values(
+				RuntimeOrigin::signed(10),
+				vec![(50, 1000)].try_into().unwrap()
+			),
+			Error::<Test, _>::NoPermission,
+		);
+
+		assert_noop!(
+			ModuleOracle::set_feeder_service(RuntimeOrigin::signed(1), Some(FEEDER_SERVICE_ID)),
+			DispatchError::BadOrigin,
+		);
+		assert_ok!(ModuleOracle::set_feeder_service(
+			RuntimeOrigin::root(),
+			Some(FEEDER_SERVICE_ID)
+		));
+		System::assert_last_event(RuntimeEvent::ModuleOracle(crate::Event::FeederServiceSet {
+			service_id: Some(FEEDER_SERVICE_ID),
+		}));
+
+		assert_ok!(ModuleOracle::feed_values(
+			RuntimeOrigin::signed(10),
+			vec![(50, 1000), (51, 900)].try_into().unwrap()
+		));
+		assert_eq!(
+			ModuleOracle::read_raw_values(&50),
+			vec![TimestampedValue { value: 1000, timestamp: 12345 }]
+		);
+		// One heartbeat per feed, regardless of the number of values.
+		assert_eq!(heartbeats(), vec![(FEEDER_SERVICE_ID, 10)]);
+
+		// Members feed without heartbeats.
+		assert_ok!(ModuleOracle::feed_values(
+			RuntimeOrigin::signed(1),
+			vec![(50, 1000)].try_into().unwrap()
+		));
+		assert_eq!(heartbeats(), vec![(FEEDER_SERVICE_ID, 10)]);
+
+		assert_ok!(ModuleOracle::set_feeder_service(RuntimeOrigin::root(), None));
+		assert_noop!(
+			ModuleOracle::feed_values(
+				RuntimeOrigin::signed(11),
+				vec![(50, 1000)].try_into().unwrap()
+			),
+			Error::<Test, _>::NoPermission,
+		);
+	});
+}
+
+#[test]
+fn misreporting_service_operators_are_slashed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_service_operators(vec![10, 11]);
+		assert_ok!(ModuleOracle::set_feeder_service(
+			RuntimeOrigin::root(),
+			Some(FEEDER_SERVICE_ID)
+		));
+
+		for feeder in [1, 2, 3] {
+			assert_ok!(ModuleOracle::feed_values(
+				RuntimeOrigin::signed(feeder),
+				vec![(50, 1000)].try_into().unwrap()
+			));
+		}
+
+		// Within 20% of the median of the other feeders.
+		assert_ok!(ModuleOracle::feed_values(
+			RuntimeOrigin::signed(11),
+			vec![(50, 1150)].try_into().unwrap()
+		));
+		assert_ok!(ModuleOracle::feed_values(
+			RuntimeOrigin::signed(10),
+			vec![(50, 1300)].try_into().unwrap()
+		));
+		assert_eq!(ModuleOracle::get(&50).map(|x| x.value), Some(1000));
+
+		// Feeders are only checked once the round is over.
+		assert!(slashes().is_empty());
+		end_round(1);
+		assert_eq!(slashes(), vec![(FEEDER_SERVICE_ID, 10, Percent::from_percent(5))]);
+		System::assert_has_event(RuntimeEvent::ModuleOracle(crate::Event::FeederMisreported {
+			feeder: 10,
+			key: 50,
+			value: 1300,
+			median: 1000,
+		}));
+	});
+}
+
+#[test]
+fn misreports_are_checked_against_the_other_feeders_of_the_round() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_service_operators(vec![10, 11]);
+		assert_ok!(ModuleOracle::set_feeder_service(
+			RuntimeOrigin::root(),
+			Some(FEEDER_SERVICE_ID)
+		));
+		for feeder in [10, 11, 1, 2, 3] {
+			assert_ok!(ModuleOracle::feed_values(
+				RuntimeOrigin::signed(feeder),
+				vec![(50, 1000)].try_into().unwrap()
+			));
+		}
+		end_round(1);
+		assert!(slashes().is_empty());
+
+		// The price moves and the feeder that skipped the round is left to the heartbeat QoS,
+		// its stale value is not checked against the new one.
+		Timestamp::set_timestamp(Timestamp::now() + 6_000);
+		for feeder in [10, 1, 2, 3] {
+			assert_ok!(ModuleOracle::feed_values(
+				RuntimeOrigin::signed(feeder),
+				vec![(50, 1500)].try_into().unwrap()
+			));
+		}
+		end_round(2);
+		assert!(slashes().is_empty());
+		assert_eq!(
+			heartbeats(),
+			vec![(FEEDER_SERVICE_ID, 10), (FEEDER_SERVICE_ID, 11), (FEEDER_SERVICE_ID, 10)]
+		);
+	});
+}
+
+#[test]
+fn misreporting_feeders_are_slashed_once_per_round() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_service_operators(vec![10]);
+		assert_ok!(ModuleOracle::set_feeder_service(
+			RuntimeOrigin::root(),
+			Some(FEEDER_SERVICE_ID)
+		));
+		for feeder in [1, 2, 3] {
+			assert_ok!(ModuleOracle::feed_values(
+				RuntimeOrigin::signed(feeder),
+				vec![(50, 1000), (51, 1000)].try_into().unwrap()
+			));
+		}
+		assert_ok!(ModuleOracle::feed_values(
+			RuntimeOrigin::signed(10),
+			vec![(50, 2000), (51, 2000)].try_into().unwrap()
+		));
+
+		end_round(1);
+		assert_eq!(slashes(), vec![(FEEDER_SERVICE_ID, 10, Percent::from_percent(5))]);
+	});
+}
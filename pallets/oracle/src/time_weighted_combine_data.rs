@@ -0,0 +1,77 @@
+use crate::{CombinedHistory, Config, MomentOf, TimestampedValue, TimestampedValueOf};
+use frame_support::traits::{Get, Time};
+use sp_runtime::traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating, Zero};
+use sp_std::{cmp::max, marker, prelude::*};
+use tangle_primitives::traits::CombineData;
+
+/// Combines the values with `Inner` and returns their time-weighted average
+/// over the last `Window`.
+///
+/// Every value produced by `Inner` is recorded in [`CombinedHistory`] and
+/// holds until the next one replaces it. Returns the value produced by
+/// `Inner` while the history does not span any time yet.
+pub struct TimeWeightedCombineData<T, Inner, Window, I = ()>(
+	marker::PhantomData<(T, I, Inner, Window)>,
+);
+
+impl<T, I, Inner, Window> CombineData<<T as Config<I>>::OracleKey, TimestampedValueOf<T, I>>
+	for TimeWeightedCombineData<T, Inner, Window, I>
+where
+	T: Config<I>,
+	I: 'static,
+	T::OracleValue: AtLeast32BitUnsigned,
+	Inner: CombineData<<T as Config<I>>::OracleKey, TimestampedValueOf<T, I>>,
+	Window: Get<MomentOf<T, I>>,
+{
+	fn combine_data(
+		key: &<T as Config<I>>::OracleKey,
+		values: Vec<TimestampedValueOf<T, I>>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<TimestampedValueOf<T, I>> {
+		let now = T::Time::now();
+		let window_start = now.saturating_sub(Window::get());
+
+		let spot = Inner::combine_data(key, values, prev_value)?;
+		let history = CombinedHistory::<T, I>::mutate(key, |history| {
+			match history.last_mut() {
+				Some(last) if last.timestamp == now => last.value = spot.value.clone(),
+				_ => {
+					while history.len() > 1 &&
+						history.get(1).is_some_and(|next| next.timestamp <= window_start)
+					{
+						history.remove(0);
+					}
+					if history.is_full() {
+						history.remove(0);
+					}
+					let _ = history
+						.try_push(TimestampedValue { value: spot.value.clone(), timestamp: now });
+				},
+			}
+			history.clone()
+		});
+
+		let mut weighted_sum: u128 = 0;
+		let mut total_time: u128 = 0;
+		for (index, entry) in history.iter().enumerate() {
+			let from = max(entry.timestamp, window_start);
+			let to = history.get(index + 1).map_or(now, |next| next.timestamp);
+			if to <= from {
+				continue;
+			}
+			let elapsed: u128 = to.saturating_sub(from).saturated_into();
+			let value: u128 = entry.value.clone().saturated_into();
+			weighted_sum = weighted_sum.saturating_add(value.saturating_mul(elapsed));
+			total_time = total_time.saturating_add(elapsed);
+		}
+
+		if total_time.is_zero() {
+			return Some(spot);
+		}
+
+		Some(TimestampedValue {
+			value: (weighted_sum / total_time).saturated_into(),
+			timestamp: spot.timestamp,
+		})
+	}
+}
@@ -32,20 +32,26 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn feed_values(c: u32, ) -> Weight;
 	fn on_finalize() -> Weight;
+	fn set_feeder_service() -> Weight;
 }
 
 /// Default weights.
 impl WeightInfo for () {
 	fn feed_values(c: u32, ) -> Weight {
-		Weight::from_parts(16_800_000, 0)
-			// Standard Error: 84_000
-			.saturating_add(Weight::from_parts(3_600_000, 0).saturating_mul(c as u64))
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
+		Weight::from_parts(24_300_000, 0)
+			// Standard Error: 91_000
+			.saturating_add(Weight::from_parts(4_100_000, 0).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(c as u64)))
 	}
 	fn on_finalize() -> Weight {
 		Weight::from_parts(3_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn set_feeder_service() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
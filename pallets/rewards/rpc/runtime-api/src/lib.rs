@@ -20,6 +20,11 @@
 #![allow(clippy::type_complexity)]
 use parity_scale_codec::Codec;
 use sp_runtime::{Serialize, traits::MaybeDisplay};
+use sp_std::vec::Vec;
+use tangle_primitives::{
+	services::Asset,
+	types::rewards::{LockMultiplier, RewardProjection, VaultApy, VaultRewardBreakdown},
+};
 
 pub type BlockNumberOf<Block> =
 	<<Block as sp_runtime::traits::HeaderProvider>::HeaderT as sp_runtime::traits::Header>::Number;
@@ -39,10 +44,68 @@ sp_api::decl_runtime_apis! {
 		/// - [`RpcRewardsWithBlueprint`]: A list of rewards with their blueprints.
 		fn query_user_rewards(
 			account_id: AccountId,
-			asset_id: tangle_primitives::services::Asset<AssetId>
+			asset_id: Asset<AssetId>
 		) -> Result<
 			Balance,
 			sp_runtime::DispatchError,
 		>;
+
+		/// Project the rewards of a new deposit over a number of blocks.
+		///
+		/// ## Arguments
+		/// - `asset_id`: The asset to deposit.
+		/// - `amount`: The amount to deposit.
+		/// - `lock_multiplier`: The lock tier of the deposit, if it is locked.
+		/// - `blocks`: The number of blocks to project the rewards over.
+		/// ## Return
+		/// - [`RewardProjection`]: The projected rewards and APY of the deposit.
+		fn query_reward_projection(
+			asset_id: Asset<AssetId>,
+			amount: Balance,
+			lock_multiplier: Option<LockMultiplier>,
+			blocks: BlockNumberOf<Block>,
+		) -> Result<
+			RewardProjection<Balance>,
+			sp_runtime::DispatchError,
+		>;
+
+		/// Query the APY of the reward vault of an asset after its deposit cap and decay.
+		///
+		/// ## Arguments
+		/// - `asset_id`: The asset in the reward vault.
+		/// ## Return
+		/// - [`VaultApy`]: The configured and effective APYs of the vault.
+		fn query_vault_apy(
+			asset_id: Asset<AssetId>,
+		) -> Result<
+			VaultApy<Balance>,
+			sp_runtime::DispatchError,
+		>;
+
+		/// Query the pending rewards of an account, broken down by vault and lock.
+		///
+		/// ## Arguments
+		/// - `account_id`: The account id.
+		/// ## Return
+		/// - [`VaultRewardBreakdown`]: The pending rewards of each deposit of the account.
+		fn query_user_reward_breakdown(
+			account_id: AccountId,
+		) -> Vec<VaultRewardBreakdown<AssetId, Balance, BlockNumberOf<Block>>>;
+
+		/// Query the number of blocks until the pending rewards of an account start to decay.
+		///
+		/// ## Arguments
+		/// - `account_id`: The account id.
+		/// - `asset_id`: The asset in the reward vault.
+		/// ## Return
+		/// - `Option<BlockNumber>`: The blocks until decay, or `None` if there is no further
+		///   decay step.
+		fn query_blocks_until_decay(
+			account_id: AccountId,
+			asset_id: Asset<AssetId>,
+		) -> Result<
+			Option<BlockNumberOf<Block>>,
+			sp_runtime::DispatchError,
+		>;
 	}
 }
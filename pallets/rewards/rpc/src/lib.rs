@@ -30,13 +30,20 @@ use sp_runtime::{
 	traits::{Block as BlockT, MaybeDisplay},
 };
 use std::sync::Arc;
-use tangle_primitives::Balance;
+use tangle_primitives::{
+	Balance,
+	services::Asset,
+	types::rewards::{LockMultiplier, RewardProjection, VaultApy, VaultRewardBreakdown},
+};
 
 pub use pallet_rewards_rpc_runtime_api::RewardsApi as RewardsRuntimeApi;
 
+type BlockNumberOf<Block> =
+	<<Block as sp_runtime::traits::HeaderProvider>::HeaderT as sp_runtime::traits::Header>::Number;
+
 /// RewardsClient RPC methods.
 #[rpc(client, server)]
-pub trait RewardsApi<BlockHash, AccountId, AssetId>
+pub trait RewardsApi<BlockHash, BlockNumber, AccountId, AssetId>
 where
 	AccountId: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
 	AssetId: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
@@ -45,9 +52,41 @@ where
 	fn query_user_rewards(
 		&self,
 		account_id: AccountId,
-		asset_id: tangle_primitives::services::Asset<AssetId>,
+		asset_id: Asset<AssetId>,
 		at: Option<BlockHash>,
 	) -> RpcResult<Balance>;
+
+	#[method(name = "rewards_queryRewardProjection")]
+	fn query_reward_projection(
+		&self,
+		asset_id: Asset<AssetId>,
+		amount: Balance,
+		lock_multiplier: Option<LockMultiplier>,
+		blocks: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<RewardProjection<Balance>>;
+
+	#[method(name = "rewards_queryVaultApy")]
+	fn query_vault_apy(
+		&self,
+		asset_id: Asset<AssetId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<VaultApy<Balance>>;
+
+	#[method(name = "rewards_queryUserRewardBreakdown")]
+	fn query_user_reward_breakdown(
+		&self,
+		account_id: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<VaultRewardBreakdown<AssetId, Balance, BlockNumber>>>;
+
+	#[method(name = "rewards_queryBlocksUntilDecay")]
+	fn query_blocks_until_decay(
+		&self,
+		account_id: AccountId,
+		asset_id: Asset<AssetId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BlockNumber>>;
 }
 
 /// Provides RPC methods to query a dispatchable's class, weight and fee.
@@ -64,7 +103,8 @@ impl<C, P> RewardsClient<C, P> {
 	}
 }
 
-impl<C, Block, AccountId, AssetId> RewardsApiServer<<Block as BlockT>::Hash, AccountId, AssetId>
+impl<C, Block, AccountId, AssetId>
+	RewardsApiServer<<Block as BlockT>::Hash, BlockNumberOf<Block>, AccountId, AssetId>
 	for RewardsClient<C, Block>
 where
 	Block: BlockT,
@@ -76,7 +116,7 @@ where
 	fn query_user_rewards(
 		&self,
 		account_id: AccountId,
-		asset_id: tangle_primitives::services::Asset<AssetId>,
+		asset_id: Asset<AssetId>,
 		at: Option<<Block as BlockT>::Hash>,
 	) -> RpcResult<Balance> {
 		let api = self.client.runtime_api();
@@ -88,6 +128,67 @@ where
 			Err(e) => Err(map_err(format!("{:?}", e), "Unable to query user rewards")),
 		}
 	}
+
+	fn query_reward_projection(
+		&self,
+		asset_id: Asset<AssetId>,
+		amount: Balance,
+		lock_multiplier: Option<LockMultiplier>,
+		blocks: BlockNumberOf<Block>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RewardProjection<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_reward_projection(at, asset_id, amount, lock_multiplier, blocks) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(map_err(format!("{:?}", e), "Unable to query reward projection")),
+			Err(e) => Err(map_err(format!("{:?}", e), "Unable to query reward projection")),
+		}
+	}
+
+	fn query_vault_apy(
+		&self,
+		asset_id: Asset<AssetId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<VaultApy<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_vault_apy(at, asset_id) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(map_err(format!("{:?}", e), "Unable to query vault APY")),
+			Err(e) => Err(map_err(format!("{:?}", e), "Unable to query vault APY")),
+		}
+	}
+
+	fn query_user_reward_breakdown(
+		&self,
+		account_id: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<VaultRewardBreakdown<AssetId, Balance, BlockNumberOf<Block>>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.query_user_reward_breakdown(at, account_id)
+			.map_err(|e| map_err(format!("{:?}", e), "Unable to query user reward breakdown"))
+	}
+
+	fn query_blocks_until_decay(
+		&self,
+		account_id: AccountId,
+		asset_id: Asset<AssetId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<BlockNumberOf<Block>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_blocks_until_decay(at, account_id, asset_id) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(map_err(format!("{:?}", e), "Unable to query blocks until decay")),
+			Err(e) => Err(map_err(format!("{:?}", e), "Unable to query blocks until decay")),
+		}
+	}
 }
 
 fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
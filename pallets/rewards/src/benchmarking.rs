@@ -22,9 +22,15 @@ use crate::{
 use frame_benchmarking::{
 	BenchmarkError, account, benchmarks, impl_benchmark_test_suite, whitelisted_caller,
 };
-use frame_support::traits::{Currency, EnsureOrigin};
+use frame_support::{
+	BoundedVec,
+	traits::{Currency, EnsureOrigin, Get},
+};
 use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
-use sp_runtime::Perbill;
+use sp_runtime::{
+	Perbill,
+	traits::{Bounded, Zero},
+};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use tangle_primitives::{rewards::UserDepositWithLocks, services::Asset};
 
@@ -59,6 +65,87 @@ where
 	(vault_id, caller)
 }
 
+/// Sets up a vault for the native asset with a funded pot, so the claimed rewards are restaked
+/// into the same asset.
+fn setup_native_vault<T: Config>() -> (T::VaultId, T::AccountId, Asset<T::AssetId>)
+where
+	<T as pallet::Config>::AssetId: From<u32>,
+{
+	let (vault_id, funder) = setup_vault::<T>();
+	let asset = Asset::Custom(T::AssetId::default());
+	RewardVaults::<T>::insert(vault_id, vec![asset]);
+	AssetLookupRewardVaults::<T>::insert(asset, vault_id);
+	RewardConfigStorage::<T>::insert(
+		vault_id,
+		RewardConfigForAssetVault {
+			apy: Perbill::from_percent(10),
+			deposit_cap: BalanceOf::<T>::max_value(),
+			incentive_cap: BalanceOf::<T>::zero(),
+			boost_multiplier: Some(1),
+		},
+	);
+	// Rewards are a share of the total issuance, which the pot can always cover.
+	let pot_account: T::AccountId = account("pot", 0, SEED);
+	let pot_balance = T::Currency::total_issuance().max(BalanceOf::<T>::from(1_000_000u32));
+	T::Currency::make_free_balance_be(&pot_account, pot_balance);
+	RewardVaultsPotAccount::<T>::insert(vault_id, pot_account);
+
+	(vault_id, funder, asset)
+}
+
+/// Fills `vault_id` with the maximum number of funded reward streams, which are all paid out on
+/// every claim.
+fn fill_reward_streams<T: Config>(
+	vault_id: T::VaultId,
+	asset: Asset<T::AssetId>,
+	funder: &T::AccountId,
+) -> Result<(), BenchmarkError> {
+	let stream_funds = BalanceOf::<T>::from(1_000u32);
+	T::Currency::make_free_balance_be(
+		funder,
+		stream_funds * T::MaxRewardStreamsPerVault::get().into() * 2u32.into(),
+	);
+	for stream_id in 0..T::MaxRewardStreamsPerVault::get() {
+		Pallet::<T>::do_create_reward_stream(
+			vault_id,
+			asset,
+			BalanceOf::<T>::from(10u32),
+			BlockNumberFor::<T>::from(1u32),
+			BlockNumberFor::<T>::from(100u32),
+			funder.clone(),
+		)?;
+		Pallet::<T>::do_fund_reward_stream(funder, vault_id, stream_id, stream_funds)?;
+	}
+	Ok(())
+}
+
+/// Sets up a delegator with pending rewards in `vault_id` that auto-compounds them with
+/// `operator`, returning its deposit.
+fn setup_compounding_delegator<T: Config>(
+	delegator: &T::AccountId,
+	operator: &T::AccountId,
+	vault_id: T::VaultId,
+	asset: Asset<T::AssetId>,
+) -> BalanceOf<T> {
+	let deposit = T::BenchmarkHelper::setup_delegation(delegator, operator, asset);
+	UserClaimedReward::<T>::insert(
+		delegator,
+		vault_id,
+		(BlockNumberFor::<T>::from(1u32), BalanceOf::<T>::zero()),
+	);
+	AutoCompoundPreferences::<T>::insert(delegator, asset, operator.clone());
+	deposit
+}
+
+/// Caps `vault_id` at twice its deposits, so they earn rewards and leave room for compounding.
+fn cap_vault_deposits<T: Config>(vault_id: T::VaultId, total_deposit: BalanceOf<T>) {
+	RewardConfigStorage::<T>::mutate(vault_id, |config| {
+		if let Some(config) = config {
+			config.deposit_cap = total_deposit * 2u32.into();
+		}
+	});
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -95,14 +182,23 @@ benchmarks! {
 	}
 
 	claim_rewards_other {
-		let (vault_id, who) = setup_vault::<T>();
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(1u32));
+		let (vault_id, funder, asset) = setup_native_vault::<T>();
 		let caller: T::AccountId = whitelisted_caller();
-		let deposit = BalanceOf::<T>::from(100u32);
-		let asset = Asset::Custom(1_u32.into());
-	}: _(RawOrigin::Signed(caller.clone()), who.clone(), asset)
+		let operator: T::AccountId = account("operator", 0, SEED);
+		fill_reward_streams::<T>(vault_id, asset, &funder)?;
+
+		// The account has pending rewards and auto-compounds them with the operator.
+		let who: T::AccountId = account("delegator", 0, SEED);
+		let deposit = setup_compounding_delegator::<T>(&who, &operator, vault_id, asset);
+		cap_vault_deposits::<T>(vault_id, deposit);
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(50u32));
+	}: _(RawOrigin::Signed(caller), who.clone(), asset)
 	verify {
-		// Verify that rewards were claimed for the target account
-		assert!(UserClaimedReward::<T>::contains_key(&who, vault_id));
+		assert_eq!(
+			UserClaimedReward::<T>::get(&who, vault_id).map(|(block, _)| block),
+			Some(BlockNumberFor::<T>::from(50u32)),
+		);
 	}
 
 	manage_asset_reward_vault {
@@ -172,6 +268,152 @@ let decay_config = RewardConfig {
 		// Verify that the APY blocks were updated
 		assert_eq!(ApyBlocks::<T>::get(), blocks);
 	}
+
+	schedule_vault_sunset {
+		let (vault_id, _) = setup_vault::<T>();
+		let sunset_block = BlockNumberFor::<T>::from(100u32);
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, vault_id, sunset_block, None)
+	verify {
+		assert!(VaultSunsets::<T>::contains_key(vault_id));
+	}
+
+	cancel_vault_sunset {
+		let (vault_id, _) = setup_vault::<T>();
+		VaultSunsets::<T>::insert(vault_id, VaultSunset {
+			sunset_block: BlockNumberFor::<T>::from(100u32),
+			successor: None,
+			status: VaultSunsetStatus::Scheduled,
+		});
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, vault_id)
+	verify {
+		assert!(!VaultSunsets::<T>::contains_key(vault_id));
+	}
+
+	close_reward_vault {
+		let n in 1 .. 100;
+		let (vault_id, caller) = setup_vault::<T>();
+		let pot_account: T::AccountId = account("pot", 0, SEED);
+		T::Currency::make_free_balance_be(&pot_account, BalanceOf::<T>::from(1000u32));
+		RewardVaultsPotAccount::<T>::insert(vault_id, pot_account);
+		for i in 0..n {
+			let depositor: T::AccountId = account("depositor", i, SEED);
+			UserClaimedReward::<T>::insert(depositor, vault_id, (BlockNumberFor::<T>::from(1u32), BalanceOf::<T>::from(0u32)));
+		}
+		VaultSunsets::<T>::insert(vault_id, VaultSunset {
+			sunset_block: BlockNumberFor::<T>::from(10u32),
+			successor: None,
+			status: VaultSunsetStatus::Scheduled,
+		});
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(11u32));
+	}: _(RawOrigin::Signed(caller), vault_id, n + 1)
+	verify {
+		assert!(!RewardConfigStorage::<T>::contains_key(vault_id));
+	}
+
+	create_reward_stream {
+		let (vault_id, caller) = setup_vault::<T>();
+		let native_asset = Asset::Custom(T::AssetId::default());
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, vault_id, native_asset, BalanceOf::<T>::from(10u32), BlockNumberFor::<T>::from(1u32), BlockNumberFor::<T>::from(100u32), caller)
+	verify {
+		assert_eq!(RewardStreams::<T>::get(vault_id).len(), 1);
+	}
+
+	fund_reward_stream {
+		let (vault_id, caller) = setup_vault::<T>();
+		Pallet::<T>::do_create_reward_stream(
+			vault_id,
+			Asset::Custom(T::AssetId::default()),
+			BalanceOf::<T>::from(10u32),
+			BlockNumberFor::<T>::from(1u32),
+			BlockNumberFor::<T>::from(100u32),
+			caller.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller), vault_id, 0, BalanceOf::<T>::from(100u32))
+	verify {
+		assert_eq!(RewardStreams::<T>::get(vault_id)[0].funded, BalanceOf::<T>::from(100u32));
+	}
+
+	cancel_reward_stream {
+		let (vault_id, caller) = setup_vault::<T>();
+		Pallet::<T>::do_create_reward_stream(
+			vault_id,
+			Asset::Custom(T::AssetId::default()),
+			BalanceOf::<T>::from(10u32),
+			BlockNumberFor::<T>::from(1u32),
+			BlockNumberFor::<T>::from(100u32),
+			caller.clone(),
+		)?;
+		Pallet::<T>::do_fund_reward_stream(&caller, vault_id, 0, BalanceOf::<T>::from(100u32))?;
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, vault_id, 0)
+	verify {
+		assert!(RewardStreams::<T>::get(vault_id).is_empty());
+	}
+
+	set_reward_payee {
+		let caller: T::AccountId = whitelisted_caller();
+		let payee: T::AccountId = account("payee", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), Some(payee.clone()))
+	verify {
+		assert_eq!(RewardPayees::<T>::get(&caller), Some(payee));
+	}
+
+	set_claim_proxy {
+		let caller: T::AccountId = whitelisted_caller();
+		let proxy: T::AccountId = account("proxy", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), Some(proxy.clone()))
+	verify {
+		assert_eq!(ClaimProxies::<T>::get(&caller), Some(proxy));
+	}
+
+	claim_rewards_for {
+		let operator: T::AccountId = account("operator", 0, SEED);
+		let proxy: T::AccountId = whitelisted_caller();
+		let payee: T::AccountId = account("payee", 0, SEED);
+		let amount = BalanceOf::<T>::from(100u32);
+		T::Currency::make_free_balance_be(&Pallet::<T>::account_id(), amount * 10u32.into());
+		T::Currency::make_free_balance_be(&payee, amount);
+		Pallet::<T>::do_set_claim_proxy(&operator, Some(proxy.clone()));
+		Pallet::<T>::do_set_reward_payee(&operator, Some(payee));
+		PendingOperatorRewards::<T>::mutate(&operator, |rewards| rewards.try_push((0, amount)))
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _(RawOrigin::Signed(proxy), operator.clone())
+	verify {
+		assert!(PendingOperatorRewards::<T>::get(&operator).is_empty());
+	}
+
+	claim_for_many {
+		let n in 1 .. T::MaxClaimsPerBatch::get();
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(1u32));
+		let (vault_id, funder, asset) = setup_native_vault::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+
+		fill_reward_streams::<T>(vault_id, asset, &funder)?;
+
+		// Every delegator has pending rewards and auto-compounds them with the operator.
+		let mut total_deposit = BalanceOf::<T>::zero();
+		let mut claims = Vec::new();
+		for i in 0..n {
+			let delegator: T::AccountId = account("delegator", i, SEED);
+			total_deposit +=
+				setup_compounding_delegator::<T>(&delegator, &operator, vault_id, asset);
+			claims.push((delegator, asset));
+		}
+		let claims = BoundedVec::try_from(claims).map_err(|_| BenchmarkError::Weightless)?;
+		cap_vault_deposits::<T>(vault_id, total_deposit);
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(50u32));
+	}: _(RawOrigin::Signed(caller), claims)
+	verify {
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+		assert_eq!(
+			UserClaimedReward::<T>::get(&delegator, vault_id).map(|(block, _)| block),
+			Some(BlockNumberFor::<T>::from(50u32)),
+		);
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
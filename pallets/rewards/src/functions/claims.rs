@@ -0,0 +1,114 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	BalanceOf, ClaimProxies, Config, Error, Event, Pallet, PendingOperatorRewards, RewardPayees,
+	functions::rewards::LOG_TARGET,
+};
+use frame_support::{
+	ensure,
+	storage::with_storage_layer,
+	traits::{Currency, ExistenceRequirement},
+};
+use sp_runtime::{
+	DispatchResult,
+	traits::{Saturating, Zero},
+};
+use sp_std::vec::Vec;
+use tangle_primitives::services::Asset;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the account that receives the rewards claimed for `account`.
+	pub fn payee_of(account: &T::AccountId) -> T::AccountId {
+		RewardPayees::<T>::get(account).unwrap_or_else(|| account.clone())
+	}
+
+	/// Sets the payee of `who`, or removes it if `payee` is `None` or `who` itself.
+	pub fn do_set_reward_payee(who: &T::AccountId, payee: Option<T::AccountId>) {
+		let payee = payee.filter(|payee| payee != who);
+		match &payee {
+			Some(payee) => RewardPayees::<T>::insert(who, payee),
+			None => RewardPayees::<T>::remove(who),
+		}
+		Self::deposit_event(Event::RewardPayeeSet { account: who.clone(), payee });
+	}
+
+	/// Sets the claim proxy of `who`, or removes it if `proxy` is `None`.
+	pub fn do_set_claim_proxy(who: &T::AccountId, proxy: Option<T::AccountId>) {
+		match &proxy {
+			Some(proxy) => ClaimProxies::<T>::insert(who, proxy),
+			None => ClaimProxies::<T>::remove(who),
+		}
+		Self::deposit_event(Event::ClaimProxySet { account: who.clone(), proxy });
+	}
+
+	/// Ensures that `who` may claim the operator rewards of `account`, which requires it to be
+	/// the account itself or its claim proxy.
+	pub fn ensure_can_claim_for(who: &T::AccountId, account: &T::AccountId) -> DispatchResult {
+		ensure!(
+			who == account || ClaimProxies::<T>::get(account).as_ref() == Some(who),
+			Error::<T>::NotClaimProxy
+		);
+		Ok(())
+	}
+
+	/// Pays all pending service rewards of `operator` to its payee.
+	///
+	/// # Errors
+	/// * `NoRewardsToClaim` - The operator has no pending rewards
+	/// * `TransferFailed` - The pallet account cannot pay the rewards
+	pub fn do_claim_operator_rewards(operator: &T::AccountId) -> DispatchResult {
+		// Retrieve and clear pending rewards for the operator.
+		let pending_rewards = PendingOperatorRewards::<T>::take(operator);
+		ensure!(!pending_rewards.is_empty(), Error::<T>::NoRewardsToClaim);
+
+		// Calculate the total amount to be claimed.
+		let total_reward = pending_rewards
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |total, (_, amount)| total.saturating_add(*amount));
+
+		// Transfer the total reward from the pallet account to the payee of the operator.
+		T::Currency::transfer(
+			&Self::account_id(),
+			&Self::payee_of(operator),
+			total_reward,
+			ExistenceRequirement::KeepAlive,
+		)
+		.map_err(|_| Error::<T>::TransferFailed)?;
+
+		Self::deposit_event(Event::OperatorRewardsClaimed {
+			operator: operator.clone(),
+			amount: total_reward,
+		});
+
+		Ok(())
+	}
+
+	/// Claims the rewards of every account and asset in `claims`.
+	///
+	/// Each claim runs in its own storage layer, so a failing claim is rolled back, reported
+	/// with `BatchClaimFailed` and skipped.
+	pub fn do_claim_for_many(claims: Vec<(T::AccountId, Asset<T::AssetId>)>) {
+		for (account, asset) in claims {
+			if let Err(error) =
+				with_storage_layer(|| Self::calculate_and_payout_rewards(&account, asset))
+			{
+				log::debug!(target: LOG_TARGET, "batch claim for {:?} failed: {:?}", account, error);
+				Self::deposit_event(Event::BatchClaimFailed { account, asset, error });
+			}
+		}
+	}
+}
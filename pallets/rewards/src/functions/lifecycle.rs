@@ -0,0 +1,287 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	AssetLookupRewardVaults, BalanceOf, Config, Error, Event, Pallet, RewardConfigStorage,
+	RewardStreamStake, RewardVaults, RewardVaultsPotAccount, TotalRewardVaultDeposit,
+	TotalRewardVaultScore, UserClaimedReward, UserRewardStreams, VaultMetadataStore,
+	VaultSettlementCursor, VaultSunset, VaultSunsetStatus, VaultSunsets,
+	functions::rewards::LOG_TARGET,
+};
+use frame_support::{
+	ensure,
+	traits::{Currency, ExistenceRequirement, Get},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	DispatchResult,
+	traits::{Saturating, Zero},
+};
+use sp_std::vec::Vec;
+use tangle_primitives::traits::MultiAssetDelegationInfo;
+
+impl<T: Config> Pallet<T> {
+	/// Schedules the sunset of `vault_id` at `sunset_block`, optionally migrating its assets and
+	/// scores into `successor` once the vault is settled.
+	pub fn do_schedule_vault_sunset(
+		vault_id: T::VaultId,
+		sunset_block: BlockNumberFor<T>,
+		successor: Option<T::VaultId>,
+	) -> DispatchResult {
+		ensure!(RewardConfigStorage::<T>::contains_key(vault_id), Error::<T>::VaultNotFound);
+		ensure!(
+			!VaultSunsets::<T>::contains_key(vault_id),
+			Error::<T>::VaultSunsetAlreadyScheduled
+		);
+		ensure!(
+			sunset_block > frame_system::Pallet::<T>::block_number(),
+			Error::<T>::InvalidSunsetBlock
+		);
+		if let Some(successor) = successor {
+			ensure!(
+				successor != vault_id &&
+					RewardConfigStorage::<T>::contains_key(successor) &&
+					!VaultSunsets::<T>::contains_key(successor),
+				Error::<T>::InvalidSuccessorVault
+			);
+		}
+
+		VaultSunsets::<T>::insert(
+			vault_id,
+			VaultSunset { sunset_block, successor, status: VaultSunsetStatus::Scheduled },
+		);
+
+		Self::deposit_event(Event::VaultSunsetScheduled { vault_id, sunset_block, successor });
+		Ok(())
+	}
+
+	/// Settles up to `max_entries` reward claim records of a vault whose sunset block has passed,
+	/// and closes the vault once every record has been scanned.
+	///
+	/// The first call detaches the vault assets, moving them and the vault scores into the
+	/// successor if it still accepts them.
+	pub fn do_close_reward_vault(vault_id: T::VaultId, max_entries: u32) -> DispatchResult {
+		ensure!(max_entries > 0, Error::<T>::InvalidSettlementLimit);
+		let mut sunset =
+			VaultSunsets::<T>::get(vault_id).ok_or(Error::<T>::VaultSunsetNotScheduled)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() > sunset.sunset_block,
+			Error::<T>::VaultSunsetNotReached
+		);
+
+		if sunset.status == VaultSunsetStatus::Scheduled {
+			// drop a successor that was closed or started settling in the meantime
+			sunset.successor = sunset.successor.filter(|successor| {
+				RewardConfigStorage::<T>::contains_key(successor) &&
+					VaultSunsets::<T>::get(successor)
+						.is_none_or(|s| s.status == VaultSunsetStatus::Scheduled)
+			});
+			Self::detach_vault_assets(vault_id, sunset.successor);
+			sunset.status = VaultSunsetStatus::Settling;
+			VaultSunsets::<T>::insert(vault_id, &sunset);
+		}
+
+		// collect the records of the vault first, the settlement mutates `UserClaimedReward`
+		let mut records = match VaultSettlementCursor::<T>::get(vault_id) {
+			Some(cursor) => UserClaimedReward::<T>::iter_from(cursor),
+			None => UserClaimedReward::<T>::iter(),
+		};
+		let mut scanned = 0_u32;
+		let mut exhausted = false;
+		let mut to_settle = Vec::new();
+		while scanned < max_entries {
+			match records.next() {
+				Some((account, record_vault, last_claim)) => {
+					scanned = scanned.saturating_add(1);
+					if record_vault == vault_id {
+						to_settle.push((account, last_claim));
+					}
+				},
+				None => {
+					exhausted = true;
+					break;
+				},
+			}
+		}
+		let cursor = records.last_raw_key().to_vec();
+
+		for (account, last_claim) in to_settle {
+			Self::settle_vault_account(vault_id, &sunset, &account, last_claim);
+		}
+
+		if exhausted {
+			Self::finalize_vault_closure(vault_id);
+		} else {
+			VaultSettlementCursor::<T>::insert(vault_id, cursor);
+		}
+
+		Ok(())
+	}
+
+	/// Removes the assets of a closing vault from the asset lookup, moving them and the vault
+	/// scores into `successor` if there is one.
+	fn detach_vault_assets(vault_id: T::VaultId, successor: Option<T::VaultId>) {
+		let assets = RewardVaults::<T>::get(vault_id).unwrap_or_default();
+		let Some(successor) = successor else {
+			for asset in assets {
+				AssetLookupRewardVaults::<T>::remove(asset);
+			}
+			return;
+		};
+
+		for asset in &assets {
+			AssetLookupRewardVaults::<T>::insert(asset, successor);
+		}
+		RewardVaults::<T>::mutate(successor, |maybe_assets| {
+			maybe_assets.get_or_insert_with(Vec::new).extend(assets)
+		});
+
+		let total_score = TotalRewardVaultScore::<T>::get(vault_id);
+		let total_deposit = TotalRewardVaultDeposit::<T>::get(vault_id);
+		TotalRewardVaultScore::<T>::mutate(successor, |score| {
+			*score = score.saturating_add(total_score)
+		});
+		TotalRewardVaultDeposit::<T>::mutate(successor, |deposit| {
+			*deposit = deposit.saturating_add(total_deposit)
+		});
+
+		Self::deposit_event(Event::VaultScoresMigrated {
+			vault_id,
+			successor,
+			total_score,
+			total_deposit,
+		});
+	}
+
+	/// Pays out the rewards and reward streams `account` accrued in a closing vault up to its
+	/// sunset block and, if the vault has a successor, starts the account's accrual there from the
+	/// sunset block and moves its reward stream stake there.
+	///
+	/// Rewards that cannot be calculated are settled as zero, and the payout is capped at the
+	/// funds left in the vault pot.
+	fn settle_vault_account(
+		vault_id: T::VaultId,
+		sunset: &VaultSunset<T::VaultId, BlockNumberFor<T>>,
+		account: &T::AccountId,
+		last_claim: (BlockNumberFor<T>, BalanceOf<T>),
+	) {
+		UserClaimedReward::<T>::remove(account, vault_id);
+
+		let assets = RewardVaults::<T>::get(vault_id).unwrap_or_default();
+		let total_score = TotalRewardVaultScore::<T>::get(vault_id);
+		let total_deposit = TotalRewardVaultDeposit::<T>::get(vault_id);
+		let mut has_deposit = false;
+		let mut rewards = BalanceOf::<T>::zero();
+		for asset in assets {
+			let Some(deposit) = T::DelegationManager::get_user_deposit_with_locks(account, asset)
+			else {
+				continue;
+			};
+			has_deposit = true;
+			let Some(config) = RewardConfigStorage::<T>::get(vault_id) else {
+				continue;
+			};
+			let asset_rewards = Self::calculate_deposit_rewards_until(
+				total_deposit,
+				total_score,
+				deposit,
+				config,
+				Some(last_claim),
+				sunset.sunset_block,
+			)
+			.unwrap_or_else(|error| {
+				log::debug!(target: LOG_TARGET, "no rewards settled for {:?}: {:?}", asset, error);
+				Zero::zero()
+			});
+			rewards = rewards.saturating_add(asset_rewards);
+		}
+
+		if let Some(pot_account) = RewardVaultsPotAccount::<T>::get(vault_id) {
+			rewards = rewards.min(T::Currency::free_balance(&pot_account));
+			if !rewards.is_zero() &&
+				T::Currency::transfer(
+					&pot_account,
+					&Self::payee_of(account),
+					rewards,
+					ExistenceRequirement::AllowDeath,
+				)
+				.is_err()
+			{
+				rewards = Zero::zero();
+			}
+		} else {
+			rewards = Zero::zero();
+		}
+
+		Self::payout_reward_streams(account, vault_id);
+		let stream_stake = RewardStreamStake::<T>::take(vault_id, account);
+		UserRewardStreams::<T>::remove(account, vault_id);
+
+		if let Some(successor) = sunset.successor {
+			if has_deposit && !UserClaimedReward::<T>::contains_key(account, successor) {
+				UserClaimedReward::<T>::insert(
+					account,
+					successor,
+					(sunset.sunset_block, BalanceOf::<T>::zero()),
+				);
+			}
+			if !stream_stake.is_zero() {
+				Self::update_reward_stream_stake(account, successor, |stake| {
+					stake.saturating_add(stream_stake)
+				});
+			}
+		}
+
+		Self::deposit_event(Event::VaultRewardsSettled {
+			vault_id,
+			account: account.clone(),
+			amount: rewards,
+		});
+	}
+
+	/// Returns the funds left in the pot of a settled vault to the `VaultFundsRecipient` and
+	/// removes the vault.
+	fn finalize_vault_closure(vault_id: T::VaultId) {
+		Self::refund_reward_streams(vault_id);
+
+		let recipient = T::VaultFundsRecipient::get();
+		let mut amount = BalanceOf::<T>::zero();
+		if let Some(pot_account) = RewardVaultsPotAccount::<T>::take(vault_id) {
+			let remaining = T::Currency::free_balance(&pot_account);
+			if !remaining.is_zero() &&
+				T::Currency::transfer(
+					&pot_account,
+					&recipient,
+					remaining,
+					ExistenceRequirement::AllowDeath,
+				)
+				.is_ok()
+			{
+				amount = remaining;
+			}
+		}
+
+		RewardConfigStorage::<T>::remove(vault_id);
+		RewardVaults::<T>::remove(vault_id);
+		TotalRewardVaultScore::<T>::remove(vault_id);
+		TotalRewardVaultDeposit::<T>::remove(vault_id);
+		VaultMetadataStore::<T>::remove(vault_id);
+		VaultSunsets::<T>::remove(vault_id);
+		VaultSettlementCursor::<T>::remove(vault_id);
+
+		Self::deposit_event(Event::RewardVaultClosed { vault_id, recipient, amount });
+	}
+}
@@ -22,8 +22,12 @@ use sp_runtime::{DispatchError, DispatchResult, traits::AccountIdConversion};
 use sp_std::vec::Vec;
 use tangle_primitives::services::Asset;
 
+pub mod claims;
+pub mod lifecycle;
+pub mod projection;
 pub mod rewards;
 pub mod services;
+pub mod streams;
 
 impl<T: Config> Pallet<T> {
 	pub fn remove_asset_from_vault(
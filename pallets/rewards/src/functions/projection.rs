@@ -0,0 +1,264 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	ApyBlocks, AssetLookupRewardVaults, BalanceOf, Config, DecayRate, DecayStartPeriod, Error,
+	Pallet, RewardConfigStorage, TotalRewardVaultDeposit, TotalRewardVaultScore, UserClaimedReward,
+	VaultSunsets,
+};
+use frame_support::traits::Currency;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	DispatchError, Perbill, SaturatedConversion,
+	traits::{One, Saturating, Zero},
+};
+use sp_std::{vec, vec::Vec};
+use tangle_primitives::{
+	services::Asset,
+	traits::MultiAssetDelegationInfo,
+	types::rewards::{
+		LockInfo, LockMultiplier, LockRewardBreakdown, RewardProjection, UserDepositWithLocks,
+		VaultApy, VaultRewardBreakdown,
+	},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Projects the rewards a new deposit into the vault of `asset` earns over the next `blocks`
+	/// blocks, when claimed once at the end of the period.
+	///
+	/// The deposit is added to the current totals of the vault and rewarded with the same math
+	/// as claims, including the deposit cap, decay, lock multipliers and the sunset of the
+	/// vault.
+	///
+	/// # Arguments
+	/// * `asset` - The asset to deposit
+	/// * `amount` - The amount to deposit
+	/// * `lock_multiplier` - The lock tier of the deposit, if it is locked
+	/// * `blocks` - The number of blocks to project the rewards over
+	///
+	/// # Errors
+	/// * `AssetNotInVault` - The asset is not in a reward vault
+	/// * `RewardConfigNotFound` - The vault has no reward configuration
+	/// * `TotalDepositLessThanIncentiveCap` - The vault does not pay rewards yet
+	pub fn project_rewards(
+		asset: Asset<T::AssetId>,
+		amount: BalanceOf<T>,
+		lock_multiplier: Option<LockMultiplier>,
+		blocks: BlockNumberFor<T>,
+	) -> Result<RewardProjection<BalanceOf<T>>, DispatchError> {
+		let vault_id =
+			AssetLookupRewardVaults::<T>::get(asset).ok_or(Error::<T>::AssetNotInVault)?;
+		let reward_config =
+			RewardConfigStorage::<T>::get(vault_id).ok_or(Error::<T>::RewardConfigNotFound)?;
+
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let end_block = VaultSunsets::<T>::get(vault_id)
+			.map_or(current_block.saturating_add(blocks), |sunset| {
+				sunset.sunset_block.min(current_block.saturating_add(blocks))
+			});
+
+		// the deposit is scored the same way `record_delegate` scores it
+		let (score, deposit) = match lock_multiplier {
+			Some(lock_multiplier) => {
+				let multiplier = T::DelegationManager::get_lock_multiplier_value(lock_multiplier);
+				let lock = LockInfo {
+					amount,
+					lock_multiplier,
+					expiry_block: current_block
+						.saturating_add(T::DelegationManager::get_lock_duration(lock_multiplier)),
+				};
+				(
+					amount.saturating_mul(multiplier.into()),
+					UserDepositWithLocks {
+						unlocked_amount: Zero::zero(),
+						amount_with_locks: Some(vec![lock]),
+					},
+				)
+			},
+			None =>
+				(amount, UserDepositWithLocks { unlocked_amount: amount, amount_with_locks: None }),
+		};
+
+		let rewards = Self::calculate_deposit_rewards_until(
+			TotalRewardVaultDeposit::<T>::get(vault_id).saturating_add(amount),
+			TotalRewardVaultScore::<T>::get(vault_id).saturating_add(score),
+			deposit,
+			reward_config,
+			Some((current_block, Zero::zero())),
+			end_block,
+		)
+		.or_else(|error| {
+			if error == DispatchError::from(Error::<T>::NoRewardsAvailable) {
+				Ok(Zero::zero())
+			} else {
+				Err(error)
+			}
+		})?;
+
+		let rewarded_blocks = end_block.saturating_sub(current_block);
+		let rewards_per_block = if rewarded_blocks.is_zero() {
+			Zero::zero()
+		} else {
+			rewards / BalanceOf::<T>::saturated_from(rewarded_blocks.saturated_into::<u128>())
+		};
+		let annual_rewards = rewards_per_block.saturating_mul(BalanceOf::<T>::saturated_from(
+			ApyBlocks::<T>::get().saturated_into::<u128>(),
+		));
+
+		Ok(RewardProjection {
+			rewards,
+			rewards_per_block,
+			apy: Perbill::from_rational(annual_rewards, amount.max(One::one())),
+		})
+	}
+
+	/// Returns the APY of the vault of `asset` after applying its deposit cap and decay.
+	///
+	/// # Errors
+	/// * `AssetNotInVault` - The asset is not in a reward vault
+	/// * `RewardConfigNotFound` - The vault has no reward configuration
+	pub fn vault_apy(asset: Asset<T::AssetId>) -> Result<VaultApy<BalanceOf<T>>, DispatchError> {
+		let vault_id =
+			AssetLookupRewardVaults::<T>::get(asset).ok_or(Error::<T>::AssetNotInVault)?;
+		let reward_config =
+			RewardConfigStorage::<T>::get(vault_id).ok_or(Error::<T>::RewardConfigNotFound)?;
+		let total_deposit = TotalRewardVaultDeposit::<T>::get(vault_id);
+		let total_score = TotalRewardVaultScore::<T>::get(vault_id);
+
+		let effective_apy = Self::calculate_propotional_apy(
+			total_deposit,
+			reward_config.deposit_cap,
+			reward_config.apy,
+		)
+		.unwrap_or_default();
+
+		// decay applies once more than `DecayStartPeriod` blocks passed since the last claim
+		let decay_start = DecayStartPeriod::<T>::get();
+		let decay_factor =
+			Self::calculate_decay_factor(decay_start.saturating_add(One::one()), Zero::zero());
+		let decayed_apy = decay_factor * effective_apy;
+
+		let deposit_apy = if reward_config.incentive_cap > total_deposit || total_score.is_zero() {
+			Perbill::zero()
+		} else {
+			let annual_rewards = effective_apy.mul_floor(T::Currency::total_issuance());
+			Perbill::from_rational(annual_rewards, total_score)
+		};
+
+		Ok(VaultApy {
+			configured_apy: reward_config.apy,
+			effective_apy,
+			decayed_apy,
+			deposit_apy,
+			total_deposit,
+			deposit_cap: reward_config.deposit_cap,
+			incentive_cap: reward_config.incentive_cap,
+		})
+	}
+
+	/// Returns the pending rewards of `account_id` in every reward vault it has a deposit in,
+	/// broken down into the unlocked amount and each lock.
+	///
+	/// Parts of a deposit that earn nothing, for example while the total deposit of the vault is
+	/// below its incentive cap, report zero rewards.
+	pub fn reward_breakdown(
+		account_id: &T::AccountId,
+	) -> Vec<VaultRewardBreakdown<T::AssetId, BalanceOf<T>, BlockNumberFor<T>>> {
+		AssetLookupRewardVaults::<T>::iter()
+			.filter_map(|(asset, vault_id)| {
+				let reward_config = RewardConfigStorage::<T>::get(vault_id)?;
+				let deposit_info =
+					T::DelegationManager::get_user_deposit_with_locks(account_id, asset)?;
+				let total_deposit = TotalRewardVaultDeposit::<T>::get(vault_id);
+				let total_score = TotalRewardVaultScore::<T>::get(vault_id);
+				let last_claim = UserClaimedReward::<T>::get(account_id, vault_id);
+				let end_block = Self::reward_end_block(&vault_id);
+
+				let pending_rewards = |deposit| {
+					Self::calculate_deposit_rewards_until(
+						total_deposit,
+						total_score,
+						deposit,
+						reward_config.clone(),
+						last_claim,
+						end_block,
+					)
+					.unwrap_or_default()
+				};
+
+				let unlocked_rewards = pending_rewards(UserDepositWithLocks {
+					unlocked_amount: deposit_info.unlocked_amount,
+					amount_with_locks: None,
+				});
+				let locks: Vec<_> = deposit_info
+					.amount_with_locks
+					.unwrap_or_default()
+					.into_iter()
+					.map(|lock| LockRewardBreakdown {
+						amount: lock.amount,
+						lock_multiplier: lock.lock_multiplier,
+						multiplier: T::DelegationManager::get_lock_multiplier_value(
+							lock.lock_multiplier,
+						),
+						expiry_block: lock.expiry_block,
+						rewards: pending_rewards(UserDepositWithLocks {
+							unlocked_amount: Zero::zero(),
+							amount_with_locks: Some(vec![lock]),
+						}),
+					})
+					.collect();
+				let total_rewards = locks
+					.iter()
+					.fold(unlocked_rewards, |acc, lock| acc.saturating_add(lock.rewards));
+
+				Some(VaultRewardBreakdown {
+					asset,
+					unlocked_amount: deposit_info.unlocked_amount,
+					unlocked_rewards,
+					locks,
+					total_rewards,
+				})
+			})
+			.collect()
+	}
+
+	/// Returns the number of blocks until the pending rewards of `account_id` for `asset` start
+	/// to decay, or `None` if they already decay or decay is disabled.
+	///
+	/// # Errors
+	/// * `AssetNotInVault` - The asset is not in a reward vault
+	pub fn blocks_until_decay(
+		account_id: &T::AccountId,
+		asset: Asset<T::AssetId>,
+	) -> Result<Option<BlockNumberFor<T>>, DispatchError> {
+		let vault_id =
+			AssetLookupRewardVaults::<T>::get(asset).ok_or(Error::<T>::AssetNotInVault)?;
+		if DecayRate::<T>::get().is_zero() {
+			return Ok(None);
+		}
+
+		let last_claim_block = UserClaimedReward::<T>::get(account_id, vault_id)
+			.map(|(block, _)| block)
+			.unwrap_or_default();
+		let elapsed = Self::reward_end_block(&vault_id).saturating_sub(last_claim_block);
+		let decay_start = DecayStartPeriod::<T>::get();
+
+		if elapsed > decay_start {
+			return Ok(None);
+		}
+		Ok(Some(decay_start.saturating_sub(elapsed).saturating_add(One::one())))
+	}
+}
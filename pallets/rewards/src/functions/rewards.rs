@@ -15,9 +15,10 @@
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	ApyBlocks, AssetLookupRewardVaults, BalanceOf, Config, DecayRate, DecayStartPeriod, Error,
-	Event, Pallet, RewardConfigForAssetVault, RewardConfigStorage, RewardVaultsPotAccount,
-	TotalRewardVaultDeposit, TotalRewardVaultScore, UserClaimedReward,
+	ApyBlocks, AssetLookupRewardVaults, AutoCompoundPreferences, BalanceOf, Config, DecayRate,
+	DecayStartPeriod, Error, Event, Pallet, RewardConfigForAssetVault, RewardConfigStorage,
+	RewardVaultsPotAccount, TotalRewardVaultDeposit, TotalRewardVaultScore, UserClaimedReward,
+	VaultSunsets,
 };
 use frame_support::{
 	ensure,
@@ -31,7 +32,9 @@ use sp_runtime::{
 };
 use sp_std::vec::Vec;
 use tangle_primitives::{
-	services::Asset, traits::MultiAssetDelegationInfo, types::rewards::UserDepositWithLocks,
+	services::Asset,
+	traits::{MultiAssetDelegationInfo, MultiAssetDelegationRestaker},
+	types::rewards::UserDepositWithLocks,
 };
 
 pub(crate) const LOG_TARGET: &str = "runtime::rewards";
@@ -62,15 +65,24 @@ impl<T: Config> Pallet<T> {
 
 		let total_deposit = TotalRewardVaultDeposit::<T>::get(vault_id);
 
-		Self::calculate_deposit_rewards_with_lock_multiplier(
+		Self::calculate_deposit_rewards_until(
 			total_deposit,
 			total_score,
 			deposit_info,
 			reward_config.ok_or(Error::<T>::RewardConfigNotFound)?,
 			last_claim,
+			Self::reward_end_block(&vault_id),
 		)
 	}
 
+	/// Returns the last block for which a vault accrues rewards, which is the current block
+	/// unless the vault has passed its sunset block.
+	pub fn reward_end_block(vault_id: &T::VaultId) -> BlockNumberFor<T> {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		VaultSunsets::<T>::get(vault_id)
+			.map_or(current_block, |sunset| sunset.sunset_block.min(current_block))
+	}
+
 	/// Calculates and pays out rewards for a given account and asset.
 	///
 	/// This function orchestrates the reward calculation and payout process by:
@@ -110,14 +122,26 @@ impl<T: Config> Pallet<T> {
 		let pot_account =
 			RewardVaultsPotAccount::<T>::get(vault_id).ok_or(Error::<T>::PotAccountNotFound)?;
 
-		// Transfer rewards from the pot account to the user
+		// auto-compounded rewards stay with the account, which restakes them, all other rewards
+		// go to the payee of the account
+		let compound_operator = AutoCompoundPreferences::<T>::get(account_id, asset);
+		let payee = if compound_operator.is_some() {
+			account_id.clone()
+		} else {
+			Self::payee_of(account_id)
+		};
+
+		// Transfer rewards from the pot account to the payee
 		T::Currency::transfer(
 			&pot_account,
-			account_id,
+			&payee,
 			rewards_to_be_paid,
 			frame_support::traits::ExistenceRequirement::AllowDeath,
 		)?;
 
+		// pay out the reward streams of the vault
+		Self::payout_reward_streams(account_id, vault_id);
+
 		// update the last claim
 		UserClaimedReward::<T>::try_mutate(
 			account_id,
@@ -137,9 +161,61 @@ impl<T: Config> Pallet<T> {
 			amount: rewards_to_be_paid,
 		});
 
+		// restake the claimed rewards if the account opted into auto-compounding
+		if let Some(operator) = compound_operator {
+			Self::compound_rewards(account_id, asset, &operator, rewards_to_be_paid);
+		}
+
 		Ok(rewards_to_be_paid)
 	}
 
+	/// Redeposits claimed rewards as the native asset and delegates them to `operator`.
+	///
+	/// Compounding is best effort: if the deposit or delegation fails (for example because the
+	/// operator is no longer active or the amount is below the minimum delegation), the claim
+	/// still succeeds, the rewards stay in the account and `RewardsCompoundFailed` is emitted.
+	///
+	/// # Arguments
+	/// * `account_id` - The account that claimed the rewards
+	/// * `asset` - The delegated asset the rewards were claimed for
+	/// * `operator` - The operator to delegate the rewards to
+	/// * `amount` - The amount of claimed rewards
+	pub fn compound_rewards(
+		account_id: &T::AccountId,
+		asset: Asset<T::AssetId>,
+		operator: &T::AccountId,
+		amount: BalanceOf<T>,
+	) {
+		if amount.is_zero() {
+			return;
+		}
+
+		let native_asset = Asset::Custom(T::AssetId::default());
+		match T::DelegationRestaker::deposit_and_delegate(
+			account_id,
+			operator,
+			native_asset,
+			amount,
+		) {
+			Ok(()) => Self::deposit_event(Event::RewardsCompounded {
+				account: account_id.clone(),
+				asset,
+				operator: operator.clone(),
+				amount,
+			}),
+			Err(error) => {
+				log::debug!(target: LOG_TARGET, "failed to compound rewards: {:?}", error);
+				Self::deposit_event(Event::RewardsCompoundFailed {
+					account: account_id.clone(),
+					asset,
+					operator: operator.clone(),
+					amount,
+					error,
+				});
+			},
+		}
+	}
+
 	/// Validates a reward configuration ensuring that:
 	/// 1. The incentive cap is not greater than the deposit cap
 	/// 2. If boost multiplier is set, it must be 1 (current limitation)
@@ -233,7 +309,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Calculate decay factor based on time since last claim
-	fn calculate_decay_factor(
+	pub(crate) fn calculate_decay_factor(
 		current_block: BlockNumberFor<T>,
 		last_claim_block: BlockNumberFor<T>,
 	) -> Perbill {
@@ -283,6 +359,27 @@ impl<T: Config> Pallet<T> {
 		deposit: UserDepositWithLocks<BalanceOf<T>, BlockNumberFor<T>>,
 		reward: RewardConfigForAssetVault<BalanceOf<T>>,
 		last_claim: Option<(BlockNumberFor<T>, BalanceOf<T>)>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::calculate_deposit_rewards_until(
+			total_deposit,
+			total_asset_score,
+			deposit,
+			reward,
+			last_claim,
+			frame_system::Pallet::<T>::block_number(),
+		)
+	}
+
+	/// Calculates rewards for deposits like
+	/// [`Self::calculate_deposit_rewards_with_lock_multiplier`], accruing only up to `end_block`
+	/// instead of the current block.
+	pub fn calculate_deposit_rewards_until(
+		total_deposit: BalanceOf<T>,
+		total_asset_score: BalanceOf<T>,
+		deposit: UserDepositWithLocks<BalanceOf<T>, BlockNumberFor<T>>,
+		reward: RewardConfigForAssetVault<BalanceOf<T>>,
+		last_claim: Option<(BlockNumberFor<T>, BalanceOf<T>)>,
+		end_block: BlockNumberFor<T>,
 	) -> Result<BalanceOf<T>, DispatchError> {
 		// Calculate the propotional apy
 		let deposit_cap = reward.deposit_cap;
@@ -307,7 +404,7 @@ impl<T: Config> Pallet<T> {
 
 		// Calculate decay factor based on time since last claim
 		let decay_factor = Self::calculate_decay_factor(
-			frame_system::Pallet::<T>::block_number(),
+			end_block,
 			last_claim.map(|(block, _)| block).unwrap_or_default(),
 		);
 		log::debug!(target: LOG_TARGET, "total annual rewards before decay: {:?}", total_annual_rewards);
@@ -326,8 +423,8 @@ impl<T: Config> Pallet<T> {
 		let user_unlocked_score = deposit.unlocked_amount;
 		let user_score = user_unlocked_score;
 
-		// Get the current block and calculate last claim block
-		let current_block = frame_system::Pallet::<T>::block_number();
+		// Get the end block and calculate last claim block
+		let current_block = end_block;
 		let last_claim_block = last_claim.map(|(block, _)| block).unwrap_or(current_block);
 		let blocks_to_be_paid = current_block.saturating_sub(last_claim_block);
 		log::debug!(target: LOG_TARGET,
@@ -353,7 +450,11 @@ impl<T: Config> Pallet<T> {
 							// Calculate lock reward:
 							// amount * APY * lock_multiplier *
 							//    (remaining_lock_time / total_lock_time)
-							let multiplier = BalanceOf::<T>::from(lock.lock_multiplier.value());
+							let multiplier = BalanceOf::<T>::from(
+								T::DelegationManager::get_lock_multiplier_value(
+									lock.lock_multiplier,
+								),
+							);
 							let lock_score = lock.amount.saturating_mul(multiplier);
 							log::debug!(target: LOG_TARGET, "user lock has not expired and still active, lock_multiplier: {:?}, lock_score: {:?}", lock.lock_multiplier, lock_score);
 
@@ -361,7 +462,11 @@ impl<T: Config> Pallet<T> {
 						} else {
 							// the lock has expired, so we only apply the lock multiplier during the
 							// unexpired period
-							let multiplier = BalanceOf::<T>::from(lock.lock_multiplier.value());
+							let multiplier = BalanceOf::<T>::from(
+								T::DelegationManager::get_lock_multiplier_value(
+									lock.lock_multiplier,
+								),
+							);
 							let lock_score = lock.amount.saturating_mul(multiplier);
 							let multiplier_applied_blocks =
 								lock.expiry_block.saturating_sub(last_claim_block);
@@ -0,0 +1,384 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	BalanceOf, Config, Error, Event, NextRewardStreamId, Pallet, RewardConfigStorage, RewardStream,
+	RewardStreamOf, RewardStreamStake, RewardStreams, SubaccountType, TotalRewardStreamStake,
+	UserRewardStream, UserRewardStreams, VaultSunsets, functions::rewards::LOG_TARGET,
+};
+use frame_support::{
+	BoundedVec, ensure,
+	traits::{
+		Currency, ExistenceRequirement, Get,
+		fungibles::{Inspect, Mutate},
+		tokens::{Fortitude, Preservation},
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	DispatchResult, FixedPointNumber, FixedU128, SaturatedConversion,
+	traits::{AccountIdConversion, Saturating, Zero},
+};
+use tangle_primitives::services::Asset;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the account holding the funds of the reward streams of `vault_id`, kept apart from
+	/// the pot paying out the TNT rewards of the vault.
+	pub fn reward_stream_pot_account(vault_id: T::VaultId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((SubaccountType::RewardStreamPot, vault_id))
+	}
+
+	/// Creates a reward stream for `vault_id` funded by `funder`.
+	pub fn do_create_reward_stream(
+		vault_id: T::VaultId,
+		reward_asset: Asset<T::AssetId>,
+		reward_per_block: BalanceOf<T>,
+		start_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+		funder: T::AccountId,
+	) -> DispatchResult {
+		ensure!(RewardConfigStorage::<T>::contains_key(vault_id), Error::<T>::VaultNotFound);
+		ensure!(!VaultSunsets::<T>::contains_key(vault_id), Error::<T>::VaultIsSunsetting);
+		ensure!(!matches!(reward_asset, Asset::Erc20(_)), Error::<T>::UnsupportedRewardAsset);
+		ensure!(
+			!reward_per_block.is_zero() &&
+				start_block < end_block &&
+				end_block > frame_system::Pallet::<T>::block_number(),
+			Error::<T>::InvalidRewardStream
+		);
+
+		let stream_id = NextRewardStreamId::<T>::get(vault_id);
+		let now = frame_system::Pallet::<T>::block_number();
+		RewardStreams::<T>::try_mutate(vault_id, |streams| {
+			streams
+				.try_push(RewardStream {
+					id: stream_id,
+					reward_asset,
+					reward_per_block,
+					start_block,
+					end_block,
+					funder: funder.clone(),
+					funded: Zero::zero(),
+					accrued: Zero::zero(),
+					distributed: Zero::zero(),
+					reward_per_share: FixedU128::zero(),
+					last_update_block: now.max(start_block),
+				})
+				.map_err(|_| Error::<T>::TooManyRewardStreams)
+		})?;
+		NextRewardStreamId::<T>::insert(vault_id, stream_id.saturating_add(1));
+
+		Self::deposit_event(Event::RewardStreamCreated {
+			vault_id,
+			stream_id,
+			reward_asset,
+			reward_per_block,
+			start_block,
+			end_block,
+			funder,
+		});
+		Ok(())
+	}
+
+	/// Deposits `amount` of the reward asset of a stream from its funder into the stream pot of
+	/// the vault.
+	///
+	/// Emissions up to the current block are accrued first, so the new funds only cover the
+	/// blocks from now on.
+	pub fn do_fund_reward_stream(
+		who: &T::AccountId,
+		vault_id: T::VaultId,
+		stream_id: u32,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let pot_account = Self::reward_stream_pot_account(vault_id);
+		Self::update_reward_streams(vault_id);
+
+		RewardStreams::<T>::try_mutate(vault_id, |streams| -> DispatchResult {
+			let stream = streams
+				.iter_mut()
+				.find(|stream| stream.id == stream_id)
+				.ok_or(Error::<T>::RewardStreamNotFound)?;
+			ensure!(stream.funder == *who, Error::<T>::NotRewardStreamFunder);
+
+			Self::transfer_reward_asset(stream.reward_asset, who, &pot_account, amount, true)?;
+			stream.funded = stream.funded.saturating_add(amount);
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::RewardStreamFunded {
+			vault_id,
+			stream_id,
+			funder: who.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Removes a reward stream and returns its undistributed funds to the funder.
+	pub fn do_cancel_reward_stream(vault_id: T::VaultId, stream_id: u32) -> DispatchResult {
+		let mut streams = RewardStreams::<T>::get(vault_id);
+		let index = streams
+			.iter()
+			.position(|stream| stream.id == stream_id)
+			.ok_or(Error::<T>::RewardStreamNotFound)?;
+		let stream = streams.remove(index);
+
+		let refunded =
+			Self::refund_reward_stream(&stream, &Self::reward_stream_pot_account(vault_id));
+		RewardStreams::<T>::insert(vault_id, streams);
+
+		Self::deposit_event(Event::RewardStreamCancelled { vault_id, stream_id, refunded });
+		Ok(())
+	}
+
+	/// Accrues the emissions of every reward stream of `vault_id` up to the last block the vault
+	/// accrues rewards for into the reward per share of the stream.
+	///
+	/// Emissions are capped at the funds deposited for each stream, and blocks in which the vault
+	/// has no stake emit nothing.
+	pub(crate) fn update_reward_streams(vault_id: T::VaultId) {
+		let end_block = Self::reward_end_block(&vault_id);
+		let total_stake = TotalRewardStreamStake::<T>::get(vault_id);
+
+		RewardStreams::<T>::mutate(vault_id, |streams| {
+			for stream in streams.iter_mut() {
+				let from = stream.start_block.max(stream.last_update_block);
+				let to = stream.end_block.min(end_block);
+				if to <= from {
+					continue;
+				}
+				stream.last_update_block = to;
+				if total_stake.is_zero() {
+					continue;
+				}
+
+				let blocks: u64 = to.saturating_sub(from).saturated_into();
+				let emitted = stream
+					.reward_per_block
+					.saturating_mul(BalanceOf::<T>::saturated_from(blocks))
+					.min(stream.funded.saturating_sub(stream.accrued));
+				stream.accrued = stream.accrued.saturating_add(emitted);
+				stream.reward_per_share = stream
+					.reward_per_share
+					.saturating_add(FixedU128::saturating_from_rational(emitted, total_stake));
+			}
+		});
+	}
+
+	/// Changes the stake `account` earns the reward streams of `vault_id` with to
+	/// `update(stake)`.
+	///
+	/// The rewards earned with the previous stake are settled first, so they are owed to the
+	/// account until its next claim.
+	pub(crate) fn update_reward_stream_stake(
+		account: &T::AccountId,
+		vault_id: T::VaultId,
+		update: impl FnOnce(BalanceOf<T>) -> BalanceOf<T>,
+	) {
+		Self::update_reward_streams(vault_id);
+
+		let stake = RewardStreamStake::<T>::get(vault_id, account);
+		let new_stake = update(stake);
+		let positions = Self::settle_reward_streams(
+			account,
+			vault_id,
+			&RewardStreams::<T>::get(vault_id),
+			stake,
+			new_stake,
+		);
+		Self::store_reward_stream_positions(account, vault_id, positions);
+
+		if new_stake.is_zero() {
+			RewardStreamStake::<T>::remove(vault_id, account);
+		} else {
+			RewardStreamStake::<T>::insert(vault_id, account, new_stake);
+		}
+		TotalRewardStreamStake::<T>::mutate(vault_id, |total| {
+			*total = total.saturating_sub(stake).saturating_add(new_stake)
+		});
+	}
+
+	/// Pays out to the payee of `account` the rewards it earned from every reward stream of
+	/// `vault_id`.
+	///
+	/// Each payout is capped at the undistributed funds of the stream. Payouts that fail stay owed
+	/// to the account.
+	pub fn payout_reward_streams(account: &T::AccountId, vault_id: T::VaultId) {
+		Self::update_reward_streams(vault_id);
+
+		let stake = RewardStreamStake::<T>::get(vault_id, account);
+		let mut streams = RewardStreams::<T>::get(vault_id);
+		let mut positions = Self::settle_reward_streams(account, vault_id, &streams, stake, stake);
+		let pot_account = Self::reward_stream_pot_account(vault_id);
+		let payee = Self::payee_of(account);
+
+		for (stream, position) in streams.iter_mut().zip(positions.iter_mut()) {
+			let amount = position.owed.min(stream.funded.saturating_sub(stream.distributed));
+			if amount.is_zero() {
+				continue;
+			}
+
+			if let Err(error) = Self::transfer_reward_asset(
+				stream.reward_asset,
+				&pot_account,
+				&payee,
+				amount,
+				false,
+			) {
+				log::debug!(target: LOG_TARGET, "failed to pay out reward stream {:?}: {:?}", stream.id, error);
+				continue;
+			}
+			position.owed = position.owed.saturating_sub(amount);
+			stream.distributed = stream.distributed.saturating_add(amount);
+
+			Self::deposit_event(Event::StreamRewardsClaimed {
+				account: account.clone(),
+				vault_id,
+				stream_id: stream.id,
+				reward_asset: stream.reward_asset,
+				amount,
+			});
+		}
+
+		RewardStreams::<T>::insert(vault_id, streams);
+		Self::store_reward_stream_positions(account, vault_id, positions);
+	}
+
+	/// Returns the positions of `account` in `streams` with the rewards earned with `stake` added
+	/// to what it is owed, and the debts rebased to `new_stake`.
+	///
+	/// Positions in streams that no longer exist are dropped.
+	fn settle_reward_streams(
+		account: &T::AccountId,
+		vault_id: T::VaultId,
+		streams: &[RewardStreamOf<T>],
+		stake: BalanceOf<T>,
+		new_stake: BalanceOf<T>,
+	) -> BoundedVec<UserRewardStream<BalanceOf<T>>, T::MaxRewardStreamsPerVault> {
+		let positions = UserRewardStreams::<T>::get(account, vault_id);
+		let settled = streams
+			.iter()
+			.map(|stream| {
+				let (debt, owed) = positions
+					.iter()
+					.find(|position| position.stream_id == stream.id)
+					.map_or((Zero::zero(), Zero::zero()), |position| {
+						(position.debt, position.owed)
+					});
+				let earned = stream.reward_per_share.saturating_mul_int(stake).saturating_sub(debt);
+				UserRewardStream {
+					stream_id: stream.id,
+					debt: stream.reward_per_share.saturating_mul_int(new_stake),
+					owed: owed.saturating_add(earned),
+				}
+			})
+			.collect::<sp_std::vec::Vec<_>>();
+		BoundedVec::truncate_from(settled)
+	}
+
+	/// Stores the positions of `account` in the reward streams of `vault_id`, removing them once
+	/// they hold neither a debt nor owed rewards.
+	fn store_reward_stream_positions(
+		account: &T::AccountId,
+		vault_id: T::VaultId,
+		positions: BoundedVec<UserRewardStream<BalanceOf<T>>, T::MaxRewardStreamsPerVault>,
+	) {
+		if positions
+			.iter()
+			.all(|position| position.debt.is_zero() && position.owed.is_zero())
+		{
+			UserRewardStreams::<T>::remove(account, vault_id);
+		} else {
+			UserRewardStreams::<T>::insert(account, vault_id, positions);
+		}
+	}
+
+	/// Returns the undistributed funds of every reward stream of a closing vault to their
+	/// funders and removes the streams along with the stake earning them.
+	pub(crate) fn refund_reward_streams(vault_id: T::VaultId) {
+		let pot_account = Self::reward_stream_pot_account(vault_id);
+		for stream in RewardStreams::<T>::take(vault_id) {
+			Self::refund_reward_stream(&stream, &pot_account);
+		}
+		NextRewardStreamId::<T>::remove(vault_id);
+		TotalRewardStreamStake::<T>::remove(vault_id);
+	}
+
+	/// Returns the undistributed funds of `stream` held by the stream pot to its funder, returning
+	/// the refunded amount.
+	fn refund_reward_stream(
+		stream: &RewardStreamOf<T>,
+		pot_account: &T::AccountId,
+	) -> BalanceOf<T> {
+		let remaining = stream.funded.saturating_sub(stream.distributed);
+		let refunded = remaining.min(Self::reward_asset_balance(stream.reward_asset, pot_account));
+		if refunded.is_zero() ||
+			Self::transfer_reward_asset(
+				stream.reward_asset,
+				pot_account,
+				&stream.funder,
+				refunded,
+				false,
+			)
+			.is_err()
+		{
+			return Zero::zero();
+		}
+		refunded
+	}
+
+	/// Returns the balance of `who` in a reward asset, the native currency being
+	/// `Asset::Custom(T::AssetId::default())`.
+	fn reward_asset_balance(asset: Asset<T::AssetId>, who: &T::AccountId) -> BalanceOf<T> {
+		match asset {
+			Asset::Custom(id) if id == T::AssetId::default() => T::Currency::free_balance(who),
+			Asset::Custom(id) => T::Fungibles::reducible_balance(
+				id,
+				who,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			),
+			Asset::Erc20(_) => Zero::zero(),
+		}
+	}
+
+	/// Transfers `amount` of a reward asset, keeping `from` alive if `keep_alive` is set.
+	fn transfer_reward_asset(
+		asset: Asset<T::AssetId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		keep_alive: bool,
+	) -> DispatchResult {
+		match asset {
+			Asset::Custom(id) if id == T::AssetId::default() => {
+				let existence = if keep_alive {
+					ExistenceRequirement::KeepAlive
+				} else {
+					ExistenceRequirement::AllowDeath
+				};
+				T::Currency::transfer(from, to, amount, existence)
+			},
+			Asset::Custom(id) => {
+				let preservation =
+					if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
+				T::Fungibles::transfer(id, from, to, amount, preservation).map(|_| ())
+			},
+			Asset::Erc20(_) => Err(Error::<T>::UnsupportedRewardAsset.into()),
+		}
+	}
+}
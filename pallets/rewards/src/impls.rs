@@ -16,12 +16,18 @@
 
 use crate::{
 	AssetLookupRewardVaults, BalanceOf, Config, Error, Event, Pallet, RewardConfigStorage,
-	TotalRewardVaultDeposit, TotalRewardVaultScore, UserClaimedReward, UserServiceReward,
+	RewardVaultsPotAccount, TotalRewardVaultDeposit, TotalRewardVaultScore, UserClaimedReward,
+	UserServiceReward,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{DispatchError, traits::Saturating};
 use tangle_primitives::{
-	services::Asset, traits::rewards::RewardsManager, types::rewards::LockMultiplier,
+	services::Asset,
+	traits::{
+		MultiAssetDelegationInfo,
+		rewards::{RewardPayeeManager, RewardsManager},
+	},
+	types::rewards::LockMultiplier,
 };
 
 impl<T: Config> RewardsManager<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumberFor<T>>
@@ -52,7 +58,9 @@ impl<T: Config> RewardsManager<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumb
 
 			// Update the reward vault score
 			let score = if let Some(lock_multiplier) = lock_multiplier {
-				amount.saturating_mul(lock_multiplier.value().into())
+				amount.saturating_mul(
+					T::DelegationManager::get_lock_multiplier_value(lock_multiplier).into(),
+				)
 			} else {
 				amount
 			};
@@ -68,6 +76,11 @@ impl<T: Config> RewardsManager<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumb
 				lock_multiplier,
 			});
 
+			// Update the stake earning the reward streams of the vault
+			Self::update_reward_stream_stake(account_id, vault_id, |stake| {
+				stake.saturating_add(amount)
+			});
+
 			// If this user has never claimed rewards, create an entry
 			// this will give us a starting point for reward claim
 			if !UserClaimedReward::<T>::contains_key(account_id, vault_id) {
@@ -84,7 +97,7 @@ impl<T: Config> RewardsManager<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumb
 	}
 
 	fn record_undelegate(
-		_account_id: &T::AccountId,
+		account_id: &T::AccountId,
 		_operator: &T::AccountId,
 		asset: Asset<T::AssetId>,
 		amount: BalanceOf<T>,
@@ -106,6 +119,37 @@ impl<T: Config> RewardsManager<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumb
 			// Update the reward vault score
 			let score = TotalRewardVaultScore::<T>::get(vault_id).saturating_sub(amount);
 			TotalRewardVaultScore::<T>::insert(vault_id, score);
+
+			// Update the stake earning the reward streams of the vault
+			Self::update_reward_stream_stake(account_id, vault_id, |stake| {
+				stake.saturating_sub(amount)
+			});
+		}
+		Ok(())
+	}
+
+	fn record_lock_multiplier_change(
+		asset: Asset<T::AssetId>,
+		amount: BalanceOf<T>,
+		old_multiplier: u32,
+		new_multiplier: u32,
+	) -> Result<(), Self::Error> {
+		// find the vault for the asset id
+		// if the asset is not in a reward vault, do nothing
+		if let Some(vault_id) = AssetLookupRewardVaults::<T>::get(asset) {
+			// Replace the score of the amount under the old multiplier with the new one
+			let score = TotalRewardVaultScore::<T>::get(vault_id)
+				.saturating_sub(amount.saturating_mul(old_multiplier.into()))
+				.saturating_add(amount.saturating_mul(new_multiplier.into()));
+			TotalRewardVaultScore::<T>::insert(vault_id, score);
+
+			// emit event
+			Self::deposit_event(Event::TotalScoreUpdated {
+				vault_id,
+				total_score: score,
+				asset,
+				lock_multiplier: None,
+			});
 		}
 		Ok(())
 	}
@@ -152,4 +196,21 @@ impl<T: Config> RewardsManager<T::AccountId, T::AssetId, BalanceOf<T>, BlockNumb
 			Err(Error::<T>::AssetNotInVault.into())
 		}
 	}
+
+	fn get_reward_vault_pot_account(asset: Asset<T::AssetId>) -> Option<T::AccountId> {
+		AssetLookupRewardVaults::<T>::get(asset).and_then(RewardVaultsPotAccount::<T>::get)
+	}
+
+	fn claim_rewards(
+		account_id: &T::AccountId,
+		asset: Asset<T::AssetId>,
+	) -> Result<BalanceOf<T>, Self::Error> {
+		Self::calculate_and_payout_rewards(account_id, asset)
+	}
+}
+
+impl<T: Config> RewardPayeeManager<T::AccountId> for Pallet<T> {
+	fn set_reward_payee(who: &T::AccountId, payee: Option<T::AccountId>) {
+		Self::do_set_reward_payee(who, payee)
+	}
 }
@@ -50,6 +50,45 @@
 //! - Three Months: 1.3x
 //! - Six Months: 1.6x
 //!
+//! ## Auto-compounding
+//!
+//! Delegators can opt into auto-compounding per delegated asset with `set_auto_compound`. Rewards
+//! claimed for that asset are then redeposited as the native asset and delegated to the chosen
+//! operator as part of the claim, instead of being left in the delegator's account.
+//!
+//! ## Reward Streams
+//!
+//! Besides the TNT yield, a vault can emit other assets through reward streams. A stream emits a
+//! fixed amount of its reward asset per block between its start and end block, shared between the
+//! stakers of the vault in proportion to their delegated stake across all assets of the vault.
+//! Emissions accrue into a reward per share of the stream whenever the stake of the vault changes,
+//! and each staker keeps a debt against it, so stake added right before a claim earns nothing for
+//! the blocks before it. Streams are created by the `ForceOrigin` and paid out of a stream pot of
+//! the vault, kept apart from the pot paying the TNT yield, which the stream funder tops up with
+//! `fund_reward_stream`. Claiming rewards for an asset pays out every stream of its vault, and
+//! emissions are capped at the funds deposited for each stream.
+//!
+//! ## Payees and Claim Proxies
+//!
+//! Operators and delegators can route their rewards to a separate payee account with
+//! `set_reward_payee`, so the staking key never has to receive or hold funds. Rewards compounded
+//! through auto-compounding stay with the account that restakes them. An account can also set a
+//! claim proxy with `set_claim_proxy`, which may claim its operator rewards with
+//! `claim_rewards_for`. Delegator rewards can be claimed by anyone, in batches with
+//! `claim_for_many`; every claim pays the payee of the claimed account.
+//!
+//! ## Vault Lifecycle
+//!
+//! Reward vaults are shut down in two steps. `schedule_vault_sunset` sets the last block for which
+//! the vault accrues rewards and, optionally, a successor vault. Once the sunset block has passed,
+//! anyone can call `close_reward_vault` repeatedly to settle the vault in batches:
+//! - The vault assets are moved to the successor together with the vault scores, or detached from
+//!   any vault if there is no successor.
+//! - Every depositor is paid the rewards accrued up to the sunset block from the vault pot, and
+//!   starts accruing in the successor from the sunset block.
+//! - Once every depositor is settled, the funds left in the pot are returned to the
+//!   `VaultFundsRecipient` account and the vault is removed.
+//!
 //! ## Notes
 //!
 //! - The reward vaults will consider all assets in parity, so only add the same type of asset in
@@ -87,6 +126,28 @@ use tangle_primitives::{
 	types::rewards::AssetType,
 };
 
+#[cfg(feature = "runtime-benchmarks")]
+/// Helper trait for benchmarking.
+pub trait BenchmarkHelper<AccountId, AssetId, Balance> {
+	/// Deposits `asset` for `who` and delegates it to `operator`, making `operator` an active
+	/// operator first if needed, and returns the delegated amount.
+	///
+	/// The delegation must be recorded with the pallet as the delegation manager would, so the
+	/// deposit earns rewards and reward stream emissions.
+	fn setup_delegation(who: &AccountId, operator: &AccountId, asset: Asset<AssetId>) -> Balance;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AccountId, AssetId, Balance: Default> BenchmarkHelper<AccountId, AssetId, Balance> for () {
+	fn setup_delegation(
+		_who: &AccountId,
+		_operator: &AccountId,
+		_asset: Asset<AssetId>,
+	) -> Balance {
+		Balance::default()
+	}
+}
+
 /// The pallet's account ID.
 #[frame_support::pallet]
 pub mod pallet {
@@ -94,14 +155,17 @@ pub mod pallet {
 	use frame_support::{
 		PalletId,
 		pallet_prelude::*,
-		traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency},
+		traits::{
+			Currency, LockableCurrency, ReservableCurrency,
+			fungibles::{Inspect, Mutate},
+		},
 	};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::{
 		Perbill,
-		traits::{AccountIdConversion, Saturating, Zero},
+		traits::{AccountIdConversion, Zero},
 	};
-	use tangle_primitives::rewards::LockMultiplier;
+	use tangle_primitives::{rewards::LockMultiplier, traits::MultiAssetDelegationInfo};
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -138,6 +202,13 @@ pub mod pallet {
 				AssetType<Self::AssetId>,
 			>;
 
+		/// Restaker used to deposit and delegate auto-compounded rewards
+		type DelegationRestaker: tangle_primitives::traits::MultiAssetDelegationRestaker<
+				Self::AccountId,
+				BalanceOf<Self>,
+				Self::AssetId,
+			>;
+
 		/// The origin that can manage reward assets
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -173,6 +244,24 @@ pub mod pallet {
 		/// The maximum number of pending reward entries an operator can have.
 		#[pallet::constant]
 		type MaxPendingRewardsPerOperator: Get<u32>;
+
+		/// The account that receives the funds left in the pot of a closed vault.
+		type VaultFundsRecipient: Get<Self::AccountId>;
+
+		/// The fungibles trait used for paying out reward streams in non-native assets.
+		type Fungibles: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+			+ Mutate<Self::AccountId, AssetId = Self::AssetId>;
+
+		/// The maximum number of reward streams a vault can have.
+		#[pallet::constant]
+		type MaxRewardStreamsPerVault: Get<u32>;
+
+		/// The maximum number of claims in a single `claim_for_many` call.
+		#[pallet::constant]
+		type MaxClaimsPerBatch: Get<u32>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::AssetId, BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -288,6 +377,97 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Stores the auto-compounding preferences of delegators.
+	/// Maps a delegator and a delegated asset to the operator that claimed rewards are restaked
+	/// with.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound_operator)]
+	pub type AutoCompoundPreferences<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Asset<T::AssetId>,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// Stores the scheduled sunset of vaults that are being shut down.
+	#[pallet::storage]
+	#[pallet::getter(fn vault_sunset)]
+	pub type VaultSunsets<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::VaultId,
+		VaultSunset<T::VaultId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Stores the raw `UserClaimedReward` key at which the settlement of a closing vault resumes.
+	#[pallet::storage]
+	pub type VaultSettlementCursor<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::VaultId, Vec<u8>, OptionQuery>;
+
+	/// Stores the reward streams of each vault.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_streams)]
+	pub type RewardStreams<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::VaultId,
+		BoundedVec<RewardStreamOf<T>, T::MaxRewardStreamsPerVault>,
+		ValueQuery,
+	>;
+
+	/// Stores the ID of the next reward stream of each vault.
+	#[pallet::storage]
+	pub type NextRewardStreamId<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::VaultId, u32, ValueQuery>;
+
+	/// Stores the stake each account earns reward stream emissions of a vault with.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_stream_stake)]
+	pub type RewardStreamStake<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::VaultId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Stores the total stake earning reward stream emissions of each vault.
+	#[pallet::storage]
+	pub type TotalRewardStreamStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::VaultId, BalanceOf<T>, ValueQuery>;
+
+	/// Stores the position of each account in the reward streams of a vault.
+	#[pallet::storage]
+	#[pallet::getter(fn user_reward_streams)]
+	pub type UserRewardStreams<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::VaultId,
+		BoundedVec<UserRewardStream<BalanceOf<T>>, T::MaxRewardStreamsPerVault>,
+		ValueQuery,
+	>;
+
+	/// Stores the account that receives the rewards claimed for an account, if it is not the
+	/// account itself.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_payee)]
+	pub type RewardPayees<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Stores the account allowed to claim the operator rewards of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn claim_proxy)]
+	pub type ClaimProxies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -339,6 +519,79 @@ pub mod pallet {
 		RewardRecorded { operator: T::AccountId, service_id: ServiceId, amount: BalanceOf<T> },
 		/// Operator rewards claimed
 		OperatorRewardsClaimed { operator: T::AccountId, amount: BalanceOf<T> },
+		/// Auto-compounding of rewards was enabled, updated or disabled (`operator` is `None`)
+		AutoCompoundSet {
+			account: T::AccountId,
+			asset: Asset<T::AssetId>,
+			operator: Option<T::AccountId>,
+		},
+		/// Claimed rewards were redeposited and delegated to the operator
+		RewardsCompounded {
+			account: T::AccountId,
+			asset: Asset<T::AssetId>,
+			operator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Claimed rewards could not be compounded and were left in the account
+		RewardsCompoundFailed {
+			account: T::AccountId,
+			asset: Asset<T::AssetId>,
+			operator: T::AccountId,
+			amount: BalanceOf<T>,
+			error: DispatchError,
+		},
+		/// The sunset of a vault was scheduled
+		VaultSunsetScheduled {
+			vault_id: T::VaultId,
+			sunset_block: BlockNumberFor<T>,
+			successor: Option<T::VaultId>,
+		},
+		/// The scheduled sunset of a vault was cancelled
+		VaultSunsetCancelled { vault_id: T::VaultId },
+		/// The assets and scores of a closing vault were migrated into its successor
+		VaultScoresMigrated {
+			vault_id: T::VaultId,
+			successor: T::VaultId,
+			total_score: BalanceOf<T>,
+			total_deposit: BalanceOf<T>,
+		},
+		/// The pending rewards of an account in a closing vault were paid out
+		VaultRewardsSettled { vault_id: T::VaultId, account: T::AccountId, amount: BalanceOf<T> },
+		/// A vault was closed and the funds left in its pot were returned
+		RewardVaultClosed { vault_id: T::VaultId, recipient: T::AccountId, amount: BalanceOf<T> },
+		/// A reward stream was created for a vault
+		RewardStreamCreated {
+			vault_id: T::VaultId,
+			stream_id: u32,
+			reward_asset: Asset<T::AssetId>,
+			reward_per_block: BalanceOf<T>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			funder: T::AccountId,
+		},
+		/// Funds were deposited into the vault pot for a reward stream
+		RewardStreamFunded {
+			vault_id: T::VaultId,
+			stream_id: u32,
+			funder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A reward stream was cancelled and its undistributed funds returned to the funder
+		RewardStreamCancelled { vault_id: T::VaultId, stream_id: u32, refunded: BalanceOf<T> },
+		/// Rewards of a reward stream were claimed
+		StreamRewardsClaimed {
+			account: T::AccountId,
+			vault_id: T::VaultId,
+			stream_id: u32,
+			reward_asset: Asset<T::AssetId>,
+			amount: BalanceOf<T>,
+		},
+		/// The payee of an account was set or removed
+		RewardPayeeSet { account: T::AccountId, payee: Option<T::AccountId> },
+		/// The claim proxy of an account was set or removed
+		ClaimProxySet { account: T::AccountId, proxy: Option<T::AccountId> },
+		/// A claim of a `claim_for_many` batch failed and was skipped
+		BatchClaimFailed { account: T::AccountId, asset: Asset<T::AssetId>, error: DispatchError },
 	}
 
 	#[pallet::error]
@@ -405,6 +658,41 @@ pub mod pallet {
 		TransferFailed,
 		/// Operator has too many pending rewards.
 		TooManyPendingRewards,
+		/// The account does not delegate the asset to the given operator.
+		NotDelegatedToOperator,
+		/// Auto-compounding is not enabled for the account and asset.
+		AutoCompoundNotEnabled,
+		/// The vault already has a scheduled sunset.
+		VaultSunsetAlreadyScheduled,
+		/// The vault has no scheduled sunset.
+		VaultSunsetNotScheduled,
+		/// The sunset block must be in the future.
+		InvalidSunsetBlock,
+		/// The successor vault does not exist, is the vault itself or is being shut down.
+		InvalidSuccessorVault,
+		/// The sunset block of the vault has not been reached yet.
+		VaultSunsetNotReached,
+		/// The vault is being shut down.
+		VaultIsSunsetting,
+		/// The settlement of the vault has already started.
+		VaultSettlementInProgress,
+		/// The number of entries to settle must be greater than zero.
+		InvalidSettlementLimit,
+		/// The vault has reached the maximum number of reward streams.
+		TooManyRewardStreams,
+		/// The reward stream must emit a non-zero amount and end after it starts and after the
+		/// current block.
+		InvalidRewardStream,
+		/// The asset cannot be paid out by a reward stream.
+		UnsupportedRewardAsset,
+		/// The reward stream does not exist.
+		RewardStreamNotFound,
+		/// Only the funder of the reward stream can fund it.
+		NotRewardStreamFunder,
+		/// The caller is neither the account nor its claim proxy.
+		NotClaimProxy,
+		/// The claim batch is empty.
+		EmptyClaimBatch,
 	}
 
 	#[pallet::call]
@@ -459,6 +747,7 @@ pub mod pallet {
 			action: AssetAction,
 		) -> DispatchResult {
 			let _who = T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(!VaultSunsets::<T>::contains_key(vault_id), Error::<T>::VaultIsSunsetting);
 
 			match action {
 				AssetAction::Add => Self::add_asset_to_vault(&vault_id, &asset)?,
@@ -655,33 +944,366 @@ pub mod pallet {
 		}
 
 		/// Allows an operator to claim all their currently pending rewards.
+		///
+		/// The rewards are paid to the payee of the operator.
 		#[pallet::call_index(10)]
 		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
 		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
 			let operator = ensure_signed(origin)?;
+			Self::do_claim_operator_rewards(&operator)
+		}
 
-			// Retrieve and clear pending rewards for the operator.
-			let pending_rewards = PendingOperatorRewards::<T>::take(&operator);
-			ensure!(!pending_rewards.is_empty(), Error::<T>::NoRewardsToClaim);
-
-			// Calculate the total amount to be claimed.
-			let mut total_reward = BalanceOf::<T>::zero();
-			for (_, amount) in pending_rewards.iter() {
-				total_reward = total_reward.saturating_add(*amount);
+		/// Enables, updates or disables auto-compounding of rewards for a delegated asset.
+		///
+		/// When enabled, rewards claimed for `asset` are redeposited as the native asset and
+		/// delegated to `operator` in the same call, instead of being left in the account.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the delegator account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `asset` - The delegated asset whose rewards are compounded
+		/// * `operator` - The operator to restake with, or `None` to disable auto-compounding
+		///
+		/// # Errors
+		///
+		/// * [`Error::NotDelegatedToOperator`] - The caller does not delegate `asset` to `operator`
+		/// * [`Error::AutoCompoundNotEnabled`] - Disabling while auto-compounding is not enabled
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			asset: Asset<T::AssetId>,
+			operator: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			match &operator {
+				Some(operator) => {
+					ensure!(
+						T::DelegationManager::get_delegators_for_operator(operator)
+							.iter()
+							.any(|(delegator, _, delegated)| delegator == &who &&
+								delegated == &asset),
+						Error::<T>::NotDelegatedToOperator
+					);
+					AutoCompoundPreferences::<T>::insert(&who, asset, operator);
+				},
+				None => {
+					ensure!(
+						AutoCompoundPreferences::<T>::contains_key(&who, asset),
+						Error::<T>::AutoCompoundNotEnabled
+					);
+					AutoCompoundPreferences::<T>::remove(&who, asset);
+				},
 			}
 
-			// Transfer the total reward from the pallet account to the operator.
-			T::Currency::transfer(
-				&Self::account_id(),
-				&operator,
-				total_reward,
-				ExistenceRequirement::KeepAlive, // Or AllowDeath depending on requirements
+			Self::deposit_event(Event::AutoCompoundSet { account: who, asset, operator });
+			Ok(())
+		}
+
+		/// Schedules the sunset of a reward vault.
+		///
+		/// The vault stops accruing rewards after `sunset_block`. Once that block has passed, the
+		/// vault can be settled and closed with `close_reward_vault`.
+		///
+		/// # Permissions
+		///
+		/// * Must pass the `ForceOrigin` check
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `vault_id` - ID of the vault to shut down
+		/// * `sunset_block` - The last block for which the vault accrues rewards
+		/// * `successor` - The vault to migrate the assets and scores into, if any
+		///
+		/// # Errors
+		///
+		/// * [`Error::VaultNotFound`] - The vault does not exist
+		/// * [`Error::VaultSunsetAlreadyScheduled`] - The vault already has a scheduled sunset
+		/// * [`Error::InvalidSunsetBlock`] - The sunset block is not in the future
+		/// * [`Error::InvalidSuccessorVault`] - The successor does not exist, is the vault itself
+		///   or is being shut down
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_vault_sunset())]
+		pub fn schedule_vault_sunset(
+			origin: OriginFor<T>,
+			vault_id: T::VaultId,
+			sunset_block: BlockNumberFor<T>,
+			successor: Option<T::VaultId>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_schedule_vault_sunset(vault_id, sunset_block, successor)
+		}
+
+		/// Cancels the scheduled sunset of a reward vault before its settlement has started.
+		///
+		/// # Permissions
+		///
+		/// * Must pass the `ForceOrigin` check
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `vault_id` - ID of the vault
+		///
+		/// # Errors
+		///
+		/// * [`Error::VaultSunsetNotScheduled`] - The vault has no scheduled sunset
+		/// * [`Error::VaultSettlementInProgress`] - The settlement of the vault has started
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_vault_sunset())]
+		pub fn cancel_vault_sunset(origin: OriginFor<T>, vault_id: T::VaultId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let sunset =
+				VaultSunsets::<T>::get(vault_id).ok_or(Error::<T>::VaultSunsetNotScheduled)?;
+			ensure!(
+				sunset.status == VaultSunsetStatus::Scheduled,
+				Error::<T>::VaultSettlementInProgress
+			);
+			VaultSunsets::<T>::remove(vault_id);
+
+			Self::deposit_event(Event::VaultSunsetCancelled { vault_id });
+			Ok(())
+		}
+
+		/// Settles a reward vault whose sunset block has passed, closing it once every depositor
+		/// is settled.
+		///
+		/// Each call scans at most `max_entries` reward claim records, paying out the rewards
+		/// accrued up to the sunset block to the depositors of the vault. The call must be
+		/// repeated until `RewardVaultClosed` is emitted.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by any account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `vault_id` - ID of the vault to close
+		/// * `max_entries` - The maximum number of reward claim records to scan
+		///
+		/// # Errors
+		///
+		/// * [`Error::VaultSunsetNotScheduled`] - The vault has no scheduled sunset
+		/// * [`Error::VaultSunsetNotReached`] - The sunset block has not passed yet
+		/// * [`Error::InvalidSettlementLimit`] - `max_entries` is zero
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::close_reward_vault(*max_entries))]
+		pub fn close_reward_vault(
+			origin: OriginFor<T>,
+			vault_id: T::VaultId,
+			max_entries: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_close_reward_vault(vault_id, max_entries)
+		}
+
+		/// Creates a reward stream emitting `reward_per_block` of `reward_asset` to the depositors
+		/// of a vault between `start_block` and `end_block`.
+		///
+		/// The stream only pays out what `funder` deposits into the vault pot with
+		/// `fund_reward_stream`.
+		///
+		/// # Permissions
+		///
+		/// * Must pass the `ForceOrigin` check
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `vault_id` - ID of the vault
+		/// * `reward_asset` - The asset paid out by the stream
+		/// * `reward_per_block` - The amount emitted per block
+		/// * `start_block` - The block from which the stream emits rewards
+		/// * `end_block` - The block at which the stream stops emitting rewards
+		/// * `funder` - The account funding the stream
+		///
+		/// # Errors
+		///
+		/// * [`Error::VaultNotFound`] - The vault does not exist
+		/// * [`Error::VaultIsSunsetting`] - The vault is being shut down
+		/// * [`Error::UnsupportedRewardAsset`] - The reward asset is an ERC20 token
+		/// * [`Error::InvalidRewardStream`] - The emission schedule is invalid
+		/// * [`Error::TooManyRewardStreams`] - The vault has too many reward streams
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_reward_stream())]
+		pub fn create_reward_stream(
+			origin: OriginFor<T>,
+			vault_id: T::VaultId,
+			reward_asset: Asset<T::AssetId>,
+			reward_per_block: BalanceOf<T>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			funder: T::AccountId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_create_reward_stream(
+				vault_id,
+				reward_asset,
+				reward_per_block,
+				start_block,
+				end_block,
+				funder,
 			)
-			.map_err(|_| Error::<T>::TransferFailed)?;
+		}
+
+		/// Deposits `amount` of the reward asset of a stream into the stream pot of the vault.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the funder of the stream
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `vault_id` - ID of the vault
+		/// * `stream_id` - ID of the reward stream
+		/// * `amount` - The amount to deposit
+		///
+		/// # Errors
+		///
+		/// * [`Error::RewardStreamNotFound`] - The reward stream does not exist
+		/// * [`Error::NotRewardStreamFunder`] - The caller is not the funder of the stream
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::fund_reward_stream())]
+		pub fn fund_reward_stream(
+			origin: OriginFor<T>,
+			vault_id: T::VaultId,
+			stream_id: u32,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_fund_reward_stream(&who, vault_id, stream_id, amount)
+		}
+
+		/// Cancels a reward stream, returning its undistributed funds to the funder.
+		///
+		/// Rewards the stream accrued to stakers that have not been claimed yet are returned too.
+		///
+		/// # Permissions
+		///
+		/// * Must pass the `ForceOrigin` check
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `vault_id` - ID of the vault
+		/// * `stream_id` - ID of the reward stream
+		///
+		/// # Errors
+		///
+		/// * [`Error::RewardStreamNotFound`] - The reward stream does not exist
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_reward_stream())]
+		pub fn cancel_reward_stream(
+			origin: OriginFor<T>,
+			vault_id: T::VaultId,
+			stream_id: u32,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_cancel_reward_stream(vault_id, stream_id)
+		}
 
-			// Emit an event.
-			Self::deposit_event(Event::OperatorRewardsClaimed { operator, amount: total_reward });
+		/// Sets or removes the account that receives the rewards claimed for the caller.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the account whose rewards are paid out
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `payee` - The account to pay rewards to, or `None` to pay the caller
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_payee())]
+		pub fn set_reward_payee(
+			origin: OriginFor<T>,
+			payee: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_reward_payee(&who, payee);
+			Ok(())
+		}
 
+		/// Sets or removes the account allowed to claim the operator rewards of the caller.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the account whose rewards are claimed
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `proxy` - The account allowed to claim, or `None` to remove the current proxy
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_claim_proxy())]
+		pub fn set_claim_proxy(
+			origin: OriginFor<T>,
+			proxy: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_claim_proxy(&who, proxy);
+			Ok(())
+		}
+
+		/// Claims the pending rewards of an operator on its behalf.
+		///
+		/// The rewards are paid to the payee of the operator.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the operator or its claim proxy
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `operator` - The operator to claim rewards for
+		///
+		/// # Errors
+		///
+		/// * [`Error::NotClaimProxy`] - The caller is neither the operator nor its claim proxy
+		/// * [`Error::NoRewardsToClaim`] - The operator has no pending rewards
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards_for())]
+		pub fn claim_rewards_for(origin: OriginFor<T>, operator: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_can_claim_for(&who, &operator)?;
+			Self::do_claim_operator_rewards(&operator)
+		}
+
+		/// Claims the rewards of many accounts in one call.
+		///
+		/// Each claim pays the payee of its account. Claims that fail are skipped and reported
+		/// with a `BatchClaimFailed` event, so one failing claim does not hold back the others.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `claims` - The accounts and assets to claim rewards for
+		///
+		/// # Errors
+		///
+		/// * [`Error::EmptyClaimBatch`] - No claims were given
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_for_many(claims.len() as u32))]
+		pub fn claim_for_many(
+			origin: OriginFor<T>,
+			claims: BoundedVec<(T::AccountId, Asset<T::AssetId>), T::MaxClaimsPerBatch>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(!claims.is_empty(), Error::<T>::EmptyClaimBatch);
+			Self::do_claim_for_many(claims.into_inner());
 			Ok(())
 		}
 	}
@@ -23,7 +23,7 @@ use frame_election_provider_support::{
 };
 use frame_support::{
 	PalletId, construct_runtime, derive_impl, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU128, OneSessionHandler},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU128, Currency, OneSessionHandler},
 };
 use pallet_session::historical as pallet_session_historical;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -32,12 +32,13 @@ use sp_core::{H160, sr25519};
 use sp_keyring::AccountKeyring;
 use sp_keystore::{KeystoreExt, KeystorePtr, testing::MemoryKeystore};
 use sp_runtime::{
-	AccountId32, BuildStorage, Perbill,
+	AccountId32, BuildStorage, DispatchError, DispatchResult, Perbill,
 	testing::UintAuthorityId,
 	traits::{ConvertInto, IdentityLookup},
 };
 use tangle_primitives::{
 	services::Asset,
+	traits::{MultiAssetDelegationInfo, MultiAssetDelegationRestaker},
 	types::rewards::{AssetType, UserDepositWithLocks},
 };
 
@@ -217,6 +218,7 @@ impl pallet_staking::Config for Runtime {
 parameter_types! {
 	pub const ServicesEVMAddress: H160 = H160([0x11; 20]);
 	pub const MaxPendingRewardsPerOperator: u32 = 100;
+	pub VaultFundsRecipient: AccountId = AccountId32::new([0xfe; 32]);
 }
 
 impl pallet_assets::Config for Runtime {
@@ -258,6 +260,7 @@ impl pallet_rewards::Config for Runtime {
 	type PalletId = RewardsPID;
 	type VaultId = u32;
 	type DelegationManager = MockDelegationManager;
+	type DelegationRestaker = MockDelegationManager;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxApy = MaxApy;
 	type MaxDepositCap = MaxDepositCap;
@@ -268,6 +271,12 @@ impl pallet_rewards::Config for Runtime {
 	type MaxVaultLogoLength = ConstU32<256>;
 	type VaultMetadataOrigin = frame_system::EnsureSigned<AccountId>;
 	type MaxPendingRewardsPerOperator = MaxPendingRewardsPerOperator;
+	type VaultFundsRecipient = VaultFundsRecipient;
+	type Fungibles = Assets;
+	type MaxRewardStreamsPerVault = ConstU32<10>;
+	type MaxClaimsPerBatch = ConstU32<10>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDelegationManager;
 	type WeightInfo = ();
 }
 
@@ -278,17 +287,13 @@ thread_local! {
 #[derive(Default)]
 pub struct MockDelegationData {
 	pub deposits: BTreeMap<(AccountId, Asset<AssetId>), UserDepositWithLocks<Balance, BlockNumber>>,
+	pub delegations: BTreeMap<AccountId, Vec<(AccountId, Balance, Asset<AssetId>)>>,
+	pub restaked: Vec<(AccountId, AccountId, Asset<AssetId>, Balance)>,
 }
 
 pub struct MockDelegationManager;
-impl
-	tangle_primitives::traits::MultiAssetDelegationInfo<
-		AccountId,
-		Balance,
-		BlockNumber,
-		AssetId,
-		AssetType<AssetId>,
-	> for MockDelegationManager
+impl MultiAssetDelegationInfo<AccountId, Balance, BlockNumber, AssetId, AssetType<AssetId>>
+	for MockDelegationManager
 {
 	fn get_current_round() -> tangle_primitives::types::RoundIndex {
 		Default::default()
@@ -315,9 +320,11 @@ impl
 	}
 
 	fn get_delegators_for_operator(
-		_operator: &AccountId,
+		operator: &AccountId,
 	) -> Vec<(AccountId, Balance, Asset<AssetId>)> {
-		Default::default()
+		MOCK_DELEGATION_INFO.with(|delegation_info| {
+			delegation_info.borrow().delegations.get(operator).cloned().unwrap_or_default()
+		})
 	}
 
 	fn get_user_deposit_with_locks(
@@ -337,6 +344,50 @@ impl
 	}
 }
 
+impl MultiAssetDelegationRestaker<AccountId, Balance, AssetId> for MockDelegationManager {
+	fn deposit_and_delegate(
+		who: &AccountId,
+		operator: &AccountId,
+		asset: Asset<AssetId>,
+		amount: Balance,
+	) -> DispatchResult {
+		if !Self::is_operator_active(operator) {
+			return Err(DispatchError::Other("operator not active"));
+		}
+		// move the funds out of the free balance, as the delegation pallet would
+		let _ = Balances::slash(who, amount);
+		MOCK_DELEGATION_INFO.with(|delegation_info| {
+			delegation_info.borrow_mut().restaked.push((
+				who.clone(),
+				operator.clone(),
+				asset,
+				amount,
+			))
+		});
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_rewards::BenchmarkHelper<AccountId, AssetId, Balance> for MockDelegationManager {
+	fn setup_delegation(who: &AccountId, operator: &AccountId, asset: Asset<AssetId>) -> Balance {
+		let amount = 100;
+		MOCK_DELEGATION_INFO.with(|delegation_info| {
+			delegation_info.borrow_mut().deposits.insert(
+				(who.clone(), asset),
+				UserDepositWithLocks { unlocked_amount: amount, amount_with_locks: None },
+			)
+		});
+		let _ = <RewardsPallet as tangle_primitives::traits::RewardsManager<
+			AccountId,
+			AssetId,
+			Balance,
+			BlockNumber,
+		>>::record_delegate(who, operator, asset, amount, None);
+		amount
+	}
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const MaxLocks: u32 = 50;
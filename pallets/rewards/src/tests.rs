@@ -20,6 +20,10 @@ use tangle_primitives::services::Asset;
 
 pub mod apy_calc;
 pub mod claim;
+pub mod claims;
+pub mod lifecycle;
 pub mod metadata;
+pub mod projection;
 pub mod reward_calc;
+pub mod streams;
 pub mod vault;
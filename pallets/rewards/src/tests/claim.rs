@@ -590,3 +590,142 @@ fn test_update_apy_blocks() {
 		assert_eq!(RewardsPallet::<Runtime>::blocks_for_apy(), 2000);
 	});
 }
+
+#[test]
+fn test_set_auto_compound_requires_delegation() {
+	new_test_ext().execute_with(|| {
+		let account: AccountId = AccountId::new([1u8; 32]);
+		let operator = mock_pub_key(2);
+		let asset = Asset::Custom(1);
+
+		// The account does not delegate the asset to the operator yet
+		assert_noop!(
+			RewardsPallet::<Runtime>::set_auto_compound(
+				RuntimeOrigin::signed(account.clone()),
+				asset,
+				Some(operator.clone())
+			),
+			Error::<Runtime>::NotDelegatedToOperator
+		);
+
+		// Disabling without enabling first fails
+		assert_noop!(
+			RewardsPallet::<Runtime>::set_auto_compound(
+				RuntimeOrigin::signed(account.clone()),
+				asset,
+				None
+			),
+			Error::<Runtime>::AutoCompoundNotEnabled
+		);
+
+		MOCK_DELEGATION_INFO.with(|m| {
+			m.borrow_mut()
+				.delegations
+				.insert(operator.clone(), vec![(account.clone(), MOCK_DEPOSIT, asset)]);
+		});
+
+		assert_ok!(RewardsPallet::<Runtime>::set_auto_compound(
+			RuntimeOrigin::signed(account.clone()),
+			asset,
+			Some(operator.clone())
+		));
+		assert_eq!(
+			RewardsPallet::<Runtime>::auto_compound_operator(&account, asset),
+			Some(operator)
+		);
+
+		assert_ok!(RewardsPallet::<Runtime>::set_auto_compound(
+			RuntimeOrigin::signed(account.clone()),
+			asset,
+			None
+		));
+		assert_eq!(RewardsPallet::<Runtime>::auto_compound_operator(&account, asset), None);
+	});
+}
+
+#[test]
+fn test_claim_rewards_auto_compounds() {
+	new_test_ext().execute_with(|| {
+		let account: AccountId = AccountId::new([1u8; 32]);
+		let operator = mock_pub_key(2);
+		let vault_id = 1u32;
+		let asset = Asset::Custom(1);
+
+		setup_vault(account.clone(), vault_id, asset).unwrap();
+
+		MOCK_DELEGATION_INFO.with(|m| {
+			m.borrow_mut()
+				.delegations
+				.insert(operator.clone(), vec![(account.clone(), MOCK_DEPOSIT, asset)]);
+		});
+
+		assert_ok!(RewardsPallet::<Runtime>::set_auto_compound(
+			RuntimeOrigin::signed(account.clone()),
+			asset,
+			Some(operator.clone())
+		));
+
+		run_to_block(1000);
+
+		// Anyone can trigger the claim, the rewards are restaked for the account
+		assert_ok!(RewardsPallet::<Runtime>::claim_rewards_other(
+			RuntimeOrigin::signed(mock_pub_key(3)),
+			account.clone(),
+			asset
+		));
+
+		let (_, claimed) = UserClaimedReward::<Runtime>::get(&account, vault_id).unwrap();
+		assert!(claimed > 0);
+
+		// The claimed rewards were delegated as the native asset to the operator
+		let restaked = MOCK_DELEGATION_INFO.with(|m| m.borrow().restaked.clone());
+		assert_eq!(restaked, vec![(account.clone(), operator.clone(), Asset::Custom(0), claimed)]);
+		assert_eq!(Balances::free_balance(&account), 0);
+
+		System::assert_last_event(RuntimeEvent::RewardsPallet(crate::Event::RewardsCompounded {
+			account,
+			asset,
+			operator,
+			amount: claimed,
+		}));
+	});
+}
+
+#[test]
+fn test_claim_rewards_auto_compound_failure_keeps_rewards() {
+	new_test_ext().execute_with(|| {
+		let account: AccountId = AccountId::new([1u8; 32]);
+		// operator 10 is inactive in the mock delegation manager
+		let operator = mock_pub_key(10);
+		let vault_id = 1u32;
+		let asset = Asset::Custom(1);
+
+		setup_vault(account.clone(), vault_id, asset).unwrap();
+
+		MOCK_DELEGATION_INFO.with(|m| {
+			m.borrow_mut()
+				.delegations
+				.insert(operator.clone(), vec![(account.clone(), MOCK_DEPOSIT, asset)]);
+		});
+
+		assert_ok!(RewardsPallet::<Runtime>::set_auto_compound(
+			RuntimeOrigin::signed(account.clone()),
+			asset,
+			Some(operator.clone())
+		));
+
+		run_to_block(1000);
+
+		assert_ok!(RewardsPallet::<Runtime>::claim_rewards_other(
+			RuntimeOrigin::signed(account.clone()),
+			account.clone(),
+			asset
+		));
+
+		// The claim succeeded and the rewards stay liquid in the account
+		let (_, claimed) = UserClaimedReward::<Runtime>::get(&account, vault_id).unwrap();
+		assert!(claimed > 0);
+		assert_eq!(Balances::free_balance(&account), claimed);
+		assert!(MOCK_DELEGATION_INFO.with(|m| m.borrow().restaked.is_empty()));
+	});
+}
@@ -0,0 +1,163 @@
+//! Tests for reward payees, claim proxies and batch claims.
+
+use crate::{
+	ClaimProxies, Error, Event, PendingOperatorRewards, RewardPayees,
+	mock::*,
+	tests::lifecycle::{run_to_block, setup_vault},
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_runtime::{BoundedVec, DispatchError};
+use tangle_primitives::services::Asset;
+
+#[test]
+fn set_reward_payee_should_work() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let payee = mock_pub_key(2);
+
+		assert_ok!(RewardsPallet::set_reward_payee(
+			RuntimeOrigin::signed(account.clone()),
+			Some(payee.clone())
+		));
+		assert_eq!(RewardPayees::<Runtime>::get(&account), Some(payee.clone()));
+		assert_eq!(RewardsPallet::payee_of(&account), payee);
+		System::assert_last_event(RuntimeEvent::RewardsPallet(Event::RewardPayeeSet {
+			account: account.clone(),
+			payee: Some(payee),
+		}));
+
+		// paying the account itself removes the payee
+		assert_ok!(RewardsPallet::set_reward_payee(
+			RuntimeOrigin::signed(account.clone()),
+			Some(account.clone())
+		));
+		assert!(!RewardPayees::<Runtime>::contains_key(&account));
+		assert_eq!(RewardsPallet::payee_of(&account), account);
+	});
+}
+
+#[test]
+fn delegator_rewards_are_paid_to_payee() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let payee = mock_pub_key(2);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		assert_ok!(RewardsPallet::set_reward_payee(
+			RuntimeOrigin::signed(account.clone()),
+			Some(payee.clone())
+		));
+		run_to_block(1_100);
+
+		let account_balance = Balances::free_balance(&account);
+		let payee_balance = Balances::free_balance(&payee);
+		let rewards = RewardsPallet::calculate_rewards(&account, asset).unwrap();
+		assert_ok!(RewardsPallet::claim_rewards_other(
+			RuntimeOrigin::signed(mock_pub_key(3)),
+			account.clone(),
+			asset
+		));
+
+		assert_eq!(Balances::free_balance(&account), account_balance);
+		assert_eq!(Balances::free_balance(&payee), payee_balance + rewards);
+	});
+}
+
+#[test]
+fn claim_proxy_can_claim_operator_rewards() {
+	new_test_ext().execute_with(|| {
+		let operator = mock_pub_key(1);
+		let proxy = mock_pub_key(2);
+		let payee = mock_pub_key(3);
+		Balances::make_free_balance_be(&RewardsPallet::account_id(), 10_000);
+		PendingOperatorRewards::<Runtime>::insert(
+			&operator,
+			BoundedVec::try_from(vec![(1, 100), (2, 50)]).unwrap(),
+		);
+		assert_ok!(RewardsPallet::set_reward_payee(
+			RuntimeOrigin::signed(operator.clone()),
+			Some(payee.clone())
+		));
+
+		assert_noop!(
+			RewardsPallet::claim_rewards_for(
+				RuntimeOrigin::signed(proxy.clone()),
+				operator.clone()
+			),
+			Error::<Runtime>::NotClaimProxy
+		);
+
+		assert_ok!(RewardsPallet::set_claim_proxy(
+			RuntimeOrigin::signed(operator.clone()),
+			Some(proxy.clone())
+		));
+		assert_eq!(ClaimProxies::<Runtime>::get(&operator), Some(proxy.clone()));
+
+		let operator_balance = Balances::free_balance(&operator);
+		let payee_balance = Balances::free_balance(&payee);
+		assert_ok!(RewardsPallet::claim_rewards_for(
+			RuntimeOrigin::signed(proxy.clone()),
+			operator.clone()
+		));
+		assert_eq!(Balances::free_balance(&operator), operator_balance);
+		assert_eq!(Balances::free_balance(&payee), payee_balance + 150);
+		System::assert_last_event(RuntimeEvent::RewardsPallet(Event::OperatorRewardsClaimed {
+			operator: operator.clone(),
+			amount: 150,
+		}));
+
+		assert_noop!(
+			RewardsPallet::claim_rewards_for(
+				RuntimeOrigin::signed(proxy.clone()),
+				operator.clone()
+			),
+			Error::<Runtime>::NoRewardsToClaim
+		);
+
+		// removing the proxy revokes its claim rights
+		assert_ok!(RewardsPallet::set_claim_proxy(RuntimeOrigin::signed(operator.clone()), None));
+		assert_noop!(
+			RewardsPallet::claim_rewards_for(RuntimeOrigin::signed(proxy), operator),
+			Error::<Runtime>::NotClaimProxy
+		);
+	});
+}
+
+#[test]
+fn claim_for_many_skips_failed_claims() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let asset = Asset::Custom(1);
+		let missing_asset = Asset::Custom(9);
+		setup_vault(&account, 1, asset);
+		run_to_block(1_100);
+
+		assert_noop!(
+			RewardsPallet::claim_for_many(
+				RuntimeOrigin::signed(mock_pub_key(3)),
+				BoundedVec::default()
+			),
+			Error::<Runtime>::EmptyClaimBatch
+		);
+
+		let balance = Balances::free_balance(&account);
+		let rewards = RewardsPallet::calculate_rewards(&account, asset).unwrap();
+		assert_ok!(RewardsPallet::claim_for_many(
+			RuntimeOrigin::signed(mock_pub_key(3)),
+			BoundedVec::try_from(vec![(account.clone(), missing_asset), (account.clone(), asset)])
+				.unwrap()
+		));
+
+		assert_eq!(Balances::free_balance(&account), balance + rewards);
+		System::assert_has_event(RuntimeEvent::RewardsPallet(Event::BatchClaimFailed {
+			account: account.clone(),
+			asset: missing_asset,
+			error: DispatchError::from(Error::<Runtime>::AssetNotInVault),
+		}));
+		System::assert_has_event(RuntimeEvent::RewardsPallet(Event::RewardsClaimed {
+			account,
+			asset,
+			amount: rewards,
+		}));
+	});
+}
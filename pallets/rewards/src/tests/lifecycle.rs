@@ -0,0 +1,253 @@
+//! Tests for the sunset, settlement and closure of reward vaults.
+
+use crate::{
+	AssetAction, AssetLookupRewardVaults, Error, Event, RewardConfigForAssetVault,
+	RewardConfigStorage, RewardVaults, RewardVaultsPotAccount, TotalRewardVaultDeposit,
+	TotalRewardVaultScore, UserClaimedReward, VaultSettlementCursor, VaultSunsetStatus,
+	VaultSunsets, mock::*, tests::reward_calc::setup_test_env,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Get},
+};
+use sp_runtime::{DispatchError, Perbill};
+use tangle_primitives::{rewards::UserDepositWithLocks, services::Asset};
+
+const EIGHTEEN_DECIMALS: u128 = 1_000_000_000_000_000_000;
+const MOCK_DEPOSIT: u128 = 100_000 * EIGHTEEN_DECIMALS;
+const MOCK_POT_FUNDING: u128 = 1_000_000 * EIGHTEEN_DECIMALS;
+
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+	}
+}
+
+fn reward_config() -> RewardConfigForAssetVault<u128> {
+	RewardConfigForAssetVault {
+		apy: Perbill::from_percent(10),
+		deposit_cap: 1_000_000 * EIGHTEEN_DECIMALS,
+		incentive_cap: 10_000 * EIGHTEEN_DECIMALS,
+		boost_multiplier: Some(1),
+	}
+}
+
+pub fn setup_vault(account: &AccountId, vault_id: u32, asset: Asset<u128>) {
+	setup_test_env();
+
+	assert_ok!(RewardsPallet::create_reward_vault(
+		RuntimeOrigin::root(),
+		vault_id,
+		reward_config()
+	));
+	assert_ok!(RewardsPallet::manage_asset_reward_vault(
+		RuntimeOrigin::root(),
+		vault_id,
+		asset,
+		AssetAction::Add,
+	));
+
+	MOCK_DELEGATION_INFO.with(|m| {
+		m.borrow_mut().deposits.insert(
+			(account.clone(), asset),
+			UserDepositWithLocks { unlocked_amount: MOCK_DEPOSIT, amount_with_locks: None },
+		);
+	});
+	TotalRewardVaultDeposit::<Runtime>::insert(vault_id, MOCK_DEPOSIT);
+	TotalRewardVaultScore::<Runtime>::insert(vault_id, MOCK_DEPOSIT);
+	UserClaimedReward::<Runtime>::insert(account, vault_id, (0, 0));
+
+	let pot_account = RewardsPallet::reward_vaults_pot_account(vault_id).unwrap();
+	Balances::make_free_balance_be(&pot_account, MOCK_POT_FUNDING);
+}
+
+#[test]
+fn schedule_vault_sunset_validates_arguments() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+
+		assert_noop!(
+			RewardsPallet::schedule_vault_sunset(RuntimeOrigin::signed(account), 1, 1500, None),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 7, 1500, None),
+			Error::<Runtime>::VaultNotFound
+		);
+		assert_noop!(
+			RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1000, None),
+			Error::<Runtime>::InvalidSunsetBlock
+		);
+		assert_noop!(
+			RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, Some(1)),
+			Error::<Runtime>::InvalidSuccessorVault
+		);
+		assert_noop!(
+			RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, Some(7)),
+			Error::<Runtime>::InvalidSuccessorVault
+		);
+
+		assert_ok!(RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, None));
+		System::assert_last_event(RuntimeEvent::RewardsPallet(Event::VaultSunsetScheduled {
+			vault_id: 1,
+			sunset_block: 1500,
+			successor: None,
+		}));
+
+		assert_noop!(
+			RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1600, None),
+			Error::<Runtime>::VaultSunsetAlreadyScheduled
+		);
+		assert_noop!(
+			RewardsPallet::manage_asset_reward_vault(
+				RuntimeOrigin::root(),
+				1,
+				Asset::Custom(2),
+				AssetAction::Add,
+			),
+			Error::<Runtime>::VaultIsSunsetting
+		);
+	});
+}
+
+#[test]
+fn rewards_stop_accruing_after_sunset_block() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		assert_ok!(RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, None));
+
+		run_to_block(1500);
+		let at_sunset = RewardsPallet::calculate_rewards(&account, asset).unwrap();
+		assert!(at_sunset > 0);
+
+		run_to_block(2000);
+		assert_eq!(RewardsPallet::calculate_rewards(&account, asset).unwrap(), at_sunset);
+	});
+}
+
+#[test]
+fn close_reward_vault_settles_depositors_and_returns_pot_funds() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		assert_ok!(RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, None));
+
+		run_to_block(1500);
+		assert_noop!(
+			RewardsPallet::close_reward_vault(RuntimeOrigin::signed(mock_pub_key(2)), 1, 10),
+			Error::<Runtime>::VaultSunsetNotReached
+		);
+
+		run_to_block(1501);
+		assert_noop!(
+			RewardsPallet::close_reward_vault(RuntimeOrigin::signed(mock_pub_key(2)), 1, 0),
+			Error::<Runtime>::InvalidSettlementLimit
+		);
+		let expected_reward = RewardsPallet::calculate_rewards(&account, asset).unwrap();
+		let initial_balance = Balances::free_balance(&account);
+		let recipient = VaultFundsRecipient::get();
+
+		assert_ok!(RewardsPallet::close_reward_vault(
+			RuntimeOrigin::signed(mock_pub_key(2)),
+			1,
+			10
+		));
+
+		assert_eq!(Balances::free_balance(&account), initial_balance + expected_reward);
+		assert_eq!(Balances::free_balance(&recipient), MOCK_POT_FUNDING - expected_reward);
+		System::assert_has_event(RuntimeEvent::RewardsPallet(Event::VaultRewardsSettled {
+			vault_id: 1,
+			account: account.clone(),
+			amount: expected_reward,
+		}));
+		System::assert_last_event(RuntimeEvent::RewardsPallet(Event::RewardVaultClosed {
+			vault_id: 1,
+			recipient,
+			amount: MOCK_POT_FUNDING - expected_reward,
+		}));
+
+		assert!(RewardConfigStorage::<Runtime>::get(1).is_none());
+		assert!(RewardVaults::<Runtime>::get(1).is_none());
+		assert!(RewardVaultsPotAccount::<Runtime>::get(1).is_none());
+		assert!(VaultSunsets::<Runtime>::get(1).is_none());
+		assert!(AssetLookupRewardVaults::<Runtime>::get(asset).is_none());
+		assert!(UserClaimedReward::<Runtime>::get(&account, 1).is_none());
+	});
+}
+
+#[test]
+fn close_reward_vault_migrates_assets_and_scores_to_successor() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		assert_ok!(RewardsPallet::create_reward_vault(RuntimeOrigin::root(), 2, reward_config()));
+		TotalRewardVaultDeposit::<Runtime>::insert(2, MOCK_DEPOSIT);
+		TotalRewardVaultScore::<Runtime>::insert(2, MOCK_DEPOSIT);
+		assert_ok!(RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, Some(2)));
+
+		run_to_block(1501);
+		// a single entry is scanned, so the settlement resumes in a second call
+		assert_ok!(RewardsPallet::close_reward_vault(RuntimeOrigin::signed(mock_pub_key(2)), 1, 1));
+		assert_eq!(VaultSunsets::<Runtime>::get(1).unwrap().status, VaultSunsetStatus::Settling);
+		assert!(VaultSettlementCursor::<Runtime>::get(1).is_some());
+		assert_noop!(
+			RewardsPallet::cancel_vault_sunset(RuntimeOrigin::root(), 1),
+			Error::<Runtime>::VaultSettlementInProgress
+		);
+
+		assert_eq!(AssetLookupRewardVaults::<Runtime>::get(asset), Some(2));
+		assert_eq!(RewardVaults::<Runtime>::get(2), Some(vec![asset]));
+		assert_eq!(TotalRewardVaultDeposit::<Runtime>::get(2), 2 * MOCK_DEPOSIT);
+		assert_eq!(TotalRewardVaultScore::<Runtime>::get(2), 2 * MOCK_DEPOSIT);
+		assert_eq!(UserClaimedReward::<Runtime>::get(&account, 2), Some((1500, 0)));
+
+		assert_ok!(RewardsPallet::close_reward_vault(
+			RuntimeOrigin::signed(mock_pub_key(2)),
+			1,
+			10
+		));
+		assert!(RewardConfigStorage::<Runtime>::get(1).is_none());
+		assert!(VaultSettlementCursor::<Runtime>::get(1).is_none());
+		assert!(RewardConfigStorage::<Runtime>::get(2).is_some());
+
+		// the depositor keeps accruing in the successor from the sunset block
+		run_to_block(2000);
+		assert!(RewardsPallet::calculate_rewards(&account, asset).unwrap() > 0);
+	});
+}
+
+#[test]
+fn cancel_vault_sunset_works() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		setup_vault(&account, 1, Asset::Custom(1));
+
+		assert_noop!(
+			RewardsPallet::cancel_vault_sunset(RuntimeOrigin::root(), 1),
+			Error::<Runtime>::VaultSunsetNotScheduled
+		);
+		assert_ok!(RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, None));
+		assert_noop!(
+			RewardsPallet::cancel_vault_sunset(RuntimeOrigin::signed(account), 1),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(RewardsPallet::cancel_vault_sunset(RuntimeOrigin::root(), 1));
+		System::assert_last_event(RuntimeEvent::RewardsPallet(Event::VaultSunsetCancelled {
+			vault_id: 1,
+		}));
+		assert!(VaultSunsets::<Runtime>::get(1).is_none());
+
+		run_to_block(1501);
+		assert_noop!(
+			RewardsPallet::close_reward_vault(RuntimeOrigin::signed(mock_pub_key(2)), 1, 10),
+			Error::<Runtime>::VaultSunsetNotScheduled
+		);
+	});
+}
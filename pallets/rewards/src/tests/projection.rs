@@ -0,0 +1,195 @@
+//! Tests for the reward projections exposed through the runtime API.
+
+use crate::{
+	DecayRate, DecayStartPeriod, Error, TotalRewardVaultDeposit, TotalRewardVaultScore,
+	UserClaimedReward,
+	mock::*,
+	tests::lifecycle::{run_to_block, setup_vault},
+};
+use frame_support::assert_err;
+use sp_runtime::Perbill;
+use tangle_primitives::{
+	rewards::UserDepositWithLocks,
+	services::Asset,
+	types::rewards::{LockInfo, LockMultiplier},
+};
+
+const EIGHTEEN_DECIMALS: u128 = 1_000_000_000_000_000_000;
+const MOCK_DEPOSIT: u128 = 100_000 * EIGHTEEN_DECIMALS;
+
+/// Deposits `amount` for `account` the way the delegation manager records it.
+fn record_deposit(
+	account: &AccountId,
+	asset: Asset<u128>,
+	amount: u128,
+	locks: Option<Vec<LockInfo<u128, u64>>>,
+) {
+	let score = locks.as_ref().map_or(amount, |locks| {
+		locks
+			.iter()
+			.map(|lock| lock.amount * lock.lock_multiplier.value() as u128)
+			.sum()
+	});
+	MOCK_DELEGATION_INFO.with(|m| {
+		m.borrow_mut().deposits.insert(
+			(account.clone(), asset),
+			UserDepositWithLocks {
+				unlocked_amount: if locks.is_some() { 0 } else { amount },
+				amount_with_locks: locks,
+			},
+		);
+	});
+	TotalRewardVaultDeposit::<Runtime>::mutate(1, |total| *total += amount);
+	TotalRewardVaultScore::<Runtime>::mutate(1, |total| *total += score);
+	UserClaimedReward::<Runtime>::insert(account, 1, (System::block_number(), 0));
+}
+
+#[test]
+fn projection_matches_claimed_rewards() {
+	new_test_ext().execute_with(|| {
+		let asset = Asset::Custom(1);
+		setup_vault(&mock_pub_key(1), 1, asset);
+		let amount = 50_000 * EIGHTEEN_DECIMALS;
+
+		let projection = RewardsPallet::project_rewards(asset, amount, None, 100).unwrap();
+		assert!(projection.rewards > 0);
+		assert_eq!(projection.rewards_per_block, projection.rewards / 100);
+
+		let account = mock_pub_key(2);
+		record_deposit(&account, asset, amount, None);
+		run_to_block(System::block_number() + 100);
+		assert_eq!(RewardsPallet::calculate_rewards(&account, asset).unwrap(), projection.rewards);
+	});
+}
+
+#[test]
+fn projection_of_locked_deposit_matches_claimed_rewards() {
+	new_test_ext().execute_with(|| {
+		let asset = Asset::Custom(1);
+		setup_vault(&mock_pub_key(1), 1, asset);
+		let amount = 50_000 * EIGHTEEN_DECIMALS;
+
+		let locked =
+			RewardsPallet::project_rewards(asset, amount, Some(LockMultiplier::ThreeMonths), 100)
+				.unwrap();
+		let unlocked = RewardsPallet::project_rewards(asset, amount, None, 100).unwrap();
+		assert!(locked.rewards > unlocked.rewards);
+
+		let account = mock_pub_key(2);
+		let expiry_block = System::block_number() + LockMultiplier::ThreeMonths.get_blocks() as u64;
+		record_deposit(
+			&account,
+			asset,
+			amount,
+			Some(vec![LockInfo {
+				amount,
+				lock_multiplier: LockMultiplier::ThreeMonths,
+				expiry_block,
+			}]),
+		);
+		run_to_block(System::block_number() + 100);
+		assert_eq!(RewardsPallet::calculate_rewards(&account, asset).unwrap(), locked.rewards);
+	});
+}
+
+#[test]
+fn projection_requires_reward_vault() {
+	new_test_ext().execute_with(|| {
+		assert_err!(
+			RewardsPallet::project_rewards(Asset::Custom(9), 100, None, 100),
+			Error::<Runtime>::AssetNotInVault
+		);
+		assert_err!(RewardsPallet::vault_apy(Asset::Custom(9)), Error::<Runtime>::AssetNotInVault);
+	});
+}
+
+#[test]
+fn vault_apy_applies_deposit_cap_and_decay() {
+	new_test_ext().execute_with(|| {
+		let asset = Asset::Custom(1);
+		setup_vault(&mock_pub_key(1), 1, asset);
+		DecayStartPeriod::<Runtime>::set(1_000);
+		DecayRate::<Runtime>::set(Perbill::from_percent(5));
+
+		let apy = RewardsPallet::vault_apy(asset).unwrap();
+		assert_eq!(apy.configured_apy, Perbill::from_percent(10));
+		// the vault holds a tenth of its deposit cap
+		assert_eq!(apy.effective_apy, Perbill::from_percent(1));
+		assert_eq!(
+			apy.decayed_apy,
+			RewardsPallet::calculate_decay_factor(1_001, 0) * Perbill::from_percent(1)
+		);
+		assert!(apy.decayed_apy < apy.effective_apy);
+		assert_eq!(apy.total_deposit, MOCK_DEPOSIT);
+
+		// below the incentive cap unlocked deposits earn nothing
+		TotalRewardVaultDeposit::<Runtime>::insert(1, EIGHTEEN_DECIMALS);
+		assert_eq!(RewardsPallet::vault_apy(asset).unwrap().deposit_apy, Perbill::zero());
+	});
+}
+
+#[test]
+fn reward_breakdown_splits_rewards_by_lock() {
+	new_test_ext().execute_with(|| {
+		let asset = Asset::Custom(1);
+		let account = mock_pub_key(1);
+		setup_vault(&account, 1, asset);
+		let lock = LockInfo {
+			amount: MOCK_DEPOSIT / 2,
+			lock_multiplier: LockMultiplier::TwoMonths,
+			expiry_block: 1_000_000,
+		};
+		MOCK_DELEGATION_INFO.with(|m| {
+			m.borrow_mut().deposits.insert(
+				(account.clone(), asset),
+				UserDepositWithLocks {
+					unlocked_amount: MOCK_DEPOSIT / 2,
+					amount_with_locks: Some(vec![lock.clone()]),
+				},
+			);
+		});
+		TotalRewardVaultScore::<Runtime>::insert(1, MOCK_DEPOSIT / 2 + MOCK_DEPOSIT);
+		run_to_block(1_100);
+
+		let breakdown = RewardsPallet::reward_breakdown(&account);
+		assert_eq!(breakdown.len(), 1);
+		let entry = &breakdown[0];
+		assert_eq!(entry.asset, asset);
+		assert_eq!(entry.unlocked_amount, MOCK_DEPOSIT / 2);
+		assert_eq!(entry.locks.len(), 1);
+		assert_eq!(entry.locks[0].amount, lock.amount);
+		assert_eq!(entry.locks[0].multiplier, 2);
+		assert!(entry.locks[0].rewards > entry.unlocked_rewards);
+		assert_eq!(entry.total_rewards, entry.unlocked_rewards + entry.locks[0].rewards);
+		assert_eq!(entry.total_rewards, RewardsPallet::calculate_rewards(&account, asset).unwrap());
+
+		assert!(RewardsPallet::reward_breakdown(&mock_pub_key(2)).is_empty());
+	});
+}
+
+#[test]
+fn blocks_until_decay_counts_from_last_claim() {
+	new_test_ext().execute_with(|| {
+		let asset = Asset::Custom(1);
+		let account = mock_pub_key(1);
+		setup_vault(&account, 1, asset);
+		UserClaimedReward::<Runtime>::insert(&account, 1, (1_000, 0));
+		DecayStartPeriod::<Runtime>::set(500);
+
+		// decay is disabled without a decay rate
+		assert_eq!(RewardsPallet::blocks_until_decay(&account, asset), Ok(None));
+
+		DecayRate::<Runtime>::set(Perbill::from_percent(5));
+		assert_eq!(RewardsPallet::blocks_until_decay(&account, asset), Ok(Some(501)));
+
+		run_to_block(1_500);
+		assert_eq!(RewardsPallet::blocks_until_decay(&account, asset), Ok(Some(1)));
+
+		run_to_block(1_501);
+		assert_eq!(RewardsPallet::blocks_until_decay(&account, asset), Ok(None));
+		assert_eq!(
+			RewardsPallet::calculate_decay_factor(1_501, 1_000),
+			RewardsPallet::calculate_decay_factor(2_000, 1_000)
+		);
+	});
+}
@@ -0,0 +1,313 @@
+//! Tests for reward streams paying out additional assets to vault depositors.
+
+use crate::{
+	AssetAction, Error, Event, RewardStreams, RewardVaultsPotAccount,
+	mock::*,
+	tests::lifecycle::{run_to_block, setup_vault},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, fungibles::Mutate},
+};
+use sp_runtime::DispatchError;
+use tangle_primitives::{services::Asset, traits::rewards::RewardsManager};
+
+const REWARD_ASSET: u128 = 7;
+
+fn stake(account: &AccountId, asset: Asset<u128>, amount: u128) {
+	assert_ok!(RewardsPallet::record_delegate(account, &mock_pub_key(9), asset, amount, None));
+}
+
+fn setup_reward_asset(funder: &AccountId) {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), REWARD_ASSET, funder.clone(), true, 1));
+	assert_ok!(<Assets as Mutate<AccountId>>::mint_into(REWARD_ASSET, funder, 10_000));
+}
+
+fn create_funded_stream(funder: &AccountId, amount: u128) {
+	assert_ok!(RewardsPallet::create_reward_stream(
+		RuntimeOrigin::root(),
+		1,
+		Asset::Custom(REWARD_ASSET),
+		10,
+		1100,
+		2000,
+		funder.clone(),
+	));
+	assert_ok!(RewardsPallet::fund_reward_stream(
+		RuntimeOrigin::signed(funder.clone()),
+		1,
+		0,
+		amount
+	));
+}
+
+#[test]
+fn create_reward_stream_validates_arguments() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let funder = mock_pub_key(3);
+		setup_vault(&account, 1, Asset::Custom(1));
+		let create = |origin, vault_id, reward_asset, reward_per_block, start_block, end_block| {
+			RewardsPallet::create_reward_stream(
+				origin,
+				vault_id,
+				reward_asset,
+				reward_per_block,
+				start_block,
+				end_block,
+				mock_pub_key(3),
+			)
+		};
+		let reward_asset = Asset::Custom(REWARD_ASSET);
+
+		assert_noop!(
+			create(RuntimeOrigin::signed(funder.clone()), 1, reward_asset, 10, 1100, 2000),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			create(RuntimeOrigin::root(), 9, reward_asset, 10, 1100, 2000),
+			Error::<Runtime>::VaultNotFound
+		);
+		assert_noop!(
+			create(RuntimeOrigin::root(), 1, Asset::Erc20(mock_address(1)), 10, 1100, 2000),
+			Error::<Runtime>::UnsupportedRewardAsset
+		);
+		assert_noop!(
+			create(RuntimeOrigin::root(), 1, reward_asset, 0, 1100, 2000),
+			Error::<Runtime>::InvalidRewardStream
+		);
+		assert_noop!(
+			create(RuntimeOrigin::root(), 1, reward_asset, 10, 2000, 2000),
+			Error::<Runtime>::InvalidRewardStream
+		);
+		assert_noop!(
+			create(RuntimeOrigin::root(), 1, reward_asset, 10, 500, 1000),
+			Error::<Runtime>::InvalidRewardStream
+		);
+
+		assert_ok!(create(RuntimeOrigin::root(), 1, reward_asset, 10, 1100, 2000));
+		System::assert_last_event(RuntimeEvent::RewardsPallet(Event::RewardStreamCreated {
+			vault_id: 1,
+			stream_id: 0,
+			reward_asset,
+			reward_per_block: 10,
+			start_block: 1100,
+			end_block: 2000,
+			funder: funder.clone(),
+		}));
+
+		assert_noop!(
+			RewardsPallet::fund_reward_stream(RuntimeOrigin::signed(account), 1, 0, 100),
+			Error::<Runtime>::NotRewardStreamFunder
+		);
+		assert_noop!(
+			RewardsPallet::fund_reward_stream(RuntimeOrigin::signed(funder), 1, 1, 100),
+			Error::<Runtime>::RewardStreamNotFound
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_pays_out_reward_streams() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let funder = mock_pub_key(3);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		stake(&account, asset, 1_000);
+		setup_reward_asset(&funder);
+		create_funded_stream(&funder, 5_000);
+
+		// the stream emits from block 1100, the account holds the whole vault stake
+		run_to_block(1300);
+		assert_ok!(RewardsPallet::claim_rewards_other(
+			RuntimeOrigin::signed(account.clone()),
+			account.clone(),
+			asset
+		));
+		assert_eq!(Assets::balance(REWARD_ASSET, &account), 2_000);
+		System::assert_has_event(RuntimeEvent::RewardsPallet(Event::StreamRewardsClaimed {
+			account: account.clone(),
+			vault_id: 1,
+			stream_id: 0,
+			reward_asset: Asset::Custom(REWARD_ASSET),
+			amount: 2_000,
+		}));
+
+		// the payout is capped at the funds deposited for the stream
+		run_to_block(2000);
+		assert_ok!(RewardsPallet::claim_rewards_other(
+			RuntimeOrigin::signed(account.clone()),
+			account.clone(),
+			asset
+		));
+		assert_eq!(Assets::balance(REWARD_ASSET, &account), 5_000);
+		assert_eq!(RewardStreams::<Runtime>::get(1)[0].distributed, 5_000);
+	});
+}
+
+#[test]
+fn cancel_reward_stream_refunds_undistributed_funds() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let funder = mock_pub_key(3);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		stake(&account, asset, 1_000);
+		setup_reward_asset(&funder);
+		create_funded_stream(&funder, 5_000);
+
+		run_to_block(1300);
+		assert_ok!(RewardsPallet::claim_rewards_other(
+			RuntimeOrigin::signed(account.clone()),
+			account.clone(),
+			asset
+		));
+
+		assert_noop!(
+			RewardsPallet::cancel_reward_stream(RuntimeOrigin::signed(funder.clone()), 1, 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(RewardsPallet::cancel_reward_stream(RuntimeOrigin::root(), 1, 0));
+		System::assert_last_event(RuntimeEvent::RewardsPallet(Event::RewardStreamCancelled {
+			vault_id: 1,
+			stream_id: 0,
+			refunded: 3_000,
+		}));
+		assert_eq!(Assets::balance(REWARD_ASSET, &funder), 8_000);
+		assert!(RewardStreams::<Runtime>::get(1).is_empty());
+	});
+}
+
+#[test]
+fn close_reward_vault_settles_and_refunds_reward_streams() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let funder = mock_pub_key(3);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		stake(&account, asset, 1_000);
+		setup_reward_asset(&funder);
+		create_funded_stream(&funder, 5_000);
+		assert_ok!(RewardsPallet::schedule_vault_sunset(RuntimeOrigin::root(), 1, 1500, None));
+
+		run_to_block(1501);
+		assert_ok!(RewardsPallet::close_reward_vault(
+			RuntimeOrigin::signed(mock_pub_key(2)),
+			1,
+			10
+		));
+
+		// the stream emitted from block 1100 to the sunset block
+		assert_eq!(Assets::balance(REWARD_ASSET, &account), 4_000);
+		assert_eq!(Assets::balance(REWARD_ASSET, &funder), 6_000);
+		assert!(RewardStreams::<Runtime>::get(1).is_empty());
+	});
+}
+
+#[test]
+fn stake_added_before_claim_only_earns_from_then() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let late_account = mock_pub_key(2);
+		let funder = mock_pub_key(3);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		stake(&account, asset, 1_000);
+		setup_reward_asset(&funder);
+		create_funded_stream(&funder, 10_000);
+
+		// the late account stakes as much as the account right before both claim
+		run_to_block(1500);
+		stake(&late_account, asset, 1_000);
+		run_to_block(1600);
+
+		assert_ok!(RewardsPallet::claim_rewards_other(
+			RuntimeOrigin::signed(account.clone()),
+			account.clone(),
+			asset
+		));
+		assert_eq!(Assets::balance(REWARD_ASSET, &account), 4_000 + 500);
+
+		RewardsPallet::payout_reward_streams(&late_account, 1);
+		assert_eq!(Assets::balance(REWARD_ASSET, &late_account), 500);
+	});
+}
+
+#[test]
+fn reward_streams_share_emissions_across_vault_assets() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let other_account = mock_pub_key(2);
+		let funder = mock_pub_key(3);
+		let asset = Asset::Custom(1);
+		let other_asset = Asset::Custom(2);
+		setup_vault(&account, 1, asset);
+		assert_ok!(RewardsPallet::manage_asset_reward_vault(
+			RuntimeOrigin::root(),
+			1,
+			other_asset,
+			AssetAction::Add,
+		));
+		stake(&account, asset, 1_000);
+		stake(&account, other_asset, 1_000);
+		stake(&other_account, asset, 2_000);
+		setup_reward_asset(&funder);
+		create_funded_stream(&funder, 5_000);
+
+		// claiming for one asset pays out the stake held in every asset of the vault
+		run_to_block(1300);
+		assert_ok!(RewardsPallet::claim_rewards_other(
+			RuntimeOrigin::signed(account.clone()),
+			account.clone(),
+			asset
+		));
+		assert_eq!(Assets::balance(REWARD_ASSET, &account), 1_000);
+
+		// removing stake settles what it earned so far
+		assert_ok!(RewardsPallet::record_undelegate(
+			&account,
+			&mock_pub_key(9),
+			other_asset,
+			1_000
+		));
+		run_to_block(1600);
+		RewardsPallet::payout_reward_streams(&account, 1);
+		assert_eq!(Assets::balance(REWARD_ASSET, &account), 1_000 + 1_000);
+		RewardsPallet::payout_reward_streams(&other_account, 1);
+		assert_eq!(Assets::balance(REWARD_ASSET, &other_account), 1_000 + 2_000);
+	});
+}
+
+#[test]
+fn native_reward_streams_are_funded_apart_from_the_vault_pot() {
+	new_test_ext().execute_with(|| {
+		let account = mock_pub_key(1);
+		let funder = mock_pub_key(3);
+		let asset = Asset::Custom(1);
+		setup_vault(&account, 1, asset);
+		stake(&account, asset, 1_000);
+		Balances::make_free_balance_be(&funder, 10_000);
+		let vault_pot = RewardVaultsPotAccount::<Runtime>::get(1).unwrap();
+		let vault_pot_balance = Balances::free_balance(&vault_pot);
+
+		assert_ok!(RewardsPallet::create_reward_stream(
+			RuntimeOrigin::root(),
+			1,
+			Asset::Custom(0),
+			10,
+			1100,
+			2000,
+			funder.clone(),
+		));
+		assert_ok!(RewardsPallet::fund_reward_stream(
+			RuntimeOrigin::signed(funder.clone()),
+			1,
+			0,
+			5_000
+		));
+
+		assert_eq!(Balances::free_balance(&vault_pot), vault_pot_balance);
+		assert_eq!(Balances::free_balance(&RewardsPallet::reward_stream_pot_account(1)), 5_000);
+	});
+}
@@ -15,14 +15,23 @@
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 use crate::Config;
 use frame_support::traits::Currency;
+use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::{Perbill, RuntimeDebug};
+use sp_runtime::{FixedU128, Perbill, RuntimeDebug};
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use tangle_primitives::services::Asset;
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+pub type RewardStreamOf<T> = RewardStream<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::AssetId,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+>;
+
 /// Configuration for rewards associated with a specific asset.
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, Eq, PartialEq)]
 pub struct RewardConfigForAssetVault<Balance> {
@@ -56,4 +65,65 @@ pub enum AssetAction {
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
 pub enum SubaccountType {
 	RewardPot,
+	RewardStreamPot,
+}
+
+/// Stage of a reward vault that is being shut down.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum VaultSunsetStatus {
+	/// The vault keeps accruing rewards until the sunset block.
+	Scheduled,
+	/// The sunset block has passed, the vault assets have been detached and depositors are being
+	/// settled.
+	Settling,
+}
+
+/// Sunset of a reward vault.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct VaultSunset<VaultId, BlockNumber> {
+	/// The last block for which the vault accrues rewards.
+	pub sunset_block: BlockNumber,
+	/// The vault that the assets and scores of this vault are migrated into, if any.
+	pub successor: Option<VaultId>,
+	/// The current stage of the sunset.
+	pub status: VaultSunsetStatus,
+}
+
+/// A stream emitting a reward asset to the depositors of a vault.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct RewardStream<AccountId, AssetId, Balance, BlockNumber> {
+	/// The ID of the stream, unique within its vault.
+	pub id: u32,
+	/// The asset paid out by the stream.
+	pub reward_asset: Asset<AssetId>,
+	/// The amount emitted per block, shared between the depositors of the vault.
+	pub reward_per_block: Balance,
+	/// The block from which the stream emits rewards.
+	pub start_block: BlockNumber,
+	/// The block at which the stream stops emitting rewards.
+	pub end_block: BlockNumber,
+	/// The account funding the stream.
+	pub funder: AccountId,
+	/// The total amount deposited into the stream pot of the vault for the stream.
+	pub funded: Balance,
+	/// The total amount emitted to the stakers of the vault so far, capped at `funded`.
+	pub accrued: Balance,
+	/// The total amount paid out by the stream.
+	pub distributed: Balance,
+	/// The amount emitted per unit of vault stake since the stream was created.
+	pub reward_per_share: FixedU128,
+	/// The block up to which emissions have been accrued into `reward_per_share`.
+	pub last_update_block: BlockNumber,
+}
+
+/// The position of an account in a reward stream.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct UserRewardStream<Balance> {
+	/// The ID of the stream.
+	pub stream_id: u32,
+	/// The `reward_per_share` of the stream applied to the account's stake when it last
+	/// changed or claimed, which the account has already been credited for.
+	pub debt: Balance,
+	/// The rewards the account has earned but not been paid yet.
+	pub owed: Balance,
 }
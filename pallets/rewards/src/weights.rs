@@ -51,6 +51,17 @@ pub trait WeightInfo {
 	fn update_apy_blocks() -> Weight;
     fn set_vault_metadata() -> Weight;
     fn remove_vault_metadata() -> Weight;
+    fn set_auto_compound() -> Weight;
+    fn schedule_vault_sunset() -> Weight;
+    fn cancel_vault_sunset() -> Weight;
+    fn close_reward_vault(n: u32) -> Weight;
+    fn create_reward_stream() -> Weight;
+    fn fund_reward_stream() -> Weight;
+    fn cancel_reward_stream() -> Weight;
+    fn set_reward_payee() -> Weight;
+    fn set_claim_proxy() -> Weight;
+    fn claim_rewards_for() -> Weight;
+    fn claim_for_many(n: u32) -> Weight;
 }
 
 /// Weight functions needed for rewards pallet.
@@ -86,16 +97,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-	/// Storage: `Rewards::RewardVaults` (r:1 w:0)
-	/// Proof: `Rewards::RewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Delegators` (r:2 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardConfigStorage` (r:2 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultScore` (r:2 w:1)
+	/// Proof: `Rewards::TotalRewardVaultScore` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultDeposit` (r:2 w:1)
+	/// Proof: `Rewards::TotalRewardVaultDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:0)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::ApyBlocks` (r:1 w:0)
+	/// Proof: `Rewards::ApyBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayStartPeriod` (r:1 w:0)
+	/// Proof: `Rewards::DecayStartPeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayRate` (r:1 w:0)
+	/// Proof: `Rewards::DecayRate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::AutoCompoundPreferences` (r:1 w:0)
+	/// Proof: `Rewards::AutoCompoundPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardPayees` (r:1 w:0)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:2 w:2)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreamStake` (r:2 w:1)
+	/// Proof: `Rewards::RewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardStreamStake` (r:2 w:1)
+	/// Proof: `Rewards::TotalRewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserRewardStreams` (r:2 w:2)
+	/// Proof: `Rewards::UserRewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:23 w:23)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Rewards::UserRewards` (r:1 w:1)
-	/// Proof: `Rewards::UserRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// The claim pays out the maximum of 10 reward streams of the vault and auto-compounds the
+    /// claimed rewards with a deposit and delegation.
     fn claim_rewards_other() -> Weight {
-        Weight::from_parts(128_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
+        Weight::from_parts(478_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(49))
+            .saturating_add(T::DbWeight::get().writes(34))
     }
 	/// Storage: `Rewards::RewardVaults` (r:1 w:1)
 	/// Proof: `Rewards::RewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -141,6 +186,161 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+    /// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::AutoCompoundPreferences` (r:0 w:1)
+	/// Proof: `Rewards::AutoCompoundPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn set_auto_compound() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:2 w:1)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn schedule_vault_sunset() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:1)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn cancel_vault_sunset() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:1)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSettlementCursor` (r:1 w:1)
+	/// Proof: `Rewards::VaultSettlementCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserClaimedReward` (r:n w:n)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Delegators` (r:n w:0)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:n w:n)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 100]`.
+    fn close_reward_vault(n: u32) -> Weight {
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(12))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(12))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+    }
+    /// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:0)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::NextRewardStreamId` (r:1 w:1)
+	/// Proof: `Rewards::NextRewardStreamId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:1 w:1)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn create_reward_stream() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:1 w:1)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    fn fund_reward_stream() -> Weight {
+        Weight::from_parts(70_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:1 w:1)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    fn cancel_reward_stream() -> Weight {
+        Weight::from_parts(70_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Storage: `Rewards::RewardPayees` (r:0 w:1)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn set_reward_payee() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::ClaimProxies` (r:0 w:1)
+	/// Proof: `Rewards::ClaimProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn set_claim_proxy() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::ClaimProxies` (r:1 w:0)
+	/// Proof: `Rewards::ClaimProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::PendingOperatorRewards` (r:1 w:1)
+	/// Proof: `Rewards::PendingOperatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardPayees` (r:1 w:0)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn claim_rewards_for() -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `Rewards::AssetLookupRewardVaults` (r:n w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Delegators` (r:2n w:n)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardConfigStorage` (r:2n w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultScore` (r:2n w:n)
+	/// Proof: `Rewards::TotalRewardVaultScore` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultDeposit` (r:2n w:n)
+	/// Proof: `Rewards::TotalRewardVaultDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserClaimedReward` (r:n w:n)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:n w:0)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::ApyBlocks` (r:n w:0)
+	/// Proof: `Rewards::ApyBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayStartPeriod` (r:n w:0)
+	/// Proof: `Rewards::DecayStartPeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayRate` (r:n w:0)
+	/// Proof: `Rewards::DecayRate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:n w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::AutoCompoundPreferences` (r:n w:0)
+	/// Proof: `Rewards::AutoCompoundPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardPayees` (r:n w:0)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:2n w:2n)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreamStake` (r:2n w:n)
+	/// Proof: `Rewards::RewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardStreamStake` (r:2n w:n)
+	/// Proof: `Rewards::TotalRewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserRewardStreams` (r:2n w:2n)
+	/// Proof: `Rewards::UserRewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Operators` (r:n w:n)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:23n w:23n)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 50]`.
+    /// Every claim pays out the maximum of 10 reward streams of its vault and auto-compounds
+    /// the claimed rewards with a deposit and delegation.
+    fn claim_for_many(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(468_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((49_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((34_u64).saturating_mul(n.into())))
+    }
 }
 
 // For backwards compatibility and tests
@@ -165,16 +365,50 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
-	/// Storage: `Rewards::RewardVaults` (r:1 w:1)
-	/// Proof: `Rewards::RewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `Rewards::AssetLookupRewardVaults` (r:1 w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Delegators` (r:2 w:1)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardConfigStorage` (r:2 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultScore` (r:2 w:1)
+	/// Proof: `Rewards::TotalRewardVaultScore` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultDeposit` (r:2 w:1)
+	/// Proof: `Rewards::TotalRewardVaultDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserClaimedReward` (r:1 w:1)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:0)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::ApyBlocks` (r:1 w:0)
+	/// Proof: `Rewards::ApyBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayStartPeriod` (r:1 w:0)
+	/// Proof: `Rewards::DecayStartPeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayRate` (r:1 w:0)
+	/// Proof: `Rewards::DecayRate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::AutoCompoundPreferences` (r:1 w:0)
+	/// Proof: `Rewards::AutoCompoundPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardPayees` (r:1 w:0)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:2 w:2)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreamStake` (r:2 w:1)
+	/// Proof: `Rewards::RewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardStreamStake` (r:2 w:1)
+	/// Proof: `Rewards::TotalRewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserRewardStreams` (r:2 w:2)
+	/// Proof: `Rewards::UserRewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Operators` (r:1 w:1)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:23 w:23)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Rewards::UserRewards` (r:1 w:1)
-	/// Proof: `Rewards::UserRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// The claim pays out the maximum of 10 reward streams of the vault and auto-compounds the
+    /// claimed rewards with a deposit and delegation.
     fn claim_rewards_other() -> Weight {
-        Weight::from_parts(135_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
+        Weight::from_parts(478_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(49))
+            .saturating_add(RocksDbWeight::get().writes(34))
     }
 	/// Storage: `Rewards::RewardVaults` (r:1 w:1)
 	/// Proof: `Rewards::RewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -220,4 +454,159 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+    /// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::AutoCompoundPreferences` (r:0 w:1)
+	/// Proof: `Rewards::AutoCompoundPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn set_auto_compound() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:2 w:1)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn schedule_vault_sunset() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:1)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn cancel_vault_sunset() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:1)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSettlementCursor` (r:1 w:1)
+	/// Proof: `Rewards::VaultSettlementCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserClaimedReward` (r:n w:n)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Delegators` (r:n w:0)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:n w:n)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 100]`.
+    fn close_reward_vault(n: u32) -> Weight {
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(12))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(12))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+    }
+    /// Storage: `Rewards::RewardConfigStorage` (r:1 w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:1 w:0)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::NextRewardStreamId` (r:1 w:1)
+	/// Proof: `Rewards::NextRewardStreamId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:1 w:1)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn create_reward_stream() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:1 w:1)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    fn fund_reward_stream() -> Weight {
+        Weight::from_parts(70_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:1 w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:1 w:1)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    fn cancel_reward_stream() -> Weight {
+        Weight::from_parts(70_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    /// Storage: `Rewards::RewardPayees` (r:0 w:1)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn set_reward_payee() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::ClaimProxies` (r:0 w:1)
+	/// Proof: `Rewards::ClaimProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn set_claim_proxy() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    /// Storage: `Rewards::ClaimProxies` (r:1 w:0)
+	/// Proof: `Rewards::ClaimProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::PendingOperatorRewards` (r:1 w:1)
+	/// Proof: `Rewards::PendingOperatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardPayees` (r:1 w:0)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn claim_rewards_for() -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    /// Storage: `Rewards::AssetLookupRewardVaults` (r:n w:0)
+	/// Proof: `Rewards::AssetLookupRewardVaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Delegators` (r:2n w:n)
+	/// Proof: `MultiAssetDelegation::Delegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardConfigStorage` (r:2n w:0)
+	/// Proof: `Rewards::RewardConfigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultScore` (r:2n w:n)
+	/// Proof: `Rewards::TotalRewardVaultScore` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardVaultDeposit` (r:2n w:n)
+	/// Proof: `Rewards::TotalRewardVaultDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserClaimedReward` (r:n w:n)
+	/// Proof: `Rewards::UserClaimedReward` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::VaultSunsets` (r:n w:0)
+	/// Proof: `Rewards::VaultSunsets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::ApyBlocks` (r:n w:0)
+	/// Proof: `Rewards::ApyBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayStartPeriod` (r:n w:0)
+	/// Proof: `Rewards::DecayStartPeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::DecayRate` (r:n w:0)
+	/// Proof: `Rewards::DecayRate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardVaultsPotAccount` (r:n w:0)
+	/// Proof: `Rewards::RewardVaultsPotAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::AutoCompoundPreferences` (r:n w:0)
+	/// Proof: `Rewards::AutoCompoundPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardPayees` (r:n w:0)
+	/// Proof: `Rewards::RewardPayees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreams` (r:2n w:2n)
+	/// Proof: `Rewards::RewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::RewardStreamStake` (r:2n w:n)
+	/// Proof: `Rewards::RewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::TotalRewardStreamStake` (r:2n w:n)
+	/// Proof: `Rewards::TotalRewardStreamStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Rewards::UserRewardStreams` (r:2n w:2n)
+	/// Proof: `Rewards::UserRewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `MultiAssetDelegation::Operators` (r:n w:n)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `System::Account` (r:23n w:23n)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 50]`.
+    /// Every claim pays out the maximum of 10 reward streams of its vault and auto-compounds
+    /// the claimed rewards with a deposit and delegation.
+    fn claim_for_many(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(468_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((49_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((34_u64).saturating_mul(n.into())))
+    }
 }
\ No newline at end of file
@@ -1,13 +1,19 @@
 use crate::{
-	BlueprintId, Config, DefaultHeartbeatInterval, DefaultHeartbeatThreshold,
-	DefaultSlashingWindow, Error, Event, InstanceId, Instances, NextUnappliedSlashIndex, Pallet,
+	BlueprintId, BlueprintSlashDestinations, Config, DefaultHeartbeatInterval,
+	DefaultHeartbeatThreshold, DefaultSlashingWindow, Error, Event, InstanceId, Instances,
+	NextUnappliedSlashIndex, Pallet, ServiceOperatorHeartbeats, ServiceSlashDestinations,
 	UnappliedSlashes,
 };
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::Percent;
+use sp_runtime::{
+	Percent,
+	traits::{Saturating, Zero},
+};
 use tangle_primitives::{
-	services::{ServiceBlueprint, UnappliedSlash},
+	services::{
+		AssetIdT, Constraints, Service, ServiceBlueprint, SlashDestination, UnappliedSlash,
+	},
 	traits::MultiAssetDelegationInfo,
 };
 
@@ -117,6 +123,109 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Records a heartbeat of `operator` in a service instance at `current_block`.
+	///
+	/// Heartbeats missed since the previous one count against the heartbeat threshold of the
+	/// service, and a heartbeat slash is scheduled once the operator has missed more than the
+	/// threshold within the slashing window.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint
+	/// * `blueprint_id` - The ID of the service blueprint
+	/// * `service_id` - The ID of the service instance
+	/// * `operator` - The operator sending the heartbeat
+	/// * `current_block` - The block the heartbeat is received in
+	///
+	/// # Errors
+	/// * [`Error::HeartbeatTooEarly`] - Not enough blocks have passed since the last heartbeat.
+	pub(crate) fn record_operator_heartbeat(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		blueprint_id: BlueprintId,
+		service_id: InstanceId,
+		operator: &T::AccountId,
+		current_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		// Get operator's heartbeat stats
+		let mut stats = ServiceOperatorHeartbeats::<T>::get((blueprint_id, service_id, operator));
+
+		// If this is the first heartbeat for this operator, initialize the stats
+		if stats.last_heartbeat_block.is_zero() {
+			stats.last_heartbeat_block = current_block.try_into().unwrap_or_default();
+			stats.last_check_block = current_block.try_into().unwrap_or_default();
+			stats.expected_heartbeats = 1;
+			stats.received_heartbeats = 1;
+		} else {
+			// Get the heartbeat interval from the QoS function
+			let heartbeat_interval =
+				Self::get_heartbeat_interval(blueprint, blueprint_id, service_id)?;
+
+			// Check if enough blocks have passed since the last heartbeat
+			let blocks_passed = current_block.saturating_sub(stats.last_heartbeat_block.into());
+			ensure!(blocks_passed >= heartbeat_interval, Error::<T>::HeartbeatTooEarly);
+
+			// Calculate how many heartbeats were expected since the last one
+			let expected_since_last =
+				(blocks_passed / heartbeat_interval).try_into().unwrap_or_default();
+
+			// Update the stats
+			stats.expected_heartbeats =
+				stats.expected_heartbeats.saturating_add(expected_since_last);
+			stats.received_heartbeats = stats.received_heartbeats.saturating_add(1);
+			stats.last_heartbeat_block = current_block.try_into().unwrap_or_default();
+
+			// Get the heartbeat threshold from the QoS function
+			let heartbeat_threshold =
+				Self::get_heartbeat_threshold(blueprint, blueprint_id, service_id)?;
+			if stats.expected_heartbeats > heartbeat_threshold.into() {
+				// Calculate how many heartbeats were missed
+				let missed = stats.expected_heartbeats.saturating_sub(stats.received_heartbeats);
+				if missed > heartbeat_threshold.into() {
+					// Get the slashing window from the QoS function
+					let slashing_window =
+						Self::get_slashing_window(blueprint, blueprint_id, service_id)?;
+					let slashing_block = stats
+						.last_heartbeat_block
+						.saturating_add(slashing_window.try_into().unwrap_or_default());
+
+					// If we're within the slashing window, schedule a slash
+					if current_block <= slashing_block.into() {
+						// Calculate slash percentage based on missed heartbeats
+						let slash_percent = Percent::from_percent(50); // TODO: Calculate based on missed heartbeats
+						Self::create_heartbeat_slash(
+							blueprint_id,
+							service_id,
+							operator.clone(),
+							slash_percent,
+						);
+					}
+				}
+			}
+		}
+
+		// Update the operator's heartbeat stats
+		ServiceOperatorHeartbeats::<T>::insert((blueprint_id, service_id, operator.clone()), stats);
+
+		Ok(())
+	}
+
+	/// Resolves where the funds of a slash against an operator of `service` are routed.
+	///
+	/// The service policy takes precedence over the blueprint policy, which defaults to the
+	/// treasury when neither is set.
+	///
+	/// # Parameters
+	/// * `service` - The slashed service
+	/// * `reporter` - The account reporting the slash, if any
+	pub(crate) fn slash_destination_for<C: Constraints, AssetId: AssetIdT>(
+		service: &Service<C, T::AccountId, BlockNumberFor<T>, AssetId>,
+		reporter: Option<T::AccountId>,
+	) -> SlashDestination<T::AccountId> {
+		ServiceSlashDestinations::<T>::get(service.id)
+			.or_else(|| BlueprintSlashDestinations::<T>::get(service.blueprint))
+			.unwrap_or_default()
+			.resolve(reporter, service.owner.clone())
+	}
+
 	/// Helper function to create and store a heartbeat slash
 	pub(crate) fn create_heartbeat_slash(
 		blueprint_id: BlueprintId,
@@ -131,6 +240,9 @@ impl<T: Config> Pallet<T> {
 			service_id,
 			operator: operator.clone(),
 			slash_percent,
+			destination: Instances::<T>::get(service_id)
+				.map(|service| Self::slash_destination_for(&service, None))
+				.unwrap_or_default(),
 		};
 
 		// Store the slash for later processing
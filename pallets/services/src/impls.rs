@@ -1,8 +1,13 @@
 use super::*;
 use crate::types::BalanceOf;
 use frame_support::traits::OneSessionHandler;
+use sp_runtime::Percent;
 use sp_std::{vec, vec::Vec};
-use tangle_primitives::{BlueprintId, services::Constraints, traits::ServiceManager};
+use tangle_primitives::{
+	BlueprintId, InstanceId,
+	services::Constraints,
+	traits::{ServiceManager, ServiceOperatorManager},
+};
 
 #[cfg(feature = "runtime-benchmarks")]
 use tangle_primitives::rewards::{AssetType, UserDepositWithLocks};
@@ -82,6 +87,57 @@ impl<T: crate::Config> ServiceManager<T::AccountId, BalanceOf<T>> for crate::Pal
 	}
 }
 
+impl<T: crate::Config> ServiceOperatorManager<T::AccountId> for crate::Pallet<T> {
+	fn service_operators(service_id: InstanceId) -> Vec<T::AccountId> {
+		Instances::<T>::get(service_id).map_or(vec![], |service| {
+			service.operator_security_commitments.into_iter().map(|(op, _)| op).collect()
+		})
+	}
+
+	/// Heartbeats arriving before the heartbeat interval has passed are ignored
+	fn record_heartbeat(service_id: InstanceId, operator: &T::AccountId) -> DispatchResult {
+		let service = Instances::<T>::get(service_id)?;
+		ensure!(
+			service.operator_security_commitments.iter().any(|(op, _)| op == operator),
+			Error::<T>::NotRegistered
+		);
+		let (_, blueprint) = Self::blueprints(service.blueprint)?;
+		let current_block = frame_system::Pallet::<T>::block_number();
+
+		match Self::record_operator_heartbeat(
+			&blueprint,
+			service.blueprint,
+			service_id,
+			operator,
+			current_block,
+		) {
+			Err(e) if e == Error::<T>::HeartbeatTooEarly.into() => Ok(()),
+			result => result,
+		}
+	}
+
+	fn report_misbehavior(
+		service_id: InstanceId,
+		operator: &T::AccountId,
+		slash_percent: Percent,
+	) -> DispatchResult {
+		let service = Instances::<T>::get(service_id)?;
+		ensure!(!slash_percent.is_zero(), Error::<T>::InvalidSlashPercentage);
+		ensure!(
+			service.operator_security_commitments.iter().any(|(op, _)| op == operator),
+			Error::<T>::OffenderNotOperator
+		);
+
+		Self::create_heartbeat_slash(
+			service.blueprint,
+			service_id,
+			operator.clone(),
+			slash_percent,
+		);
+		Ok(())
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct BenchmarkingOperatorDelegationManager<T: crate::Config, Balance: Default>(
 	core::marker::PhantomData<(T, Balance)>,
@@ -42,6 +42,7 @@ use tangle_primitives::{
 
 pub mod functions;
 mod impls;
+pub mod migrations;
 mod payment_processing;
 mod rpc;
 pub mod types;
@@ -96,6 +97,9 @@ pub mod module {
 				PricingModel = PricingModel<BlockNumberFor<Self>, BalanceOf<Self>>,
 			>;
 
+		/// The on-chain credit ledger from which blueprints accepting credits are paid.
+		type CreditsLedger: tangle_primitives::traits::CreditsLedger<Self::AccountId, BalanceOf<Self>>;
+
 		/// PalletId used for deriving the AccountId and EVM address.
 		/// This account receives slashed assets upon slash event processing.
 		#[pallet::constant]
@@ -530,6 +534,10 @@ pub mod module {
 		SubscriptionNotValid,
 		/// Service not owned by caller
 		ServiceNotOwned,
+		/// The credit conversion rate of a credit payment policy must be greater than zero
+		InvalidCreditConversionRate,
+		/// The payer holds neither enough credits nor enough of the payment asset
+		InsufficientCredits,
 	}
 
 	#[pallet::event]
@@ -778,9 +786,49 @@ pub mod module {
 			/// The new default heartbeat slashing window.
 			window: BlockNumberFor<T>,
 		},
+		/// The slash destination policy of a blueprint has been updated.
+		BlueprintSlashDestinationUpdated {
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The new slash destination policy.
+			policy: SlashDestinationPolicy,
+		},
+		/// The slash destination policy of a service has been updated.
+		ServiceSlashDestinationUpdated {
+			/// The ID of the service.
+			service_id: u64,
+			/// The new slash destination policy, `None` if the blueprint policy applies.
+			policy: Option<SlashDestinationPolicy>,
+		},
+		/// The credit payment policy of a blueprint has been updated.
+		BlueprintCreditPolicyUpdated {
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The new credit payment policy, `None` if the blueprint no longer accepts credits.
+			policy: Option<CreditPaymentPolicy>,
+		},
+		/// A job payment has been charged from the payer's credits.
+		JobPaidWithCredits {
+			/// The ID of the service.
+			service_id: u64,
+			/// The index of the job.
+			job: u8,
+			/// The ID of the job call.
+			call_id: u64,
+			/// The account whose credits were spent.
+			payer: T::AccountId,
+			/// The price of the job in the payment asset.
+			amount: BalanceOf<T>,
+			/// The credits spent.
+			credits: BalanceOf<T>,
+		},
 	}
 
+	/// The in-code storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Slashing is enabled.
@@ -977,6 +1025,31 @@ pub mod module {
 		ResultQuery<Error<T>::UnappliedSlashNotFound>,
 	>;
 
+	/// The slash destination policy declared by a blueprint owner.
+	///
+	/// Blueprint ID -> Slash Destination Policy
+	#[pallet::storage]
+	#[pallet::getter(fn blueprint_slash_destination)]
+	pub type BlueprintSlashDestinations<T: Config> =
+		StorageMap<_, Identity, BlueprintId, SlashDestinationPolicy, OptionQuery>;
+
+	/// The slash destination policy declared by a service owner, overriding the blueprint policy.
+	///
+	/// Service ID -> Slash Destination Policy
+	#[pallet::storage]
+	#[pallet::getter(fn service_slash_destination)]
+	pub type ServiceSlashDestinations<T: Config> =
+		StorageMap<_, Identity, InstanceId, SlashDestinationPolicy, OptionQuery>;
+
+	/// The credit payment policy declared by a blueprint owner. Blueprints without a policy do not
+	/// accept credits.
+	///
+	/// Blueprint ID -> Credit Payment Policy
+	#[pallet::storage]
+	#[pallet::getter(fn blueprint_credit_policy)]
+	pub type BlueprintCreditPolicies<T: Config> =
+		StorageMap<_, Identity, BlueprintId, CreditPaymentPolicy, OptionQuery>;
+
 	/// All the Master Blueprint Service Managers revisions.
 	///
 	/// Where the index is the revision number.
@@ -1525,6 +1598,7 @@ pub mod module {
 			}
 
 			ServiceStatus::<T>::remove(blueprint_id, service_id);
+			ServiceSlashDestinations::<T>::remove(service_id);
 			Self::deposit_event(Event::ServiceTerminated {
 				owner: caller.clone(),
 				service_id,
@@ -1729,6 +1803,7 @@ pub mod module {
 				service_id: service.id,
 				operator: offender.clone(),
 				slash_percent,
+				destination: Self::slash_destination_for(&service, Some(caller)),
 			};
 
 			// Store the slash for later processing
@@ -2170,64 +2245,13 @@ pub mod module {
 				Error::<T>::HeartbeatSignatureVerificationFailed
 			);
 
-			// Get operator's heartbeat stats
-			let mut stats =
-				ServiceOperatorHeartbeats::<T>::get((blueprint_id, service_id, &caller));
-
-			// If this is the first heartbeat for this operator, initialize the stats
-			if stats.last_heartbeat_block.is_zero() {
-				stats.last_heartbeat_block = current_block.try_into().unwrap_or_default();
-				stats.last_check_block = current_block.try_into().unwrap_or_default();
-				stats.expected_heartbeats = 1;
-				stats.received_heartbeats = 1;
-			} else {
-				// Get the heartbeat interval from the QoS function
-				let heartbeat_interval =
-					Self::get_heartbeat_interval(&blueprint, blueprint_id, service_id)?;
-
-				// Check if enough blocks have passed since the last heartbeat
-				let blocks_passed = current_block.saturating_sub(stats.last_heartbeat_block.into());
-				ensure!(blocks_passed >= heartbeat_interval, Error::<T>::HeartbeatTooEarly);
-
-				// Calculate how many heartbeats were expected since the last one
-				let expected_since_last =
-					(blocks_passed / heartbeat_interval).try_into().unwrap_or_default();
-
-				// Update the stats
-				stats.expected_heartbeats =
-					stats.expected_heartbeats.saturating_add(expected_since_last);
-				stats.received_heartbeats = stats.received_heartbeats.saturating_add(1);
-				stats.last_heartbeat_block = current_block.try_into().unwrap_or_default();
-
-				// Get the heartbeat threshold from the QoS function
-				let heartbeat_threshold =
-					Self::get_heartbeat_threshold(&blueprint, blueprint_id, service_id)?;
-				if stats.expected_heartbeats > heartbeat_threshold.into() {
-					// Calculate how many heartbeats were missed
-					let missed =
-						stats.expected_heartbeats.saturating_sub(stats.received_heartbeats);
-					if missed > heartbeat_threshold.into() {
-						// Get the slashing window from the QoS function
-						let slashing_window =
-							Self::get_slashing_window(&blueprint, blueprint_id, service_id)?;
-						let slashing_block = stats
-							.last_heartbeat_block
-							.saturating_add(slashing_window.try_into().unwrap_or_default());
-
-						// If we're within the slashing window, schedule a slash
-						if current_block <= slashing_block.into() {
-							// Calculate slash percentage based on missed heartbeats
-							let slash_percent = Percent::from_percent(50); // TODO: Calculate based on missed heartbeats
-							Self::create_heartbeat_slash(
-								blueprint_id,
-								service_id,
-								caller.clone(),
-								slash_percent,
-							);
-						}
-					}
-				}
-			}
+			Self::record_operator_heartbeat(
+				&blueprint,
+				blueprint_id,
+				service_id,
+				&caller,
+				current_block,
+			)?;
 
 			// Update the heartbeat storage
 			ServiceHeartbeats::<T>::insert(
@@ -2236,12 +2260,6 @@ pub mod module {
 				(current_block, bounded_metrics_data),
 			);
 
-			// Update the operator's heartbeat stats
-			ServiceOperatorHeartbeats::<T>::insert(
-				(blueprint_id, service_id, caller.clone()),
-				stats,
-			);
-
 			// Emit event for heartbeat received
 			Self::deposit_event(Event::<T>::HeartbeatReceived {
 				blueprint_id,
@@ -2327,5 +2345,126 @@ pub mod module {
 
 			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
 		}
+
+		/// Sets the slash destination policy of a blueprint.
+		///
+		/// The policy decides where the funds of slashes against operators of the blueprint's
+		/// services are routed: burned, sent to the treasury, split between the treasury and the
+		/// reporter, or paid to the service owner as a compensation.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the blueprint owner
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `blueprint_id` - The ID of the blueprint
+		/// * `policy` - The new slash destination policy
+		///
+		/// # Errors
+		///
+		/// * [`Error::BlueprintNotFound`] - The blueprint_id does not exist
+		/// * [`DispatchError::BadOrigin`] - Caller is not the blueprint owner
+		#[pallet::call_index(23)]
+		#[pallet::weight(10_000)]
+		pub fn set_blueprint_slash_destination(
+			origin: OriginFor<T>,
+			#[pallet::compact] blueprint_id: u64,
+			policy: SlashDestinationPolicy,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let (owner, _) = Self::blueprints(blueprint_id)?;
+			ensure!(owner == caller, DispatchError::BadOrigin);
+
+			BlueprintSlashDestinations::<T>::insert(blueprint_id, policy);
+
+			Self::deposit_event(Event::<T>::BlueprintSlashDestinationUpdated {
+				blueprint_id,
+				policy,
+			});
+
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Sets or clears the slash destination policy of a service.
+		///
+		/// A service policy overrides the policy declared by the blueprint. Passing `None` falls
+		/// back to the blueprint policy. Slashes that are already reported keep the destination
+		/// they were reported with.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the service owner
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `service_id` - The ID of the service
+		/// * `policy` - The new slash destination policy, or `None` to use the blueprint policy
+		///
+		/// # Errors
+		///
+		/// * [`Error::ServiceNotFound`] - The service_id does not exist
+		/// * [`Error::ServiceNotOwned`] - Caller is not the service owner
+		#[pallet::call_index(24)]
+		#[pallet::weight(10_000)]
+		pub fn set_service_slash_destination(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			policy: Option<SlashDestinationPolicy>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let service = Self::services(service_id)?;
+			ensure!(service.owner == caller, Error::<T>::ServiceNotOwned);
+
+			ServiceSlashDestinations::<T>::set(service_id, policy);
+
+			Self::deposit_event(Event::<T>::ServiceSlashDestinationUpdated { service_id, policy });
+
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Sets or clears the credit payment policy of a blueprint.
+		///
+		/// Blueprints with a policy accept credits from the on-chain credit ledger as a payment
+		/// for their jobs. The policy declares how many credits are charged per unit of the job
+		/// price and whether credits are tried before or after the payment asset.
+		///
+		/// # Permissions
+		///
+		/// * Must be signed by the blueprint owner
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `blueprint_id` - The ID of the blueprint
+		/// * `policy` - The new credit payment policy, or `None` to stop accepting credits
+		///
+		/// # Errors
+		///
+		/// * [`Error::BlueprintNotFound`] - The blueprint_id does not exist
+		/// * [`Error::InvalidCreditConversionRate`] - The conversion rate is zero
+		/// * [`DispatchError::BadOrigin`] - Caller is not the blueprint owner
+		#[pallet::call_index(25)]
+		#[pallet::weight(10_000)]
+		pub fn set_blueprint_credit_policy(
+			origin: OriginFor<T>,
+			#[pallet::compact] blueprint_id: u64,
+			policy: Option<CreditPaymentPolicy>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let (owner, _) = Self::blueprints(blueprint_id)?;
+			ensure!(owner == caller, DispatchError::BadOrigin);
+			if let Some(policy) = &policy {
+				ensure!(!policy.conversion_rate.is_zero(), Error::<T>::InvalidCreditConversionRate);
+			}
+
+			BlueprintCreditPolicies::<T>::set(blueprint_id, policy);
+
+			Self::deposit_event(Event::<T>::BlueprintCreditPolicyUpdated { blueprint_id, policy });
+
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
 	}
 }
@@ -0,0 +1,94 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, STORAGE_VERSION, UnappliedSlashes};
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+use sp_runtime::Percent;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+use tangle_primitives::services::{SlashDestination, UnappliedSlash};
+
+/// Migration to add the `destination` field to the pending `UnappliedSlash` records.
+///
+/// Slashes reported before the upgrade keep routing their funds to the treasury. The migration
+/// only runs once, when bumping the pallet to [`STORAGE_VERSION`].
+pub struct UnappliedSlashDestinationMigration<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for UnappliedSlashDestinationMigration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			log::info!("UnappliedSlashDestinationMigration: already applied, skipping migration");
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		// Define the old version of the structure
+		#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, Eq, PartialEq)]
+		pub struct OldUnappliedSlash<AccountId> {
+			pub era: u32,
+			pub blueprint_id: u64,
+			pub service_id: u64,
+			pub operator: AccountId,
+			pub slash_percent: Percent,
+		}
+
+		let mut migrated_count = 0;
+
+		UnappliedSlashes::<T>::translate::<OldUnappliedSlash<T::AccountId>, _>(
+			|_era, _index, old| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				migrated_count += 1;
+				Some(UnappliedSlash {
+					era: old.era,
+					blueprint_id: old.blueprint_id,
+					service_id: old.service_id,
+					operator: old.operator,
+					slash_percent: old.slash_percent,
+					destination: SlashDestination::Treasury,
+				})
+			},
+		);
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		log::info!(
+			"UnappliedSlashDestinationMigration: Migrated {} unapplied slashes",
+			migrated_count
+		);
+
+		weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		// Count how many entries we have pre-migration
+		let count = UnappliedSlashes::<T>::iter_keys().count() as u32;
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		// Ensure every entry still decodes with the new layout
+		let pre_count =
+			u32::decode(&mut &state[..]).expect("pre_upgrade should have encoded a u32");
+		let post_count = UnappliedSlashes::<T>::iter().count() as u32;
+
+		assert_eq!(pre_count, post_count, "Number of unapplied slashes changed during migration");
+
+		Ok(())
+	}
+}
@@ -48,7 +48,7 @@ use std::{cell::RefCell, collections::BTreeMap, sync::Arc};
 pub use tangle_crypto_primitives::crypto::AuthorityId as RoleKeyId;
 use tangle_primitives::{
 	services::{Asset, EvmAddressMapping, EvmGasWeightMapping, EvmRunner, PricingModel},
-	traits::{RewardRecorder, RewardsManager},
+	traits::{CreditsLedger, RewardRecorder, RewardsManager},
 	types::{BlockNumber, rewards::LockMultiplier},
 };
 
@@ -442,6 +442,7 @@ impl pallet_services::Config for Runtime {
 	type DefaultParameterUpdateOrigin = EnsureRoot<AccountId>;
 	type RoleKeyId = RoleKeyId;
 	type RewardRecorder = MockRewardsManager;
+	type CreditsLedger = MockCreditsLedger;
 	type RewardsManager = MockRewardsManager;
 	type WeightInfo = ();
 }
@@ -451,6 +452,8 @@ type Block = frame_system::mocking::MockBlock<Runtime>;
 thread_local! {
 	static DELEGATE_CALLS: RefCell<Vec<(AccountId, AccountId, Asset<AssetId>, Balance, Option<LockMultiplier>)>> = RefCell::new(Vec::new());
 	static UNDELEGATE_CALLS: RefCell<Vec<(AccountId, AccountId, Asset<AssetId>, Balance)>> = RefCell::new(Vec::new());
+	static CREDITS: RefCell<BTreeMap<AccountId, Balance>> = RefCell::new(BTreeMap::new());
+	static RECORDED_REWARDS: RefCell<Vec<(AccountId, u64, Balance)>> = RefCell::new(Vec::new());
 }
 
 pub struct MockRewardsManager;
@@ -516,9 +519,38 @@ impl MockRewardsManager {
 		UNDELEGATE_CALLS.with(|calls| calls.borrow().clone())
 	}
 
+	pub fn recorded_rewards() -> Vec<(AccountId, u64, Balance)> {
+		RECORDED_REWARDS.with(|rewards| rewards.borrow().clone())
+	}
+
 	pub fn clear_all() {
 		DELEGATE_CALLS.with(|calls| calls.borrow_mut().clear());
 		UNDELEGATE_CALLS.with(|calls| calls.borrow_mut().clear());
+		RECORDED_REWARDS.with(|rewards| rewards.borrow_mut().clear());
+	}
+}
+
+pub struct MockCreditsLedger;
+
+impl MockCreditsLedger {
+	pub fn set_credits(who: &AccountId, amount: Balance) {
+		CREDITS.with(|credits| credits.borrow_mut().insert(who.clone(), amount));
+	}
+}
+
+impl CreditsLedger<AccountId, Balance> for MockCreditsLedger {
+	fn spendable_credits(who: &AccountId) -> Balance {
+		CREDITS.with(|credits| credits.borrow().get(who).copied().unwrap_or_default())
+	}
+
+	fn spend_credits(who: &AccountId, amount: Balance) -> DispatchResult {
+		CREDITS.with(|credits| {
+			let mut credits = credits.borrow_mut();
+			let balance = credits.entry(who.clone()).or_default();
+			*balance =
+				balance.checked_sub(amount).ok_or(DispatchError::Other("InsufficientCredits"))?;
+			Ok(())
+		})
 	}
 }
 
@@ -526,11 +558,13 @@ impl RewardRecorder<AccountId, u64, Balance> for MockRewardsManager {
 	type PricingModel = PricingModel<BlockNumber, Balance>;
 
 	fn record_reward(
-		_operator: &AccountId,
-		_service_id: u64,
-		_amount: Balance,
+		operator: &AccountId,
+		service_id: u64,
+		amount: Balance,
 		_model: &Self::PricingModel,
 	) -> DispatchResult {
+		RECORDED_REWARDS
+			.with(|rewards| rewards.borrow_mut().push((operator.clone(), service_id, amount)));
 		Ok(())
 	}
 }
@@ -559,6 +593,16 @@ parameter_types! {
 	#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 	pub const MaxDelegations: u32 = 10;
+
+	#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub const MaxOperatorProfileFieldLength: u32 = 64;
+
+	#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub const MaxOperatorProfileDescriptionLength: u32 = 256;
+	pub const OperatorProfileDepositBase: Balance = 10;
+	pub const OperatorProfileDepositPerByte: Balance = 1;
 	pub const PID: PalletId = PalletId(*b"tngl/mad");
 }
 
@@ -566,6 +610,7 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type SlashRecipient = SlashRecipient;
+	type NominationSlashHandler = ();
 	type MinOperatorBondAmount = MinOperatorBondAmount;
 	type CurrencyToVote = U128CurrencyToVote;
 	type StakingInterface = Staking;
@@ -584,10 +629,15 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type MaxWithdrawRequests = MaxWithdrawRequests;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type MaxDelegations = MaxDelegations;
+	type MaxOperatorProfileFieldLength = MaxOperatorProfileFieldLength;
+	type MaxOperatorProfileDescriptionLength = MaxOperatorProfileDescriptionLength;
+	type OperatorProfileDepositBase = OperatorProfileDepositBase;
+	type OperatorProfileDepositPerByte = OperatorProfileDepositPerByte;
 	type EvmRunner = MockedEvmRunner;
 	type EvmGasWeightMapping = PalletEVMGasWeightMapping;
 	type EvmAddressMapping = PalletEVMAddressMapping;
 	type RewardsManager = MockRewardsManager;
+	type DelegationHooks = ();
 	type WeightInfo = ();
 }
 
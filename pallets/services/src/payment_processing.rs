@@ -1,6 +1,6 @@
 use crate::{
-	BalanceOf, BlockNumberFor, Config, Error, JobPayments, JobSubscriptionBillings, Pallet,
-	ServiceStatus, UserSubscriptionCount,
+	BalanceOf, BlockNumberFor, BlueprintCreditPolicies, Config, Error, Event, JobPayments,
+	JobSubscriptionBillings, Pallet, ServiceStatus, UserSubscriptionCount,
 };
 use frame_support::{
 	dispatch::DispatchResult,
@@ -11,12 +11,21 @@ use frame_support::{
 use sp_runtime::traits::{CheckedMul, SaturatedConversion, Saturating, Zero};
 use tangle_primitives::{
 	services::{
-		Asset, JobPayment, JobSubscriptionBilling, PricingModel, ServiceBlueprint,
-		StagingServicePayment,
+		Asset, CreditPaymentOrder, JobPayment, JobSubscriptionBilling, PricingModel,
+		ServiceBlueprint, StagingServicePayment,
 	},
-	traits::RewardRecorder as RewardRecorderTrait,
+	traits::{CreditsLedger, RewardRecorder as RewardRecorderTrait},
 };
 
+/// The source a job payment was charged from.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PaymentSource {
+	/// The payment was charged in the payment asset.
+	Asset,
+	/// The payment was charged from the payer's credits.
+	Credits,
+}
+
 impl<T: Config> Pallet<T> {
 	/// Process a one-time payment for a service (not job-specific)
 	pub fn process_pay_once_payment(
@@ -113,21 +122,25 @@ impl<T: Config> Pallet<T> {
 		}
 
 		// Charge the payment from the payer with authorization check
-		Self::charge_payment(caller, payer, amount)?;
+		let source =
+			Self::charge_job_payment(service_id, job_index, call_id, caller, payer, amount)?;
 
-		// Record the payment
+		// Record the payment. Payments made with credits carry no asset amount.
 		let payment = JobPayment {
 			service_id,
 			job_index,
 			call_id,
 			payer: payer.clone(),
-			asset: Asset::Custom(0u32),      // Default to native asset ID 0
-			amount: amount.saturated_into(), // Convert to u128
+			asset: Asset::Custom(0u32), // Default to native asset ID 0
+			amount: match source {
+				PaymentSource::Asset => amount.saturated_into(), // Convert to u128
+				PaymentSource::Credits => 0,
+			},
 		};
 
 		JobPayments::<T>::insert(service_id, call_id, &payment);
 
-		// Record the reward with the rewards pallet
+		// Record the reward with the rewards pallet, whichever way the job was paid
 		let service = Self::services(service_id)?;
 		let (_, blueprint) = Self::blueprints(service.blueprint)?;
 		let job_def = blueprint.jobs.get(job_index as usize).ok_or(Error::<T>::InvalidJobId)?;
@@ -173,7 +186,7 @@ impl<T: Config> Pallet<T> {
 	pub fn process_job_subscription_payment(
 		service_id: u64,
 		job_index: u8,
-		_call_id: u64,
+		call_id: u64,
 		caller: &T::AccountId,
 		payer: &T::AccountId,
 		rate_per_interval: BalanceOf<T>,
@@ -263,13 +276,20 @@ impl<T: Config> Pallet<T> {
 
 		if payment_due {
 			// Process the subscription payment with authorization check
-			Self::charge_payment(caller, payer, rate_per_interval)?;
+			Self::charge_job_payment(
+				service_id,
+				job_index,
+				call_id,
+				caller,
+				payer,
+				rate_per_interval,
+			)?;
 
 			// Update last billed block
 			billing.last_billed = current_block;
 			JobSubscriptionBillings::<T>::insert(&billing_key, &billing);
 
-			// Record the reward
+			// Record the reward, whichever way the subscription was paid
 			let service = Self::services(service_id)?;
 			let (_, blueprint) = Self::blueprints(service.blueprint)?;
 			let _job_def =
@@ -310,7 +330,7 @@ impl<T: Config> Pallet<T> {
 	pub fn process_job_event_driven_payment(
 		service_id: u64,
 		job_index: u8,
-		_call_id: u64,
+		call_id: u64,
 		caller: &T::AccountId,
 		payer: &T::AccountId,
 		reward_per_event: BalanceOf<T>,
@@ -328,9 +348,9 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::PaymentCalculationOverflow)?;
 
 		// Charge the payment with authorization check
-		Self::charge_payment(caller, payer, total_reward)?;
+		Self::charge_job_payment(service_id, job_index, call_id, caller, payer, total_reward)?;
 
-		// Record the reward with the rewards pallet
+		// Record the reward with the rewards pallet, whichever way the events were paid
 		let runtime_pricing_model = PricingModel::EventDriven { reward_per_event };
 		T::RewardRecorder::record_reward(payer, service_id, total_reward, &runtime_pricing_model)?;
 
@@ -345,6 +365,65 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Charge a job payment, honouring the credit payment policy of the service's blueprint.
+	///
+	/// Blueprints without a policy are paid in the payment asset. Otherwise the policy's
+	/// [`CreditPaymentOrder`] decides whether credits or the asset are tried first. Payments made
+	/// with credits move no funds and emit a [`Event::JobPaidWithCredits`] event, the reward for
+	/// the job is recorded by the callers on both paths.
+	pub fn charge_job_payment(
+		service_id: u64,
+		job_index: u8,
+		call_id: u64,
+		caller: &T::AccountId,
+		payer: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<PaymentSource, DispatchError> {
+		// SECURITY CHECK: Ensure the caller has authorization to charge the payer
+		ensure!(caller == payer, Error::<T>::InvalidRequestInput);
+
+		let service = Self::services(service_id)?;
+		let Some(policy) = BlueprintCreditPolicies::<T>::get(service.blueprint) else {
+			Self::charge_payment(caller, payer, amount)?;
+			return Ok(PaymentSource::Asset);
+		};
+
+		let credits: BalanceOf<T> = policy
+			.credits_for(amount.saturated_into())
+			.ok_or(Error::<T>::PaymentCalculationOverflow)?
+			.saturated_into();
+		let has_credits = T::CreditsLedger::spendable_credits(payer) >= credits;
+		let has_asset = T::Currency::free_balance(payer) >= amount;
+
+		let source = match policy.order {
+			CreditPaymentOrder::CreditsFirst if has_credits => PaymentSource::Credits,
+			CreditPaymentOrder::CreditsFirst => PaymentSource::Asset,
+			CreditPaymentOrder::AssetFirst if has_asset || !has_credits => PaymentSource::Asset,
+			CreditPaymentOrder::AssetFirst => PaymentSource::Credits,
+			CreditPaymentOrder::CreditsOnly => {
+				ensure!(has_credits, Error::<T>::InsufficientCredits);
+				PaymentSource::Credits
+			},
+		};
+
+		match source {
+			PaymentSource::Asset => Self::charge_payment(caller, payer, amount)?,
+			PaymentSource::Credits => {
+				T::CreditsLedger::spend_credits(payer, credits)?;
+				Self::deposit_event(Event::JobPaidWithCredits {
+					service_id,
+					job: job_index,
+					call_id,
+					payer: payer.clone(),
+					amount,
+					credits,
+				});
+			},
+		}
+
+		Ok(source)
+	}
+
 	/// Charge payment from a user account with proper authorization checks
 	fn charge_payment_with_asset(
 		caller: &T::AccountId,
@@ -17,8 +17,11 @@
 use super::*;
 use frame_support::{assert_err, assert_noop, assert_ok, traits::ConstU128};
 use sp_core::{H160, U256};
-use sp_runtime::TokenError;
-use tangle_primitives::services::PricingModel;
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, TokenError};
+use tangle_primitives::{
+	services::{CreditPaymentOrder, CreditPaymentPolicy, PricingModel},
+	traits::CreditsLedger,
+};
 
 #[test]
 fn test_payment_refunds_on_failure() {
@@ -838,3 +841,145 @@ pub fn create_blueprint_with_pricing(
 		supported_membership_models: vec![MembershipModelType::Fixed].try_into().unwrap(),
 	}
 }
+
+#[test]
+fn test_job_payment_with_credits() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Services::update_master_blueprint_service_manager(RuntimeOrigin::root(), MBSM));
+		let alice = mock_pub_key(ALICE);
+		assert_ok!(create_test_blueprint_with_pricing(
+			RuntimeOrigin::signed(alice.clone()),
+			cggmp21_blueprint(),
+			PricingModel::PayOnce { amount: 100 }
+		));
+
+		let bob = mock_pub_key(BOB);
+		assert_ok!(join_and_register(
+			bob.clone(),
+			0,
+			test_ecdsa_key(),
+			1000,
+			Some("https://example.com/rpc")
+		));
+
+		let eve = mock_pub_key(EVE);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			None,
+			0,
+			vec![],
+			vec![bob.clone()],
+			Default::default(),
+			vec![get_security_requirement(TNT, &[10, 20])],
+			100,
+			Asset::Custom(USDC),
+			0,
+			MembershipModel::Fixed { min_operators: 1 },
+		));
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			vec![get_security_commitment(TNT, 10)]
+		));
+
+		// Only the blueprint owner can declare a credit policy, with a non-zero rate.
+		let policy = CreditPaymentPolicy {
+			conversion_rate: FixedU128::from_u32(2),
+			order: CreditPaymentOrder::CreditsFirst,
+		};
+		assert_noop!(
+			Services::set_blueprint_credit_policy(
+				RuntimeOrigin::signed(eve.clone()),
+				0,
+				Some(policy)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Services::set_blueprint_credit_policy(
+				RuntimeOrigin::signed(alice.clone()),
+				0,
+				Some(CreditPaymentPolicy { conversion_rate: FixedU128::from_u32(0), ..policy })
+			),
+			Error::<Runtime>::InvalidCreditConversionRate
+		);
+		assert_ok!(Services::set_blueprint_credit_policy(
+			RuntimeOrigin::signed(alice.clone()),
+			0,
+			Some(policy)
+		));
+
+		// Not enough credits: falls back to the payment asset.
+		MockCreditsLedger::set_credits(&eve, 150);
+		let reserved = Balances::reserved_balance(eve.clone());
+		assert_ok!(Services::process_job_pay_once_payment(0, 0, 0, &eve, &eve, 100));
+		assert_eq!(Balances::reserved_balance(eve.clone()), reserved + 100);
+		assert_eq!(MockCreditsLedger::spendable_credits(&eve), 150);
+
+		// Enough credits: paid with credits, the asset is untouched.
+		MockCreditsLedger::set_credits(&eve, 500);
+		let recorded = MockRewardsManager::recorded_rewards().len();
+		assert_ok!(Services::process_job_pay_once_payment(0, 0, 1, &eve, &eve, 100));
+		assert_eq!(Balances::reserved_balance(eve.clone()), reserved + 100);
+		assert_eq!(MockCreditsLedger::spendable_credits(&eve), 300);
+		assert_eq!(JobPayments::<Runtime>::get(0, 1).unwrap().amount, 0);
+		System::assert_last_event(RuntimeEvent::Services(crate::Event::JobPaidWithCredits {
+			service_id: 0,
+			job: 0,
+			call_id: 1,
+			payer: eve.clone(),
+			amount: 100,
+			credits: 200,
+		}));
+
+		// The reward of the job is recorded like for a payment in the asset.
+		assert_eq!(
+			MockRewardsManager::recorded_rewards()[recorded..].to_vec(),
+			vec![(eve.clone(), 0, 100)]
+		);
+
+		// Fractional rates round the credits charged up.
+		assert_ok!(Services::set_blueprint_credit_policy(
+			RuntimeOrigin::signed(alice.clone()),
+			0,
+			Some(CreditPaymentPolicy {
+				conversion_rate: FixedU128::saturating_from_rational(1, 3),
+				..policy
+			})
+		));
+		assert_ok!(Services::process_job_pay_once_payment(0, 0, 4, &eve, &eve, 100));
+		assert_eq!(MockCreditsLedger::spendable_credits(&eve), 266);
+		assert_ok!(Services::set_blueprint_credit_policy(
+			RuntimeOrigin::signed(alice.clone()),
+			0,
+			Some(policy)
+		));
+
+		// Asset first: credits are left alone while the asset covers the payment.
+		assert_ok!(Services::set_blueprint_credit_policy(
+			RuntimeOrigin::signed(alice.clone()),
+			0,
+			Some(CreditPaymentPolicy { order: CreditPaymentOrder::AssetFirst, ..policy })
+		));
+		assert_ok!(Services::process_job_pay_once_payment(0, 0, 2, &eve, &eve, 100));
+		assert_eq!(Balances::reserved_balance(eve.clone()), reserved + 200);
+		assert_eq!(MockCreditsLedger::spendable_credits(&eve), 300);
+
+		// Credits only: never falls back to the asset.
+		assert_ok!(Services::set_blueprint_credit_policy(
+			RuntimeOrigin::signed(alice.clone()),
+			0,
+			Some(CreditPaymentPolicy { order: CreditPaymentOrder::CreditsOnly, ..policy })
+		));
+		MockCreditsLedger::set_credits(&eve, 0);
+		assert_noop!(
+			Services::process_job_pay_once_payment(0, 0, 3, &eve, &eve, 100),
+			Error::<Runtime>::InsufficientCredits
+		);
+
+		// Clearing the policy stops accepting credits.
+		assert_ok!(Services::set_blueprint_credit_policy(RuntimeOrigin::signed(alice), 0, None));
+		assert_eq!(Services::blueprint_credit_policy(0), None);
+	});
+}
@@ -18,7 +18,7 @@ use super::*;
 use frame_support::{assert_err, assert_ok};
 use sp_core::bounded_vec;
 use sp_runtime::{DispatchError, Percent};
-use tangle_primitives::services::Asset;
+use tangle_primitives::{services::Asset, traits::ServiceOperatorManager};
 
 #[test]
 fn test_zero_percentage_slash() {
@@ -510,3 +510,143 @@ fn test_slash_with_unauthorized_origin() {
 		);
 	});
 }
+
+#[test]
+fn slash_destination_follows_blueprint_and_service_policies() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let Deployment { blueprint_id, service_id, .. } = deploy();
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let service = Services::services(service_id).unwrap();
+		let slashing_origin =
+			Services::query_slashing_origin(&service).map(|(o, _)| o.unwrap()).unwrap();
+		let slash_percent = Percent::from_percent(10);
+
+		// Without any policy, slashed funds go to the treasury.
+		assert_ok!(Services::slash(
+			RuntimeOrigin::signed(slashing_origin.clone()),
+			bob.clone(),
+			service_id,
+			slash_percent
+		));
+		assert_eq!(
+			Services::unapplied_slashes(0, 0).unwrap().destination,
+			SlashDestination::Treasury
+		);
+
+		// Only the blueprint owner can set the blueprint policy.
+		let policy = SlashDestinationPolicy::TreasuryAndReporter {
+			reporter_share: Percent::from_percent(20),
+		};
+		assert_err!(
+			Services::set_blueprint_slash_destination(
+				RuntimeOrigin::signed(eve.clone()),
+				blueprint_id,
+				policy
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Services::set_blueprint_slash_destination(
+			RuntimeOrigin::signed(alice.clone()),
+			blueprint_id,
+			policy
+		));
+		System::assert_has_event(RuntimeEvent::Services(
+			crate::Event::BlueprintSlashDestinationUpdated { blueprint_id, policy },
+		));
+
+		assert_ok!(Services::slash(
+			RuntimeOrigin::signed(slashing_origin.clone()),
+			bob.clone(),
+			service_id,
+			slash_percent
+		));
+		assert_eq!(
+			Services::unapplied_slashes(0, 1).unwrap().destination,
+			SlashDestination::TreasuryAndReporter {
+				reporter: slashing_origin.clone(),
+				reporter_share: Percent::from_percent(20),
+			}
+		);
+
+		// The service owner can override the blueprint policy for their service.
+		assert_err!(
+			Services::set_service_slash_destination(
+				RuntimeOrigin::signed(alice.clone()),
+				service_id,
+				Some(SlashDestinationPolicy::ServiceOwner)
+			),
+			Error::<Runtime>::ServiceNotOwned
+		);
+		assert_ok!(Services::set_service_slash_destination(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			Some(SlashDestinationPolicy::ServiceOwner)
+		));
+
+		assert_ok!(Services::slash(
+			RuntimeOrigin::signed(slashing_origin.clone()),
+			bob.clone(),
+			service_id,
+			slash_percent
+		));
+		assert_eq!(
+			Services::unapplied_slashes(0, 2).unwrap().destination,
+			SlashDestination::ServiceOwner(eve.clone())
+		);
+
+		// Clearing the service policy falls back to the blueprint policy.
+		assert_ok!(Services::set_service_slash_destination(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			None
+		));
+		assert_eq!(Services::service_slash_destination(service_id), None);
+		assert_eq!(Services::blueprint_slash_destination(blueprint_id), Some(policy));
+	});
+}
+
+#[test]
+fn service_operator_manager_reports_misbehavior() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let Deployment { blueprint_id, service_id, .. } = deploy();
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+
+		assert_eq!(
+			<Services as ServiceOperatorManager<_>>::service_operators(service_id),
+			vec![bob.clone()]
+		);
+		assert!(
+			<Services as ServiceOperatorManager<_>>::service_operators(service_id + 1).is_empty()
+		);
+
+		// A feed counts as the operator's first heartbeat.
+		assert_ok!(<Services as ServiceOperatorManager<_>>::record_heartbeat(service_id, &bob));
+		let stats = Services::service_operator_heartbeats((blueprint_id, service_id, bob.clone()));
+		assert_eq!((stats.expected_heartbeats, stats.received_heartbeats), (1, 1));
+
+		assert_err!(
+			<Services as ServiceOperatorManager<_>>::report_misbehavior(
+				service_id,
+				&eve,
+				Percent::from_percent(5)
+			),
+			Error::<Runtime>::OffenderNotOperator
+		);
+
+		let index = Services::next_unapplied_slash_index();
+		assert_ok!(<Services as ServiceOperatorManager<_>>::report_misbehavior(
+			service_id,
+			&bob,
+			Percent::from_percent(5)
+		));
+		let slash = UnappliedSlashes::<Runtime>::get(0, index).unwrap();
+		assert_eq!(slash.operator, bob);
+		assert_eq!(slash.service_id, service_id);
+		assert_eq!(slash.slash_percent, Percent::from_percent(5));
+	});
+}
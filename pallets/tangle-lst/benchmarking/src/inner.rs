@@ -5,20 +5,22 @@ use frame_benchmarking::v1::{account, whitelist_account};
 use frame_election_provider_support::SortedListProvider;
 use frame_support::{
 	BoundedVec,
-	traits::{Currency, Get},
+	traits::{Currency, Get, Hooks, fungibles::Inspect},
 };
 use frame_system::RawOrigin as RuntimeOrigin;
 use pallet_staking::MaxNominationsOf;
 use pallet_tangle_lst::{
-	BalanceOf, BondedPools, Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp,
-	GlobalMaxCommission, MaxPools, Metadata, MinCreateBond, MinJoinBond, Pallet as Lst,
-	RewardPools,
+	AutoNominationStrategy, AutoNominations, BalanceOf, BondedPools, Commission,
+	CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
+	InstantUnbondBuffers, LastPoolId, MaxPools, Metadata, MinCreateBond, MinJoinBond,
+	Pallet as Lst, PoolId, RewardPools, ScoreWeights,
 };
 use sp_runtime::{
 	Perbill,
-	traits::{Bounded, StaticLookup},
+	traits::{Bounded, StaticLookup, Zero},
 };
 use sp_staking::{EraIndex, StakingInterface};
+use tangle_primitives::traits::{NominationDelegator, ValidatorInfoProvider};
 // `frame_benchmarking::benchmarks!` macro needs this
 use pallet_tangle_lst::Call;
 
@@ -30,6 +32,14 @@ const MAX_SPANS: u32 = 100;
 pub trait Config:
 	pallet_tangle_lst::Config + pallet_staking::Config + pallet_bags_list::Config
 {
+	/// Makes `operator` an active operator that pools can restake with.
+	fn setup_restake_operator(operator: &Self::AccountId);
+
+	/// Makes the unrestakes scheduled so far ready to be executed.
+	fn make_unrestakes_ready();
+
+	/// Makes `validators` the candidates of the automatic nominations.
+	fn setup_auto_nomination_candidates(validators: &[Self::AccountId]);
 }
 
 pub struct Pallet<T: Config>(Lst<T>);
@@ -79,6 +89,65 @@ fn create_pool_account<T: pallet_tangle_lst::Config>(
 	(pool_creator, pool_account)
 }
 
+// Create a pool and restake half of its bond with a new operator, returning the depositor, the
+// pool account, the operator and the restaked amount.
+fn create_restaked_pool<T: Config>() -> (T::AccountId, T::AccountId, T::AccountId, BalanceOf<T>) {
+	let (depositor, pool_account) =
+		create_pool_account::<T>(0, Lst::<T>::depositor_min_bond() * 2u32.into(), None);
+	let operator: T::AccountId = account("operator", 0, USER_SEED);
+	T::setup_restake_operator(&operator);
+	let amount = Lst::<T>::depositor_min_bond();
+	Lst::<T>::restake(
+		RuntimeOrigin::Signed(depositor.clone()).into(),
+		1,
+		operator.clone(),
+		amount,
+		None,
+	)
+	.unwrap();
+	(depositor, pool_account, operator, amount)
+}
+
+// Create a pool bonding `balance` with an instant unbond buffer of `target`, returning the
+// depositor and the pool id.
+fn create_pool_with_buffer<T: Config>(
+	n: u32,
+	balance: BalanceOf<T>,
+	target: Perbill,
+) -> (T::AccountId, PoolId) {
+	let (depositor, _) = create_pool_account::<T>(n, balance, None);
+	let pool_id = LastPoolId::<T>::get();
+	Lst::<T>::set_instant_unbond_buffer(
+		RuntimeOrigin::Signed(depositor.clone()).into(),
+		pool_id,
+		target,
+		T::MaxInstantUnbondFee::get(),
+	)
+	.unwrap();
+	(depositor, pool_id)
+}
+
+// Create a validator bonding `balance`.
+fn create_validator<T: Config>(n: u32, balance: BalanceOf<T>) -> T::AccountId {
+	let validator = create_funded_user_with_balance::<T>("validator", n, balance * 2u32.into());
+	T::Staking::bond(&validator, balance, &validator).unwrap();
+	pallet_staking::Pallet::<T>::validate(
+		RuntimeOrigin::Signed(validator.clone()).into(),
+		Default::default(),
+	)
+	.unwrap();
+	validator
+}
+
+// The automatic nomination strategy selecting the most validators, by every criterion.
+fn max_auto_nomination_strategy<T: Config>() -> AutoNominationStrategy {
+	AutoNominationStrategy {
+		max_validators: T::MaxAutoNominations::get(),
+		interval: 1,
+		weights: ScoreWeights { commission: 1, self_stake: 1, era_points: 1, slashing: 1 },
+	}
+}
+
 fn vote_to_balance<T: pallet_tangle_lst::Config>(vote: u64) -> Result<BalanceOf<T>, &'static str> {
 	vote.try_into().map_err(|_| "could not convert u64 to Balance")
 }
@@ -298,6 +367,190 @@ frame_benchmarking::benchmarks! {
 		});
 	}
 
+	compound {
+		let (depositor, pool_account) = create_pool_account::<T>(0, Lst::<T>::depositor_min_bond() * 2u32.into(), None);
+
+		// Nominate with the pool, so bonding the rewards also moves it in the voter list.
+		let validators: Vec<_> = (0..MaxNominationsOf::<T>::get())
+			.map(|i| account::<T::AccountId>("stash", USER_SEED, i))
+			.collect();
+		Lst::<T>::nominate(RuntimeOrigin::Signed(depositor).into(), 1, validators).unwrap();
+
+		// Pay rewards to the pool.
+		let rewards = CurrencyOf::<T>::minimum_balance() * 100u32.into();
+		let reward_account = Lst::<T>::create_reward_account(1);
+		CurrencyOf::<T>::make_free_balance_be(
+			&reward_account,
+			CurrencyOf::<T>::free_balance(&reward_account) + rewards,
+		);
+		let bonded = T::Staking::active_stake(&pool_account).unwrap();
+
+		let caller: T::AccountId = account("caller", 0, USER_SEED);
+		whitelist_account!(caller);
+	}:_(RuntimeOrigin::Signed(caller), 1)
+	verify {
+		assert_eq!(T::Staking::active_stake(&pool_account).unwrap(), bonded + rewards);
+	}
+
+	restake {
+		let (depositor, pool_account) = create_pool_account::<T>(0, Lst::<T>::depositor_min_bond() * 2u32.into(), None);
+		let operator: T::AccountId = account("operator", 0, USER_SEED);
+		T::setup_restake_operator(&operator);
+		let amount = Lst::<T>::depositor_min_bond();
+
+		// Restake for the maximum number of blueprints.
+		let blueprints: BoundedVec<_, _> = (0..T::MaxRestakeBlueprints::get() as u64)
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		whitelist_account!(depositor);
+	}:_(RuntimeOrigin::Signed(depositor), 1, operator, amount, Some(blueprints))
+	verify {
+		assert_eq!(T::NominationDelegator::total_nomination_delegations(&pool_account), amount);
+	}
+
+	unrestake {
+		let (depositor, pool_account, operator, amount) = create_restaked_pool::<T>();
+
+		whitelist_account!(depositor);
+	}:_(RuntimeOrigin::Signed(depositor), 1, operator, amount)
+	verify {
+		// The funds stay restaked until the unrestake is executed.
+		assert_eq!(T::NominationDelegator::total_nomination_delegations(&pool_account), amount);
+	}
+
+	execute_unrestake {
+		let (depositor, pool_account, operator, amount) = create_restaked_pool::<T>();
+		Lst::<T>::unrestake(
+			RuntimeOrigin::Signed(depositor).into(),
+			1,
+			operator.clone(),
+			amount,
+		)
+		.unwrap();
+		T::make_unrestakes_ready();
+
+		let caller: T::AccountId = account("caller", 0, USER_SEED);
+		whitelist_account!(caller);
+	}:_(RuntimeOrigin::Signed(caller), 1, operator)
+	verify {
+		assert!(T::NominationDelegator::total_nomination_delegations(&pool_account).is_zero());
+	}
+
+	set_instant_unbond_buffer {
+		let (depositor, _) = create_pool_account::<T>(0, Lst::<T>::depositor_min_bond() * 2u32.into(), None);
+		assert_eq!(InstantUnbondBuffers::<T>::count(), 0);
+
+		whitelist_account!(depositor);
+	}:_(RuntimeOrigin::Signed(depositor), 1, Perbill::from_percent(10), T::MaxInstantUnbondFee::get())
+	verify {
+		assert_eq!(InstantUnbondBuffers::<T>::count(), 1);
+	}
+
+	instant_unbond {
+		let (_, pool_id) = create_pool_with_buffer::<T>(0, Lst::<T>::depositor_min_bond() * 2u32.into(), Perbill::from_percent(10));
+
+		// Add a new member
+		let min_join_bond = MinJoinBond::<T>::get().max(CurrencyOf::<T>::minimum_balance());
+		let joiner = create_funded_user_with_balance::<T>("joiner", 0, min_join_bond * 2u32.into());
+		Lst::<T>::join(RuntimeOrigin::Signed(joiner.clone()).into(), min_join_bond, pool_id)
+			.unwrap();
+		let points = T::Fungibles::balance(pool_id.into(), &joiner);
+
+		// Fill the buffer, so it covers the whole bond of the member.
+		CurrencyOf::<T>::make_free_balance_be(
+			&Lst::<T>::create_buffer_account(pool_id),
+			CurrencyOf::<T>::minimum_balance() + min_join_bond * 2u32.into(),
+		);
+
+		whitelist_account!(joiner);
+	}:_(RuntimeOrigin::Signed(joiner.clone()), pool_id, points)
+	verify {
+		assert!(T::Fungibles::balance(pool_id.into(), &joiner).is_zero());
+		assert!(CurrencyOf::<T>::free_balance(&joiner) > min_join_bond);
+	}
+
+	rebalance_instant_unbond_buffers {
+		let n in 0 .. T::MaxInstantUnbondBuffers::get();
+
+		MaxPools::<T>::kill();
+		let bond = Lst::<T>::depositor_min_bond().max(CurrencyOf::<T>::minimum_balance()) * 100u32.into();
+		let pools: Vec<_> = (0..n)
+			.map(|i| create_pool_with_buffer::<T>(i, bond, Perbill::from_percent(10)))
+			.collect();
+
+		// Start a refill of every buffer and let it mature.
+		pallet_staking::CurrentEra::<T>::put(1);
+		Lst::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+		pallet_staking::CurrentEra::<T>::put(1 + T::Staking::bonding_duration());
+
+		// Raise the targets, so every buffer withdraws its refill and starts the next one.
+		for (depositor, pool_id) in &pools {
+			Lst::<T>::set_instant_unbond_buffer(
+				RuntimeOrigin::Signed(depositor.clone()).into(),
+				*pool_id,
+				Perbill::from_percent(20),
+				T::MaxInstantUnbondFee::get(),
+			)
+			.unwrap();
+		}
+	}: {
+		Lst::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+	}
+	verify {
+		for (_, pool_id) in &pools {
+			assert!(!InstantUnbondBuffers::<T>::get(pool_id).unwrap().unbonding.is_zero());
+			assert!(!Lst::<T>::instant_unbond_liquidity(*pool_id).is_zero());
+		}
+	}
+
+	set_auto_nomination {
+		let (depositor, _) = create_pool_account::<T>(0, Lst::<T>::depositor_min_bond() * 2u32.into(), None);
+		assert_eq!(AutoNominations::<T>::count(), 0);
+
+		whitelist_account!(depositor);
+	}:_(RuntimeOrigin::Signed(depositor), 1, Some(max_auto_nomination_strategy::<T>()))
+	verify {
+		assert_eq!(AutoNominations::<T>::count(), 1);
+	}
+
+	auto_nominate {
+		let n in 0 .. T::MaxAutoNominatingPools::get();
+		let c in 0 .. T::MaxAutoNominationCandidates::get();
+
+		let stake = T::Staking::minimum_validator_bond().max(CurrencyOf::<T>::minimum_balance()) * 10u32.into();
+		let validators: Vec<_> = (0..c).map(|i| create_validator::<T>(i, stake)).collect();
+		T::setup_auto_nomination_candidates(&validators);
+		pallet_staking::ActiveEra::<T>::put(pallet_staking::ActiveEraInfo { index: 1, start: None });
+
+		// Pools that do not nominate yet, so every selection also adds a voter.
+		MaxPools::<T>::kill();
+		let pools: Vec<_> = (0..n)
+			.map(|i| {
+				let (depositor, pool_account) = create_pool_account::<T>(i, Lst::<T>::depositor_min_bond() * 2u32.into(), None);
+				Lst::<T>::set_auto_nomination(
+					RuntimeOrigin::Signed(depositor).into(),
+					LastPoolId::<T>::get(),
+					Some(max_auto_nomination_strategy::<T>()),
+				)
+				.unwrap();
+				pool_account
+			})
+			.collect();
+		pallet_staking::CurrentEra::<T>::put(1);
+	}: {
+		Lst::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+	}
+	verify {
+		assert!(AutoNominations::<T>::iter().all(|(_, auto_nomination)| auto_nomination.next_era == 2));
+		// Without candidates the pools keep not nominating.
+		let has_candidates = !T::Validators::validators(1).is_empty();
+		for pool_account in &pools {
+			assert_eq!(T::Staking::nominations(pool_account).is_some(), has_candidates);
+		}
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
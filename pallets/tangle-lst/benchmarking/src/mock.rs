@@ -88,7 +88,13 @@ parameter_types! {
 	pub static ExistentialDeposit: Balance = 5;
 }
 
-impl crate::Config for Runtime {}
+impl crate::Config for Runtime {
+	fn setup_restake_operator(_operator: &AccountId) {}
+
+	fn make_unrestakes_ready() {}
+
+	fn setup_auto_nomination_candidates(_validators: &[AccountId]) {}
+}
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = frame_support::traits::ConstU32<1024>;
@@ -166,6 +172,7 @@ parameter_types! {
 	pub static MaxMetadataLen: u32 = 2;
 	pub static CheckLevel: u8 = 255;
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const MaxInstantUnbondFee: Perbill = Perbill::from_percent(5);
 }
 
 impl pallet_tangle_lst::Config for Runtime {
@@ -178,6 +185,7 @@ impl pallet_tangle_lst::Config for Runtime {
 	type BalanceToU256 = BalanceToU256;
 	type U256ToBalance = U256ToBalance;
 	type Staking = Staking;
+	type StakingSlash = Staking;
 	type PostUnbondingPoolsWindow = PostUnbondingPoolsWindow;
 	type PalletId = PoolsPalletId;
 	type MaxMetadataLen = MaxMetadataLen;
@@ -188,6 +196,15 @@ impl pallet_tangle_lst::Config for Runtime {
 	type PoolId = PoolId;
 	type ForceOrigin = frame_system::EnsureRoot<u128>;
 	type MaxPointsToBalance = frame_support::traits::ConstU8<10>;
+	type NominationDelegator = ();
+	type RewardPayees = ();
+	type MaxRestakeBlueprints = ConstU32<4>;
+	type MaxInstantUnbondFee = MaxInstantUnbondFee;
+	type MaxInstantUnbondBuffers = ConstU32<4>;
+	type Validators = ();
+	type MaxAutoNominations = ConstU32<16>;
+	type MaxAutoNominationCandidates = ConstU32<64>;
+	type MaxAutoNominatingPools = ConstU32<4>;
 }
 
 impl pallet_assets::Config for Runtime {
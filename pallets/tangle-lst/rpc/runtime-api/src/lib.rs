@@ -0,0 +1,36 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the tangle-lst pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait TangleLstApi<Balance>
+	where
+		Balance: Codec,
+	{
+		/// Query the balance members of a pool can currently unbond instantly.
+		///
+		/// ## Arguments
+		/// - `pool_id`: The pool id.
+		/// ## Return
+		/// - [`Balance`]: The available instant unbond liquidity, zero if the pool does not offer
+		///   instant unbonds.
+		fn instant_unbond_liquidity(pool_id: u32) -> Balance;
+	}
+}
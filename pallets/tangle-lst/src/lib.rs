@@ -92,6 +92,12 @@
 //! The pallet now supports the creation of liquid staking tokens for each pool. When a member joins
 //! a pool, they receive liquid staking tokens representing their share of the pool. These tokens
 //! can be transferred or used in other DeFi applications while the underlying stake remains bonded.
+//!
+//! Each pool's tokens are a `T::Fungibles` asset with the pool id as asset id, so they move like
+//! any other asset, including through its ERC20 representation. Rewards are not paid out per member
+//! but bonded back into the pool with [`Call::compound`], which raises the balance every token can
+//! unbond. A transfer therefore carries the full claim on bonded funds and rewards with it, and no
+//! pending rewards have to be settled when tokens change hands.
 
 //! ### Pool Creation with Specific ID
 
@@ -104,6 +110,36 @@
 //! excess Existential Deposit (ED) from the pool's reward account. This ensures that the pool
 //! always has the correct ED, even if the ED requirement changes over time.
 
+//! ### Restaking
+
+//! The pool's nominator or root role can restake part of the pool's bonded funds with restaking
+//! operators using [`Call::restake`]. The funds stay bonded in staking and are additionally
+//! delegated as a nomination delegation, for either a chosen set of blueprints or all of them.
+//! Restaking rewards are paid to the pool's reward account, so they are subject to commission and
+//! can be compounded like staking rewards. Slashes of the delegation are shared pro rata between
+//! the bonded pool and its unbonding pools. Restaked funds are released with [`Call::unrestake`]
+//! and [`Call::execute_unrestake`]; until then members cannot unbond them.
+
+//! ### Instant Unbond
+
+//! The pool's root role can give a pool an instant unbond buffer with
+//! [`Call::set_instant_unbond_buffer`]. The buffer keeps a target share of the pool's funds
+//! unbonded in a separate buffer account, and members can exit through it with
+//! [`Call::instant_unbond`] without waiting for the bonding duration. The pool keeps a fee of each
+//! instant unbond in the buffer, which raises the balance of the remaining members' points. At the
+//! start of every era the buffers are rebalanced: a deficit is unbonded from the pool's unrestaked
+//! funds and moved into the buffer once withdrawable, and a surplus is bonded back. The available
+//! liquidity is exposed through [`Pallet::instant_unbond_liquidity`].
+
+//! ### Automatic Nominations
+
+//! Instead of calling [`Call::nominate`], the pool's nominator or root role can opt in to
+//! automatic nominations with [`Call::set_auto_nomination`]. The pool then nominates the best
+//! scored validators and selects them again every few eras, as set by its strategy. Validators are
+//! scored by commission, self-stake, era points and slashing history, and oversubscribed or offline
+//! validators are never selected. A [`Event::PoolAutoNominated`] event is emitted whenever the
+//! selection changes.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit, clippy::useless_conversion, clippy::type_complexity)]
 
@@ -111,7 +147,7 @@ use codec::Codec;
 use frame_support::{
 	DefaultNoBound, PalletError, defensive, defensive_assert, ensure,
 	pallet_prelude::{MaxEncodedLen, *},
-	storage::bounded_btree_map::BoundedBTreeMap,
+	storage::{bounded_btree_map::BoundedBTreeMap, with_storage_layer},
 	traits::{
 		Currency, Defensive, DefensiveOption, DefensiveResult, DefensiveSaturating,
 		ExistenceRequirement, Get, LockableCurrency, ReservableCurrency, fungibles,
@@ -133,6 +169,13 @@ use sp_runtime::{
 };
 use sp_staking::{EraIndex, StakingInterface};
 use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, ops::Div, vec::Vec};
+use tangle_primitives::{
+	BlueprintId,
+	traits::{
+		NominationDelegator, NominationSlashHandler, RewardPayeeManager, ValidatorInfo,
+		ValidatorInfoProvider,
+	},
+};
 
 /// The log target of this pallet.
 pub const LOG_TARGET: &str = "runtime::nomination-pools";
@@ -243,6 +286,12 @@ pub mod pallet {
 		/// The interface for nominating.
 		type Staking: StakingInterface<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
 
+		/// The interface for slashing the bonded funds of a pool whose restaked funds are slashed.
+		///
+		/// The slashed pool is updated by this pallet, so the staking slash must not be reported to
+		/// it again through [`sp_staking::OnStakingUpdate`].
+		type StakingSlash: StakingSlash<Self::AccountId, BalanceOf<Self>>;
+
 		/// The amount of eras a `SubPools::with_era` pool can exist before it gets merged into the
 		/// `SubPools::no_era` pool. In other words, this is the amount of eras a member will be
 		/// able to withdraw from an unbonding pool which is guaranteed to have the correct ratio of
@@ -281,6 +330,43 @@ pub mod pallet {
 
 		/// The origin with privileged access
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The interface for restaking the bonded funds of a pool with operators.
+		type NominationDelegator: NominationDelegator<Self::AccountId, BalanceOf<Self>>;
+
+		/// The interface for paying the restaking rewards of a pool to its reward account.
+		type RewardPayees: RewardPayeeManager<Self::AccountId>;
+
+		/// The maximum number of blueprints a pool can restake for with one operator.
+		#[pallet::constant]
+		type MaxRestakeBlueprints: Get<u32>;
+
+		/// The maximum fee a pool can charge for instant unbonds.
+		#[pallet::constant]
+		type MaxInstantUnbondFee: Get<Perbill>;
+
+		/// The maximum number of pools with an instant unbond buffer.
+		///
+		/// Bounds the work done when the buffers are rebalanced at the start of an era.
+		#[pallet::constant]
+		type MaxInstantUnbondBuffers: Get<u32>;
+
+		/// The interface for reading the validators pools can nominate automatically.
+		type Validators: ValidatorInfoProvider<Self::AccountId, BalanceOf<Self>>;
+
+		/// The maximum number of validators a pool can nominate automatically.
+		///
+		/// Should not exceed the maximum number of nominations of the staking system.
+		#[pallet::constant]
+		type MaxAutoNominations: Get<u32>;
+
+		/// The maximum number of validators scored for automatic nominations.
+		#[pallet::constant]
+		type MaxAutoNominationCandidates: Get<u32>;
+
+		/// The maximum number of pools that nominate automatically.
+		#[pallet::constant]
+		type MaxAutoNominatingPools: Get<u32>;
 	}
 
 	/// The sum of funds across all pools.
@@ -362,6 +448,22 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// The instant unbond buffers of pools that offer instant unbonds.
+	#[pallet::storage]
+	pub type InstantUnbondBuffers<T: Config> =
+		CountedStorageMap<_, Twox64Concat, PoolId, InstantUnbondBuffer<T>>;
+
+	/// The automatic nominations of pools that opted in to them.
+	#[pallet::storage]
+	pub type AutoNominations<T: Config> =
+		CountedStorageMap<_, Twox64Concat, PoolId, AutoNomination<T>>;
+
+	/// The last era in which the per-era maintenance of the pools ran.
+	///
+	/// The maintenance rebalances the instant unbond buffers and selects the automatic nominations.
+	#[pallet::storage]
+	pub type LastMaintenanceEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -474,6 +576,45 @@ pub mod pallet {
 		},
 		/// Pool commission has been claimed.
 		PoolCommissionClaimed { pool_id: PoolId, commission: BalanceOf<T> },
+		/// The rewards of a pool have been bonded into the pool.
+		RewardsCompounded { pool_id: PoolId, amount: BalanceOf<T> },
+		/// Bonded funds of a pool have been restaked with an operator.
+		PoolRestaked { pool_id: PoolId, operator: T::AccountId, amount: BalanceOf<T> },
+		/// Restaked funds of a pool have been scheduled to be released from an operator.
+		PoolUnrestakeScheduled { pool_id: PoolId, operator: T::AccountId, amount: BalanceOf<T> },
+		/// Restaked funds of a pool have been released from an operator.
+		PoolUnrestaked { pool_id: PoolId, operator: T::AccountId, amount: BalanceOf<T> },
+		/// The instant unbond buffer of a pool has been configured.
+		InstantUnbondBufferSet { pool_id: PoolId, target: Perbill, fee: Perbill },
+		/// A member has unbonded from their pool through the instant unbond buffer.
+		///
+		/// The member received `balance` minus `fee`; the `fee` stays with the pool.
+		InstantUnbonded {
+			member: T::AccountId,
+			pool_id: PoolId,
+			points: BalanceOf<T>,
+			balance: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// The share of the buffered funds of a pool has been paid out to an unbonding member.
+		///
+		/// The buffered funds are not bonded, so they are paid directly instead of being unbonded.
+		UnbondedFromBuffer { member: T::AccountId, pool_id: PoolId, balance: BalanceOf<T> },
+		/// The instant unbond buffer of a pool has been rebalanced.
+		///
+		/// - `buffered` is the balance available in the buffer account.
+		/// - `unbonding` is the balance being unbonded from staking to refill the buffer.
+		InstantUnbondBufferRebalanced {
+			pool_id: PoolId,
+			buffered: BalanceOf<T>,
+			unbonding: BalanceOf<T>,
+		},
+		/// The instant unbond buffer of a pool has been drained and removed.
+		InstantUnbondBufferRemoved { pool_id: PoolId },
+		/// The automatic nomination strategy of a pool has been set or removed.
+		AutoNominationSet { pool_id: PoolId, strategy: Option<AutoNominationStrategy> },
+		/// A pool has automatically nominated a new set of validators.
+		PoolAutoNominated { pool_id: PoolId, validators: Vec<T::AccountId> },
 		/// Topped up deficit in frozen ED of the reward pool.
 		MinBalanceDeficitAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
 		/// Claimed excess frozen ED of the reward pool.
@@ -558,6 +699,27 @@ pub mod pallet {
 		PoolTokenCreationFailed,
 		/// No balance to unbond.
 		NoBalanceToUnbond,
+		/// There are no pending rewards to compound.
+		NothingToCompound,
+		/// The funds to unbond are restaked with operators and have to be unrestaked first.
+		FundsRestaked,
+		/// The pool does not offer instant unbonds.
+		NoInstantUnbondBuffer,
+		/// The instant unbond buffer of the pool does not hold enough funds.
+		InsufficientInstantLiquidity,
+		/// The instant unbond fee exceeds [`Config::MaxInstantUnbondFee`].
+		InstantUnbondFeeTooHigh,
+		/// The system is maxed out on instant unbond buffers.
+		MaxInstantUnbondBuffers,
+		/// The automatic nomination strategy selects no validators, too many of them, never
+		/// selects them again or weights no criterion.
+		InvalidAutoNominationStrategy,
+		/// The pool does not nominate automatically.
+		NoAutoNomination,
+		/// The pool nominates automatically, so its nominations cannot be set manually.
+		AutoNominationEnabled,
+		/// The system is maxed out on automatically nominating pools.
+		MaxAutoNominatingPools,
 	}
 
 	#[derive(Encode, Decode, PartialEq, TypeInfo, PalletError, RuntimeDebug)]
@@ -695,6 +857,9 @@ pub mod pallet {
 
 		/// Unbond points from a member's pool position, collecting any pending rewards.
 		///
+		/// The share of the points in the instant unbond buffer of the pool is paid out directly,
+		/// only the share in the bonded funds is unbonded from staking.
+		///
 		/// # Arguments
 		///
 		/// * `origin` - Origin of the call
@@ -716,6 +881,7 @@ pub mod pallet {
 		///
 		/// * [`Error::PoolNotFound`] - Pool does not exist
 		/// * [`Error::NoBalanceToUnbond`] - Member has insufficient points
+		/// * [`Error::FundsRestaked`] - The points are backed by restaked funds
 		/// * [`Error::DefensiveError`] - Not enough space in unbond pool
 		///
 		/// # Note
@@ -741,6 +907,27 @@ pub mod pallet {
 
 			bonded_pool.ok_to_unbond_with(&who, &member_account, total_points, unbonding_points)?;
 
+			// NOTE: the pool points are the token supply, so the balance must be computed before
+			// the tokens are burned.
+			let balance = bonded_pool.dissolve(unbonding_points);
+
+			// The pool balance includes the instant unbond buffer, only the share of the bonded
+			// funds is unbonded from staking. The buffered share is paid directly, as far as the
+			// buffer can cover it, and taken from staking otherwise.
+			let bonded_account = bonded_pool.bonded_account();
+			let active = T::Staking::active_stake(&bonded_account).unwrap_or_default();
+			let buffered = balance
+				.saturating_sub(Self::point_to_balance(balance, bonded_pool.balance(), active))
+				.min(Self::instant_unbond_liquidity(pool_id));
+			let unbonding_balance = balance.saturating_sub(buffered);
+
+			// Restaked funds have to be unrestaked before they can leave the pool.
+			let restaked = T::NominationDelegator::total_nomination_delegations(&bonded_account);
+			ensure!(
+				active.saturating_sub(unbonding_balance) >= restaked,
+				Error::<T>::FundsRestaked
+			);
+
 			// let burn the pool tokens
 			T::Fungibles::burn_from(
 				pool_id.into(),
@@ -751,12 +938,28 @@ pub mod pallet {
 				Fortitude::Force,
 			)?;
 
+			if !buffered.is_zero() {
+				T::Currency::transfer(
+					&bonded_pool.buffer_account(),
+					&member_account,
+					buffered,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				Self::deposit_event(Event::<T>::UnbondedFromBuffer {
+					member: member_account.clone(),
+					pool_id,
+					balance: buffered,
+				});
+				if unbonding_balance.is_zero() {
+					return Ok(());
+				}
+			}
+
 			let current_era = T::Staking::current_era();
 			let unbond_era = T::Staking::bonding_duration().saturating_add(current_era);
 
 			// Unbond in the actual underlying nominator.
-			let unbonding_balance = bonded_pool.dissolve(unbonding_points);
-			T::Staking::unbond(&bonded_pool.bonded_account(), unbonding_balance)?;
+			T::Staking::unbond(&bonded_account, unbonding_balance)?;
 
 			// Note that we lazily create the unbonding pools here if they don't already exist
 			let mut sub_pools = SubPoolsStorage::<T>::get(pool_id)
@@ -1097,6 +1300,7 @@ pub mod pallet {
 		///
 		/// * [`Error::PoolNotFound`] - Pool does not exist
 		/// * [`Error::NotNominator`] - Caller lacks nominator permissions
+		/// * [`Error::AutoNominationEnabled`] - Pool nominates automatically
 		///
 		/// # Note
 		///
@@ -1111,6 +1315,10 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+			ensure!(
+				!AutoNominations::<T>::contains_key(pool_id),
+				Error::<T>::AutoNominationEnabled
+			);
 			T::Staking::nominate(&bonded_pool.bonded_account(), validators)
 		}
 
@@ -1328,13 +1536,24 @@ pub mod pallet {
 		///
 		/// * [`Error::PoolNotFound`] - Pool does not exist
 		/// * [`Error::NotNominator`] - Origin lacks nomination permission
+		///
+		/// # Note
+		///
+		/// A pool that nominates automatically nominates again at its next selection.
 		#[pallet::call_index(13)]
 		#[pallet::weight(T::WeightInfo::chill())]
 		pub fn chill(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
-			T::Staking::chill(&bonded_pool.bonded_account())
+			T::Staking::chill(&bonded_pool.bonded_account())?;
+
+			AutoNominations::<T>::mutate(pool_id, |maybe_auto_nomination| {
+				if let Some(auto_nomination) = maybe_auto_nomination {
+					auto_nomination.nominated.clear();
+				}
+			});
+			Ok(())
 		}
 
 		/// Bond additional funds for a pool member into their respective pool.
@@ -1566,10 +1785,376 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::LastPoolIdUpdated { pool_id });
 			Ok(())
 		}
+
+		/// Bond the pending rewards of a pool into the pool.
+		///
+		/// Rewards are bonded without issuing new points, so every point, and with it every pool
+		/// token, can unbond a larger balance afterwards. Pending commission stays in the reward
+		/// account to be claimed with [`Call::claim_commission`].
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the call. Must be signed by any account.
+		/// * `pool_id` - The identifier of the pool to compound the rewards of.
+		///
+		/// # Errors
+		///
+		/// * [`Error::PoolNotFound`] - Pool does not exist
+		/// * [`Error::NothingToCompound`] - The pool has no pending rewards
+		/// * [`Error::DefensiveError`] - Reward pool not found
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::compound())]
+		pub fn compound(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+			Self::do_compound(pool_id)
+		}
+
+		/// Restake bonded funds of the pool with a restaking operator.
+		///
+		/// The funds stay bonded in staking and are additionally delegated to `operator`. The first
+		/// restake of a pool pays its restaking rewards to the pool's reward account.
+		///
+		/// # Permissions
+		///
+		/// * Pool nominator or root role can restake
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `pool_id` - Pool identifier
+		/// * `operator` - Operator to restake with
+		/// * `amount` - Amount of the bonded funds to restake
+		/// * `blueprints` - Blueprints to restake for, or all blueprints if `None`
+		///
+		/// # Errors
+		///
+		/// * [`Error::PoolNotFound`] - Pool does not exist
+		/// * [`Error::NotNominator`] - Caller lacks nominator permissions
+		///
+		/// # Note
+		///
+		/// Forwards the delegation to the restaking system using pool's bonded account.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::restake())]
+		pub fn restake(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			operator: T::AccountId,
+			#[pallet::compact] amount: BalanceOf<T>,
+			blueprints: Option<BoundedVec<BlueprintId, T::MaxRestakeBlueprints>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+
+			let bonded_account = bonded_pool.bonded_account();
+			if T::NominationDelegator::total_nomination_delegations(&bonded_account).is_zero() {
+				T::RewardPayees::set_reward_payee(
+					&bonded_account,
+					Some(bonded_pool.reward_account()),
+				);
+			}
+			T::NominationDelegator::delegate_nomination(
+				&bonded_account,
+				&operator,
+				amount,
+				blueprints.map(Into::into),
+			)?;
+
+			Self::deposit_event(Event::<T>::PoolRestaked { pool_id, operator, amount });
+			Ok(())
+		}
+
+		/// Schedule restaked funds of the pool to be released from a restaking operator.
+		///
+		/// # Permissions
+		///
+		/// * Pool nominator or root role can unrestake
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `pool_id` - Pool identifier
+		/// * `operator` - Operator to release the funds from
+		/// * `amount` - Amount of the restaked funds to release
+		///
+		/// # Errors
+		///
+		/// * [`Error::PoolNotFound`] - Pool does not exist
+		/// * [`Error::NotNominator`] - Caller lacks nominator permissions
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::unrestake())]
+		pub fn unrestake(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			operator: T::AccountId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+
+			T::NominationDelegator::schedule_nomination_unstake(
+				&bonded_pool.bonded_account(),
+				&operator,
+				amount,
+			)?;
+
+			Self::deposit_event(Event::<T>::PoolUnrestakeScheduled { pool_id, operator, amount });
+			Ok(())
+		}
+
+		/// Release the scheduled restaked funds of the pool from a restaking operator.
+		///
+		/// Once released, members can unbond the funds again.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the call. Must be signed by any account.
+		/// * `pool_id` - Pool identifier
+		/// * `operator` - Operator to release the funds from
+		///
+		/// # Errors
+		///
+		/// * [`Error::PoolNotFound`] - Pool does not exist
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::execute_unrestake())]
+		pub fn execute_unrestake(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			operator: T::AccountId,
+		) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			let amount = T::NominationDelegator::execute_nomination_unstake(
+				&bonded_pool.bonded_account(),
+				&operator,
+			)?;
+
+			Self::deposit_event(Event::<T>::PoolUnrestaked { pool_id, operator, amount });
+			Ok(())
+		}
+
+		/// Configure the instant unbond buffer of a pool.
+		///
+		/// The buffer is filled from the pool's bonded funds towards `target` at the start of the
+		/// next era. A zero `target` disables the buffer: its funds are bonded back and it is
+		/// removed once drained.
+		///
+		/// # Permissions
+		///
+		/// * Pool root role can configure the buffer
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `pool_id` - Pool identifier
+		/// * `target` - Share of the pool's funds to keep in the buffer
+		/// * `fee` - Share of the unbonded balance kept by the pool on instant unbonds
+		///
+		/// # Errors
+		///
+		/// * [`Error::PoolNotFound`] - Pool does not exist
+		/// * [`Error::DoesNotHavePermission`] - Caller is not the pool root
+		/// * [`Error::InstantUnbondFeeTooHigh`] - Fee exceeds [`Config::MaxInstantUnbondFee`]
+		/// * [`Error::NoInstantUnbondBuffer`] - Disabling a buffer the pool does not have
+		/// * [`Error::MaxInstantUnbondBuffers`] - Too many pools with a buffer
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::set_instant_unbond_buffer())]
+		pub fn set_instant_unbond_buffer(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			target: Perbill,
+			fee: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_manage_instant_unbond(&who), Error::<T>::DoesNotHavePermission);
+			ensure!(fee <= T::MaxInstantUnbondFee::get(), Error::<T>::InstantUnbondFeeTooHigh);
+
+			InstantUnbondBuffers::<T>::try_mutate(pool_id, |maybe_buffer| -> DispatchResult {
+				match maybe_buffer {
+					Some(buffer) => {
+						buffer.target = target;
+						buffer.fee = fee;
+					},
+					None => {
+						ensure!(!target.is_zero(), Error::<T>::NoInstantUnbondBuffer);
+						ensure!(
+							InstantUnbondBuffers::<T>::count() < T::MaxInstantUnbondBuffers::get(),
+							Error::<T>::MaxInstantUnbondBuffers
+						);
+						*maybe_buffer = Some(InstantUnbondBuffer::new(target, fee));
+					},
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::InstantUnbondBufferSet { pool_id, target, fee });
+			Ok(())
+		}
+
+		/// Unbond points of the caller and receive their balance immediately from the instant
+		/// unbond buffer of the pool.
+		///
+		/// The pool keeps the buffer's fee, which raises the balance of the remaining points.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call, the unbonding member
+		/// * `pool_id` - Pool identifier
+		/// * `points` - Amount of points to unbond
+		///
+		/// # Errors
+		///
+		/// * [`Error::PoolNotFound`] - Pool does not exist
+		/// * [`Error::NoInstantUnbondBuffer`] - Pool does not offer instant unbonds
+		/// * [`Error::NoBalanceToUnbond`] - Member has insufficient points
+		/// * [`Error::InsufficientInstantLiquidity`] - The buffer cannot cover the unbond
+		///
+		/// # Note
+		///
+		/// The same minimum bond rules as for [`Call::unbond`] apply.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::instant_unbond())]
+		pub fn instant_unbond(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			#[pallet::compact] points: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let buffer =
+				InstantUnbondBuffers::<T>::get(pool_id).ok_or(Error::<T>::NoInstantUnbondBuffer)?;
+
+			let total_points = T::Fungibles::balance(pool_id.into(), &who);
+			ensure!(total_points >= points, Error::<T>::NoBalanceToUnbond);
+
+			bonded_pool.ok_to_unbond_with(&who, &who, total_points, points)?;
+
+			// NOTE: the pool points are the token supply, so the balance must be computed before
+			// the tokens are burned.
+			let balance = bonded_pool.dissolve(points);
+			let fee = buffer.fee.mul_floor(balance);
+			let payout = balance.saturating_sub(fee);
+			ensure!(
+				payout <= Self::instant_unbond_liquidity(pool_id),
+				Error::<T>::InsufficientInstantLiquidity
+			);
+
+			T::Fungibles::burn_from(
+				pool_id.into(),
+				&who,
+				points,
+				Preservation::Preserve,
+				Precision::Exact,
+				Fortitude::Force,
+			)?;
+			T::Currency::transfer(
+				&bonded_pool.buffer_account(),
+				&who,
+				payout,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::<T>::InstantUnbonded {
+				member: who,
+				pool_id,
+				points,
+				balance,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Set or remove the automatic nomination strategy of a pool.
+		///
+		/// A pool with a strategy nominates the `max_validators` best scored validators from the
+		/// next era on, and selects them again every `interval` eras. Validators are scored by
+		/// commission, self-stake, era points of the last era and slashing history, weighted by
+		/// the strategy. Oversubscribed validators and validators that earned no era points are
+		/// not selected.
+		///
+		/// # Permissions
+		///
+		/// * Pool nominator or root role can set the strategy
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the call
+		/// * `pool_id` - Pool identifier
+		/// * `strategy` - The strategy, or `None` to nominate manually again
+		///
+		/// # Errors
+		///
+		/// * [`Error::PoolNotFound`] - Pool does not exist
+		/// * [`Error::NotNominator`] - Caller lacks nominator permissions
+		/// * [`Error::InvalidAutoNominationStrategy`] - The strategy cannot select validators
+		/// * [`Error::MaxAutoNominatingPools`] - Too many pools nominate automatically
+		/// * [`Error::NoAutoNomination`] - Removing a strategy the pool does not have
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_auto_nomination())]
+		pub fn set_auto_nomination(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			strategy: Option<AutoNominationStrategy>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+
+			if let Some(strategy) = strategy {
+				ensure!(
+					(1..=T::MaxAutoNominations::get()).contains(&strategy.max_validators) &&
+						!strategy.interval.is_zero() &&
+						!strategy.weights.is_zero(),
+					Error::<T>::InvalidAutoNominationStrategy
+				);
+				AutoNominations::<T>::try_mutate(
+					pool_id,
+					|maybe_auto_nomination| -> DispatchResult {
+						if maybe_auto_nomination.is_none() {
+							ensure!(
+								AutoNominations::<T>::count() < T::MaxAutoNominatingPools::get(),
+								Error::<T>::MaxAutoNominatingPools
+							);
+						}
+						let nominated = maybe_auto_nomination
+							.take()
+							.map(|auto_nomination| auto_nomination.nominated)
+							.unwrap_or_default();
+						*maybe_auto_nomination = Some(AutoNomination {
+							strategy,
+							next_era: T::Staking::current_era(),
+							nominated,
+						});
+						Ok(())
+					},
+				)?;
+			} else {
+				ensure!(AutoNominations::<T>::contains_key(pool_id), Error::<T>::NoAutoNomination);
+				AutoNominations::<T>::remove(pool_id);
+			}
+
+			Self::deposit_event(Event::<T>::AutoNominationSet { pool_id, strategy });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let current_era = T::Staking::current_era();
+			if current_era <= LastMaintenanceEra::<T>::get() {
+				return T::DbWeight::get().reads(2);
+			}
+			LastMaintenanceEra::<T>::put(current_era);
+
+			Self::rebalance_instant_unbond_buffers(current_era)
+				.saturating_add(Self::auto_nominate(current_era))
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			Self::do_try_state(u8::MAX)
@@ -1615,6 +2200,8 @@ impl<T: Config> Pallet<T> {
 		ReversePoolIdLookup::<T>::remove(&bonded_account);
 		RewardPools::<T>::remove(bonded_pool.id);
 		SubPoolsStorage::<T>::remove(bonded_pool.id);
+		InstantUnbondBuffers::<T>::remove(bonded_pool.id);
+		AutoNominations::<T>::remove(bonded_pool.id);
 
 		// remove the ED restriction from the pool reward account.
 		let _ = Self::unfreeze_pool_deposit(&bonded_pool.reward_account()).defensive();
@@ -1650,6 +2237,16 @@ impl<T: Config> Pallet<T> {
 			ExistenceRequirement::AllowDeath,
 		);
 
+		// Whatever is left in the instant unbond buffer, such as fees collected after the last
+		// rebalance, goes to the depositor as well.
+		let buffer_account = bonded_pool.buffer_account();
+		let _ = T::Currency::transfer(
+			&buffer_account,
+			&bonded_pool.roles.depositor,
+			T::Currency::free_balance(&buffer_account),
+			ExistenceRequirement::AllowDeath,
+		);
+
 		defensive_assert!(
 			T::Currency::total_balance(&reward_account) == Zero::zero(),
 			"could not transfer all amount to depositor while dissolving pool"
@@ -1681,6 +2278,21 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account_truncating((AccountType::Reward, id))
 	}
 
+	/// Create the instant unbond buffer account of a pool with the given id.
+	pub fn create_buffer_account(id: PoolId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((AccountType::Buffer, id))
+	}
+
+	/// The balance members of the given pool can currently unbond instantly.
+	pub fn instant_unbond_liquidity(pool_id: PoolId) -> BalanceOf<T> {
+		if !InstantUnbondBuffers::<T>::contains_key(pool_id) {
+			return Zero::zero();
+		}
+		// The buffer account is kept alive, so its existential deposit is never available.
+		T::Currency::free_balance(&Self::create_buffer_account(pool_id))
+			.saturating_sub(T::Currency::minimum_balance())
+	}
+
 	/// Calculate the equivalent point of `new_funds` in a pool with `current_balance` and
 	/// `current_points`.
 	fn balance_to_point(
@@ -1888,6 +2500,252 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_compound(pool_id: PoolId) -> DispatchResult {
+		let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let mut reward_pool = RewardPools::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(DefensiveError::RewardPoolNotFound.into())?;
+
+		// IMPORTANT: split newly received rewards into commission and member rewards before
+		// bonding, so that commission is never compounded.
+		reward_pool.update_records(
+			pool_id,
+			bonded_pool.points(),
+			bonded_pool.commission.current(),
+		)?;
+
+		// Leave the existential deposit in the reward account unless it is already reserved.
+		let reward_account = bonded_pool.reward_account();
+		let untouchable = T::Currency::minimum_balance()
+			.saturating_sub(T::Currency::reserved_balance(&reward_account));
+		let rewards = RewardPool::<T>::current_balance(pool_id)
+			.saturating_sub(reward_pool.total_commission_pending)
+			.saturating_sub(untouchable);
+		ensure!(!rewards.is_zero(), Error::<T>::NothingToCompound);
+
+		let bonded_account = bonded_pool.bonded_account();
+		T::Currency::transfer(
+			&reward_account,
+			&bonded_account,
+			rewards,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		T::Staking::bond_extra(&bonded_account, rewards)?;
+		TotalValueLocked::<T>::mutate(|tvl| {
+			tvl.saturating_accrue(rewards);
+		});
+
+		// The compounded rewards left the reward account like claimed rewards do.
+		reward_pool.register_claimed_reward(rewards);
+		RewardPools::<T>::insert(pool_id, reward_pool);
+
+		Self::deposit_event(Event::<T>::RewardsCompounded { pool_id, amount: rewards });
+		Ok(())
+	}
+
+	/// Rebalance the instant unbond buffers of all pools.
+	fn rebalance_instant_unbond_buffers(current_era: EraIndex) -> Weight {
+		let pool_ids = InstantUnbondBuffers::<T>::iter_keys().collect::<Vec<_>>();
+		for pool_id in &pool_ids {
+			let result = with_storage_layer(|| {
+				Self::do_rebalance_instant_unbond_buffer(*pool_id, current_era)
+			});
+			if let Err(e) = result {
+				log!(
+					warn,
+					"failed to rebalance the instant unbond buffer of pool {}: {:?}",
+					pool_id,
+					e
+				);
+			}
+		}
+
+		T::WeightInfo::rebalance_instant_unbond_buffers(pool_ids.len() as u32)
+	}
+
+	/// Move the instant unbond buffer of a pool towards its target.
+	///
+	/// A matured refill is first withdrawn into the buffer. A deficit is then unbonded from the
+	/// pool's unrestaked funds, unless a refill is still pending, and a surplus is bonded back.
+	fn do_rebalance_instant_unbond_buffer(
+		pool_id: PoolId,
+		current_era: EraIndex,
+	) -> DispatchResult {
+		let mut buffer = InstantUnbondBuffers::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(Error::<T>::NoInstantUnbondBuffer)?;
+		let bonded_pool = BondedPool::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(DefensiveError::PoolNotFound.into())?;
+		let bonded_account = bonded_pool.bonded_account();
+		let buffer_account = bonded_pool.buffer_account();
+
+		if !buffer.unbonding.is_zero() && buffer.unlock_era <= current_era {
+			bonded_pool.withdraw_from_staking(0)?;
+			let refill = buffer.unbonding.min(bonded_pool.transferable_balance());
+			T::Currency::transfer(
+				&bonded_account,
+				&buffer_account,
+				refill,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			buffer.unbonding = Zero::zero();
+		}
+
+		let buffered = T::Currency::free_balance(&buffer_account);
+		let held = buffered.saturating_add(buffer.unbonding);
+		let active = T::Staking::active_stake(&bonded_account).unwrap_or_default();
+		// A destroying pool bonds its buffer back, so the remaining members can unbond it.
+		let target = if bonded_pool.is_destroying() { Perbill::zero() } else { buffer.target }
+			.mul_floor(active.saturating_add(held));
+
+		if held < target {
+			let restaked = T::NominationDelegator::total_nomination_delegations(&bonded_account);
+			let deficit = target.saturating_sub(held).min(active.saturating_sub(restaked));
+			// Only one refill is pending at a time, and it must be able to create the account.
+			if buffer.unbonding.is_zero() &&
+				!deficit.is_zero() &&
+				(!buffered.is_zero() || deficit >= T::Currency::minimum_balance())
+			{
+				T::Staking::unbond(&bonded_account, deficit)?;
+				buffer.unbonding = deficit;
+				buffer.unlock_era = current_era.saturating_add(T::Staking::bonding_duration());
+			}
+		} else {
+			let surplus = held.saturating_sub(target).min(buffered);
+			let (surplus, existence_requirement) = if surplus == buffered {
+				(surplus, ExistenceRequirement::AllowDeath)
+			} else {
+				(
+					surplus.min(buffered.saturating_sub(T::Currency::minimum_balance())),
+					ExistenceRequirement::KeepAlive,
+				)
+			};
+			if !surplus.is_zero() {
+				T::Currency::transfer(
+					&buffer_account,
+					&bonded_account,
+					surplus,
+					existence_requirement,
+				)?;
+				T::Staking::bond_extra(&bonded_account, surplus)?;
+				TotalValueLocked::<T>::mutate(|tvl| {
+					tvl.saturating_accrue(surplus);
+				});
+			}
+		}
+
+		let buffered = T::Currency::free_balance(&buffer_account);
+		if buffer.is_drained(buffered) {
+			InstantUnbondBuffers::<T>::remove(pool_id);
+			Self::deposit_event(Event::<T>::InstantUnbondBufferRemoved { pool_id });
+		} else {
+			let unbonding = buffer.unbonding;
+			InstantUnbondBuffers::<T>::insert(pool_id, buffer);
+			Self::deposit_event(Event::<T>::InstantUnbondBufferRebalanced {
+				pool_id,
+				buffered,
+				unbonding,
+			});
+		}
+		Ok(())
+	}
+
+	/// Select the validators of all pools whose automatic nomination is due.
+	///
+	/// The candidates are read once and shared by all pools.
+	fn auto_nominate(current_era: EraIndex) -> Weight {
+		let pool_ids = AutoNominations::<T>::iter()
+			.filter(|(_, auto_nomination)| auto_nomination.next_era <= current_era)
+			.map(|(pool_id, _)| pool_id)
+			.collect::<Vec<_>>();
+		if pool_ids.is_empty() {
+			return T::WeightInfo::auto_nominate(0, 0);
+		}
+
+		let max_candidates = T::MaxAutoNominationCandidates::get();
+		let candidates = Self::auto_nomination_candidates(max_candidates);
+		for pool_id in &pool_ids {
+			let result =
+				with_storage_layer(|| Self::do_auto_nominate(*pool_id, current_era, &candidates));
+			if let Err(e) = result {
+				log!(warn, "failed to auto nominate for pool {}: {:?}", pool_id, e);
+			}
+		}
+
+		T::WeightInfo::auto_nominate(pool_ids.len() as u32, candidates.len() as u32)
+			.saturating_add(T::Validators::validators_weight(max_candidates))
+	}
+
+	/// The validators that can be nominated automatically.
+	///
+	/// Oversubscribed validators are left out, and so are validators that earned no era points
+	/// while others did, as they were offline.
+	fn auto_nomination_candidates(max: u32) -> Vec<(T::AccountId, ValidatorInfo<BalanceOf<T>>)> {
+		let mut candidates = T::Validators::validators(max);
+		candidates.retain(|(_, info)| !info.oversubscribed);
+		if candidates.iter().any(|(_, info)| info.era_points > 0) {
+			candidates.retain(|(_, info)| info.era_points > 0);
+		}
+		candidates
+	}
+
+	/// The best scored `candidates` according to `strategy`, ordered by account.
+	pub fn select_validators(
+		strategy: &AutoNominationStrategy,
+		candidates: &[(T::AccountId, ValidatorInfo<BalanceOf<T>>)],
+	) -> Vec<T::AccountId> {
+		let max_self_stake =
+			candidates.iter().map(|(_, info)| info.self_stake).max().unwrap_or_default();
+		let max_era_points =
+			candidates.iter().map(|(_, info)| info.era_points).max().unwrap_or_default();
+
+		let mut scored = candidates
+			.iter()
+			.map(|(who, info)| {
+				let score = strategy.weights.score(
+					Perbill::one().saturating_sub(info.commission),
+					Perbill::from_rational(info.self_stake, max_self_stake),
+					Perbill::from_rational(info.era_points, max_era_points),
+					Perbill::from_rational(1, info.slashes.saturating_add(1)),
+				);
+				(score, who)
+			})
+			.collect::<Vec<_>>();
+		// Best score first, ties are broken by account to keep the selection deterministic.
+		scored.sort_by(|(score, who), (other_score, other)| {
+			other_score.cmp(score).then_with(|| who.cmp(other))
+		});
+
+		let mut selected = scored
+			.into_iter()
+			.take(strategy.max_validators as usize)
+			.map(|(_, who)| who.clone())
+			.collect::<Vec<_>>();
+		selected.sort();
+		selected
+	}
+
+	/// Nominate the validators selected for a pool, if they changed since the last selection.
+	fn do_auto_nominate(
+		pool_id: PoolId,
+		current_era: EraIndex,
+		candidates: &[(T::AccountId, ValidatorInfo<BalanceOf<T>>)],
+	) -> DispatchResult {
+		let mut auto_nomination = AutoNominations::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(Error::<T>::NoAutoNomination)?;
+		let bonded_pool = BondedPool::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(DefensiveError::PoolNotFound.into())?;
+		auto_nomination.next_era = current_era.saturating_add(auto_nomination.strategy.interval);
+
+		let selected = Self::select_validators(&auto_nomination.strategy, candidates);
+		if !selected.is_empty() && auto_nomination.nominated != selected {
+			T::Staking::nominate(&bonded_pool.bonded_account(), selected.clone())?;
+			auto_nomination.nominated = BoundedVec::truncate_from(selected.clone());
+			Self::deposit_event(Event::<T>::PoolAutoNominated { pool_id, validators: selected });
+		}
+
+		AutoNominations::<T>::insert(pool_id, auto_nomination);
+		Ok(())
+	}
+
 	fn do_adjust_pool_deposit(who: T::AccountId, pool: PoolId) -> DispatchResult {
 		let bonded_pool = BondedPool::<T>::get(pool).ok_or(Error::<T>::PoolNotFound)?;
 		let reward_acc = &bonded_pool.reward_account();
@@ -1970,22 +2828,73 @@ impl<T: Config> sp_staking::OnStakingUpdate<T::AccountId, BalanceOf<T>> for Pall
 			tvl.defensive_saturating_reduce(total_slashed);
 		});
 
-		if let Some(mut sub_pools) = SubPoolsStorage::<T>::get(pool_id) {
-			// set the reduced balance for each of the `SubPools`
-			slashed_unlocking.iter().for_each(|(era, slashed_balance)| {
-				if let Some(pool) = sub_pools.with_era.get_mut(era).defensive() {
-					pool.balance = *slashed_balance;
-					Self::deposit_event(Event::<T>::UnbondingPoolSlashed {
-						era: *era,
-						pool_id,
-						balance: *slashed_balance,
-					});
+		let mut sub_pools = SubPoolsStorage::<T>::get(pool_id);
+		let mut buffer = InstantUnbondBuffers::<T>::get(pool_id);
+		// set the reduced balance for each of the `SubPools`
+		slashed_unlocking.iter().for_each(|(era, slashed_balance)| {
+			let mut slashed_balance = *slashed_balance;
+			let pool = sub_pools.as_mut().and_then(|sub_pools| sub_pools.with_era.get_mut(era));
+			// The refill of an instant unbond buffer shares the unlocking chunk of its era with
+			// the unbonding pool, so the slashed chunk is split pro rata between the two.
+			if let Some(buffer) = buffer
+				.as_mut()
+				.filter(|buffer| !buffer.unbonding.is_zero() && buffer.unlock_era == *era)
+			{
+				let pool_balance = pool.as_ref().map_or(Zero::zero(), |pool| pool.balance);
+				buffer.unbonding = Self::point_to_balance(
+					slashed_balance,
+					buffer.unbonding.saturating_add(pool_balance),
+					buffer.unbonding,
+				);
+				slashed_balance.saturating_reduce(buffer.unbonding);
+				if pool.is_none() {
+					return;
 				}
-			});
+			}
+			if let Some(pool) = pool.defensive() {
+				pool.balance = slashed_balance;
+				Self::deposit_event(Event::<T>::UnbondingPoolSlashed {
+					era: *era,
+					pool_id,
+					balance: slashed_balance,
+				});
+			}
+		});
+		if let Some(sub_pools) = sub_pools {
 			SubPoolsStorage::<T>::insert(pool_id, sub_pools);
-		} else if !slashed_unlocking.is_empty() {
-			defensive!("Expected SubPools were not found");
+		}
+		if let Some(buffer) = buffer {
+			InstantUnbondBuffers::<T>::insert(pool_id, buffer);
 		}
 		Self::deposit_event(Event::<T>::PoolSlashed { pool_id, balance: slashed_bonded });
 	}
 }
+
+impl<T: Config> NominationSlashHandler<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	/// Slashes a pool whose bonded funds are restaked with an operator.
+	///
+	/// The slash is applied to the staking ledger of the pool, so the active and unlocking funds
+	/// are reduced together with the balance, and the [`SubPools`] are updated like for any
+	/// staking slash.
+	///
+	/// Emits the `UnbondingPoolSlashed` and `PoolSlashed` events.
+	fn slash_nomination(
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if !ReversePoolIdLookup::<T>::contains_key(who) {
+			return Ok(Zero::zero());
+		}
+
+		let (bonded, slashed_unlocking, slashed) = T::StakingSlash::slash(who, amount);
+		if !slashed.is_zero() {
+			<Self as sp_staking::OnStakingUpdate<_, _>>::on_slash(
+				who,
+				bonded,
+				&slashed_unlocking,
+				slashed,
+			);
+		}
+		Ok(slashed)
+	}
+}
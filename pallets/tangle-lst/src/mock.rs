@@ -65,6 +65,37 @@ impl StakingMock {
 	}
 }
 
+impl StakingSlash<AccountId, Balance> for StakingMock {
+	fn slash(who: &AccountId, amount: Balance) -> (Balance, BTreeMap<EraIndex, Balance>, Balance) {
+		let mut bonded = BondedBalanceMap::get();
+		let mut unbonding = UnbondingBalanceMap::get();
+		let Some(active) = bonded.get_mut(who) else { return (0, Default::default(), 0) };
+		let chunks = unbonding.entry(*who).or_default();
+		let total = *active + chunks.iter().map(|(_, value)| value).sum::<Balance>();
+		let amount = amount.min(total);
+		if amount.is_zero() {
+			return (*active, Default::default(), 0);
+		}
+
+		// the slash is shared pro rata between the active balance and the unlocking chunks.
+		let mut slashed_unlocking = BTreeMap::new();
+		let mut slashed = 0;
+		for (era, value) in chunks.iter_mut() {
+			let share = amount * *value / total;
+			*value -= share;
+			slashed += share;
+			*slashed_unlocking.entry(*era).or_default() += *value;
+		}
+		*active = active.saturating_sub(amount - slashed);
+		let active = *active;
+		BondedBalanceMap::set(&bonded);
+		UnbondingBalanceMap::set(&unbonding);
+
+		let _ = <Balances as frame_support::traits::Currency<_>>::slash(who, amount);
+		(active, slashed_unlocking, amount)
+	}
+}
+
 impl sp_staking::StakingInterface for StakingMock {
 	type Balance = Balance;
 	type AccountId = AccountId;
@@ -211,6 +242,121 @@ impl sp_staking::StakingInterface for StakingMock {
 	}
 }
 
+parameter_types! {
+	// map from a (nominator, operator) pair to the restaked and the scheduled to unrestake amount.
+	pub storage RestakedBalanceMap: BTreeMap<(AccountId, AccountId), (Balance, Balance)> = Default::default();
+	pub storage RewardPayeeMap: BTreeMap<AccountId, AccountId> = Default::default();
+}
+
+pub struct NominationDelegatorMock;
+
+impl NominationDelegator<AccountId, Balance> for NominationDelegatorMock {
+	fn delegate_nomination(
+		who: &AccountId,
+		operator: &AccountId,
+		amount: Balance,
+		_blueprints: Option<Vec<BlueprintId>>,
+	) -> DispatchResult {
+		let active = StakingMock::active_stake(who)?;
+		ensure!(
+			Self::total_nomination_delegations(who) + amount <= active,
+			DispatchError::Other("InsufficientBalance")
+		);
+		let mut x = RestakedBalanceMap::get();
+		x.entry((*who, *operator)).or_default().0 += amount;
+		RestakedBalanceMap::set(&x);
+		Ok(())
+	}
+
+	fn schedule_nomination_unstake(
+		who: &AccountId,
+		operator: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		let mut x = RestakedBalanceMap::get();
+		let (restaked, scheduled) = x
+			.get_mut(&(*who, *operator))
+			.ok_or(DispatchError::Other("NoActiveDelegation"))?;
+		ensure!(*restaked >= *scheduled + amount, DispatchError::Other("InsufficientBalance"));
+		*scheduled += amount;
+		RestakedBalanceMap::set(&x);
+		Ok(())
+	}
+
+	fn execute_nomination_unstake(
+		who: &AccountId,
+		operator: &AccountId,
+	) -> Result<Balance, DispatchError> {
+		let mut x = RestakedBalanceMap::get();
+		let (restaked, scheduled) =
+			x.get_mut(&(*who, *operator)).ok_or(DispatchError::Other("NoBondLessRequest"))?;
+		let amount = core::mem::take(scheduled);
+		*restaked -= amount;
+		RestakedBalanceMap::set(&x);
+		Ok(amount)
+	}
+
+	fn total_nomination_delegations(who: &AccountId) -> Balance {
+		RestakedBalanceMap::get()
+			.iter()
+			.filter(|((nominator, _), _)| nominator == who)
+			.map(|(_, (restaked, _))| restaked)
+			.sum()
+	}
+}
+
+pub struct RewardPayeesMock;
+
+impl RewardPayeeManager<AccountId> for RewardPayeesMock {
+	fn set_reward_payee(who: &AccountId, payee: Option<AccountId>) {
+		let mut x = RewardPayeeMap::get();
+		match payee {
+			Some(payee) => x.insert(*who, payee),
+			None => x.remove(who),
+		};
+		RewardPayeeMap::set(&x);
+	}
+}
+
+parameter_types! {
+	pub static ValidatorInfos: BTreeMap<AccountId, ValidatorInfo<Balance>> = Default::default();
+}
+
+pub struct ValidatorsMock;
+
+impl ValidatorsMock {
+	pub(crate) fn set_validator(
+		who: AccountId,
+		commission: u32,
+		self_stake: Balance,
+		era_points: u32,
+		slashes: u32,
+	) {
+		ValidatorInfos::mutate(|x| {
+			x.insert(
+				who,
+				ValidatorInfo {
+					commission: Perbill::from_percent(commission),
+					self_stake,
+					era_points,
+					slashes,
+					oversubscribed: false,
+				},
+			);
+		});
+	}
+}
+
+impl ValidatorInfoProvider<AccountId, Balance> for ValidatorsMock {
+	fn validators(max: u32) -> Vec<(AccountId, ValidatorInfo<Balance>)> {
+		ValidatorInfos::get().into_iter().take(max as usize).collect()
+	}
+
+	fn validators_weight(_max: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
 	type SS58Prefix = ();
@@ -277,6 +423,7 @@ parameter_types! {
 	pub static MaxMetadataLen: u32 = 2;
 	pub static CheckLevel: u8 = 255;
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const MaxInstantUnbondFee: Perbill = Perbill::from_percent(5);
 }
 
 impl pallet_lst::Config for Runtime {
@@ -288,6 +435,7 @@ impl pallet_lst::Config for Runtime {
 	type BalanceToU256 = BalanceToU256;
 	type U256ToBalance = U256ToBalance;
 	type Staking = StakingMock;
+	type StakingSlash = StakingMock;
 	type PostUnbondingPoolsWindow = PostUnbondingPoolsWindow;
 	type PalletId = PoolsPalletId;
 	type MaxMetadataLen = MaxMetadataLen;
@@ -299,6 +447,15 @@ impl pallet_lst::Config for Runtime {
 	type PoolId = PoolId;
 	type ForceOrigin = frame_system::EnsureRoot<u128>;
 	type MaxPointsToBalance = frame_support::traits::ConstU8<10>;
+	type NominationDelegator = NominationDelegatorMock;
+	type RewardPayees = RewardPayeesMock;
+	type MaxRestakeBlueprints = ConstU32<4>;
+	type MaxInstantUnbondFee = MaxInstantUnbondFee;
+	type MaxInstantUnbondBuffers = ConstU32<4>;
+	type Validators = ValidatorsMock;
+	type MaxAutoNominations = ConstU32<3>;
+	type MaxAutoNominationCandidates = ConstU32<8>;
+	type MaxAutoNominatingPools = ConstU32<1>;
 }
 
 impl pallet_assets::Config for Runtime {
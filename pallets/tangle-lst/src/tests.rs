@@ -22,10 +22,14 @@ use crate::{
 };
 use frame_support::traits::Currency as CurrencyT;
 
+mod auto_nominate;
 mod bond_extra;
 mod bonded_pool;
+mod compound;
 mod create;
+mod instant_unbond;
 mod join;
+mod restake;
 mod slash;
 mod sub_pools;
 mod update_roles;
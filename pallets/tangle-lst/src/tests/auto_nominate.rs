@@ -0,0 +1,140 @@
+use super::*;
+use crate::{Event, mock::Currency};
+use frame_support::{assert_noop, assert_ok, traits::Currency as CurrencyT};
+
+fn strategy(max_validators: u32, interval: EraIndex) -> AutoNominationStrategy {
+	AutoNominationStrategy {
+		max_validators,
+		interval,
+		weights: ScoreWeights { commission: 1, self_stake: 0, era_points: 0, slashing: 1 },
+	}
+}
+
+#[test]
+fn set_auto_nomination_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// only the nominator and the root can configure the strategy.
+		assert_noop!(
+			Lst::set_auto_nomination(RuntimeOrigin::signed(902), 1, Some(strategy(2, 1))),
+			Error::<T>::NotNominator
+		);
+		for invalid in [
+			strategy(0, 1),
+			strategy(4, 1),
+			strategy(2, 0),
+			AutoNominationStrategy { weights: ScoreWeights::default(), ..strategy(2, 1) },
+		] {
+			assert_noop!(
+				Lst::set_auto_nomination(RuntimeOrigin::signed(901), 1, Some(invalid)),
+				Error::<T>::InvalidAutoNominationStrategy
+			);
+		}
+		assert_noop!(
+			Lst::set_auto_nomination(RuntimeOrigin::signed(901), 1, None),
+			Error::<T>::NoAutoNomination
+		);
+
+		// when
+		assert_ok!(Lst::set_auto_nomination(RuntimeOrigin::signed(901), 1, Some(strategy(2, 1))));
+
+		// then
+		assert_eq!(AutoNominations::<T>::get(1).unwrap().strategy, strategy(2, 1));
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				Event::Created { depositor: 10, pool_id: 1 },
+				Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+				Event::AutoNominationSet { pool_id: 1, strategy: Some(strategy(2, 1)) },
+			]
+		);
+		// manual nominations are disabled while the strategy is set.
+		assert_noop!(
+			Lst::nominate(RuntimeOrigin::signed(901), 1, vec![21]),
+			Error::<T>::AutoNominationEnabled
+		);
+
+		// the number of auto nominating pools is capped.
+		Currency::make_free_balance_be(&11, 1000);
+		assert_ok!(Lst::create(RuntimeOrigin::signed(11), 10, 11, 11, 11, None, None));
+		assert_noop!(
+			Lst::set_auto_nomination(RuntimeOrigin::signed(11), 2, Some(strategy(2, 1))),
+			Error::<T>::MaxAutoNominatingPools
+		);
+		// but existing strategies can still be updated.
+		assert_ok!(Lst::set_auto_nomination(RuntimeOrigin::signed(900), 1, Some(strategy(3, 2))));
+
+		// when
+		assert_ok!(Lst::set_auto_nomination(RuntimeOrigin::signed(901), 1, None));
+
+		// then
+		assert!(!AutoNominations::<T>::contains_key(1));
+		assert_ok!(Lst::nominate(RuntimeOrigin::signed(901), 1, vec![21]));
+		assert_ok!(Lst::set_auto_nomination(RuntimeOrigin::signed(11), 2, Some(strategy(2, 1))));
+	})
+}
+
+#[test]
+fn auto_nominate_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		ValidatorsMock::set_validator(100, 10, 100, 10, 0);
+		ValidatorsMock::set_validator(101, 5, 100, 10, 0);
+		ValidatorsMock::set_validator(102, 20, 100, 10, 0);
+		ValidatorsMock::set_validator(103, 1, 100, 10, 0);
+		assert_ok!(Lst::set_auto_nomination(RuntimeOrigin::signed(901), 1, Some(strategy(2, 2))));
+		let _ = pool_events_since_last_call();
+
+		// when
+		CurrentEra::set(1);
+		run_to_block(2);
+
+		// then the validators with the lowest commission are nominated.
+		assert_eq!(Nominations::get(), Some(vec![101, 103]));
+		assert_eq!(AutoNominations::<T>::get(1).unwrap().next_era, 3);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::PoolAutoNominated { pool_id: 1, validators: vec![101, 103] }]
+		);
+
+		// the selection is not repeated before the interval passed.
+		ValidatorsMock::set_validator(100, 0, 100, 10, 0);
+		CurrentEra::set(2);
+		run_to_block(3);
+		assert_eq!(Nominations::get(), Some(vec![101, 103]));
+
+		// when
+		CurrentEra::set(3);
+		run_to_block(4);
+
+		// then
+		assert_eq!(Nominations::get(), Some(vec![100, 103]));
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::PoolAutoNominated { pool_id: 1, validators: vec![100, 103] }]
+		);
+
+		// an unchanged selection is not nominated again.
+		CurrentEra::set(5);
+		run_to_block(5);
+		assert_eq!(AutoNominations::<T>::get(1).unwrap().next_era, 7);
+		assert_eq!(pool_events_since_last_call(), vec![]);
+	})
+}
+
+#[test]
+fn offline_and_slashed_validators_are_ranked_out() {
+	ExtBuilder::default().build_and_execute(|| {
+		// no era points in the last era.
+		ValidatorsMock::set_validator(100, 0, 100, 0, 0);
+		// slashed twice.
+		ValidatorsMock::set_validator(101, 0, 100, 10, 2);
+		ValidatorsMock::set_validator(102, 10, 100, 10, 0);
+		assert_ok!(Lst::set_auto_nomination(RuntimeOrigin::signed(901), 1, Some(strategy(1, 1))));
+
+		// when
+		CurrentEra::set(1);
+		run_to_block(2);
+
+		// then
+		assert_eq!(Nominations::get(), Some(vec![102]));
+	})
+}
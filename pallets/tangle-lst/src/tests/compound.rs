@@ -0,0 +1,91 @@
+use super::*;
+use crate::{Event, mock::Currency};
+use frame_support::{assert_noop, assert_ok, traits::Currency as CurrencyT};
+
+#[test]
+fn compound_bonds_rewards_into_pool() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		let bonded_pool = BondedPool::<Runtime>::get(1).unwrap();
+		assert_eq!(TotalValueLocked::<T>::get(), 30);
+		assert_eq!(bonded_pool.points_to_balance(20), 20);
+
+		// the reward account keeps its existential deposit of 5.
+		Currency::make_free_balance_be(&default_reward_account(), 35);
+
+		// when
+		assert_ok!(Lst::compound(RuntimeOrigin::signed(99), 1));
+
+		// then
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				Event::Created { depositor: 10, pool_id: 1 },
+				Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+				Event::Bonded { member: 20, pool_id: 1, bonded: 20, joined: true },
+				Event::RewardsCompounded { pool_id: 1, amount: 30 },
+			]
+		);
+		assert_eq!(Currency::free_balance(default_reward_account()), 5);
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 60);
+		assert_eq!(TotalValueLocked::<T>::get(), 60);
+
+		// no new points are issued, so every point is worth twice as much.
+		assert_eq!(Assets::total_issuance(1), 30);
+		assert_eq!(bonded_pool.points_to_balance(20), 40);
+
+		// nothing left to compound.
+		assert_noop!(Lst::compound(RuntimeOrigin::signed(99), 1), Error::<T>::NothingToCompound);
+	})
+}
+
+#[test]
+fn compound_leaves_pending_commission() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		assert_ok!(Lst::set_commission(
+			RuntimeOrigin::signed(900),
+			1,
+			Some((Perbill::from_percent(50), 900))
+		));
+		Currency::make_free_balance_be(&default_reward_account(), 45);
+
+		// when
+		assert_ok!(Lst::compound(RuntimeOrigin::signed(99), 1));
+
+		// then half of the rewards is bonded and the commission stays claimable.
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 50);
+		assert_eq!(RewardPools::<Runtime>::get(1).unwrap().total_commission_pending, 20);
+		assert_eq!(Currency::free_balance(default_reward_account()), 25);
+	})
+}
+
+#[test]
+fn transferred_tokens_carry_compounded_rewards() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		Currency::make_free_balance_be(&default_reward_account(), 35);
+		assert_ok!(Lst::compound(RuntimeOrigin::signed(99), 1));
+
+		// 20 moves half of their tokens to 30.
+		Currency::make_free_balance_be(&30, 100);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(20), 1, 30, 10));
+		let _ = pool_events_since_last_call();
+
+		// when
+		assert_ok!(Lst::unbond(RuntimeOrigin::signed(30), 30, 1, 10));
+
+		// then the receiver unbonds the tokens together with their share of the rewards.
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::Unbonded { member: 30, pool_id: 1, balance: 20, points: 20, era: 3 }]
+		);
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 40);
+		assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points_to_balance(10), 20);
+	})
+}
+
+#[test]
+fn compound_fails_without_rewards() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(Lst::compound(RuntimeOrigin::signed(99), 1), Error::<T>::NothingToCompound);
+		assert_noop!(Lst::compound(RuntimeOrigin::signed(99), 2), Error::<T>::PoolNotFound);
+	})
+}
@@ -0,0 +1,253 @@
+use super::*;
+use crate::{Event, mock::Currency};
+use frame_support::{assert_noop, assert_ok, traits::Currency as CurrencyT};
+use sp_staking::OnStakingUpdate;
+
+fn buffer_account() -> AccountId {
+	Lst::create_buffer_account(1)
+}
+
+#[test]
+fn set_instant_unbond_buffer_works() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		let target = Perbill::from_percent(20);
+		let fee = Perbill::from_percent(5);
+
+		// only the root can configure the buffer.
+		assert_noop!(
+			Lst::set_instant_unbond_buffer(RuntimeOrigin::signed(901), 1, target, fee),
+			Error::<T>::DoesNotHavePermission
+		);
+		assert_noop!(
+			Lst::set_instant_unbond_buffer(
+				RuntimeOrigin::signed(900),
+				1,
+				target,
+				Perbill::from_percent(6)
+			),
+			Error::<T>::InstantUnbondFeeTooHigh
+		);
+		// a buffer that does not exist cannot be disabled.
+		assert_noop!(
+			Lst::set_instant_unbond_buffer(RuntimeOrigin::signed(900), 1, Perbill::zero(), fee),
+			Error::<T>::NoInstantUnbondBuffer
+		);
+
+		// when
+		assert_ok!(Lst::set_instant_unbond_buffer(RuntimeOrigin::signed(900), 1, target, fee));
+
+		// then
+		assert_eq!(InstantUnbondBuffers::<T>::get(1), Some(InstantUnbondBuffer::new(target, fee)));
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				Event::Created { depositor: 10, pool_id: 1 },
+				Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+				Event::Bonded { member: 20, pool_id: 1, bonded: 20, joined: true },
+				Event::InstantUnbondBufferSet { pool_id: 1, target, fee },
+			]
+		);
+		// the buffer is empty until the next era.
+		assert_eq!(Lst::instant_unbond_liquidity(1), 0);
+	})
+}
+
+#[test]
+fn instant_unbond_works() {
+	ExtBuilder::default().add_members(vec![(20, 1000)]).build_and_execute(|| {
+		assert_ok!(Lst::set_instant_unbond_buffer(
+			RuntimeOrigin::signed(900),
+			1,
+			Perbill::from_percent(20),
+			Perbill::from_percent(5)
+		));
+		assert_noop!(
+			Lst::instant_unbond(RuntimeOrigin::signed(20), 1, 100),
+			Error::<T>::InsufficientInstantLiquidity
+		);
+		let _ = pool_events_since_last_call();
+
+		// the refill is unbonded at the start of the next era, without changing the pool balance.
+		CurrentEra::set(1);
+		run_to_block(2);
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 808);
+		assert_eq!(BondedPool::<T>::get(1).unwrap().points_to_balance(1010), 1010);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::InstantUnbondBufferRebalanced { pool_id: 1, buffered: 0, unbonding: 202 }]
+		);
+
+		// and moved into the buffer once it can be withdrawn.
+		CurrentEra::set(4);
+		run_to_block(3);
+		assert_eq!(Currency::free_balance(buffer_account()), 202);
+		assert_eq!(Lst::instant_unbond_liquidity(1), 197);
+		assert_eq!(TotalValueLocked::<T>::get(), 808);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::InstantUnbondBufferRebalanced { pool_id: 1, buffered: 202, unbonding: 0 }]
+		);
+
+		// when
+		assert_ok!(Lst::instant_unbond(RuntimeOrigin::signed(20), 1, 100));
+
+		// then
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::InstantUnbonded {
+				member: 20,
+				pool_id: 1,
+				points: 100,
+				balance: 100,
+				fee: 5
+			}]
+		);
+		assert_eq!(Currency::free_balance(20), 1095);
+		assert_eq!(Assets::balance(1, 20), 900);
+		// the fee stays with the remaining members.
+		assert_eq!(BondedPool::<T>::get(1).unwrap().points_to_balance(910), 915);
+		assert_eq!(Lst::instant_unbond_liquidity(1), 102);
+		assert_noop!(
+			Lst::instant_unbond(RuntimeOrigin::signed(20), 1, 200),
+			Error::<T>::InsufficientInstantLiquidity
+		);
+
+		// the next rebalance refills the buffer.
+		CurrentEra::set(5);
+		run_to_block(4);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::InstantUnbondBufferRebalanced { pool_id: 1, buffered: 107, unbonding: 76 }]
+		);
+	})
+}
+
+#[test]
+fn unbond_pays_the_buffered_share_directly() {
+	ExtBuilder::default().add_members(vec![(20, 1000)]).build_and_execute(|| {
+		assert_ok!(Lst::set_instant_unbond_buffer(
+			RuntimeOrigin::signed(900),
+			1,
+			Perbill::from_percent(20),
+			Perbill::from_percent(5)
+		));
+		CurrentEra::set(1);
+		run_to_block(2);
+		CurrentEra::set(4);
+		run_to_block(3);
+		assert_eq!(Currency::free_balance(buffer_account()), 202);
+		let _ = pool_events_since_last_call();
+
+		// when
+		assert_ok!(Lst::unbond(RuntimeOrigin::signed(20), 20, 1, 100));
+
+		// then only the share of the bonded funds is unbonded from staking.
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				Event::UnbondedFromBuffer { member: 20, pool_id: 1, balance: 20 },
+				Event::Unbonded { member: 20, pool_id: 1, balance: 80, points: 80, era: 7 },
+			]
+		);
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 728);
+		assert_eq!(Currency::free_balance(buffer_account()), 182);
+		assert_eq!(Currency::free_balance(20), 1020);
+		// the remaining members keep their balance.
+		assert_eq!(BondedPool::<T>::get(1).unwrap().points_to_balance(910), 910);
+	})
+}
+
+#[test]
+fn disabled_buffer_is_bonded_back() {
+	ExtBuilder::default().add_members(vec![(20, 1000)]).build_and_execute(|| {
+		assert_ok!(Lst::set_instant_unbond_buffer(
+			RuntimeOrigin::signed(900),
+			1,
+			Perbill::from_percent(20),
+			Perbill::from_percent(5)
+		));
+		CurrentEra::set(1);
+		run_to_block(2);
+		CurrentEra::set(4);
+		run_to_block(3);
+		assert_eq!(Currency::free_balance(buffer_account()), 202);
+		let _ = pool_events_since_last_call();
+
+		// when
+		assert_ok!(Lst::set_instant_unbond_buffer(
+			RuntimeOrigin::signed(900),
+			1,
+			Perbill::zero(),
+			Perbill::from_percent(5)
+		));
+		CurrentEra::set(5);
+		run_to_block(4);
+
+		// then
+		assert_eq!(Currency::free_balance(buffer_account()), 0);
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 1010);
+		assert_eq!(TotalValueLocked::<T>::get(), 1010);
+		assert_eq!(InstantUnbondBuffers::<T>::get(1), None);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				Event::InstantUnbondBufferSet {
+					pool_id: 1,
+					target: Perbill::zero(),
+					fee: Perbill::from_percent(5)
+				},
+				Event::InstantUnbondBufferRemoved { pool_id: 1 },
+			]
+		);
+		assert_noop!(
+			Lst::instant_unbond(RuntimeOrigin::signed(20), 1, 10),
+			Error::<T>::NoInstantUnbondBuffer
+		);
+	})
+}
+
+#[test]
+fn refill_skips_restaked_funds() {
+	ExtBuilder::default().add_members(vec![(20, 1000)]).build_and_execute(|| {
+		assert_ok!(Lst::restake(RuntimeOrigin::signed(901), 1, 50, 1000, None));
+		assert_ok!(Lst::set_instant_unbond_buffer(
+			RuntimeOrigin::signed(900),
+			1,
+			Perbill::from_percent(20),
+			Perbill::from_percent(5)
+		));
+
+		// when
+		CurrentEra::set(1);
+		run_to_block(2);
+
+		// then
+		assert_eq!(InstantUnbondBuffers::<T>::get(1).unwrap().unbonding, 10);
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 1000);
+	})
+}
+
+#[test]
+fn slash_is_shared_with_the_refill() {
+	ExtBuilder::default().add_members(vec![(20, 1000)]).build_and_execute(|| {
+		assert_ok!(Lst::set_instant_unbond_buffer(
+			RuntimeOrigin::signed(900),
+			1,
+			Perbill::from_percent(20),
+			Perbill::from_percent(5)
+		));
+		CurrentEra::set(1);
+		assert_ok!(Lst::unbond(RuntimeOrigin::signed(20), 20, 1, 110));
+		run_to_block(2);
+		// the member and the buffer share the unlocking chunk of era 4.
+		assert_eq!(SubPoolsStorage::<T>::get(1).unwrap().with_era.get(&4).unwrap().balance, 110);
+		assert_eq!(InstantUnbondBuffers::<T>::get(1).unwrap().unbonding, 180);
+
+		// when
+		Lst::on_slash(&default_bonded_account(), 720, &BTreeMap::from([(4, 145)]), 145);
+
+		// then
+		assert_eq!(SubPoolsStorage::<T>::get(1).unwrap().with_era.get(&4).unwrap().balance, 55);
+		assert_eq!(InstantUnbondBuffers::<T>::get(1).unwrap().unbonding, 90);
+	})
+}
@@ -0,0 +1,138 @@
+use super::*;
+use crate::{Event, mock::Currency};
+use frame_support::{assert_noop, assert_ok, traits::Currency as CurrencyT};
+
+#[test]
+fn restake_works() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		// only the nominator or root can restake.
+		assert_noop!(
+			Lst::restake(RuntimeOrigin::signed(20), 1, 50, 20, None),
+			Error::<T>::NotNominator
+		);
+
+		// when
+		assert_ok!(Lst::restake(RuntimeOrigin::signed(901), 1, 50, 20, None));
+
+		// then
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				Event::Created { depositor: 10, pool_id: 1 },
+				Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+				Event::Bonded { member: 20, pool_id: 1, bonded: 20, joined: true },
+				Event::PoolRestaked { pool_id: 1, operator: 50, amount: 20 },
+			]
+		);
+		assert_eq!(RestakedBalanceMap::get().get(&(default_bonded_account(), 50)), Some(&(20, 0)));
+		// restaking rewards are paid to the reward account.
+		assert_eq!(
+			RewardPayeeMap::get().get(&default_bonded_account()),
+			Some(&default_reward_account())
+		);
+
+		// the pool cannot restake more than it has bonded.
+		assert_noop!(
+			Lst::restake(RuntimeOrigin::signed(900), 1, 51, 11, None),
+			DispatchError::Other("InsufficientBalance")
+		);
+		assert_ok!(Lst::restake(
+			RuntimeOrigin::signed(900),
+			1,
+			51,
+			10,
+			Some(vec![1, 2].try_into().unwrap())
+		));
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::PoolRestaked { pool_id: 1, operator: 51, amount: 10 }]
+		);
+	})
+}
+
+#[test]
+fn restaked_funds_cannot_be_unbonded() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		assert_ok!(Lst::restake(RuntimeOrigin::signed(901), 1, 50, 20, None));
+
+		// the unrestaked part of the pool can be unbonded.
+		assert_noop!(Lst::unbond(RuntimeOrigin::signed(20), 20, 1, 20), Error::<T>::FundsRestaked);
+		assert_ok!(Lst::unbond(RuntimeOrigin::signed(20), 20, 1, 10));
+		assert_noop!(Lst::unbond(RuntimeOrigin::signed(20), 20, 1, 10), Error::<T>::FundsRestaked);
+
+		// when
+		assert_noop!(
+			Lst::unrestake(RuntimeOrigin::signed(20), 1, 50, 10),
+			Error::<T>::NotNominator
+		);
+		assert_ok!(Lst::unrestake(RuntimeOrigin::signed(901), 1, 50, 10));
+		assert_ok!(Lst::execute_unrestake(RuntimeOrigin::signed(99), 1, 50));
+
+		// then
+		let _ = pool_events_since_last_call();
+		assert_ok!(Lst::unbond(RuntimeOrigin::signed(20), 20, 1, 10));
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![Event::Unbonded { member: 20, pool_id: 1, balance: 10, points: 10, era: 3 }]
+		);
+		assert_eq!(RestakedBalanceMap::get().get(&(default_bonded_account(), 50)), Some(&(10, 0)));
+	})
+}
+
+#[test]
+fn execute_unrestake_emits_released_amount() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		assert_ok!(Lst::restake(RuntimeOrigin::signed(901), 1, 50, 20, None));
+		assert_ok!(Lst::unrestake(RuntimeOrigin::signed(901), 1, 50, 15));
+
+		// when
+		assert_ok!(Lst::execute_unrestake(RuntimeOrigin::signed(99), 1, 50));
+
+		// then
+		assert_eq!(
+			pool_events_since_last_call()[3..],
+			vec![
+				Event::PoolRestaked { pool_id: 1, operator: 50, amount: 20 },
+				Event::PoolUnrestakeScheduled { pool_id: 1, operator: 50, amount: 15 },
+				Event::PoolUnrestaked { pool_id: 1, operator: 50, amount: 15 },
+			]
+		);
+		assert_noop!(
+			Lst::execute_unrestake(RuntimeOrigin::signed(99), 2, 50),
+			Error::<T>::PoolNotFound
+		);
+	})
+}
+
+#[test]
+fn restake_slash_is_shared_with_unbonding_pools() {
+	ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+		// 10 of the pool's 30 are unbonding.
+		assert_ok!(Lst::unbond(RuntimeOrigin::signed(20), 20, 1, 10));
+		assert_ok!(Lst::restake(RuntimeOrigin::signed(901), 1, 50, 20, None));
+		let _ = pool_events_since_last_call();
+		assert_eq!(Currency::free_balance(default_bonded_account()), 30);
+
+		// when
+		assert_eq!(Lst::slash_nomination(&default_bonded_account(), 15), Ok(15));
+
+		// then a third of the slash hits the unbonding pool.
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				Event::UnbondingPoolSlashed { pool_id: 1, era: 3, balance: 5 },
+				Event::PoolSlashed { pool_id: 1, balance: 10 },
+			]
+		);
+		assert_eq!(SubPoolsStorage::<T>::get(1).unwrap().with_era[&3].balance, 5);
+		// the slash is taken from the staking ledger, so the bonded funds stay backed.
+		assert_eq!(StakingMock::active_stake(&default_bonded_account()).unwrap(), 10);
+		assert_eq!(StakingMock::total_stake(&default_bonded_account()).unwrap(), 15);
+		assert_eq!(UnbondingBalanceMap::get()[&default_bonded_account()], vec![(3, 5)]);
+		assert_eq!(Currency::free_balance(default_bonded_account()), 15);
+		assert_eq!(TotalValueLocked::<T>::get(), 15);
+
+		// accounts that are not pools are not slashed.
+		assert_eq!(Lst::slash_nomination(&99, 10), Ok(0));
+	})
+}
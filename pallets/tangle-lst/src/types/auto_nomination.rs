@@ -0,0 +1,68 @@
+use super::*;
+
+/// The relative weights of the criteria validators are scored by.
+///
+/// Each criterion is scored between zero and one, and the score of a validator is the sum of the
+/// criteria multiplied by their weight.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct ScoreWeights {
+	/// Weight of a low commission.
+	pub commission: u8,
+	/// Weight of a high self-stake, relative to the highest self-stake of all candidates.
+	pub self_stake: u8,
+	/// Weight of the era points of the last era, relative to the highest of all candidates.
+	pub era_points: u8,
+	/// Weight of a clean slashing history.
+	pub slashing: u8,
+}
+
+impl ScoreWeights {
+	/// Whether no criterion is weighted at all.
+	pub fn is_zero(&self) -> bool {
+		self.commission == 0 && self.self_stake == 0 && self.era_points == 0 && self.slashing == 0
+	}
+
+	/// The score of a validator with the given criteria.
+	pub fn score(
+		&self,
+		commission: Perbill,
+		self_stake: Perbill,
+		era_points: Perbill,
+		slashing: Perbill,
+	) -> u64 {
+		[
+			(self.commission, commission),
+			(self.self_stake, self_stake),
+			(self.era_points, era_points),
+			(self.slashing, slashing),
+		]
+		.into_iter()
+		.map(|(weight, criterion)| u64::from(weight) * u64::from(criterion.deconstruct()))
+		.sum()
+	}
+}
+
+/// How a pool automatically selects the validators it nominates.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AutoNominationStrategy {
+	/// The number of best scored validators to nominate.
+	pub max_validators: u32,
+	/// The number of eras between two selections.
+	pub interval: EraIndex,
+	/// The weights validators are scored by.
+	pub weights: ScoreWeights,
+}
+
+/// The automatic nomination state of a pool.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebugNoBound)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq))]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct AutoNomination<T: Config> {
+	/// The strategy the validators are selected by.
+	pub strategy: AutoNominationStrategy,
+	/// The first era in which the validators are selected again.
+	pub next_era: EraIndex,
+	/// The validators selected last.
+	pub nominated: BoundedVec<T::AccountId, T::MaxAutoNominations>,
+}
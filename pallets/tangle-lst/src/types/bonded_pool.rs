@@ -85,6 +85,11 @@ impl<T: Config> BondedPool<T> {
 		Pallet::<T>::create_reward_account(self.id)
 	}
 
+	/// Get the instant unbond buffer account id of this pool.
+	pub fn buffer_account(&self) -> T::AccountId {
+		Pallet::<T>::create_buffer_account(self.id)
+	}
+
 	/// Consume self and put into storage.
 	pub fn put(self) {
 		BondedPools::<T>::insert(self.id, self.inner);
@@ -104,18 +109,28 @@ impl<T: Config> BondedPool<T> {
 	///
 	/// This is often used for bonding and issuing new funds into the pool.
 	pub fn balance_to_point(&self, new_funds: BalanceOf<T>) -> BalanceOf<T> {
-		let bonded_balance =
-			T::Staking::active_stake(&self.bonded_account()).unwrap_or(Zero::zero());
-		Pallet::<T>::balance_to_point(bonded_balance, self.points(), new_funds)
+		Pallet::<T>::balance_to_point(self.balance(), self.points(), new_funds)
 	}
 
 	/// Convert the given number of points to balance given the current pool state.
 	///
 	/// This is often used for unbonding.
 	pub fn points_to_balance(&self, points: BalanceOf<T>) -> BalanceOf<T> {
+		Pallet::<T>::point_to_balance(self.balance(), self.points(), points)
+	}
+
+	/// The balance backing the points of the pool.
+	///
+	/// This is the active stake plus the funds held and being refilled for instant unbonds.
+	pub fn balance(&self) -> BalanceOf<T> {
 		let bonded_balance =
 			T::Staking::active_stake(&self.bonded_account()).unwrap_or(Zero::zero());
-		Pallet::<T>::point_to_balance(bonded_balance, self.points(), points)
+		let buffer_unbonding = InstantUnbondBuffers::<T>::get(self.id)
+			.map(|buffer| buffer.unbonding)
+			.unwrap_or_default();
+		bonded_balance
+			.saturating_add(T::Currency::free_balance(&self.buffer_account()))
+			.saturating_add(buffer_unbonding)
 	}
 
 	/// Issue points to [`Self`] for `new_funds`.
@@ -179,6 +194,10 @@ impl<T: Config> BondedPool<T> {
 		self.is_root(who)
 	}
 
+	pub fn can_manage_instant_unbond(&self, who: &T::AccountId) -> bool {
+		self.is_root(who)
+	}
+
 	pub fn can_claim_commission(&self, who: &T::AccountId) -> bool {
 		if let Some(permission) = self.commission.claim_permission.as_ref() {
 			match permission {
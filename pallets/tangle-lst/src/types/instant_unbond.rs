@@ -0,0 +1,33 @@
+use super::*;
+
+/// The instant unbond buffer of a pool.
+///
+/// The buffer keeps part of the pool's funds unbonded in the pool's buffer account, so members
+/// can leave the pool without waiting for the bonding duration. It is rebalanced towards `target`
+/// once per era.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebugNoBound)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq))]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct InstantUnbondBuffer<T: Config> {
+	/// The share of the pool's funds to keep in the buffer. Zero drains the buffer.
+	pub target: Perbill,
+	/// The share of the unbonded balance kept by the pool when a member unbonds instantly.
+	pub fee: Perbill,
+	/// The balance being unbonded from staking to refill the buffer.
+	pub unbonding: BalanceOf<T>,
+	/// The era in which `unbonding` can be withdrawn into the buffer.
+	pub unlock_era: EraIndex,
+}
+
+impl<T: Config> InstantUnbondBuffer<T> {
+	/// Create a new, empty buffer with the given target and fee.
+	pub fn new(target: Perbill, fee: Perbill) -> Self {
+		Self { target, fee, unbonding: Zero::zero(), unlock_era: Zero::zero() }
+	}
+
+	/// Whether the buffer is disabled and holds no funds anymore.
+	pub fn is_drained(&self, buffered: BalanceOf<T>) -> bool {
+		self.target.is_zero() && self.unbonding.is_zero() && buffered.is_zero()
+	}
+}
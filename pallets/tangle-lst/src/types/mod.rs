@@ -1,12 +1,18 @@
 use super::*;
+pub mod auto_nomination;
 pub mod bonded_pool;
 pub mod commission;
+pub mod instant_unbond;
 pub mod pools;
+pub mod staking_slash;
 pub mod sub_pools;
 
+pub use auto_nomination::*;
 pub use bonded_pool::*;
 pub use commission::*;
+pub use instant_unbond::*;
 pub use pools::*;
+pub use staking_slash::*;
 pub use sub_pools::*;
 
 /// The balance type used by the currency system.
@@ -50,6 +56,7 @@ pub enum BondExtra<Balance> {
 pub enum AccountType {
 	Bonded,
 	Reward,
+	Buffer,
 }
 
 /// The permission a pool member can set for other accounts to claim rewards on their behalf.
@@ -0,0 +1,72 @@
+use super::*;
+
+/// Slashes the funds an account has bonded in staking.
+///
+/// Restaking slashes are applied by the pools themselves rather than by staking, so they have to
+/// reduce the staking ledger together with the balance to keep the bonded funds backed.
+pub trait StakingSlash<AccountId, Balance> {
+	/// Slash up to `amount` of the active and unlocking funds of `who` and burn them.
+	///
+	/// Returns the active balance left, the remaining balance of each slashed unlocking chunk by
+	/// era and the slashed amount, as expected by [`sp_staking::OnStakingUpdate::on_slash`].
+	fn slash(who: &AccountId, amount: Balance) -> (Balance, BTreeMap<EraIndex, Balance>, Balance);
+}
+
+impl<AccountId, Balance: Zero> StakingSlash<AccountId, Balance> for () {
+	fn slash(
+		_who: &AccountId,
+		_amount: Balance,
+	) -> (Balance, BTreeMap<EraIndex, Balance>, Balance) {
+		(Zero::zero(), BTreeMap::new(), Zero::zero())
+	}
+}
+
+impl<T: pallet_staking::Config> StakingSlash<T::AccountId, pallet_staking::BalanceOf<T>>
+	for pallet_staking::Pallet<T>
+{
+	fn slash(
+		who: &T::AccountId,
+		amount: pallet_staking::BalanceOf<T>,
+	) -> (
+		pallet_staking::BalanceOf<T>,
+		BTreeMap<EraIndex, pallet_staking::BalanceOf<T>>,
+		pallet_staking::BalanceOf<T>,
+	) {
+		let ledger =
+			|| pallet_staking::Bonded::<T>::get(who).and_then(pallet_staking::Ledger::<T>::get);
+		let Some(before) = ledger() else {
+			return (Zero::zero(), BTreeMap::new(), Zero::zero());
+		};
+
+		// All chunks that are still unlocking are slashable.
+		let slash_era = pallet_staking::CurrentEra::<T>::get()
+			.unwrap_or_default()
+			.saturating_sub(T::BondingDuration::get());
+		// Dropping the imbalance burns the slashed funds.
+		pallet_staking::slashing::do_slash::<T>(
+			who,
+			amount,
+			&mut Zero::zero(),
+			&mut Default::default(),
+			slash_era,
+		);
+
+		let Some(after) = ledger().defensive() else {
+			return (Zero::zero(), BTreeMap::new(), before.total);
+		};
+		let slashed_unlocking = before
+			.unlocking
+			.iter()
+			.filter_map(|chunk| {
+				let value = after
+					.unlocking
+					.iter()
+					.find(|remaining| remaining.era == chunk.era)
+					.map_or(Zero::zero(), |remaining| remaining.value);
+				(value != chunk.value).then_some((chunk.era, value))
+			})
+			.collect();
+
+		(after.active, slashed_unlocking, before.total.saturating_sub(after.total))
+	}
+}
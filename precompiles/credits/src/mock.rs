@@ -441,6 +441,10 @@ impl pallet_credits::Config for Runtime {
 	type MaxStakeTiers = MaxStakeTiers;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxRatePerBlock = MaxRatePerBlock;
+	type CreditExpiryBlocks = ConstU64<{ tangle_primitives::credits::CREDIT_EXPIRY_BLOCKS }>;
+	type MaxCreditBatches = ConstU32<{ tangle_primitives::credits::MAX_CREDIT_BATCHES }>;
+	type MaxStakeCheckpoints = ConstU32<{ tangle_primitives::credits::MAX_STAKE_CHECKPOINTS }>;
+	type CreditSpendOrigin = frame_system::EnsureRoot<AccountId>;
 	type WeightInfo = ();
 }
 
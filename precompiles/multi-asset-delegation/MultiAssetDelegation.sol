@@ -120,4 +120,80 @@ interface MultiAssetDelegation {
     /// @return The delegated nomination balance of the delegator.
     /// @custom:selector d1909653
     function delegatedNominationBalance(address who) external view returns (uint256);
+
+    /// @dev Set or update the profile of the calling operator.
+    /// @param name The display name of the operator.
+    /// @param website The website of the operator.
+    /// @param contact Contact information of the operator.
+    /// @param region The geographic region of the operator.
+    /// @param hardwareClass The class of hardware the operator runs.
+    /// @param blueprintsDescription A description of the blueprints the operator supports.
+    /// @custom:selector fefb15b9
+    function setOperatorProfile(
+        bytes memory name,
+        bytes memory website,
+        bytes memory contact,
+        bytes memory region,
+        bytes memory hardwareClass,
+        bytes memory blueprintsDescription
+    ) external;
+
+    /// @dev Clear the profile of the calling operator and release its deposit.
+    /// @custom:selector 3a6e3739
+    function clearOperatorProfile() external;
+
+    /// @dev Get the profile of an operator.
+    /// @param operator The address of the operator.
+    /// @return name The display name of the operator.
+    /// @return website The website of the operator.
+    /// @return contact Contact information of the operator.
+    /// @return region The geographic region of the operator.
+    /// @return hardwareClass The class of hardware the operator runs.
+    /// @return blueprintsDescription A description of the blueprints the operator supports.
+    /// @custom:selector 6aa44dd8
+    function operatorProfile(bytes32 operator)
+        external
+        view
+        returns (
+            bytes memory name,
+            bytes memory website,
+            bytes memory contact,
+            bytes memory region,
+            bytes memory hardwareClass,
+            bytes memory blueprintsDescription
+        );
+
+    /// @dev Get the operators whose profile matches the given region and hardware class.
+    /// @param region The region to filter by, or empty to match any region.
+    /// @param hardwareClass The hardware class to filter by, or empty to match any hardware class.
+    /// @return The matching operators.
+    /// @custom:selector 5627170e
+    function operatorsByProfile(bytes memory region, bytes memory hardwareClass)
+        external
+        view
+        returns (bytes32[] memory);
+
+    /// @dev Delegate deposited assets through the liquid vault of an operator, minting receipt shares.
+    /// @param operator The address of the operator.
+    /// @param assetId The ID of the asset (0 for ERC20).
+    /// @param tokenAddress The address of the ERC20 token (if assetId is 0).
+    /// @param amount The amount to delegate.
+    /// @custom:selector 890f3b68
+    function liquidDelegate(bytes32 operator, uint256 assetId, address tokenAddress, uint256 amount) external;
+
+    /// @dev Burn liquid vault receipt shares and schedule the unstake of their value.
+    /// @param operator The address of the operator.
+    /// @param assetId The ID of the asset (0 for ERC20).
+    /// @param tokenAddress The address of the ERC20 token (if assetId is 0).
+    /// @param shares The amount of receipt shares to burn.
+    /// @custom:selector 3a5489b5
+    function liquidUnstake(bytes32 operator, uint256 assetId, address tokenAddress, uint256 shares) external;
+
+    /// @dev Execute the ready liquid vault unstakes of the caller, returning them to its deposits.
+    /// @custom:selector 1f1eec8a
+    function executeLiquidUnstake() external;
+
+    /// @dev Execute every matured unstake and withdraw request of the caller at once.
+    /// @custom:selector bb598d18
+    function executeAllReady() external;
 }
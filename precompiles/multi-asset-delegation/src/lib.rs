@@ -48,10 +48,13 @@ use evm_erc20_utils::*;
 use fp_evm::PrecompileHandle;
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
-	traits::Currency,
+	traits::{Currency, Get},
+	BoundedVec,
 };
 use pallet_evm::AddressMapping;
-use pallet_multi_asset_delegation::types::DelegatorBlueprintSelection;
+use pallet_multi_asset_delegation::types::{
+	DelegatorBlueprintSelection, OperatorProfile, OperatorProfileOf,
+};
 use precompile_utils::prelude::*;
 use sp_core::{H256, U256};
 use sp_runtime::traits::Dispatchable;
@@ -65,6 +68,23 @@ type BalanceOf<Runtime> =
 
 type AssetIdOf<Runtime> = <Runtime as pallet_multi_asset_delegation::Config>::AssetId;
 
+/// The fields of an operator profile, as exposed to the EVM.
+type OperatorProfileTuple = (
+	UnboundedBytes,
+	UnboundedBytes,
+	UnboundedBytes,
+	UnboundedBytes,
+	UnboundedBytes,
+	UnboundedBytes,
+);
+
+/// Converts EVM bytes into a bounded profile field, reverting if the field is too long.
+fn bounded_field<S: Get<u32>>(bytes: UnboundedBytes, field: &str) -> EvmResult<BoundedVec<u8, S>> {
+	Vec::<u8>::from(bytes)
+		.try_into()
+		.map_err(|_| RevertReason::value_is_too_large(field).into())
+}
+
 pub struct MultiAssetDelegationPrecompile<Runtime>(PhantomData<Runtime>);
 
 #[precompile_utils::precompile]
@@ -76,7 +96,7 @@ where
 	Runtime::RuntimeCall: From<pallet_multi_asset_delegation::Call<Runtime>>,
 	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
 	AssetIdOf<Runtime>: TryFrom<U256> + Into<U256> + From<u32>,
-	Runtime::AccountId: From<WrappedAccountId32>,
+	Runtime::AccountId: From<WrappedAccountId32> + AsRef<[u8; 32]>,
 {
 	#[precompile::public("balanceOf(address,uint256,address)")]
 	#[precompile::view]
@@ -532,4 +552,223 @@ where
 
 		Ok(balance.into())
 	}
+
+	#[precompile::public("setOperatorProfile(bytes,bytes,bytes,bytes,bytes,bytes)")]
+	fn set_operator_profile(
+		handle: &mut impl PrecompileHandle,
+		name: UnboundedBytes,
+		website: UnboundedBytes,
+		contact: UnboundedBytes,
+		region: UnboundedBytes,
+		hardware_class: UnboundedBytes,
+		blueprints_description: UnboundedBytes,
+	) -> EvmResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let caller = handle.context().caller;
+		let who = Runtime::AddressMapping::into_account_id(caller);
+
+		let profile: OperatorProfileOf<Runtime> = OperatorProfile {
+			name: bounded_field(name, "name")?,
+			website: bounded_field(website, "website")?,
+			contact: bounded_field(contact, "contact")?,
+			region: bounded_field(region, "region")?,
+			hardware_class: bounded_field(hardware_class, "hardwareClass")?,
+			blueprints_description: bounded_field(blueprints_description, "blueprintsDescription")?,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_multi_asset_delegation::Call::<Runtime>::set_operator_profile { profile },
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("clearOperatorProfile()")]
+	fn clear_operator_profile(handle: &mut impl PrecompileHandle) -> EvmResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let caller = handle.context().caller;
+		let who = Runtime::AddressMapping::into_account_id(caller);
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_multi_asset_delegation::Call::<Runtime>::clear_operator_profile {},
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("operatorProfile(bytes32)")]
+	#[precompile::view]
+	fn operator_profile(
+		handle: &mut impl PrecompileHandle,
+		operator: H256,
+	) -> EvmResult<OperatorProfileTuple> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let operator = Runtime::AccountId::from(WrappedAccountId32(operator.0));
+		let Some((profile, _)) =
+			pallet_multi_asset_delegation::Pallet::<Runtime>::operator_profiles(&operator)
+		else {
+			return Err(RevertReason::custom("Operator has no profile").into());
+		};
+
+		Ok((
+			profile.name.into_inner().into(),
+			profile.website.into_inner().into(),
+			profile.contact.into_inner().into(),
+			profile.region.into_inner().into(),
+			profile.hardware_class.into_inner().into(),
+			profile.blueprints_description.into_inner().into(),
+		))
+	}
+
+	#[precompile::public("operatorsByProfile(bytes,bytes)")]
+	#[precompile::view]
+	fn operators_by_profile(
+		handle: &mut impl PrecompileHandle,
+		region: UnboundedBytes,
+		hardware_class: UnboundedBytes,
+	) -> EvmResult<Vec<H256>> {
+		let region: Vec<u8> = region.into();
+		let hardware_class: Vec<u8> = hardware_class.into();
+		let region = (!region.is_empty()).then_some(region.as_slice());
+		let hardware_class = (!hardware_class.is_empty()).then_some(hardware_class.as_slice());
+
+		// Iterates over every profile, so charge a read per profile as it is read
+		let mut operators = Vec::new();
+		for (operator, (profile, _)) in
+			pallet_multi_asset_delegation::OperatorProfiles::<Runtime>::iter()
+		{
+			handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+			if profile.matches(region, hardware_class) {
+				operators.push(H256::from(*operator.as_ref()));
+			}
+		}
+
+		Ok(operators)
+	}
+
+	#[precompile::public("liquidDelegate(bytes32,uint256,address,uint256)")]
+	fn liquid_delegate(
+		handle: &mut impl PrecompileHandle,
+		operator: H256,
+		asset_id: U256,
+		token_address: Address,
+		amount: U256,
+	) -> EvmResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let caller = handle.context().caller;
+		let who = Runtime::AddressMapping::into_account_id(caller);
+		let operator = Runtime::AccountId::from(WrappedAccountId32(operator.0));
+
+		let asset = match (asset_id.as_u32(), token_address.0 .0) {
+			(0, erc20_token) if erc20_token != [0; 20] => Asset::Erc20(erc20_token.into()),
+			(other_asset_id, _) => Asset::Custom(other_asset_id.into()),
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_multi_asset_delegation::Call::<Runtime>::liquid_delegate {
+				operator,
+				asset,
+				amount: amount
+					.try_into()
+					.map_err(|_| RevertReason::value_is_too_large("amount"))?,
+			},
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("liquidUnstake(bytes32,uint256,address,uint256)")]
+	fn liquid_unstake(
+		handle: &mut impl PrecompileHandle,
+		operator: H256,
+		asset_id: U256,
+		token_address: Address,
+		shares: U256,
+	) -> EvmResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let caller = handle.context().caller;
+		let who = Runtime::AddressMapping::into_account_id(caller);
+		let operator = Runtime::AccountId::from(WrappedAccountId32(operator.0));
+
+		let asset = match (asset_id.as_u32(), token_address.0 .0) {
+			(0, erc20_token) if erc20_token != [0; 20] => Asset::Erc20(erc20_token.into()),
+			(other_asset_id, _) => Asset::Custom(other_asset_id.into()),
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_multi_asset_delegation::Call::<Runtime>::liquid_unstake {
+				operator,
+				asset,
+				shares: shares
+					.try_into()
+					.map_err(|_| RevertReason::value_is_too_large("shares"))?,
+			},
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("executeLiquidUnstake()")]
+	fn execute_liquid_unstake(handle: &mut impl PrecompileHandle) -> EvmResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_multi_asset_delegation::Call::<Runtime>::execute_liquid_unstake {};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("executeAllReady()")]
+	fn execute_all_ready(handle: &mut impl PrecompileHandle) -> EvmResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let caller = handle.context().caller;
+		let who = Runtime::AddressMapping::into_account_id(caller);
+
+		let pallet_account_id = pallet_multi_asset_delegation::Pallet::<Runtime>::pallet_account();
+		let pallet_address = pallet_multi_asset_delegation::Pallet::<Runtime>::pallet_evm_account();
+
+		// Unstakes only return funds to the deposits, so the ERC20 transfers are exactly those of
+		// the withdraw requests that are ready before the call.
+		let erc20_transfers =
+			pallet_multi_asset_delegation::Pallet::<Runtime>::ready_withdraw_requests(&who)
+				.map(|requests| {
+					requests
+						.filter_map(|request| match request.asset {
+							Asset::Erc20(token) => Some((token, request.amount)),
+							_ => None,
+						})
+						.collect::<Vec<_>>()
+				})
+				.unwrap_or_default();
+
+		for (token, amount) in erc20_transfers {
+			let v: U256 = amount.into();
+			if !erc20_transfer(handle, token.into(), pallet_address.into(), caller.into(), v)? {
+				return Err(revert("Failed to transfer ERC20 tokens"));
+			}
+		}
+
+		let call = pallet_multi_asset_delegation::Call::<Runtime>::execute_all_ready {
+			evm_address: Some(caller),
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(pallet_account_id).into(), call)?;
+
+		Ok(())
+	}
 }
@@ -338,6 +338,15 @@ parameter_types! {
 	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub const MaxDelegations: u32 = 50;
 
+	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub const MaxOperatorProfileFieldLength: u32 = 64;
+
+	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub const MaxOperatorProfileDescriptionLength: u32 = 256;
+
+	pub const OperatorProfileDepositBase: Balance = 10;
+	pub const OperatorProfileDepositPerByte: Balance = 1;
+
 	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub const SlashRecipient: AccountId = AccountId32::new([9u8; 32]);
 }
@@ -388,6 +397,7 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type Currency = Balances;
 	type MinOperatorBondAmount = MinOperatorBondAmount;
 	type SlashRecipient = SlashRecipient;
+	type NominationSlashHandler = ();
 
 	type CurrencyToVote = ();
 	type StakingInterface = Staking;
@@ -408,8 +418,13 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type MaxWithdrawRequests = MaxWithdrawRequests;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type MaxDelegations = MaxDelegations;
+	type MaxOperatorProfileFieldLength = MaxOperatorProfileFieldLength;
+	type MaxOperatorProfileDescriptionLength = MaxOperatorProfileDescriptionLength;
+	type OperatorProfileDepositBase = OperatorProfileDepositBase;
+	type OperatorProfileDepositPerByte = OperatorProfileDepositPerByte;
 	type PalletId = PID;
 	type RewardsManager = MockRewardsManager;
+	type DelegationHooks = ();
 	type WeightInfo = ();
 }
 
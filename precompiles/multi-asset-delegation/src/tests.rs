@@ -1,6 +1,6 @@
 use crate::{mock::*, mock_evm::*, U256};
 use frame_support::{assert_ok, traits::Currency};
-use pallet_multi_asset_delegation::{CurrentRound, Delegators, Operators};
+use pallet_multi_asset_delegation::{types::OperatorProfile, CurrentRound, Delegators, Operators};
 use precompile_utils::{prelude::*, testing::*};
 use sp_core::{H160, H256};
 use sp_runtime::AccountId32;
@@ -942,3 +942,43 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 		PCall::supports_selector,
 	)
 }
+
+#[test]
+fn operators_by_profile_charges_every_profile_read() {
+	ExtBuilder::default().build().execute_with(|| {
+		for (who, region) in [(TestAccount::Bobo, &b"eu-west"[..]), (TestAccount::Dave, b"us-east")]
+		{
+			let operator: AccountId = who.into();
+			Balances::make_free_balance_be(&operator, 20_000);
+			assert_ok!(MultiAssetDelegation::join_operators(
+				RuntimeOrigin::signed(operator.clone()),
+				10_000
+			));
+			assert_ok!(MultiAssetDelegation::set_operator_profile(
+				RuntimeOrigin::signed(operator),
+				OperatorProfile {
+					name: b"Operator".to_vec().try_into().unwrap(),
+					website: Default::default(),
+					contact: Default::default(),
+					region: region.to_vec().try_into().unwrap(),
+					hardware_class: b"gpu".to_vec().try_into().unwrap(),
+					blueprints_description: Default::default(),
+				}
+			));
+		}
+
+		// Both profiles are read, even though only one matches
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::operators_by_profile {
+					region: b"eu-west".to_vec().into(),
+					hardware_class: Default::default(),
+				},
+			)
+			.expect_cost(2 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(vec![H256::from(AccountId32::from(TestAccount::Bobo).as_ref())]);
+	});
+}
@@ -689,6 +689,7 @@ impl pallet_services::Config for Runtime {
 	type DefaultParameterUpdateOrigin = EnsureRoot<AccountId>;
 	type RoleKeyId = RoleKeyId;
 	type RewardRecorder = MockRewardsManager;
+	type CreditsLedger = ();
 	type RewardsManager = MockRewardsManager;
 	type WeightInfo = ();
 }
@@ -0,0 +1,98 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Utilities shared by the signature verification precompiles: parsing the
+//! arguments of a batch and pricing verifications, so that a batch always costs
+//! less than verifying its signatures in separate calls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use precompile_utils::prelude::*;
+use sp_core::ConstU32;
+use sp_std::prelude::*;
+
+/// The maximum number of signatures or public keys in one batch.
+pub const BATCH_LIMIT: u32 = 2u32.pow(7);
+/// The fixed gas cost of a verification call, whether of one signature or of a batch.
+pub const VERIFY_BASE_COST: u64 = 600;
+/// The gas cost of hashing each 32 bytes word of a message, matching `KECCAK256`.
+pub const MESSAGE_WORD_COST: u64 = 6;
+
+/// The maximum number of items of a batch argument.
+pub type GetBatchLimit = ConstU32<BATCH_LIMIT>;
+
+/// The public keys, signatures and messages of a batch, as raw bytes.
+pub type Batch = (Vec<Vec<u8>>, Vec<Vec<u8>>, Vec<Vec<u8>>);
+
+/// Returns the gas cost of hashing `message`.
+pub fn message_cost(message: &[u8]) -> u64 {
+	MESSAGE_WORD_COST.saturating_mul((message.len() as u64).div_ceil(32))
+}
+
+/// Returns the gas cost of hashing all the `messages`.
+pub fn messages_cost(messages: &[Vec<u8>]) -> u64 {
+	messages
+		.iter()
+		.fold(0, |cost, message| cost.saturating_add(message_cost(message)))
+}
+
+/// Returns the gas cost of verifying `size` signatures one after the other, each at
+/// `per_signature_cost`.
+///
+/// This is for schemes without batch verification: only the base cost of the call is
+/// shared by the signatures of a batch.
+pub fn linear_verify_cost(per_signature_cost: u64, size: usize) -> u64 {
+	VERIFY_BASE_COST.saturating_add(per_signature_cost.saturating_mul(size as u64))
+}
+
+/// Converts a list argument into raw bytes.
+pub fn bytes_list<T: Into<Vec<u8>>>(list: BoundedVec<T, GetBatchLimit>) -> Vec<Vec<u8>> {
+	Vec::from(list).into_iter().map(Into::into).collect()
+}
+
+/// Reverts if the first of the list arguments of a batch, whose `lengths` are given, is
+/// empty or if they have different lengths.
+pub fn ensure_batch_lengths(lengths: &[usize]) -> EvmResult {
+	if lengths.first().copied().unwrap_or_default() == 0 {
+		return Err(revert("Empty batch"));
+	}
+	if lengths.windows(2).any(|pair| pair[0] != pair[1]) {
+		return Err(revert("Batch arrays have different lengths"));
+	}
+
+	Ok(())
+}
+
+/// Parses the arguments of a `verifyBatch` call, reverting if the batch is empty or the
+/// arrays have different lengths.
+pub fn parse_batch<K, S, M>(
+	public_bytes: BoundedVec<K, GetBatchLimit>,
+	signature_bytes: BoundedVec<S, GetBatchLimit>,
+	messages: BoundedVec<M, GetBatchLimit>,
+) -> EvmResult<Batch>
+where
+	K: Into<Vec<u8>>,
+	S: Into<Vec<u8>>,
+	M: Into<Vec<u8>>,
+{
+	let public_bytes = bytes_list(public_bytes);
+	let signature_bytes = bytes_list(signature_bytes);
+	let messages = bytes_list(messages);
+
+	ensure_batch_lengths(&[public_bytes.len(), signature_bytes.len(), messages.len()])?;
+
+	Ok((public_bytes, signature_bytes, messages))
+}
@@ -506,6 +506,7 @@ parameter_types! {
 	pub static MaxMetadataLen: u32 = 2;
 	pub static CheckLevel: u8 = 255;
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const MaxInstantUnbondFee: Perbill = Perbill::from_percent(5);
 }
 
 impl pallet_tangle_lst::Config for Runtime {
@@ -517,6 +518,7 @@ impl pallet_tangle_lst::Config for Runtime {
 	type BalanceToU256 = BalanceToU256;
 	type U256ToBalance = U256ToBalance;
 	type Staking = StakingMock;
+	type StakingSlash = ();
 	type PostUnbondingPoolsWindow = PostUnbondingPoolsWindow;
 	type PalletId = PoolsPalletId;
 	type MaxMetadataLen = MaxMetadataLen;
@@ -528,6 +530,15 @@ impl pallet_tangle_lst::Config for Runtime {
 	type PoolId = PoolId;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxPointsToBalance = frame_support::traits::ConstU8<10>;
+	type NominationDelegator = ();
+	type RewardPayees = ();
+	type MaxRestakeBlueprints = ConstU32<4>;
+	type MaxInstantUnbondFee = MaxInstantUnbondFee;
+	type MaxInstantUnbondBuffers = ConstU32<4>;
+	type Validators = ();
+	type MaxAutoNominations = ConstU32<16>;
+	type MaxAutoNominationCandidates = ConstU32<64>;
+	type MaxAutoNominatingPools = ConstU32<4>;
 }
 
 /// Build test externalities, prepopulated with data for testing democracy precompiles
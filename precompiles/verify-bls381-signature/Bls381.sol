@@ -13,4 +13,35 @@ interface IBls381 {
         bytes calldata signature,
         bytes calldata message
     ) external view returns (bool);
-}
+
+    /**
+     * @dev Verify a batch of signed messages, each by its own public key.
+     * @return A boolean confirming whether every public key is signer for its message.
+     */
+    function verifyBatch(
+        bytes[] calldata public_keys,
+        bytes[] calldata signatures,
+        bytes[] calldata messages
+    ) external view returns (bool);
+
+    /**
+     * @dev Verify an aggregate signature of the same message by all the public keys.
+     * The public keys must have proven possession of their secret key.
+     * @return A boolean confirming whether the public keys are signers for the message.
+     */
+    function fastAggregateVerify(
+        bytes[] calldata public_keys,
+        bytes calldata signature,
+        bytes calldata message
+    ) external view returns (bool);
+
+    /**
+     * @dev Verify an aggregate signature of distinct messages, each by its own public key.
+     * @return A boolean confirming whether every public key is signer for its message.
+     */
+    function aggregateVerify(
+        bytes[] calldata public_keys,
+        bytes calldata signature,
+        bytes[] calldata messages
+    ) external view returns (bool);
+}
\ No newline at end of file
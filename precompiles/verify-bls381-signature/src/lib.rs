@@ -16,9 +16,15 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use evm_signature_utils::{
+	bytes_list, ensure_batch_lengths, message_cost, messages_cost, parse_batch, GetBatchLimit,
+};
 use fp_evm::PrecompileHandle;
+use parity_scale_codec::Encode;
 use precompile_utils::prelude::*;
-use sp_core::{bytes::to_hex, ConstU32};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use snowbridge_milagro_bls::{AggregatePublicKey, AggregateSignature, PublicKey, Signature};
+use sp_core::{bytes::to_hex, hashing::blake2_256, ConstU32};
 use sp_std::{marker::PhantomData, prelude::*};
 
 #[cfg(test)]
@@ -26,6 +32,53 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+// Bls-381 uncompressed public key bytes
+type BlsPubKeyBytes = ConstU32<96>;
+// Bls-381 signature bytes
+type BlsSignatureBytes = ConstU32<384>;
+
+/// The base gas cost of a pairing check, following EIP-2537.
+pub const PAIRING_BASE_COST: u64 = 37_700;
+/// The gas cost of each pair of a pairing check, following EIP-2537.
+pub const PAIRING_PER_PAIR_COST: u64 = 32_600;
+/// The gas cost of hashing a message to G2, following EIP-2537.
+pub const HASH_TO_G2_COST: u64 = 23_800;
+/// The gas cost of adding two G1 points, following EIP-2537.
+pub const G1_ADD_COST: u64 = 375;
+/// The gas cost of multiplying a G1 point by a scalar, following EIP-2537.
+pub const G1_MUL_COST: u64 = 12_000;
+/// The gas cost of multiplying a G2 point by a scalar, following EIP-2537.
+pub const G2_MUL_COST: u64 = 22_500;
+/// The gas cost of weighting a signature of a batch by its random scalar, one
+/// multiplication in each group. The scalars have 64 bits, a quarter of the bits
+/// of the full scalars the multiplication costs are priced for.
+pub const BATCH_RANDOMIZATION_COST: u64 = (G1_MUL_COST + G2_MUL_COST) / 4;
+
+/// Returns the gas cost of checking `messages` distinct messages against one
+/// (aggregate) signature, which takes one pairing per message plus one for the
+/// signature.
+pub fn aggregate_verify_cost(messages: usize) -> u64 {
+	let messages = messages as u64;
+	PAIRING_BASE_COST
+		.saturating_add(PAIRING_PER_PAIR_COST.saturating_mul(messages.saturating_add(1)))
+		.saturating_add(HASH_TO_G2_COST.saturating_mul(messages))
+}
+
+/// Returns the gas cost of verifying a batch of `size` signatures, excluding the
+/// hashing of their messages.
+///
+/// The batch pays once for the single pairing check it takes, with the pair of the
+/// combined signatures. Each signature then only adds its own pair, the hashing of
+/// its message to G2 and its random weighting, which is less than verifying it on
+/// its own, so the batch is cheaper than verifying the signatures one by one from
+/// two signatures on.
+pub fn batch_verify_cost(size: usize) -> u64 {
+	let signature_cost = PAIRING_PER_PAIR_COST
+		.saturating_add(HASH_TO_G2_COST)
+		.saturating_add(BATCH_RANDOMIZATION_COST);
+	aggregate_verify_cost(0).saturating_add(signature_cost.saturating_mul(size as u64))
+}
+
 /// A precompile to verify Bls-381 signatures
 pub struct Bls381Precompile<Runtime>(PhantomData<Runtime>);
 
@@ -34,7 +87,7 @@ impl<Runtime: pallet_evm::Config> Bls381Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<96>>,
 		signature_bytes: BoundedBytes<ConstU32<384>>,
 		message: UnboundedBytes,
@@ -44,6 +97,8 @@ impl<Runtime: pallet_evm::Config> Bls381Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(aggregate_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		log::trace!(
 			target: "Bls-381-Precompile",
 			"Verify signature {:?} for public {:?} and message {:?}",
@@ -75,4 +130,165 @@ impl<Runtime: pallet_evm::Config> Bls381Precompile<Runtime> {
 
 		Ok(is_confirmed)
 	}
+
+	/// Verifies a batch of signatures, each of its own message by its own public
+	/// key, with a single randomized pairing check.
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<BlsPubKeyBytes>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<BlsSignatureBytes>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		let public_keys = if let Some(keys) = parse_public_keys(&public_bytes) {
+			keys
+		} else {
+			return Ok(false);
+		};
+		let signatures = if let Some(sigs) = signature_bytes
+			.iter()
+			.map(|bytes| Signature::from_bytes(bytes).ok())
+			.collect::<Option<Vec<_>>>()
+		{
+			sigs
+		} else {
+			return Ok(false);
+		};
+
+		let public_keys: Vec<_> =
+			public_keys.iter().map(AggregatePublicKey::from_public_key).collect();
+		let signatures: Vec<_> =
+			signatures.iter().map(AggregateSignature::from_signature).collect();
+
+		// The random coefficients are derived from the whole batch, so that they
+		// are deterministic but can not be chosen by the caller.
+		let mut rng = ChaCha20Rng::from_seed(blake2_256(
+			&(&public_bytes, &signature_bytes, &messages).encode(),
+		));
+
+		let is_confirmed = AggregateSignature::verify_multiple_aggregate_signatures(
+			&mut rng,
+			signatures.iter().zip(public_keys.iter()).zip(messages.iter()).map(
+				|((signature, public_key), message)| (signature, public_key, message.as_slice()),
+			),
+		);
+
+		log::trace!(
+			target: "Bls-381-Precompile",
+			"Verified batch of {} signatures is {:?}",
+			public_bytes.len(), is_confirmed,
+		);
+
+		Ok(is_confirmed)
+	}
+
+	/// Verifies an aggregate signature of the same message by all the public keys,
+	/// which are aggregated into a single one.
+	///
+	/// The public keys must come with a proof of possession of their secret key,
+	/// otherwise the aggregate public key is open to rogue key attacks.
+	#[precompile::public("fastAggregateVerify(bytes[],bytes,bytes)")]
+	#[precompile::view]
+	fn fast_aggregate_verify(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<BlsPubKeyBytes>, GetBatchLimit>,
+		signature_bytes: BoundedBytes<BlsSignatureBytes>,
+		message: UnboundedBytes,
+	) -> EvmResult<bool> {
+		// Parse arguments
+		let public_bytes = bytes_list(public_bytes);
+		let signature_bytes: Vec<u8> = signature_bytes.into();
+		let message: Vec<u8> = message.into();
+
+		ensure_batch_lengths(&[public_bytes.len()])?;
+
+		handle.record_cost(
+			aggregate_verify_cost(1)
+				.saturating_add(G1_ADD_COST.saturating_mul(public_bytes.len() as u64 - 1))
+				.saturating_add(message_cost(&message)),
+		)?;
+
+		let public_keys = if let Some(keys) = parse_public_keys(&public_bytes) {
+			keys
+		} else {
+			return Ok(false);
+		};
+		let signature = if let Ok(sig) = AggregateSignature::from_bytes(&signature_bytes) {
+			sig
+		} else {
+			return Ok(false);
+		};
+
+		let public_keys: Vec<&PublicKey> = public_keys.iter().collect();
+		let is_confirmed = signature.fast_aggregate_verify(&message, &public_keys);
+
+		log::trace!(
+			target: "Bls-381-Precompile",
+			"Verified aggregate signature {} of {} public keys is {:?}",
+			to_hex(&signature_bytes, false), public_keys.len(), is_confirmed,
+		);
+
+		Ok(is_confirmed)
+	}
+
+	/// Verifies an aggregate signature of distinct messages, each by its own
+	/// public key.
+	#[precompile::public("aggregateVerify(bytes[],bytes,bytes[])")]
+	#[precompile::view]
+	fn aggregate_verify(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<BlsPubKeyBytes>, GetBatchLimit>,
+		signature_bytes: BoundedBytes<BlsSignatureBytes>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		// Parse arguments
+		let public_bytes = bytes_list(public_bytes);
+		let signature_bytes: Vec<u8> = signature_bytes.into();
+		let messages = bytes_list(messages);
+
+		ensure_batch_lengths(&[public_bytes.len(), messages.len()])?;
+
+		handle.record_cost(
+			aggregate_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		let public_keys = if let Some(keys) = parse_public_keys(&public_bytes) {
+			keys
+		} else {
+			return Ok(false);
+		};
+		let signature = if let Ok(sig) = AggregateSignature::from_bytes(&signature_bytes) {
+			sig
+		} else {
+			return Ok(false);
+		};
+
+		let public_keys: Vec<&PublicKey> = public_keys.iter().collect();
+		let messages: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+		let is_confirmed = signature.aggregate_verify(&messages, &public_keys);
+
+		log::trace!(
+			target: "Bls-381-Precompile",
+			"Verified aggregate signature {} of {} messages is {:?}",
+			to_hex(&signature_bytes, false), messages.len(), is_confirmed,
+		);
+
+		Ok(is_confirmed)
+	}
+}
+
+/// Parses uncompressed public keys, returning `None` if any of them is invalid.
+fn parse_public_keys(public_bytes: &[Vec<u8>]) -> Option<Vec<PublicKey>> {
+	public_bytes
+		.iter()
+		.map(|bytes| PublicKey::from_uncompressed_bytes(bytes).ok())
+		.collect()
 }
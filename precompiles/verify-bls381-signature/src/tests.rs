@@ -14,16 +14,38 @@
 // You should have received a copy of the GNU General Public License
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::mock::*;
+use crate::{aggregate_verify_cost, batch_verify_cost, mock::*, G1_ADD_COST};
+use evm_signature_utils::{BATCH_LIMIT, MESSAGE_WORD_COST};
 use hex_literal::hex;
-use precompile_utils::testing::*;
-use snowbridge_milagro_bls::{PublicKey, SecretKey, Signature};
+use precompile_utils::{prelude::*, testing::*};
+use snowbridge_milagro_bls::{AggregateSignature, PublicKey, SecretKey, Signature};
 use sp_core::{keccak_256, H160};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
 }
 
+fn bounded<T: From<Vec<u8>>, S>(items: Vec<Vec<u8>>) -> BoundedVec<T, S> {
+	items.into_iter().map(Into::into).collect::<Vec<T>>().into()
+}
+
+/// Generates `n` key pairs, returning the secret keys and the uncompressed
+/// public keys.
+fn key_pairs(n: u8) -> (Vec<SecretKey>, Vec<Vec<u8>>) {
+	(1..=n)
+		.map(|i| {
+			let secret_key = SecretKey::from_bytes(&[i; 32]).unwrap();
+			let pub_key = PublicKey::from_secret_key(&secret_key);
+			(secret_key, pub_key.as_uncompressed_bytes().to_vec())
+		})
+		.unzip()
+}
+
+/// Returns `n` distinct message hashes.
+fn messages(n: u8) -> Vec<Vec<u8>> {
+	(0..n).map(|i| keccak_256(&[i]).to_vec()).collect()
+}
+
 #[test]
 fn wrong_signature_length_returns_false() {
 	ExtBuilder.build().execute_with(|| {
@@ -108,7 +130,194 @@ fn signature_verification_works_with_bls318() {
 					message: msg_hash.to_vec().into(),
 				},
 			)
+			.expect_cost(aggregate_verify_cost(1) + MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn batch_verification_works_with_bls381() {
+	ExtBuilder.build().execute_with(|| {
+		let (secret_keys, public_keys) = key_pairs(4);
+		let messages = messages(4);
+		let signatures: Vec<_> = secret_keys
+			.iter()
+			.zip(&messages)
+			.map(|(secret_key, message)| Signature::new(message, secret_key).as_bytes().to_vec())
+			.collect();
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: bounded(public_keys),
+					signature_bytes: bounded(signatures),
+					messages: bounded(messages),
+				},
+			)
+			.expect_cost(batch_verify_cost(4) + 4 * MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn batch_verification_fails_with_one_bad_signature() {
+	ExtBuilder.build().execute_with(|| {
+		let (secret_keys, public_keys) = key_pairs(4);
+		let messages = messages(4);
+		let mut signatures: Vec<_> = secret_keys
+			.iter()
+			.zip(&messages)
+			.map(|(secret_key, message)| Signature::new(message, secret_key).as_bytes().to_vec())
+			.collect();
+		signatures[2] = Signature::new(&messages[3], &secret_keys[2]).as_bytes().to_vec();
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: bounded(public_keys),
+					signature_bytes: bounded(signatures),
+					messages: bounded(messages),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn batch_verification_reverts_with_invalid_batch() {
+	ExtBuilder.build().execute_with(|| {
+		let (secret_keys, public_keys) = key_pairs(2);
+		let messages = messages(2);
+		let signature = Signature::new(&messages[0], &secret_keys[0]).as_bytes().to_vec();
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: bounded(public_keys),
+					signature_bytes: bounded(vec![signature]),
+					messages: bounded(messages),
+				},
+			)
+			.execute_reverts(|output| output == b"Batch arrays have different lengths");
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: bounded(vec![]),
+					signature_bytes: bounded(vec![]),
+					messages: bounded(vec![]),
+				},
+			)
+			.execute_reverts(|output| output == b"Empty batch");
+	});
+}
+
+#[test]
+fn batch_verification_is_cheaper_than_single_verifications() {
+	for size in 2..=BATCH_LIMIT as usize {
+		assert!(batch_verify_cost(size) < aggregate_verify_cost(1) * size as u64);
+		// The pairing check is shared, so each further signature costs less than
+		// verifying it on its own.
+		assert!(batch_verify_cost(size) - batch_verify_cost(size - 1) < aggregate_verify_cost(1));
+	}
+}
+
+#[test]
+fn fast_aggregate_verification_works_with_bls381() {
+	ExtBuilder.build().execute_with(|| {
+		let (secret_keys, public_keys) = key_pairs(4);
+		let message = keccak_256(b"Hello, world!");
+		let signatures: Vec<_> = secret_keys
+			.iter()
+			.map(|secret_key| Signature::new(&message, secret_key))
+			.collect();
+		let signature = AggregateSignature::aggregate(&signatures.iter().collect::<Vec<_>>());
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::fast_aggregate_verify {
+					public_bytes: bounded(public_keys.clone()),
+					signature_bytes: signature.as_bytes().to_vec().into(),
+					message: message.to_vec().into(),
+				},
+			)
+			.expect_cost(aggregate_verify_cost(1) + 3 * G1_ADD_COST + MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		// A signature missing from the aggregate.
+		let signature =
+			AggregateSignature::aggregate(&signatures.iter().skip(1).collect::<Vec<_>>());
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::fast_aggregate_verify {
+					public_bytes: bounded(public_keys),
+					signature_bytes: signature.as_bytes().to_vec().into(),
+					message: message.to_vec().into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn aggregate_verification_works_with_bls381() {
+	ExtBuilder.build().execute_with(|| {
+		let (secret_keys, public_keys) = key_pairs(4);
+		let messages = messages(4);
+		let signatures: Vec<_> = secret_keys
+			.iter()
+			.zip(&messages)
+			.map(|(secret_key, message)| Signature::new(message, secret_key))
+			.collect();
+		let signature = AggregateSignature::aggregate(&signatures.iter().collect::<Vec<_>>());
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::aggregate_verify {
+					public_bytes: bounded(public_keys.clone()),
+					signature_bytes: signature.as_bytes().to_vec().into(),
+					messages: bounded(messages.clone()),
+				},
+			)
+			.expect_cost(aggregate_verify_cost(4) + 4 * MESSAGE_WORD_COST)
 			.expect_no_logs()
 			.execute_returns(true);
+
+		// The messages signed by other public keys.
+		let mut swapped = messages;
+		swapped.swap(0, 1);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::aggregate_verify {
+					public_bytes: bounded(public_keys),
+					signature_bytes: signature.as_bytes().to_vec().into(),
+					messages: bounded(swapped),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
 	});
 }
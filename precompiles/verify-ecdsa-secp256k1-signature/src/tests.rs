@@ -14,9 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 #![allow(clippy::all)]
-use crate::mock::*;
+use crate::{mock::*, verify_cost};
+use evm_signature_utils::{BATCH_LIMIT, MESSAGE_WORD_COST};
 use hex_literal::hex;
-use precompile_utils::testing::*;
+use precompile_utils::{prelude::*, testing::*};
 use sp_core::{ecdsa, keccak_256, Pair, H160};
 
 fn precompiles() -> Precompiles<Runtime> {
@@ -100,7 +101,60 @@ fn signature_verification_works_secp256k1_ecdsa() {
                     message: hash_message.into(),
                 },
             )
+            .expect_cost(verify_cost(1) + MESSAGE_WORD_COST)
             .expect_no_logs()
             .execute_returns(true);
     });
 }
+
+#[test]
+fn batch_verification_works_secp256k1_ecdsa() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (mut public_bytes, mut signature_bytes, mut messages) = (vec![], vec![], vec![]);
+		for i in 1..=4u8 {
+			let pair = ecdsa::Pair::from_seed(&[i; 32]);
+			let hash_message = keccak_256(&[i]);
+			let signature = pair.sign_prehashed(&hash_message);
+			public_bytes.push(BoundedBytes::from(pair.public().0.to_vec()));
+			signature_bytes.push(BoundedBytes::from(signature.0[..64].to_vec()));
+			messages.push(UnboundedBytes::from(hash_message.to_vec()));
+		}
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: public_bytes.clone().into(),
+					signature_bytes: signature_bytes.clone().into(),
+					messages: messages.clone().into(),
+				},
+			)
+			.expect_cost(verify_cost(4) + 4 * MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		// One of the signatures is of another message.
+		messages.swap(0, 1);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: public_bytes.into(),
+					signature_bytes: signature_bytes.into(),
+					messages: messages.into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn batch_verification_is_cheaper_than_single_verifications() {
+	for size in 2..=BATCH_LIMIT as usize {
+		assert!(verify_cost(size) < size as u64 * verify_cost(1));
+	}
+}
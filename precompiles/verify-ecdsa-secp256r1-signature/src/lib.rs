@@ -16,6 +16,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use evm_signature_utils::{
+	linear_verify_cost, message_cost, messages_cost, parse_batch, GetBatchLimit,
+};
 use fp_evm::PrecompileHandle;
 use p256::{ecdsa::signature::hazmat::PrehashVerifier, elliptic_curve::group::GroupEncoding};
 use precompile_utils::prelude::*;
@@ -32,6 +35,18 @@ type ECDSAPubKeyBytes = ConstU32<33>;
 // ECDSA signature bytes
 type ECDSASignatureBytes = ConstU32<65>;
 
+/// The gas cost of verifying one signature, which with the base cost of the call matches
+/// `ecrecover`.
+pub const VERIFY_COST: u64 = 2_400;
+
+/// The gas cost of verifying `size` signatures, excluding their messages.
+///
+/// ECDSA signatures can not be verified in a batch, so the cost is linear: a batch only
+/// saves the base cost of the calls it replaces.
+pub fn verify_cost(size: usize) -> u64 {
+	linear_verify_cost(VERIFY_COST, size)
+}
+
 /// A precompile to verify EcdsaSecp256r1 signature
 pub struct EcdsaSecp256r1Precompile<Runtime>(PhantomData<Runtime>);
 
@@ -40,7 +55,7 @@ impl<Runtime: pallet_evm::Config> EcdsaSecp256r1Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ECDSAPubKeyBytes>,
 		signature_bytes: BoundedBytes<ECDSASignatureBytes>,
 		message: UnboundedBytes,
@@ -50,32 +65,59 @@ impl<Runtime: pallet_evm::Config> EcdsaSecp256r1Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
-		log::trace!(
-			target: "Ecdsa-Secp256r1-Precompile",
-			"Verify signature {:?} for public {:?} and message {:?}",
-			signature_bytes, public_bytes, message,
-		);
+		handle.record_cost(verify_cost(1).saturating_add(message_cost(&message)))?;
+
+		Ok(verify_signature(&public_bytes, &signature_bytes, &message))
+	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ECDSAPubKeyBytes>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ECDSASignatureBytes>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
 
-		let maybe_pub_key_point = p256::AffinePoint::from_bytes(public_bytes.as_slice().into());
+		Ok(public_bytes.iter().zip(&signature_bytes).zip(&messages).all(
+			|((public_bytes, signature_bytes), message)| {
+				verify_signature(public_bytes, signature_bytes, message)
+			},
+		))
+	}
+}
 
-		let pub_key_point =
-			if let Some(x) = maybe_pub_key_point.into() { x } else { return Ok(false) };
+/// Verifies the Secp256r1 ECDSA `signature_bytes` of the prehashed `message` by `public_bytes`.
+fn verify_signature(public_bytes: &[u8], signature_bytes: &[u8], message: &[u8]) -> bool {
+	log::trace!(
+		target: "Ecdsa-Secp256r1-Precompile",
+		"Verify signature {:?} for public {:?} and message {:?}",
+		signature_bytes, public_bytes, message,
+	);
 
-		let maybe_verifying_key = p256::ecdsa::VerifyingKey::from_affine(pub_key_point);
-		let verifying_key = if let Ok(x) = maybe_verifying_key { x } else { return Ok(false) };
+	let maybe_pub_key_point = p256::AffinePoint::from_bytes(public_bytes.into());
 
-		let maybe_signature = p256::ecdsa::Signature::from_slice(signature_bytes.as_slice());
-		let signature = if let Ok(x) = maybe_signature { x } else { return Ok(false) };
+	let pub_key_point = if let Some(x) = maybe_pub_key_point.into() { x } else { return false };
 
-		let is_confirmed =
-			verifying_key.verify_prehash(&message, &signature).map(|_| signature).is_ok();
+	let maybe_verifying_key = p256::ecdsa::VerifyingKey::from_affine(pub_key_point);
+	let verifying_key = if let Ok(x) = maybe_verifying_key { x } else { return false };
 
-		log::trace!(
-			target: "Ecdsa-Secp256r1-Precompile",
-			"Verified signature {:?} is {:?}",
-			signature, is_confirmed,
-		);
+	let maybe_signature = p256::ecdsa::Signature::from_slice(signature_bytes);
+	let signature = if let Ok(x) = maybe_signature { x } else { return false };
 
-		Ok(is_confirmed)
-	}
+	let is_confirmed = verifying_key.verify_prehash(message, &signature).map(|_| signature).is_ok();
+
+	log::trace!(
+		target: "Ecdsa-Secp256r1-Precompile",
+		"Verified signature {:?} is {:?}",
+		signature, is_confirmed,
+	);
+
+	is_confirmed
 }
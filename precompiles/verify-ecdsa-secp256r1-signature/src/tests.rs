@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 #![allow(clippy::all)]
-use crate::mock::*;
+use crate::{mock::*, verify_cost};
+use evm_signature_utils::MESSAGE_WORD_COST;
 use hex_literal::hex;
 use p256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey, VerifyingKey};
-use precompile_utils::testing::*;
+use precompile_utils::{prelude::*, testing::*};
 use rand_core::OsRng;
 use sp_core::{ecdsa, keccak_256, Pair, H160};
 
@@ -99,3 +100,51 @@ fn signature_verification_works_secp256r1_ecdsa() {
 			.execute_returns(true);
 	});
 }
+
+#[test]
+fn batch_verification_works_secp256r1_ecdsa() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut rng = OsRng;
+		let (mut public_bytes, mut signature_bytes, mut messages) = (vec![], vec![], vec![]);
+		for i in 0..4u8 {
+			let secret_key = SigningKey::random(&mut rng);
+			let public_key = VerifyingKey::from(&secret_key);
+			let prehash = keccak_256(&[i]);
+			let (signature, _) = secret_key.sign_prehash(&prehash).unwrap();
+			public_bytes
+				.push(BoundedBytes::from(public_key.to_encoded_point(true).to_bytes().to_vec()));
+			signature_bytes.push(BoundedBytes::from(signature.to_vec()));
+			messages.push(UnboundedBytes::from(prehash.to_vec()));
+		}
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: public_bytes.clone().into(),
+					signature_bytes: signature_bytes.clone().into(),
+					messages: messages.clone().into(),
+				},
+			)
+			.expect_cost(verify_cost(4) + 4 * MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		// One of the signatures is of another message.
+		messages.swap(0, 1);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::verify_batch {
+					public_bytes: public_bytes.into(),
+					signature_bytes: signature_bytes.into(),
+					messages: messages.into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
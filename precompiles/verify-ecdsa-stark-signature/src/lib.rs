@@ -16,6 +16,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use evm_signature_utils::{
+	linear_verify_cost, message_cost, messages_cost, parse_batch, GetBatchLimit,
+};
 use fp_evm::PrecompileHandle;
 use generic_ec::{coords::HasAffineX, curves::Stark, Point, Scalar};
 use precompile_utils::prelude::*;
@@ -32,6 +35,18 @@ type ECDSAPubKeyBytes = ConstU32<33>;
 // ECDSA signature bytes
 type ECDSASignatureBytes = ConstU32<65>;
 
+/// The gas cost of verifying one signature, which with the base cost of the call matches
+/// `ecrecover`.
+pub const VERIFY_COST: u64 = 2_400;
+
+/// The gas cost of verifying `size` signatures, excluding their messages.
+///
+/// ECDSA signatures can not be verified in a batch, so the cost is linear: a batch only
+/// saves the base cost of the calls it replaces.
+pub fn verify_cost(size: usize) -> u64 {
+	linear_verify_cost(VERIFY_COST, size)
+}
+
 /// A precompile to verify EcdsaStark signature
 pub struct EcdsaStarkPrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -40,7 +55,7 @@ impl<Runtime: pallet_evm::Config> EcdsaStarkPrecompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ECDSAPubKeyBytes>,
 		signature_bytes: BoundedBytes<ECDSASignatureBytes>,
 		message: UnboundedBytes,
@@ -50,42 +65,71 @@ impl<Runtime: pallet_evm::Config> EcdsaStarkPrecompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
-		log::trace!(
-			target: "Ecdsa-Stark-Precompile",
-			"Verify signature {:?} for public {:?} and message {:?}",
-			signature_bytes, public_bytes, message,
-		);
+		handle.record_cost(verify_cost(1).saturating_add(message_cost(&message)))?;
+
+		Ok(verify_signature(&public_bytes, &signature_bytes, &message))
+	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ECDSAPubKeyBytes>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ECDSASignatureBytes>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		Ok(public_bytes.iter().zip(&signature_bytes).zip(&messages).all(
+			|((public_bytes, signature_bytes), message)| {
+				verify_signature(public_bytes, signature_bytes, message)
+			},
+		))
+	}
+}
 
-		// Parse Signature
-		let r_bytes = &signature_bytes[0..signature_bytes.len() / 2];
-		let s_bytes = &signature_bytes[signature_bytes.len() / 2..];
-		let r = if let Ok(x) = Scalar::from_be_bytes(r_bytes) { x } else { return Ok(false) };
+/// Verifies the Stark ECDSA `signature_bytes` of `message` by `public_bytes`.
+fn verify_signature(public_bytes: &[u8], signature_bytes: &[u8], message: &[u8]) -> bool {
+	log::trace!(
+		target: "Ecdsa-Stark-Precompile",
+		"Verify signature {:?} for public {:?} and message {:?}",
+		signature_bytes, public_bytes, message,
+	);
 
-		let s = if let Ok(x) = Scalar::from_be_bytes(s_bytes) { x } else { return Ok(false) };
+	// Parse Signature
+	let r_bytes = &signature_bytes[0..signature_bytes.len() / 2];
+	let s_bytes = &signature_bytes[signature_bytes.len() / 2..];
+	let r = if let Ok(x) = Scalar::from_be_bytes(r_bytes) { x } else { return false };
 
-		let public_key_point =
-			if let Ok(x) = Point::from_bytes(public_bytes) { x } else { return Ok(false) };
+	let s = if let Ok(x) = Scalar::from_be_bytes(s_bytes) { x } else { return false };
 
-		let public_key_x: Scalar<Stark> =
-			if let Some(x) = public_key_point.x() { x.to_scalar() } else { return Ok(false) };
+	let public_key_point =
+		if let Ok(x) = Point::from_bytes(public_bytes) { x } else { return false };
 
-		let public_key = convert_stark_scalar(&public_key_x);
-		let msg = convert_stark_scalar(&Scalar::<Stark>::from_be_bytes_mod_order(message));
+	let public_key_x: Scalar<Stark> =
+		if let Some(x) = public_key_point.x() { x.to_scalar() } else { return false };
 
-		let r = convert_stark_scalar(&r);
+	let public_key = convert_stark_scalar(&public_key_x);
+	let msg = convert_stark_scalar(&Scalar::<Stark>::from_be_bytes_mod_order(message));
 
-		let s = convert_stark_scalar(&s);
+	let r = convert_stark_scalar(&r);
 
-		let is_confirmed = starknet_crypto::verify(&public_key, &msg, &r, &s).is_ok();
+	let s = convert_stark_scalar(&s);
 
-		log::trace!(
-			target: "Ecdsa-Stark-Precompile",
-			"Verified signature {:?} is {:?}",
-			signature_bytes, is_confirmed,
-		);
+	let is_confirmed = starknet_crypto::verify(&public_key, &msg, &r, &s).is_ok();
 
-		Ok(is_confirmed)
-	}
+	log::trace!(
+		target: "Ecdsa-Stark-Precompile",
+		"Verified signature {:?} is {:?}",
+		signature_bytes, is_confirmed,
+	);
+
+	is_confirmed
 }
 
 pub fn convert_stark_scalar(x: &Scalar<Stark>) -> starknet_crypto::Felt {
@@ -13,4 +13,14 @@ interface ISchnorrSignatureVerifier {
         bytes calldata signature,
         bytes calldata message
     ) external view returns (bool);
+
+    /**
+     * @dev Verify a batch of signed messages, each by its own public key.
+     * @return A boolean confirming whether every public key is signer for its message.
+     */
+    function verifyBatch(
+        bytes[] calldata public_keys,
+        bytes[] calldata signatures,
+        bytes[] calldata messages
+    ) external view returns (bool);
 }
\ No newline at end of file
@@ -16,11 +16,20 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 use fp_evm::{ExitError, PrecompileFailure, PrecompileHandle};
+use parity_scale_codec::Encode;
 use precompile_utils::prelude::*;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 use sp_core::{sr25519, ConstU32};
-use sp_io::{crypto::sr25519_verify, hashing::keccak_256};
+use sp_io::{
+	crypto::sr25519_verify,
+	hashing::{blake2_256, keccak_256},
+};
 use sp_std::{marker::PhantomData, prelude::*};
 
+use evm_signature_utils::{
+	linear_verify_cost, message_cost, messages_cost, parse_batch, GetBatchLimit, VERIFY_BASE_COST,
+};
+
 use frost_core::{Signature, VerifyingKey};
 use frost_ed25519::Ed25519Sha512;
 use frost_p256::P256Sha256;
@@ -71,6 +80,58 @@ macro_rules! verify_tg_frost_signature {
 	}};
 }
 
+/// Macro to verify a batch of Schnorr signatures using the specified signature scheme and
+/// frost crate.
+macro_rules! verify_signature_batch {
+	($frost:ident, $impl_type:ty, $keys:expr, $signatures:expr, $messages:expr) => {{
+		let mut verifier = $frost::batch::Verifier::<$impl_type>::new();
+		for ((key, signature), msg) in $keys.iter().zip($signatures.iter()).zip($messages.iter()) {
+			let verifying_key = $frost::VerifyingKey::<$impl_type>::deserialize(key)
+				.map_err(|_| revert("InvalidVerifyingKeyDeserialization"))?;
+			let sig = $frost::Signature::<$impl_type>::deserialize(signature)
+				.map_err(|_| revert("InvalidSignatureDeserialization"))?;
+			verifier.queue(
+				$frost::batch::Item::new(verifying_key, sig, msg)
+					.map_err(|_| revert("InvalidSignature"))?,
+			);
+		}
+		Ok(verifier.verify(batch_rng($keys, $signatures, $messages)).is_ok())
+	}};
+}
+
+/// The gas cost of verifying one Schnorr signature, which with the base cost of the call
+/// matches `ecrecover`.
+pub const VERIFY_COST: u64 = 2_400;
+
+/// The gas cost of verifying a batch of `size` Schnorr signatures, excluding their messages.
+///
+/// The signatures of a batch are verified with a single multi-scalar multiplication, whose
+/// cost grows with `size / log(size)`, so the `n`-th signature of a batch only costs
+/// `VERIFY_COST / (log2(n) + 1)`. A single verification is a batch of one signature.
+pub fn batch_verify_cost(size: usize) -> u64 {
+	(1..=size as u64)
+		.map(|n| VERIFY_COST / (u64::from(n.ilog2()) + 1))
+		.fold(VERIFY_BASE_COST, u64::saturating_add)
+}
+
+/// The gas cost of verifying `size` Sr25519 signatures, excluding their messages.
+///
+/// Sr25519 signatures are verified one by one through the host, so the cost is linear: a
+/// batch only saves the base cost of the calls it replaces.
+pub fn sr25519_verify_cost(size: usize) -> u64 {
+	linear_verify_cost(VERIFY_COST, size)
+}
+
+/// Seeds the random coefficients of a batch verification with the hash of the whole batch,
+/// so they can not be chosen by the caller.
+fn batch_rng(
+	public_bytes: &[Vec<u8>],
+	signature_bytes: &[Vec<u8>],
+	messages: &[Vec<u8>],
+) -> ChaCha20Rng {
+	ChaCha20Rng::from_seed(blake2_256(&(public_bytes, signature_bytes, messages).encode()))
+}
+
 /// Utility function to create slice of fixed size
 pub fn to_slice_32(val: &[u8]) -> Option<[u8; 32]> {
 	if val.len() == 32 {
@@ -90,7 +151,7 @@ impl<Runtime: pallet_evm::Config> SchnorrSr25519Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<32>>,
 		signature_bytes: BoundedBytes<ConstU32<65>>,
 		message: UnboundedBytes,
@@ -100,6 +161,8 @@ impl<Runtime: pallet_evm::Config> SchnorrSr25519Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(sr25519_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		// Convert the signature from bytes to sr25519::Signature
 		let signature: sr25519::Signature =
 			signature_bytes.as_slice().try_into().map_err(|_| revert("Invalid Signature"))?;
@@ -116,6 +179,38 @@ impl<Runtime: pallet_evm::Config> SchnorrSr25519Precompile<Runtime> {
 		let is_confirmed = sr25519_verify(&signature, &hash, &public_key);
 		Ok(is_confirmed)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<32>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<65>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			sr25519_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		for ((public_bytes, signature_bytes), message) in
+			public_bytes.iter().zip(signature_bytes.iter()).zip(messages.iter())
+		{
+			let signature: sr25519::Signature =
+				signature_bytes.as_slice().try_into().map_err(|_| revert("Invalid Signature"))?;
+			let public_key: sr25519::Public = sr25519::Public::from_raw(
+				public_bytes.as_slice().try_into().map_err(|_| revert("Invalid Publci Key"))?,
+			);
+
+			if !sr25519_verify(&signature, &keccak_256(message), &public_key) {
+				return Ok(false);
+			}
+		}
+
+		Ok(true)
+	}
 }
 
 /// A precompile to verify SchnorrSecp256k1 signature
@@ -126,7 +221,7 @@ impl<Runtime: pallet_evm::Config> SchnorrSecp256k1Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<33>>,
 		signature_bytes: BoundedBytes<ConstU32<65>>,
 		message: UnboundedBytes,
@@ -136,6 +231,8 @@ impl<Runtime: pallet_evm::Config> SchnorrSecp256k1Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(batch_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		verify_signature!(
 			Secp256K1Sha256,
 			public_bytes.as_slice(),
@@ -145,6 +242,30 @@ impl<Runtime: pallet_evm::Config> SchnorrSecp256k1Precompile<Runtime> {
 			&[0u8; 65]
 		)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<33>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<65>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		verify_signature_batch!(
+			frost_core,
+			Secp256K1Sha256,
+			&public_bytes,
+			&signature_bytes,
+			&messages
+		)
+	}
 }
 
 /// A precompile to verify SchnorrEd25519 signature
@@ -155,7 +276,7 @@ impl<Runtime: pallet_evm::Config> SchnorrEd25519Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<32>>,
 		signature_bytes: BoundedBytes<ConstU32<64>>,
 		message: UnboundedBytes,
@@ -165,6 +286,8 @@ impl<Runtime: pallet_evm::Config> SchnorrEd25519Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(batch_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		verify_signature!(
 			Ed25519Sha512,
 			public_bytes.as_slice(),
@@ -174,6 +297,30 @@ impl<Runtime: pallet_evm::Config> SchnorrEd25519Precompile<Runtime> {
 			&[0u8; 64]
 		)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<32>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<64>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		verify_signature_batch!(
+			frost_core,
+			Ed25519Sha512,
+			&public_bytes,
+			&signature_bytes,
+			&messages
+		)
+	}
 }
 
 /// A precompile to verify SchnorrP256 signature
@@ -184,7 +331,7 @@ impl<Runtime: pallet_evm::Config> SchnorrP256Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<33>>,
 		signature_bytes: BoundedBytes<ConstU32<65>>,
 		message: UnboundedBytes,
@@ -194,6 +341,8 @@ impl<Runtime: pallet_evm::Config> SchnorrP256Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(batch_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		verify_signature!(
 			P256Sha256,
 			public_bytes.as_slice(),
@@ -203,6 +352,24 @@ impl<Runtime: pallet_evm::Config> SchnorrP256Precompile<Runtime> {
 			&[0u8; 65]
 		)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<33>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<65>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		verify_signature_batch!(frost_core, P256Sha256, &public_bytes, &signature_bytes, &messages)
+	}
 }
 
 /// A precompile to verify SchnorrRistretto255 signature
@@ -213,7 +380,7 @@ impl<Runtime: pallet_evm::Config> SchnorrRistretto255Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<32>>,
 		signature_bytes: BoundedBytes<ConstU32<64>>,
 		message: UnboundedBytes,
@@ -223,6 +390,8 @@ impl<Runtime: pallet_evm::Config> SchnorrRistretto255Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(batch_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		verify_signature!(
 			Ristretto255Sha512,
 			public_bytes.as_slice(),
@@ -232,6 +401,30 @@ impl<Runtime: pallet_evm::Config> SchnorrRistretto255Precompile<Runtime> {
 			&[0u8; 64]
 		)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<32>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<64>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		verify_signature_batch!(
+			frost_core,
+			Ristretto255Sha512,
+			&public_bytes,
+			&signature_bytes,
+			&messages
+		)
+	}
 }
 
 /* THESE LIBS USING LOCAL CUSTOM TG FROST CORE DUE TO NO_STD AND PUBLIC ISSUES */
@@ -244,7 +437,7 @@ impl<Runtime: pallet_evm::Config> SchnorrEd448Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<57>>,
 		signature_bytes: BoundedBytes<ConstU32<114>>,
 		message: UnboundedBytes,
@@ -254,6 +447,8 @@ impl<Runtime: pallet_evm::Config> SchnorrEd448Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(batch_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		verify_tg_frost_signature!(
 			Ed448Shake256,
 			public_bytes.as_slice(),
@@ -265,6 +460,30 @@ impl<Runtime: pallet_evm::Config> SchnorrEd448Precompile<Runtime> {
 
 		Ok(false)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<57>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<114>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		verify_signature_batch!(
+			tg_frost_core,
+			Ed448Shake256,
+			&public_bytes,
+			&signature_bytes,
+			&messages
+		)
+	}
 }
 
 /// A precompile to verify SchnorrTaproot signature
@@ -275,7 +494,7 @@ impl<Runtime: pallet_evm::Config> SchnorrTaprootPrecompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<33>>,
 		signature_bytes: BoundedBytes<ConstU32<65>>,
 		message: UnboundedBytes,
@@ -285,6 +504,8 @@ impl<Runtime: pallet_evm::Config> SchnorrTaprootPrecompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(batch_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		verify_tg_frost_signature!(
 			Secp256K1Sha256TR,
 			public_bytes.as_slice(),
@@ -296,6 +517,30 @@ impl<Runtime: pallet_evm::Config> SchnorrTaprootPrecompile<Runtime> {
 
 		Ok(false)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<33>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<65>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		verify_signature_batch!(
+			tg_frost_core,
+			Secp256K1Sha256TR,
+			&public_bytes,
+			&signature_bytes,
+			&messages
+		)
+	}
 }
 
 /// A precompile to verify SchnorrP384 signature
@@ -306,7 +551,7 @@ impl<Runtime: pallet_evm::Config> SchnorrP384Precompile<Runtime> {
 	#[precompile::public("verify(bytes,bytes,bytes)")]
 	#[precompile::view]
 	fn verify(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		public_bytes: BoundedBytes<ConstU32<49>>,
 		signature_bytes: BoundedBytes<ConstU32<97>>,
 		message: UnboundedBytes,
@@ -316,6 +561,8 @@ impl<Runtime: pallet_evm::Config> SchnorrP384Precompile<Runtime> {
 		let signature_bytes: Vec<u8> = signature_bytes.into();
 		let message: Vec<u8> = message.into();
 
+		handle.record_cost(batch_verify_cost(1).saturating_add(message_cost(&message)))?;
+
 		verify_tg_frost_signature!(
 			P384Sha384,
 			public_bytes.as_slice(),
@@ -327,4 +574,28 @@ impl<Runtime: pallet_evm::Config> SchnorrP384Precompile<Runtime> {
 
 		Ok(false)
 	}
+
+	#[precompile::public("verifyBatch(bytes[],bytes[],bytes[])")]
+	#[precompile::view]
+	fn verify_batch(
+		handle: &mut impl PrecompileHandle,
+		public_bytes: BoundedVec<BoundedBytes<ConstU32<49>>, GetBatchLimit>,
+		signature_bytes: BoundedVec<BoundedBytes<ConstU32<97>>, GetBatchLimit>,
+		messages: BoundedVec<UnboundedBytes, GetBatchLimit>,
+	) -> EvmResult<bool> {
+		let (public_bytes, signature_bytes, messages) =
+			parse_batch(public_bytes, signature_bytes, messages)?;
+
+		handle.record_cost(
+			batch_verify_cost(public_bytes.len()).saturating_add(messages_cost(&messages)),
+		)?;
+
+		verify_signature_batch!(
+			tg_frost_core,
+			P384Sha384,
+			&public_bytes,
+			&signature_bytes,
+			&messages
+		)
+	}
 }
@@ -225,6 +225,8 @@ pub type Precompiles<R> = PrecompileSetBuilder<
 >;
 
 pub type PcallSchnorrSr25519 = SchnorrSr25519PrecompileCall<Runtime>;
+pub type PcallSchnorrEd25519 = SchnorrEd25519PrecompileCall<Runtime>;
+pub type PcallSchnorrEd448 = SchnorrEd448PrecompileCall<Runtime>;
 
 parameter_types! {
 	pub SuicideQuickClearLimit: u32 = 0;
@@ -14,14 +14,23 @@
 // You should have received a copy of the GNU General Public License
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::mock::*;
-use precompile_utils::testing::*;
+use crate::{batch_verify_cost, mock::*, sr25519_verify_cost, VERIFY_COST};
+use evm_signature_utils::{BATCH_LIMIT, MESSAGE_WORD_COST, VERIFY_BASE_COST};
+use precompile_utils::{prelude::*, testing::*};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 use sp_core::{sr25519, Pair, H160};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
 }
 
+fn bounded<T, S>(items: Vec<Vec<u8>>) -> BoundedVec<T, S>
+where
+	T: From<Vec<u8>>,
+{
+	items.into_iter().map(Into::into).collect::<Vec<T>>().into()
+}
+
 #[test]
 fn signature_verification_works_sr25519_schnorr() {
 	ExtBuilder.build().execute_with(|| {
@@ -40,7 +49,140 @@ fn signature_verification_works_sr25519_schnorr() {
 					message: message.into(),
 				},
 			)
+			.expect_cost(sr25519_verify_cost(1) + MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
+
+/// Signs `messages` with new keys, returning the serialized keys and signatures.
+macro_rules! sign_batch {
+	($frost:ident, $impl_type:ty, $messages:expr) => {{
+		let mut rng = ChaCha20Rng::seed_from_u64(0);
+		let mut public_bytes: Vec<Vec<u8>> = Vec::new();
+		let mut signature_bytes: Vec<Vec<u8>> = Vec::new();
+		for message in $messages {
+			let signing_key = $frost::SigningKey::<$impl_type>::new(&mut rng);
+			let signature = signing_key.sign(&mut rng, message);
+			public_bytes.push($frost::VerifyingKey::from(&signing_key).serialize().unwrap());
+			signature_bytes.push(signature.serialize().unwrap());
+		}
+		(public_bytes, signature_bytes)
+	}};
+}
+
+fn batch_messages() -> Vec<Vec<u8>> {
+	(0u8..8).map(|i| vec![i; 32]).collect()
+}
+
+#[test]
+fn batch_verification_works_ed25519_schnorr() {
+	ExtBuilder.build().execute_with(|| {
+		let messages = batch_messages();
+		let (public_bytes, signature_bytes) =
+			sign_batch!(frost_core, frost_ed25519::Ed25519Sha512, &messages);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(3),
+				PcallSchnorrEd25519::verify_batch {
+					public_bytes: bounded(public_bytes),
+					signature_bytes: bounded(signature_bytes),
+					messages: bounded(messages),
+				},
+			)
+			.expect_cost(batch_verify_cost(8) + 8 * MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn batch_verification_fails_with_one_bad_signature() {
+	ExtBuilder.build().execute_with(|| {
+		let mut messages = batch_messages();
+		let (public_bytes, signature_bytes) =
+			sign_batch!(frost_core, frost_ed25519::Ed25519Sha512, &messages);
+		messages[5] = b"not the signed message".to_vec();
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(3),
+				PcallSchnorrEd25519::verify_batch {
+					public_bytes: bounded(public_bytes),
+					signature_bytes: bounded(signature_bytes),
+					messages: bounded(messages),
+				},
+			)
 			.expect_no_logs()
 			.execute_returns(false);
 	});
 }
+
+#[test]
+fn batch_verification_works_ed448_schnorr() {
+	ExtBuilder.build().execute_with(|| {
+		let messages = batch_messages();
+		let (public_bytes, signature_bytes) =
+			sign_batch!(tg_frost_core, frost_ed448::Ed448Shake256, &messages);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(4),
+				PcallSchnorrEd448::verify_batch {
+					public_bytes: bounded(public_bytes),
+					signature_bytes: bounded(signature_bytes),
+					messages: bounded(messages),
+				},
+			)
+			.expect_cost(batch_verify_cost(8) + 8 * MESSAGE_WORD_COST)
+			.expect_no_logs()
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn batch_verification_reverts_with_invalid_batch() {
+	ExtBuilder.build().execute_with(|| {
+		let messages = batch_messages();
+		let (public_bytes, signature_bytes) =
+			sign_batch!(frost_core, frost_ed25519::Ed25519Sha512, &messages);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(3),
+				PcallSchnorrEd25519::verify_batch {
+					public_bytes: bounded(public_bytes),
+					signature_bytes: bounded(signature_bytes[1..].to_vec()),
+					messages: bounded(messages),
+				},
+			)
+			.execute_reverts(|output| output == b"Batch arrays have different lengths");
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(3),
+				PcallSchnorrEd25519::verify_batch {
+					public_bytes: bounded(vec![]),
+					signature_bytes: bounded(vec![]),
+					messages: bounded(vec![]),
+				},
+			)
+			.execute_reverts(|output| output == b"Empty batch");
+	});
+}
+
+#[test]
+fn batch_verification_cost_is_sub_linear() {
+	assert_eq!(batch_verify_cost(1), VERIFY_BASE_COST + VERIFY_COST);
+	for size in 2..=BATCH_LIMIT as usize {
+		assert!(batch_verify_cost(size) > batch_verify_cost(size - 1));
+		assert!(batch_verify_cost(size) < batch_verify_cost(1) * size as u64);
+		assert!(sr25519_verify_cost(size) < sr25519_verify_cost(1) * size as u64);
+	}
+}
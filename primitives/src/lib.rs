@@ -319,6 +319,15 @@ pub mod credits {
 
 	/// The maximum accrual window duration in blocks
 	pub const CLAIM_WINDOW_BLOCKS: u64 = DAYS * 7;
+
+	/// The number of blocks after which on-chain ledger credits expire
+	pub const CREDIT_EXPIRY_BLOCKS: u64 = DAYS * 90;
+
+	/// The maximum number of distinct expiry batches held by a single ledger holder
+	pub const MAX_CREDIT_BATCHES: u32 = 64;
+
+	/// The maximum number of stake checkpoints kept per account for time-weighted accrual
+	pub const MAX_STAKE_CHECKPOINTS: u32 = 32;
 }
 
 pub mod multi_asset_delegation {
@@ -349,6 +358,12 @@ pub mod multi_asset_delegation {
 	/// Maximum number of delegations per delegator
 	pub const MAX_DELEGATIONS: u32 = 50;
 
+	/// Maximum length of a text field in an operator profile
+	pub const MAX_OPERATOR_PROFILE_FIELD_LENGTH: u32 = 128;
+
+	/// Maximum length of the supported blueprints description in an operator profile
+	pub const MAX_OPERATOR_PROFILE_DESCRIPTION_LENGTH: u32 = 1024;
+
 	/// Leave operators delay for fast runtime
 	pub const LEAVE_OPERATORS_DELAY_FAST: u32 = 1;
 
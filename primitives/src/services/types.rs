@@ -21,7 +21,10 @@ use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Deserializer, Serialize};
 use sp_core::{Get, H160, RuntimeDebug};
-use sp_runtime::{Percent, traits::AtLeast32BitUnsigned};
+use sp_runtime::{
+	FixedPointNumber, FixedU128, Percent, Rounding,
+	helpers_128bit::multiply_by_rational_with_rounding, traits::AtLeast32BitUnsigned,
+};
 use sp_staking::EraIndex;
 use sp_std::fmt::Display;
 
@@ -411,6 +414,80 @@ pub struct UnappliedSlash<AccountId> {
 	pub operator: AccountId,
 	/// The slash percentage
 	pub slash_percent: Percent,
+	/// Where the slashed funds are routed once the slash is applied.
+	pub destination: SlashDestination<AccountId>,
+}
+
+/// The policy a blueprint or a service declares for routing slashed funds.
+///
+/// The policy is resolved into a [`SlashDestination`] when the slash is reported, since only then
+/// the reporter and the service owner are known.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, Copy, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SlashDestinationPolicy {
+	/// The slashed funds are burned.
+	#[codec(index = 0)]
+	Burn,
+	/// The slashed funds are sent to the configured slash recipient (the treasury).
+	#[default]
+	#[codec(index = 1)]
+	Treasury,
+	/// The slashed funds are split between the treasury and the account that reported the slash.
+	#[codec(index = 2)]
+	TreasuryAndReporter {
+		/// The share of the slashed funds paid to the reporter.
+		reporter_share: Percent,
+	},
+	/// The slashed funds are paid to the owner of the service as a compensation.
+	#[codec(index = 3)]
+	ServiceOwner,
+}
+
+impl SlashDestinationPolicy {
+	/// Resolves the policy into a [`SlashDestination`].
+	///
+	/// Slashes without a reporter (e.g. heartbeat slashes) fall back to the treasury when the
+	/// policy requires one.
+	pub fn resolve<AccountId>(
+		self,
+		reporter: Option<AccountId>,
+		service_owner: AccountId,
+	) -> SlashDestination<AccountId> {
+		match (self, reporter) {
+			(Self::Burn, _) => SlashDestination::Burn,
+			(Self::Treasury, _) | (Self::TreasuryAndReporter { .. }, None) =>
+				SlashDestination::Treasury,
+			(Self::TreasuryAndReporter { reporter_share }, Some(reporter)) =>
+				SlashDestination::TreasuryAndReporter { reporter, reporter_share },
+			(Self::ServiceOwner, _) => SlashDestination::ServiceOwner(service_owner),
+		}
+	}
+}
+
+/// The resolved destination of the funds of an [`UnappliedSlash`].
+#[derive(Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SlashDestination<AccountId> {
+	/// The slashed funds are burned.
+	#[codec(index = 0)]
+	Burn,
+	/// The slashed funds are sent to the configured slash recipient (the treasury).
+	#[default]
+	#[codec(index = 1)]
+	Treasury,
+	/// The slashed funds are split between the treasury and the reporter.
+	#[codec(index = 2)]
+	TreasuryAndReporter {
+		/// The account that reported the slash.
+		reporter: AccountId,
+		/// The share of the slashed funds paid to the reporter.
+		reporter_share: Percent,
+	},
+	/// The slashed funds are paid to the owner of the slashed service.
+	#[codec(index = 3)]
+	ServiceOwner(AccountId),
 }
 
 pub type ServiceId = u64;
@@ -446,6 +523,51 @@ impl<BlockNumber, Balance: Default> Default for PricingModel<BlockNumber, Balanc
 	}
 }
 
+/// The order in which the payment sources of a job payment are tried when a blueprint accepts
+/// credits.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, Copy, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CreditPaymentOrder {
+	/// Pay with credits if the payer holds enough, otherwise with the payment asset.
+	#[default]
+	#[codec(index = 0)]
+	CreditsFirst,
+	/// Pay with the payment asset if the payer holds enough, otherwise with credits.
+	#[codec(index = 1)]
+	AssetFirst,
+	/// Only accept credits.
+	#[codec(index = 2)]
+	CreditsOnly,
+}
+
+/// The policy a blueprint declares for accepting credits from the on-chain credit ledger as a
+/// payment for its jobs.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, Copy, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CreditPaymentPolicy {
+	/// The number of credits charged per unit of the job price.
+	pub conversion_rate: FixedU128,
+	/// The order in which credits and the payment asset are tried.
+	pub order: CreditPaymentOrder,
+}
+
+impl CreditPaymentPolicy {
+	/// Returns the credits charged for a job price of `amount`, rounded up, or `None` on
+	/// overflow.
+	pub fn credits_for(&self, amount: u128) -> Option<u128> {
+		multiply_by_rational_with_rounding(
+			amount,
+			self.conversion_rate.into_inner(),
+			FixedU128::DIV,
+			Rounding::Up,
+		)
+	}
+}
+
 /// Price targets for service pricing (placeholder type)
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, Default)]
 pub struct PriceTargets {
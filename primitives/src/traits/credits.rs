@@ -0,0 +1,43 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use sp_runtime::{DispatchError, DispatchResult, traits::Zero};
+
+/// Trait exposing the on-chain credit ledger to other pallets.
+///
+/// Credits are held per account and spent earliest-expiring first. Expired credits are never
+/// spendable.
+pub trait CreditsLedger<AccountId, Balance> {
+	/// Returns the unexpired credits currently held by `who`.
+	fn spendable_credits(who: &AccountId) -> Balance;
+
+	/// Spends `amount` credits held by `who`.
+	///
+	/// # Errors
+	/// Fails without spending anything if `who` holds fewer than `amount` spendable credits or
+	/// the ledger is disabled.
+	fn spend_credits(who: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+impl<AccountId, Balance: Zero> CreditsLedger<AccountId, Balance> for () {
+	fn spendable_credits(_who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	fn spend_credits(_who: &AccountId, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Other("Credit ledger not available"))
+	}
+}
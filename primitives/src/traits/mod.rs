@@ -1,13 +1,17 @@
 pub mod assets;
+pub mod credits;
 pub mod data_provider;
 pub mod multi_asset_delegation;
 pub mod rewards;
 pub mod services;
 pub mod slash;
+pub mod staking;
 
 pub use assets::*;
+pub use credits::*;
 pub use data_provider::*;
 pub use multi_asset_delegation::*;
 pub use rewards::*;
 pub use services::*;
 pub use slash::*;
+pub use staking::*;
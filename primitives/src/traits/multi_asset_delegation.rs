@@ -1,7 +1,12 @@
 use crate::{
+	BlueprintId,
 	services::Asset,
-	types::{RoundIndex, rewards::UserDepositWithLocks},
+	types::{
+		RoundIndex,
+		rewards::{LockMultiplier, UserDepositWithLocks},
+	},
 };
+use sp_runtime::{DispatchError, DispatchResult, traits::Zero};
 use sp_std::prelude::*;
 
 /// A trait to provide information about multi-asset delegation.
@@ -136,4 +141,171 @@ pub trait MultiAssetDelegationInfo<AccountId, Balance, BlockNumber, AssetId, Ass
 	/// An `Option` containing the user's deposit information if it exists:
 	/// - `Some(UserDepositWithLocks)` containing the unlocked amount and any time-locks
 	fn get_user_deposit_by_asset_type(who: &AccountId, asset_type: AssetType) -> Option<Balance>;
+
+	/// Get the reward multiplier of a lock tier.
+	///
+	/// Defaults to the multiplier of the [`LockMultiplier`] itself, for implementations without
+	/// configurable lock tiers.
+	///
+	/// # Parameters
+	///
+	/// * `lock_multiplier`: The lock tier to get the multiplier for.
+	///
+	/// # Returns
+	///
+	/// The multiplier applied to amounts locked in the tier.
+	fn get_lock_multiplier_value(lock_multiplier: LockMultiplier) -> u32 {
+		lock_multiplier.value()
+	}
+
+	/// Get the number of blocks a lock of a tier lasts.
+	///
+	/// Defaults to the duration of the [`LockMultiplier`] itself, for implementations without
+	/// configurable lock tiers.
+	///
+	/// # Parameters
+	///
+	/// * `lock_multiplier`: The lock tier to get the duration for.
+	///
+	/// # Returns
+	///
+	/// The duration of a new lock in the tier.
+	fn get_lock_duration(lock_multiplier: LockMultiplier) -> BlockNumber
+	where
+		BlockNumber: From<u32>,
+	{
+		lock_multiplier.get_blocks().into()
+	}
+}
+
+/// A trait to restake funds into the multi-asset delegation system on behalf of a delegator.
+///
+/// This is used by other pallets (for example the rewards pallet) to deposit funds held by a
+/// delegator and delegate them to an operator in a single step.
+pub trait MultiAssetDelegationRestaker<AccountId, Balance, AssetId> {
+	/// Deposit `amount` of `asset` from `who` and delegate it to `operator`.
+	///
+	/// If `who` already delegates `asset` to `operator`, the existing delegation is increased and
+	/// keeps its blueprint selection. Implementations must not leave a partial deposit behind
+	/// when the delegation fails.
+	///
+	/// # Parameters
+	///
+	/// * `who`: The account of the delegator providing the funds.
+	/// * `operator`: The operator to delegate to.
+	/// * `asset`: The asset to deposit and delegate.
+	/// * `amount`: The amount to deposit and delegate.
+	fn deposit_and_delegate(
+		who: &AccountId,
+		operator: &AccountId,
+		asset: Asset<AssetId>,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+/// Hooks notified by the multi-asset delegation system whenever the stake of a delegator changes.
+///
+/// This is used by other pallets (for example the credits pallet) to keep a history of
+/// delegator stakes instead of relying on the stake at a single point in time.
+pub trait DelegationHooks<AccountId, AssetId> {
+	/// Called after the deposits or delegations of `who` in `asset` have changed.
+	///
+	/// # Parameters
+	///
+	/// * `who`: The account of the delegator whose stake changed.
+	/// * `asset`: The asset whose deposit or delegation changed.
+	fn on_stake_changed(who: &AccountId, asset: &Asset<AssetId>);
+}
+
+impl<AccountId, AssetId> DelegationHooks<AccountId, AssetId> for () {
+	fn on_stake_changed(_who: &AccountId, _asset: &Asset<AssetId>) {}
+}
+
+/// A trait to delegate stake that is bonded through staking to restaking operators.
+///
+/// This is used by other pallets (for example the tangle-lst pallet) to restake the funds their
+/// accounts nominate with, without moving them out of staking.
+pub trait NominationDelegator<AccountId, Balance> {
+	/// Delegate `amount` of the stake `who` nominates with to `operator`.
+	///
+	/// # Parameters
+	///
+	/// * `who`: The nominator whose stake is delegated.
+	/// * `operator`: The operator to delegate to.
+	/// * `amount`: The amount of nominated stake to delegate.
+	/// * `blueprints`: The blueprints the delegation works with, or all blueprints if `None`.
+	fn delegate_nomination(
+		who: &AccountId,
+		operator: &AccountId,
+		amount: Balance,
+		blueprints: Option<Vec<BlueprintId>>,
+	) -> DispatchResult;
+
+	/// Schedule `amount` of the nomination delegation of `who` to `operator` to be undelegated.
+	fn schedule_nomination_unstake(
+		who: &AccountId,
+		operator: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+
+	/// Undelegate the scheduled requests of `who` to `operator` that are ready, returning the
+	/// undelegated amount.
+	fn execute_nomination_unstake(
+		who: &AccountId,
+		operator: &AccountId,
+	) -> Result<Balance, DispatchError>;
+
+	/// The total amount of the nominated stake of `who` that is delegated to operators.
+	fn total_nomination_delegations(who: &AccountId) -> Balance;
+}
+
+impl<AccountId, Balance: Zero> NominationDelegator<AccountId, Balance> for () {
+	fn delegate_nomination(
+		_who: &AccountId,
+		_operator: &AccountId,
+		_amount: Balance,
+		_blueprints: Option<Vec<BlueprintId>>,
+	) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn schedule_nomination_unstake(
+		_who: &AccountId,
+		_operator: &AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn execute_nomination_unstake(
+		_who: &AccountId,
+		_operator: &AccountId,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn total_nomination_delegations(_who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+}
+
+/// Handler for slashes of delegated nominations.
+///
+/// The funds of a nomination delegation stay bonded in staking, so the multi-asset delegation
+/// system cannot move them itself and leaves slashing them to this handler.
+pub trait NominationSlashHandler<AccountId, Balance> {
+	/// Slash up to `amount` of the stake `who` nominates with and remove it from the total
+	/// issuance, returning the amount that was slashed.
+	///
+	/// # Parameters
+	///
+	/// * `who`: The nominator whose delegated stake is slashed.
+	/// * `amount`: The amount to slash.
+	fn slash_nomination(who: &AccountId, amount: Balance) -> Result<Balance, DispatchError>;
+}
+
+impl<AccountId, Balance: Zero> NominationSlashHandler<AccountId, Balance> for () {
+	fn slash_nomination(_who: &AccountId, _amount: Balance) -> Result<Balance, DispatchError> {
+		Ok(Zero::zero())
+	}
 }
@@ -53,6 +53,23 @@ pub trait RewardsManager<AccountId, AssetId, Balance, BlockNumber> {
 		amount: Balance,
 	) -> Result<(), Self::Error>;
 
+	/// Records that the reward multiplier applied to an amount of an asset delegated under a
+	/// lock changed, for example because the lock was extended, broken or its tier reconfigured.
+	///
+	/// # Parameters
+	/// * `asset` - The delegated asset
+	/// * `amount` - The delegated amount the multiplier applies to
+	/// * `old_multiplier` - The multiplier previously applied to the amount
+	/// * `new_multiplier` - The multiplier applied to the amount from now on
+	fn record_lock_multiplier_change(
+		_asset: Asset<AssetId>,
+		_amount: Balance,
+		_old_multiplier: u32,
+		_new_multiplier: u32,
+	) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
 	/// Records a service reward for an account.
 	///
 	/// # Parameters
@@ -86,6 +103,39 @@ pub trait RewardsManager<AccountId, AssetId, Balance, BlockNumber> {
 	/// * `Ok(Balance)` - The incentive cap for the asset
 	/// * `Err(Self::Error)` - If there was an error retrieving the cap
 	fn get_asset_incentive_cap(asset: Asset<AssetId>) -> Result<Balance, Self::Error>;
+
+	/// Gets the pot account of the reward vault an asset belongs to.
+	/// Funds sent to this account are distributed as rewards of the vault.
+	///
+	/// # Parameters
+	/// * `asset` - The asset to query the vault pot account for
+	///
+	/// # Returns
+	/// * `Some(AccountId)` - The pot account of the vault of the asset
+	/// * `None` - If the asset is not in a reward vault
+	fn get_reward_vault_pot_account(_asset: Asset<AssetId>) -> Option<AccountId> {
+		None
+	}
+
+	/// Claims the pending rewards of an account for an asset, paying them to the payee of the
+	/// account.
+	///
+	/// # Parameters
+	/// * `account_id` - The account to claim the rewards of
+	/// * `asset` - The asset to claim the rewards for
+	///
+	/// # Returns
+	/// * `Ok(Balance)` - The amount of rewards claimed
+	/// * `Err(Self::Error)` - If the rewards could not be claimed
+	fn claim_rewards(
+		_account_id: &AccountId,
+		_asset: Asset<AssetId>,
+	) -> Result<Balance, Self::Error>
+	where
+		Balance: Zero,
+	{
+		Ok(Balance::zero())
+	}
 }
 
 impl<AccountId, AssetId, Balance, BlockNumber>
@@ -178,3 +228,16 @@ impl<AccountId, ServiceId, Balance> RewardRecorder<AccountId, ServiceId, Balance
 		Ok(())
 	}
 }
+
+/// A trait to route the rewards of an account to a payee.
+///
+/// This is used by other pallets (for example the tangle-lst pallet) to have the rewards earned
+/// by an account they control paid to a different account.
+pub trait RewardPayeeManager<AccountId> {
+	/// Pays the rewards of `who` to `payee`, or to `who` itself if `payee` is `None`.
+	fn set_reward_payee(who: &AccountId, payee: Option<AccountId>);
+}
+
+impl<AccountId> RewardPayeeManager<AccountId> for () {
+	fn set_reward_payee(_who: &AccountId, _payee: Option<AccountId>) {}
+}
@@ -1,4 +1,6 @@
+use crate::InstanceId;
 use scale_info::prelude::vec::Vec;
+use sp_runtime::{DispatchResult, Percent};
 
 /// A trait to manage and query services and blueprints for operators.
 ///
@@ -73,3 +75,67 @@ pub trait ServiceManager<AccountId, Balance> {
 	/// `true` if the operator has active services, otherwise `false`.
 	fn has_active_services(operator: &AccountId) -> bool;
 }
+
+/// A trait for pallets that serve data on behalf of a service instance, such as the oracle, to
+/// hold the operators of that service accountable.
+///
+/// # Type Parameters
+///
+/// * `AccountId`: The type representing an account identifier.
+pub trait ServiceOperatorManager<AccountId> {
+	/// Get the operators of a service instance.
+	///
+	/// # Parameters
+	///
+	/// * `service_id`: The ID of the service instance.
+	///
+	/// # Returns
+	///
+	/// The operators of the service, or an empty list if the service does not exist.
+	fn service_operators(service_id: InstanceId) -> Vec<AccountId>;
+
+	/// Record that an operator was live in a service instance.
+	///
+	/// This counts as a heartbeat of the operator, so that the rounds it misses count against the
+	/// heartbeat QoS of the service.
+	///
+	/// # Parameters
+	///
+	/// * `service_id`: The ID of the service instance.
+	/// * `operator`: A reference to the account identifier of the operator.
+	fn record_heartbeat(service_id: InstanceId, operator: &AccountId) -> DispatchResult;
+
+	/// Report misbehavior of an operator in a service instance.
+	///
+	/// This schedules an unapplied slash against the operator, which goes through the regular
+	/// slashing pipeline and can still be disputed.
+	///
+	/// # Parameters
+	///
+	/// * `service_id`: The ID of the service instance.
+	/// * `operator`: A reference to the account identifier of the operator.
+	/// * `slash_percent`: The percentage of the operator's exposure to slash.
+	fn report_misbehavior(
+		service_id: InstanceId,
+		operator: &AccountId,
+		slash_percent: Percent,
+	) -> DispatchResult;
+}
+
+impl<AccountId> ServiceOperatorManager<AccountId> for () {
+	fn service_operators(_service_id: InstanceId) -> Vec<AccountId> {
+		Vec::new()
+	}
+
+	fn record_heartbeat(_service_id: InstanceId, _operator: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn report_misbehavior(
+		_service_id: InstanceId,
+		_operator: &AccountId,
+		_slash_percent: Percent,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
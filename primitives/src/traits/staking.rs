@@ -0,0 +1,53 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Tangle Foundation.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::weights::Weight;
+use sp_runtime::{Perbill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The staking record of a validator that nominations are chosen by.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ValidatorInfo<Balance> {
+	/// The commission the validator takes from its rewards.
+	pub commission: Perbill,
+	/// The stake the validator has bonded itself.
+	pub self_stake: Balance,
+	/// The reward points the validator earned in the last era.
+	pub era_points: u32,
+	/// The number of times the validator has been slashed.
+	pub slashes: u32,
+	/// Whether the validator has more nominators than get paid in a single payout page.
+	pub oversubscribed: bool,
+}
+
+/// Trait for reading the validators that can be nominated from the staking system.
+pub trait ValidatorInfoProvider<AccountId, Balance> {
+	/// Returns at most `max` validators that accept nominations, with their staking records.
+	fn validators(max: u32) -> Vec<(AccountId, ValidatorInfo<Balance>)>;
+
+	/// The weight of reading at most `max` validators with [`Self::validators`].
+	fn validators_weight(max: u32) -> Weight;
+}
+
+impl<AccountId, Balance> ValidatorInfoProvider<AccountId, Balance> for () {
+	fn validators(_max: u32) -> Vec<(AccountId, ValidatorInfo<Balance>)> {
+		Vec::new()
+	}
+
+	fn validators_weight(_max: u32) -> Weight {
+		Weight::zero()
+	}
+}
@@ -29,6 +29,12 @@ pub const MAX_VAULT_LOGO_LENGTH: u32 = 256;
 /// Maximum pending rewards per operator
 pub const MAX_PENDING_REWARDS_PER_OPERATOR: u32 = 100;
 
+/// Maximum reward streams per vault
+pub const MAX_REWARD_STREAMS_PER_VAULT: u32 = 10;
+
+/// Maximum claims in a single batch claim
+pub const MAX_CLAIMS_PER_BATCH: u32 = 50;
+
 /// Mainnet-specific constants
 pub mod mainnet {
 	use super::*;
@@ -126,15 +132,24 @@ impl<Balance: Default, BlockNumber: Default, AssetId: AssetIdT, MaxServiceReward
 
 /// Lock multiplier for rewards, representing months of lock period
 #[derive(Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum LockMultiplier {
 	/// One month lock period (1x multiplier)
-	OneMonth = 1,
+	#[codec(index = 1)]
+	OneMonth,
 	/// Two months lock period (2x multiplier)
-	TwoMonths = 2,
+	#[codec(index = 2)]
+	TwoMonths,
 	/// Three months lock period (3x multiplier)
-	ThreeMonths = 3,
+	#[codec(index = 3)]
+	ThreeMonths,
 	/// Six months lock period (6x multiplier)
-	SixMonths = 6,
+	#[codec(index = 6)]
+	SixMonths,
+	/// A lock tier whose duration and multiplier are only defined by governance, identified by
+	/// its ID. Without a configuration it has no duration and no boost.
+	#[codec(index = 7)]
+	Custom(u8),
 }
 
 impl Default for LockMultiplier {
@@ -146,7 +161,13 @@ impl Default for LockMultiplier {
 impl LockMultiplier {
 	/// Get the multiplier value
 	pub fn value(&self) -> u32 {
-		*self as u32
+		match self {
+			LockMultiplier::OneMonth => 1,
+			LockMultiplier::TwoMonths => 2,
+			LockMultiplier::ThreeMonths => 3,
+			LockMultiplier::SixMonths => 6,
+			LockMultiplier::Custom(_) => 1,
+		}
 	}
 
 	/// Get the block number for each multiplier
@@ -157,6 +178,7 @@ impl LockMultiplier {
 			LockMultiplier::TwoMonths => 864000,
 			LockMultiplier::ThreeMonths => 1296000,
 			LockMultiplier::SixMonths => 2592000,
+			LockMultiplier::Custom(_) => 0,
 		}
 	}
 
@@ -166,6 +188,16 @@ impl LockMultiplier {
 	}
 }
 
+/// Governance-configured duration and reward multiplier of a lock tier, overriding the defaults
+/// of [`LockMultiplier`] or defining a [`LockMultiplier::Custom`] tier.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct LockTier<BlockNumber> {
+	/// The number of blocks a lock of this tier lasts
+	pub duration: BlockNumber,
+	/// The reward multiplier applied to locked amounts
+	pub multiplier: u32,
+}
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
 pub struct UserDepositWithLocks<Balance, BlockNumber> {
 	pub unlocked_amount: Balance,
@@ -179,3 +211,68 @@ pub struct LockInfo<Balance, BlockNumber> {
 	pub lock_multiplier: LockMultiplier,
 	pub expiry_block: BlockNumber,
 }
+
+/// Projected rewards of a hypothetical deposit into a reward vault.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RewardProjection<Balance> {
+	/// The rewards the deposit earns over the projected period
+	pub rewards: Balance,
+	/// The average rewards per block over the projected period
+	pub rewards_per_block: Balance,
+	/// The annualised yield of the deposit, capped at 100%
+	pub apy: Perbill,
+}
+
+/// APY of a reward vault after applying its deposit cap and decay.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct VaultApy<Balance> {
+	/// The APY configured for the vault
+	pub configured_apy: Perbill,
+	/// The APY scaled by the share of the deposit cap that is filled
+	pub effective_apy: Perbill,
+	/// The effective APY once decay applies to unclaimed rewards
+	pub decayed_apy: Perbill,
+	/// The annualised yield of an unlocked deposit, capped at 100%, or zero while the total
+	/// deposit is below the incentive cap
+	pub deposit_apy: Perbill,
+	/// The total deposit of the vault
+	pub total_deposit: Balance,
+	/// The deposit cap of the vault
+	pub deposit_cap: Balance,
+	/// The total deposit needed before the vault pays rewards
+	pub incentive_cap: Balance,
+}
+
+/// Pending rewards of an account for one asset of a reward vault, broken down by lock.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct VaultRewardBreakdown<AssetId, Balance, BlockNumber> {
+	/// The deposited asset
+	pub asset: Asset<AssetId>,
+	/// The unlocked amount of the deposit
+	pub unlocked_amount: Balance,
+	/// The pending rewards of the unlocked amount
+	pub unlocked_rewards: Balance,
+	/// The pending rewards of each lock of the deposit
+	pub locks: Vec<LockRewardBreakdown<Balance, BlockNumber>>,
+	/// The total pending rewards of the deposit
+	pub total_rewards: Balance,
+}
+
+/// Pending rewards of a single lock.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct LockRewardBreakdown<Balance, BlockNumber> {
+	/// The locked amount
+	pub amount: Balance,
+	/// The tier of the lock
+	pub lock_multiplier: LockMultiplier,
+	/// The reward multiplier currently applied to the lock
+	pub multiplier: u32,
+	/// The block the lock expires at
+	pub expiry_block: BlockNumber,
+	/// The pending rewards of the lock
+	pub rewards: Balance,
+}
@@ -15,9 +15,70 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::NegativeImbalance;
-use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
-use tangle_primitives::{impl_deal_with_fees, impl_to_author};
+use crate::{AccountId, Balance, NegativeImbalance, Runtime, Session, Staking};
+use frame_support::{
+	traits::{Currency, Get, Imbalance, OnUnbalanced},
+	weights::Weight,
+};
+use sp_staking::StakingInterface;
+use sp_std::vec::Vec;
+use tangle_primitives::{
+	impl_deal_with_fees, impl_to_author,
+	traits::{ValidatorInfo, ValidatorInfoProvider},
+};
 
 impl_to_author!();
 impl_deal_with_fees!();
+
+/// Reads the validators tangle-lst pools nominate automatically from the staking pallet.
+pub struct StakingValidators;
+
+impl ValidatorInfoProvider<AccountId, Balance> for StakingValidators {
+	fn validators(max: u32) -> Vec<(AccountId, ValidatorInfo<Balance>)> {
+		let Some(active_era) = pallet_staking::ActiveEra::<Runtime>::get().map(|era| era.index)
+		else {
+			return Vec::new();
+		};
+		// The reward points of all validators are decoded once and shared by the candidates.
+		let era_points =
+			pallet_staking::ErasRewardPoints::<Runtime>::get(active_era.saturating_sub(1))
+				.individual;
+
+		// The candidates are the active validators, as only they earn era points.
+		Session::validators()
+			.into_iter()
+			.take(max as usize)
+			.filter_map(|who| {
+				let prefs = pallet_staking::Validators::<Runtime>::try_get(&who).ok()?;
+				if prefs.blocked {
+					return None;
+				}
+				let stake = Staking::stake(&who).ok()?;
+				// Every slash starts a new slashing span.
+				let slashes = pallet_staking::SlashingSpans::<Runtime>::get(&who)
+					.map_or(0, |spans| spans.iter().count().saturating_sub(1) as u32);
+				let oversubscribed =
+					pallet_staking::ErasStakersOverview::<Runtime>::get(active_era, &who)
+						.is_some_and(|overview| {
+							overview.nominator_count >
+								<Runtime as pallet_staking::Config>::MaxExposurePageSize::get()
+						});
+				let info = ValidatorInfo {
+					commission: prefs.commission,
+					self_stake: stake.active,
+					era_points: era_points.get(&who).copied().unwrap_or_default(),
+					slashes,
+					oversubscribed,
+				};
+				Some((who, info))
+			})
+			.collect()
+	}
+
+	fn validators_weight(max: u32) -> Weight {
+		// `ActiveEra`, `ErasRewardPoints` and the session validators once, and `Validators`,
+		// `Bonded`, `Ledger`, `SlashingSpans` and `ErasStakersOverview` per candidate.
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads(5u64.saturating_mul(max.into()).saturating_add(3))
+	}
+}
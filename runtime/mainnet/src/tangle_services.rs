@@ -228,5 +228,6 @@ impl pallet_services::Config for Runtime {
 	type RoleKeyId = RoleKeyId;
 	type WeightInfo = ();
 	type RewardRecorder = Rewards;
+	type CreditsLedger = Credits;
 	type RewardsManager = Rewards;
 }
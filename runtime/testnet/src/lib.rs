@@ -1103,6 +1103,7 @@ impl pallet_multisig::Config for Runtime {
 parameter_types! {
 	pub Prefix: &'static [u8] = b"Claim TNTs to the account:";
 	pub MaxVestingSchedules: u32 = 16;
+	pub const ClaimsPalletId: PalletId = PalletId(*b"py/claim");
 }
 
 impl pallet_airdrop_claims::Config for Runtime {
@@ -1114,6 +1115,11 @@ impl pallet_airdrop_claims::Config for Runtime {
 	type MaxVestingSchedules = MaxVestingSchedules;
 	type MoveClaimOrigin = frame_system::EnsureRoot<AccountId>;
 	type WeightInfo = ();
+	type AssetId = AssetId;
+	type Fungibles = Assets;
+	type DelegationRestaker = MultiAssetDelegation;
+	type PalletId = ClaimsPalletId;
+	type MaxMerkleProofLength = ConstU32<32>;
 }
 
 parameter_types! {
@@ -1202,6 +1208,8 @@ parameter_types! {
 	pub const MaxMetadataLen: u32 = 2;
 	pub const CheckLevel: u8 = 255;
 	pub const LstPalletId: PalletId = PalletId(*b"py/tnlst");
+	pub const MaxInstantUnbondFee: Perbill = Perbill::from_percent(5);
+	pub const MaxInstantUnbondBuffers: u32 = 64;
 }
 
 impl pallet_tangle_lst::Config for Runtime {
@@ -1213,6 +1221,7 @@ impl pallet_tangle_lst::Config for Runtime {
 	type BalanceToU256 = BalanceToU256;
 	type U256ToBalance = U256ToBalance;
 	type Staking = Staking;
+	type StakingSlash = Staking;
 	type PostUnbondingPoolsWindow = ConstU32<4>;
 	type PalletId = LstPalletId;
 	type MaxMetadataLen = MaxMetadataLen;
@@ -1225,6 +1234,16 @@ impl pallet_tangle_lst::Config for Runtime {
 	type MaxIconLength = ConstU32<500>;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxPointsToBalance = frame_support::traits::ConstU8<10>;
+	type NominationDelegator = MultiAssetDelegation;
+	type RewardPayees = Rewards;
+	type MaxRestakeBlueprints =
+		<Runtime as pallet_multi_asset_delegation::Config>::MaxDelegatorBlueprints;
+	type MaxInstantUnbondFee = MaxInstantUnbondFee;
+	type MaxInstantUnbondBuffers = MaxInstantUnbondBuffers;
+	type Validators = impls::StakingValidators;
+	type MaxAutoNominations = ConstU32<MAX_QUOTA_NOMINATIONS>;
+	type MaxAutoNominationCandidates = ConstU32<256>;
+	type MaxAutoNominatingPools = ConstU32<64>;
 }
 
 parameter_types! {
@@ -1245,6 +1264,7 @@ impl pallet_rewards::Config for Runtime {
 	type PalletId = RewardsPID;
 	type VaultId = u32;
 	type DelegationManager = MultiAssetDelegation;
+	type DelegationRestaker = MultiAssetDelegation;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxApy = MaxApy;
 	type MaxDepositCap = MaxDepositCap;
@@ -1256,9 +1276,43 @@ impl pallet_rewards::Config for Runtime {
 	type VaultMetadataOrigin = EnsureRootOrHalfCouncil;
 	type MaxPendingRewardsPerOperator =
 		ConstU32<{ tangle_primitives::types::rewards::MAX_PENDING_REWARDS_PER_OPERATOR }>;
+	type VaultFundsRecipient = TreasuryAccount;
+	type Fungibles = Assets;
+	type MaxRewardStreamsPerVault =
+		ConstU32<{ tangle_primitives::types::rewards::MAX_REWARD_STREAMS_PER_VAULT }>;
+	type MaxClaimsPerBatch = ConstU32<{ tangle_primitives::types::rewards::MAX_CLAIMS_PER_BATCH }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RewardsBenchmarkHelper;
 	type WeightInfo = ();
 }
 
+/// Sets up delegations through the multi-asset delegation pallet for the rewards benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RewardsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_rewards::BenchmarkHelper<AccountId, AssetId, Balance> for RewardsBenchmarkHelper {
+	fn setup_delegation(
+		who: &AccountId,
+		operator: &AccountId,
+		asset: tangle_primitives::services::Asset<AssetId>,
+	) -> Balance {
+		use tangle_primitives::traits::MultiAssetDelegationRestaker;
+
+		if !pallet_multi_asset_delegation::Operators::<Runtime>::contains_key(operator) {
+			let bond = MinOperatorBondAmount::get();
+			Balances::make_free_balance_be(operator, bond.saturating_mul(2));
+			let _ =
+				MultiAssetDelegation::handle_deposit_and_create_operator(operator.clone(), bond);
+		}
+
+		let amount = MinDelegateAmount::get().saturating_mul(10);
+		Balances::make_free_balance_be(who, amount.saturating_mul(2));
+		let _ = MultiAssetDelegation::deposit_and_delegate(who, operator, asset, amount);
+		amount
+	}
+}
+
 parameter_types! {
 	#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -1285,6 +1339,10 @@ impl pallet_credits::Config for Runtime {
 	type MaxStakeTiers = MaxStakeTiers;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type MaxRatePerBlock = MaxRatePerBlock;
+	type CreditExpiryBlocks = ConstU64<{ tangle_primitives::credits::CREDIT_EXPIRY_BLOCKS }>;
+	type MaxCreditBatches = ConstU32<{ tangle_primitives::credits::MAX_CREDIT_BATCHES }>;
+	type MaxStakeCheckpoints = ConstU32<{ tangle_primitives::credits::MAX_STAKE_CHECKPOINTS }>;
+	type CreditSpendOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = ();
 }
 
@@ -1413,6 +1471,12 @@ pub type CheckedExtrinsic =
 	fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_airdrop_claims::migrations::FundClaimsPot<Runtime>,
+	pallet_services::migrations::UnappliedSlashDestinationMigration<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1420,7 +1484,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	(),
+	Migrations,
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -1552,6 +1616,15 @@ parameter_types! {
 
 	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub const MaxDelegations: u32 = tangle_primitives::multi_asset_delegation::MAX_DELEGATIONS;
+
+	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub const MaxOperatorProfileFieldLength: u32 = tangle_primitives::multi_asset_delegation::MAX_OPERATOR_PROFILE_FIELD_LENGTH;
+
+	#[derive(PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub const MaxOperatorProfileDescriptionLength: u32 = tangle_primitives::multi_asset_delegation::MAX_OPERATOR_PROFILE_DESCRIPTION_LENGTH;
+
+	pub const OperatorProfileDepositBase: Balance = deposit(1, 0);
+	pub const OperatorProfileDepositPerByte: Balance = deposit(0, 1);
 }
 
 #[cfg(feature = "fast-runtime")]
@@ -1588,6 +1661,7 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type SlashRecipient = TreasuryAccount;
+	type NominationSlashHandler = Lst;
 	type MinOperatorBondAmount = MinOperatorBondAmount;
 
 	type CurrencyToVote = U128CurrencyToVote;
@@ -1603,11 +1677,16 @@ impl pallet_multi_asset_delegation::Config for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type PalletId = PID;
 	type RewardsManager = Rewards;
+	type DelegationHooks = Credits;
 	type MaxDelegatorBlueprints = MaxDelegatorBlueprints;
 	type MaxOperatorBlueprints = MaxOperatorBlueprints;
 	type MaxWithdrawRequests = MaxWithdrawRequests;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type MaxDelegations = MaxDelegations;
+	type MaxOperatorProfileFieldLength = MaxOperatorProfileFieldLength;
+	type MaxOperatorProfileDescriptionLength = MaxOperatorProfileDescriptionLength;
+	type OperatorProfileDepositBase = OperatorProfileDepositBase;
+	type OperatorProfileDepositPerByte = OperatorProfileDepositPerByte;
 	type EvmRunner = crate::tangle_services::PalletEvmRunner;
 	type EvmGasWeightMapping = crate::tangle_services::PalletEVMGasWeightMapping;
 	type EvmAddressMapping = crate::tangle_services::PalletEVMAddressMapping;
@@ -1621,7 +1700,31 @@ extern crate frame_benchmarking;
 #[cfg(feature = "runtime-benchmarks")]
 mod benches {
 	pub use pallet_tangle_lst_benchmarking::Pallet as LstBench;
-	impl pallet_tangle_lst_benchmarking::Config for crate::Runtime {}
+	impl pallet_tangle_lst_benchmarking::Config for crate::Runtime {
+		fn setup_restake_operator(operator: &crate::AccountId) {
+			use frame_support::traits::Currency;
+
+			let bond = crate::MinOperatorBondAmount::get();
+			crate::Balances::make_free_balance_be(operator, bond.saturating_mul(2));
+			let _ = crate::MultiAssetDelegation::handle_deposit_and_create_operator(
+				operator.clone(),
+				bond,
+			);
+		}
+
+		fn make_unrestakes_ready() {
+			use frame_support::traits::Get;
+
+			let delay = <crate::Runtime as pallet_multi_asset_delegation::Config>::DelegationBondLessDelay::get();
+			pallet_multi_asset_delegation::CurrentRound::<crate::Runtime>::mutate(|round| {
+				*round = round.saturating_add(delay)
+			});
+		}
+
+		fn setup_auto_nomination_candidates(validators: &[crate::AccountId]) {
+			pallet_session::Validators::<crate::Runtime>::put(validators.to_vec());
+		}
+	}
 
 	define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
@@ -1713,6 +1816,34 @@ impl_runtime_apis! {
 		) -> Result<Balance, sp_runtime::DispatchError> {
 			Rewards::calculate_rewards(&account_id, asset_id)
 		}
+
+		fn query_reward_projection(
+			asset_id: tangle_primitives::services::Asset<AssetId>,
+			amount: Balance,
+			lock_multiplier: Option<tangle_primitives::types::rewards::LockMultiplier>,
+			blocks: BlockNumberOf<Block>,
+		) -> Result<tangle_primitives::types::rewards::RewardProjection<Balance>, sp_runtime::DispatchError> {
+			Rewards::project_rewards(asset_id, amount, lock_multiplier, blocks)
+		}
+
+		fn query_vault_apy(
+			asset_id: tangle_primitives::services::Asset<AssetId>,
+		) -> Result<tangle_primitives::types::rewards::VaultApy<Balance>, sp_runtime::DispatchError> {
+			Rewards::vault_apy(asset_id)
+		}
+
+		fn query_user_reward_breakdown(
+			account_id: AccountId,
+		) -> Vec<tangle_primitives::types::rewards::VaultRewardBreakdown<AssetId, Balance, BlockNumberOf<Block>>> {
+			Rewards::reward_breakdown(&account_id)
+		}
+
+		fn query_blocks_until_decay(
+			account_id: AccountId,
+			asset_id: tangle_primitives::services::Asset<AssetId>,
+		) -> Result<Option<BlockNumberOf<Block>>, sp_runtime::DispatchError> {
+			Rewards::blocks_until_decay(&account_id, asset_id)
+		}
 	}
 
 	impl pallet_credits_rpc_runtime_api::CreditsApi<Block, AccountId, Balance, AssetId> for Runtime {
@@ -1728,6 +1859,44 @@ impl_runtime_apis! {
 		) -> Result<Balance, sp_runtime::DispatchError> {
 			Credits::get_accrued_amount_for_asset(&account_id, None, asset_id)
 		}
+
+		fn query_ledger_credits(
+			account_id: AccountId,
+		) -> Result<Balance, sp_runtime::DispatchError> {
+			Ok(Credits::ledger_balance(&pallet_credits::types::CreditHolder::Account(account_id)))
+		}
+	}
+
+	impl pallet_tangle_lst_rpc_runtime_api::TangleLstApi<Block, Balance> for Runtime {
+		fn instant_unbond_liquidity(pool_id: u32) -> Balance {
+			Lst::instant_unbond_liquidity(pool_id)
+		}
+	}
+
+	impl pallet_multi_asset_delegation_rpc_runtime_api::MultiAssetDelegationApi<
+		Block,
+		AccountId,
+		pallet_multi_asset_delegation::types::OperatorProfileOf<Runtime>,
+		pallet_multi_asset_delegation::types::PendingRequestOf<Runtime>,
+	> for Runtime {
+		fn operator_profile(
+			operator: AccountId,
+		) -> Option<pallet_multi_asset_delegation::types::OperatorProfileOf<Runtime>> {
+			MultiAssetDelegation::operator_profiles(&operator).map(|(profile, _)| profile)
+		}
+
+		fn operators_by_profile(
+			region: Option<Vec<u8>>,
+			hardware_class: Option<Vec<u8>>,
+		) -> Vec<(AccountId, pallet_multi_asset_delegation::types::OperatorProfileOf<Runtime>)> {
+			MultiAssetDelegation::operators_by_profile(region, hardware_class)
+		}
+
+		fn pending_requests(
+			who: AccountId,
+		) -> Vec<pallet_multi_asset_delegation::types::PendingRequestOf<Runtime>> {
+			MultiAssetDelegation::pending_requests(&who)
+		}
 	}
 
 	impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
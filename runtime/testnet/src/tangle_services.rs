@@ -178,6 +178,7 @@ impl pallet_services::Config for Runtime {
 	type Currency = Balances;
 	type Fungibles = Assets;
 	type RewardRecorder = Rewards;
+	type CreditsLedger = Credits;
 	type RewardsManager = Rewards;
 	type PalletEvmAccount = ServicesPalletEvmAccount;
 	type SlashManager = ();